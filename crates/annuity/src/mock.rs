@@ -87,6 +87,8 @@ impl reward::Config for Test {
     type StakeId = AccountId;
     type CurrencyId = CurrencyId;
     type MaxRewardCurrencies = ConstU32<10>;
+    type CheckpointInterval = ();
+    type MaxCheckpoints = ();
 }
 
 pub const fn unit(amount: Balance) -> Balance {
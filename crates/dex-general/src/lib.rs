@@ -44,7 +44,7 @@ pub use default_weights::WeightInfo;
 pub use primitives::{
     AssetBalance, BootstrapParameter, PairMetadata, PairStatus,
     PairStatus::{Bootstrap, Disable, Trading},
-    DEFAULT_FEE_RATE, FEE_ADJUSTMENT,
+    PriceCumulative, DEFAULT_FEE_RATE, FEE_ADJUSTMENT, PRICE_PRECISION,
 };
 pub use rpc::PairInfo;
 pub use traits::{ExportDexGeneral, GenerateLpAssetId, ValidateAsset};
@@ -173,6 +173,12 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn pair_price_cumulative)]
+    /// (T::AssetId, T::AssetId) -> PriceCumulative
+    pub type PairPriceCumulative<T: Config> =
+        StorageMap<_, Twox64Concat, (T::AssetId, T::AssetId), PriceCumulative<BlockNumberFor<T>>, ValueQuery>;
+
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     /// Refer: https://github.com/Uniswap/uniswap-v2-core/blob/master/contracts/UniswapV2Pair.sol#L88
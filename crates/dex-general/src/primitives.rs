@@ -10,6 +10,9 @@ pub type AssetBalance = u128;
 pub const DEFAULT_FEE_RATE: u128 = 30;
 pub const FEE_ADJUSTMENT: u128 = 10000;
 
+// precision of the accumulated prices
+pub const PRICE_PRECISION: u128 = ::traits::PRICE_CUMULATIVE_PRECISION;
+
 /// Status for TradingPair
 #[derive(Clone, Copy, Encode, Decode, RuntimeDebug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
 pub enum PairStatus<Balance, BlockNumber, Account> {
@@ -59,3 +62,15 @@ pub struct PairMetadata<Balance, Account> {
     pub total_supply: Balance,
     pub fee_rate: Balance,
 }
+
+/// Time-weighted price accumulators of a trading pair.
+/// Refer: https://github.com/Uniswap/uniswap-v2-core/blob/master/contracts/UniswapV2Pair.sol#L79
+#[derive(Encode, Decode, Clone, Copy, Default, RuntimeDebug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub struct PriceCumulative<BlockNumber> {
+    /// sum of the price of asset_0 in asset_1, scaled by `PRICE_PRECISION`, per block.
+    pub price_0_cumulative: U256,
+    /// sum of the price of asset_1 in asset_0, scaled by `PRICE_PRECISION`, per block.
+    pub price_1_cumulative: U256,
+    /// block of the last update.
+    pub block_number_last: BlockNumber,
+}
@@ -10,6 +10,7 @@
 
 use super::*;
 use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{traits::Saturating, SaturatedConversion};
 
 #[cfg(test)]
 mod mock;
@@ -55,6 +56,49 @@ impl<T: Config> Pallet<T> {
         T::LpGenerate::generate_lp_asset_id(asset_0, asset_1)
     }
 
+    /// Accumulates the prices of the pair at its reserves before they are first changed in
+    /// the current block, so that prices cannot be manipulated within a single block.
+    pub(crate) fn update_price_cumulative(asset_0: T::AssetId, asset_1: T::AssetId) {
+        let pair = Self::sort_asset_id(asset_0, asset_1);
+        PairPriceCumulative::<T>::mutate(pair, |cumulative| {
+            *cumulative = Self::accumulate_prices(pair, *cumulative);
+        });
+    }
+
+    /// Returns the price accumulators of the pair as of the current block.
+    pub fn current_price_cumulative(asset_0: T::AssetId, asset_1: T::AssetId) -> PriceCumulative<BlockNumberFor<T>> {
+        let pair = Self::sort_asset_id(asset_0, asset_1);
+        Self::accumulate_prices(pair, Self::pair_price_cumulative(pair))
+    }
+
+    fn accumulate_prices(
+        (asset_0, asset_1): (T::AssetId, T::AssetId),
+        mut cumulative: PriceCumulative<BlockNumberFor<T>>,
+    ) -> PriceCumulative<BlockNumberFor<T>> {
+        let now = frame_system::Pallet::<T>::block_number();
+        // the first update only starts the accumulation
+        if !cumulative.block_number_last.is_zero() {
+            let elapsed = U256::from(now.saturating_sub(cumulative.block_number_last).saturated_into::<u64>());
+            let pair_account = Self::pair_account_id(asset_0, asset_1);
+            let reserve_0 = T::MultiCurrency::free_balance(asset_0, &pair_account);
+            let reserve_1 = T::MultiCurrency::free_balance(asset_1, &pair_account);
+            if !reserve_0.is_zero() && !reserve_1.is_zero() {
+                let price_0 = U256::from(reserve_1).saturating_mul(U256::from(PRICE_PRECISION)) / U256::from(reserve_0);
+                let price_1 = U256::from(reserve_0).saturating_mul(U256::from(PRICE_PRECISION)) / U256::from(reserve_1);
+                cumulative.price_0_cumulative = cumulative
+                    .price_0_cumulative
+                    .overflowing_add(price_0.saturating_mul(elapsed))
+                    .0;
+                cumulative.price_1_cumulative = cumulative
+                    .price_1_cumulative
+                    .overflowing_add(price_1.saturating_mul(elapsed))
+                    .0;
+            }
+        }
+        cumulative.block_number_last = now;
+        cumulative
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn inner_add_liquidity(
         who: &T::AccountId,
@@ -65,6 +109,7 @@ impl<T: Config> Pallet<T> {
         amount_0_min: AssetBalance,
         amount_1_min: AssetBalance,
     ) -> DispatchResult {
+        Self::update_price_cumulative(asset_0, asset_1);
         let pair = Self::sort_asset_id(asset_0, asset_1);
         PairStatuses::<T>::try_mutate(pair, |status| {
             if let Trading(parameter) = status {
@@ -151,6 +196,7 @@ impl<T: Config> Pallet<T> {
         amount_1_min: AssetBalance,
         recipient: &T::AccountId,
     ) -> DispatchResult {
+        Self::update_price_cumulative(asset_0, asset_1);
         let pair = Self::sort_asset_id(asset_0, asset_1);
         PairStatuses::<T>::try_mutate(pair, |status| {
             if let Trading(parameter) = status {
@@ -231,6 +277,10 @@ impl<T: Config> Pallet<T> {
             Error::<T>::InsufficientTargetAmount
         );
 
+        for [asset_0, asset_1] in path.array_windows() {
+            Self::update_price_cumulative(*asset_0, *asset_1);
+        }
+
         let pair_account = Self::pair_account_id(path[0], path[1]);
 
         T::MultiCurrency::transfer(path[0], who, &pair_account, amount_in)?;
@@ -263,6 +313,10 @@ impl<T: Config> Pallet<T> {
 
         ensure!(amounts[0] <= amount_in_max, Error::<T>::ExcessiveSoldAmount);
 
+        for [asset_0, asset_1] in path.array_windows() {
+            Self::update_price_cumulative(*asset_0, *asset_1);
+        }
+
         let pair_account = Self::pair_account_id(path[0], path[1]);
 
         T::MultiCurrency::transfer(path[0], who, &pair_account, amounts[0])?;
@@ -986,6 +1040,34 @@ impl<T: Config> Pallet<T> {
     }
 }
 
+impl<T: Config> ::traits::PriceCumulativeApi<T::AssetId> for Pallet<T> {
+    fn price_cumulative(base: T::AssetId, quote: T::AssetId) -> Option<U256> {
+        let (asset_0, asset_1) = Self::sort_asset_id(base, quote);
+        if !matches!(Self::pair_status((asset_0, asset_1)), Trading(_)) {
+            return None;
+        }
+        let cumulative = Self::current_price_cumulative(asset_0, asset_1);
+        if base == asset_0 {
+            Some(cumulative.price_0_cumulative)
+        } else {
+            Some(cumulative.price_1_cumulative)
+        }
+    }
+}
+
+impl<T: Config> ::traits::PairReservesApi<T::AssetId> for Pallet<T> {
+    fn pair_reserves(asset_0: T::AssetId, asset_1: T::AssetId) -> Option<(AssetBalance, AssetBalance, AssetBalance)> {
+        match Self::pair_status(Self::sort_asset_id(asset_0, asset_1)) {
+            Trading(metadata) => Some((
+                T::MultiCurrency::free_balance(asset_0, &metadata.pair_account),
+                T::MultiCurrency::free_balance(asset_1, &metadata.pair_account),
+                metadata.total_supply,
+            )),
+            _ => None,
+        }
+    }
+}
+
 impl<T: Config> ExportDexGeneral<T::AccountId, T::AssetId> for Pallet<T> {
     fn get_amount_in_by_path(
         amount_out: AssetBalance,
@@ -8,6 +8,7 @@ use frame_system::RawOrigin;
 use orml_traits::MultiCurrency;
 use sp_core::U256;
 use sp_runtime::{traits::Zero, DispatchError::BadOrigin};
+use traits::{PairReservesApi, PriceCumulativeApi};
 
 const DOT_ASSET_ID: CurrencyId = CurrencyId::Token(2);
 const BTC_ASSET_ID: CurrencyId = CurrencyId::Token(3);
@@ -2553,3 +2554,112 @@ fn bootstrap_zero_reward_claim_should_work() {
         assert_eq!(<Test as Config>::MultiCurrency::free_balance(KSM_ASSET_ID, &ALICE), 0);
     })
 }
+
+#[test]
+fn price_cumulative_should_accumulate_reserves_at_block_start() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(<Test as Config>::MultiCurrency::deposit(
+            DOT_ASSET_ID,
+            &ALICE,
+            MAX_BALANCE
+        ));
+        assert_ok!(<Test as Config>::MultiCurrency::deposit(
+            BTC_ASSET_ID,
+            &ALICE,
+            MAX_BALANCE
+        ));
+        assert_ok!(DexPallet::create_pair(
+            RawOrigin::Root.into(),
+            DOT_ASSET_ID,
+            BTC_ASSET_ID,
+            DEFAULT_FEE_RATE,
+        ));
+        assert_eq!(
+            DexGeneral::price_cumulative(BTC_ASSET_ID, DOT_ASSET_ID),
+            Some(U256::zero())
+        );
+
+        assert_ok!(DexPallet::add_liquidity(
+            RawOrigin::Signed(ALICE).into(),
+            DOT_ASSET_ID,
+            BTC_ASSET_ID,
+            1 * DOT_UNIT,
+            1 * BTC_UNIT,
+            0,
+            0,
+            100
+        ));
+
+        System::set_block_number(11);
+        let price_btc = U256::from(DOT_UNIT / BTC_UNIT) * U256::from(crate::PRICE_PRECISION);
+        let price_dot = U256::from(BTC_UNIT) * U256::from(crate::PRICE_PRECISION) / U256::from(DOT_UNIT);
+        assert_eq!(
+            DexGeneral::price_cumulative(BTC_ASSET_ID, DOT_ASSET_ID),
+            Some(price_btc * 10)
+        );
+        assert_eq!(
+            DexGeneral::price_cumulative(DOT_ASSET_ID, BTC_ASSET_ID),
+            Some(price_dot * 10)
+        );
+
+        // trades within the block don't affect the accumulated prices
+        assert_ok!(DexGeneral::inner_swap_exact_assets_for_assets(
+            &ALICE,
+            DOT_UNIT / 2,
+            0,
+            &vec![DOT_ASSET_ID, BTC_ASSET_ID],
+            &ALICE,
+        ));
+        assert_eq!(
+            DexGeneral::price_cumulative(BTC_ASSET_ID, DOT_ASSET_ID),
+            Some(price_btc * 10)
+        );
+    })
+}
+
+#[test]
+fn pair_reserves_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(<Test as Config>::MultiCurrency::deposit(
+            DOT_ASSET_ID,
+            &ALICE,
+            MAX_BALANCE
+        ));
+        assert_ok!(<Test as Config>::MultiCurrency::deposit(
+            BTC_ASSET_ID,
+            &ALICE,
+            MAX_BALANCE
+        ));
+        assert_eq!(DexGeneral::pair_reserves(DOT_ASSET_ID, BTC_ASSET_ID), None);
+        assert_ok!(DexPallet::create_pair(
+            RawOrigin::Root.into(),
+            DOT_ASSET_ID,
+            BTC_ASSET_ID,
+            DEFAULT_FEE_RATE,
+        ));
+        assert_ok!(DexPallet::add_liquidity(
+            RawOrigin::Signed(ALICE).into(),
+            DOT_ASSET_ID,
+            BTC_ASSET_ID,
+            2 * DOT_UNIT,
+            1 * BTC_UNIT,
+            0,
+            0,
+            100
+        ));
+
+        let total_supply = match DexPallet::pair_status(DexPallet::sort_asset_id(DOT_ASSET_ID, BTC_ASSET_ID)) {
+            Trading(metadata) => metadata.total_supply,
+            _ => unreachable!(),
+        };
+        assert_eq!(
+            DexGeneral::pair_reserves(DOT_ASSET_ID, BTC_ASSET_ID),
+            Some((2 * DOT_UNIT, 1 * BTC_UNIT, total_supply))
+        );
+        assert_eq!(
+            DexGeneral::pair_reserves(BTC_ASSET_ID, DOT_ASSET_ID),
+            Some((1 * BTC_UNIT, 2 * DOT_UNIT, total_supply))
+        );
+    })
+}
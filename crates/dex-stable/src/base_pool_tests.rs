@@ -1240,6 +1240,39 @@ fn calculate_virtual_price_should_work() {
     })
 }
 
+#[test]
+fn stable_lp_underlying_should_work() {
+    new_test_ext().execute_with(|| {
+        use ::traits::StableLpPriceApi;
+
+        let (pool_id, lp_currency_id) = setup_test_base_pool();
+        assert_eq!(
+            StableAmm::stable_lp_underlying(lp_currency_id, Token(TOKEN1_SYMBOL), 1e18 as Balance),
+            Some(1e18 as Balance)
+        );
+
+        assert_ok!(StableAmm::swap(
+            RawOrigin::Signed(BOB).into(),
+            pool_id,
+            0,
+            1,
+            1e17 as Balance,
+            0,
+            BOB,
+            u64::MAX
+        ));
+        // fees accrue to the LP token
+        assert_eq!(
+            StableAmm::stable_lp_underlying(lp_currency_id, Token(TOKEN2_SYMBOL), 1e18 as Balance),
+            Some(1000050005862349911)
+        );
+        assert_eq!(
+            StableAmm::stable_lp_underlying(lp_currency_id, Token(TOKEN3_SYMBOL), 1e18 as Balance),
+            None
+        );
+    })
+}
+
 #[test]
 fn calculate_virtual_price_after_swap_should_work() {
     new_test_ext().execute_with(|| {
@@ -245,3 +245,57 @@ impl<T: Config> StableAmmApi<T::PoolId, T::CurrencyId, T::AccountId, Balance> fo
         Self::inner_swap_pool_to_base(who, pool_id, base_pool_id, in_index, out_index, dx, min_dy, to)
     }
 }
+
+impl<T: Config> ::traits::StableLpPriceApi<T::CurrencyId> for Pallet<T> {
+    fn stable_lp_underlying(
+        lp_currency_id: T::CurrencyId,
+        currency_id: T::CurrencyId,
+        amount: Balance,
+    ) -> Option<Balance> {
+        let pool = Self::pools(Self::lp_currencies(lp_currency_id)?)?;
+        let virtual_price = Self::get_pool_virtual_price(&pool)?;
+        let info = match pool {
+            Pool::Base(bp) => bp,
+            // the base pool LP token is not valued at the virtual price of the meta pool
+            Pool::Meta(mp) if mp.info.currency_ids.last() == Some(&currency_id) => return None,
+            Pool::Meta(mp) => mp.info,
+        };
+        let index = info.currency_ids.iter().position(|c| *c == currency_id)?;
+        let multiplier = info.token_multipliers.get(index)?;
+        U256::from(amount)
+            .checked_mul(U256::from(virtual_price))?
+            .checked_div(U256::from(BASE_VIRTUAL_PRICE_PRECISION))?
+            .checked_div(U256::from(*multiplier))?
+            .try_into()
+            .ok()
+    }
+}
+
+impl<T: Config> ::traits::StableLiquidityApi<T::AccountId, T::CurrencyId, Balance> for Pallet<T> {
+    fn add_liquidity_one_currency(
+        who: &T::AccountId,
+        lp_currency_id: T::CurrencyId,
+        currency_id: T::CurrencyId,
+        amount: Balance,
+        min_mint_amount: Balance,
+    ) -> Result<Balance, DispatchError> {
+        let pool_id = Self::lp_currencies(lp_currency_id).ok_or(Error::<T>::InvalidLpCurrency)?;
+        let pool = Self::pools(pool_id).ok_or(Error::<T>::InvalidPoolId)?;
+        let index = Self::get_currency_index(pool_id, currency_id).ok_or(Error::<T>::InvalidPooledCurrency)?;
+        let mut amounts = vec![Balance::default(); pool.get_currency_ids().len()];
+        amounts[index as usize] = amount;
+        Self::inner_add_liquidity(who, pool_id, &amounts, min_mint_amount, who)
+    }
+
+    fn remove_liquidity_one_currency(
+        who: &T::AccountId,
+        lp_currency_id: T::CurrencyId,
+        currency_id: T::CurrencyId,
+        lp_amount: Balance,
+        min_amount: Balance,
+    ) -> Result<Balance, DispatchError> {
+        let pool_id = Self::lp_currencies(lp_currency_id).ok_or(Error::<T>::InvalidLpCurrency)?;
+        let index = Self::get_currency_index(pool_id, currency_id).ok_or(Error::<T>::InvalidPooledCurrency)?;
+        Self::inner_remove_liquidity_one_currency(pool_id, who, lp_amount, index, min_amount, who)
+    }
+}
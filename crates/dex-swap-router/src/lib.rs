@@ -27,7 +27,7 @@ use frame_support::{
     transactional,
 };
 use sp_runtime::traits::{AtLeast32BitUnsigned, One, Zero};
-use sp_std::{fmt::Debug, prelude::*, vec::Vec};
+use sp_std::{fmt::Debug, prelude::*, vec, vec::Vec};
 
 use dex_general::{AssetBalance, ExportDexGeneral, WeightInfo as DexGeneralWeightInfo};
 use dex_stable::{traits::StableAmmApi, WeightInfo as DexStableWeightInfo};
@@ -164,36 +164,49 @@ pub mod pallet {
             let now = frame_system::Pallet::<T>::block_number();
             ensure!(deadline > now, Error::<T>::Deadline);
 
-            Self::validate_routes(&routes)?;
+            Self::inner_swap_exact_tokens_for_tokens(&who, amount_in, amount_out_min, routes, &to)?;
 
-            let mut amount_out = amount_in;
-            let mut receiver = who.clone();
+            Ok(())
+        }
+    }
+}
 
-            let num_routes = routes.len();
-            for (i, route) in routes.into_iter().enumerate() {
-                if i == num_routes - 1 {
-                    receiver = to.clone();
+impl<T: Config> Pallet<T> {
+    /// Execute a series of trades, returning the amount received by `to`.
+    pub fn inner_swap_exact_tokens_for_tokens(
+        who: &T::AccountId,
+        amount_in: T::Balance,
+        amount_out_min: T::Balance,
+        routes: Vec<Route<T::StablePoolId, T::CurrencyId>>,
+        to: &T::AccountId,
+    ) -> Result<T::Balance, DispatchError> {
+        Self::validate_routes(&routes)?;
+
+        let mut amount_out = amount_in;
+        let mut receiver = who.clone();
+
+        let num_routes = routes.len();
+        for (i, route) in routes.into_iter().enumerate() {
+            if i == num_routes - 1 {
+                receiver = to.clone();
+            }
+            match route {
+                Route::General(path) => {
+                    let amounts = T::GeneralAmm::get_amount_out_by_path(amount_out.into(), &path)?;
+                    Self::swap(who, amount_out, &path, &receiver)?;
+                    amount_out = T::Balance::from(*amounts.last().ok_or(Error::<T>::InvalidPath)?);
                 }
-                match route {
-                    Route::General(path) => {
-                        let amounts = T::GeneralAmm::get_amount_out_by_path(amount_out.into(), &path)?;
-                        Self::swap(&who, amount_out, &path, &receiver)?;
-                        amount_out = T::Balance::from(*amounts.last().ok_or(Error::<T>::InvalidPath)?);
-                    }
-                    Route::Stable(stable_path) => {
-                        (amount_out) = Self::stable_swap(&who, &stable_path, amount_out, &receiver)?;
-                    }
+                Route::Stable(stable_path) => {
+                    (amount_out) = Self::stable_swap(who, &stable_path, amount_out, &receiver)?;
                 }
             }
+        }
 
-            ensure!(amount_out >= amount_out_min, Error::<T>::AmountSlippage);
+        ensure!(amount_out >= amount_out_min, Error::<T>::AmountSlippage);
 
-            Ok(())
-        }
+        Ok(amount_out)
     }
-}
 
-impl<T: Config> Pallet<T> {
     fn stable_swap(
         who: &T::AccountId,
         path: &StablePath<T::StablePoolId, T::CurrencyId>,
@@ -258,3 +271,16 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 }
+
+impl<T: Config> traits::SwapApi<T::AccountId, T::CurrencyId, T::Balance> for Pallet<T> {
+    fn swap_exact_tokens_for_tokens(
+        who: &T::AccountId,
+        currency_in: T::CurrencyId,
+        amount_in: T::Balance,
+        currency_out: T::CurrencyId,
+        amount_out_min: T::Balance,
+    ) -> Result<T::Balance, DispatchError> {
+        let routes = vec![Route::General(vec![currency_in, currency_out])];
+        Self::inner_swap_exact_tokens_for_tokens(who, amount_in, amount_out_min, routes, who)
+    }
+}
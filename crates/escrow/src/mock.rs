@@ -89,6 +89,8 @@ impl reward::Config for Test {
     type StakeId = AccountId;
     type CurrencyId = CurrencyId;
     type MaxRewardCurrencies = ConstU32<1>;
+    type CheckpointInterval = ();
+    type MaxCheckpoints = ();
 }
 
 parameter_types! {
@@ -93,6 +93,8 @@ impl reward::Config for Test {
     type StakeId = AccountId;
     type CurrencyId = CurrencyId;
     type MaxRewardCurrencies = ConstU32<10>;
+    type CheckpointInterval = ();
+    type MaxCheckpoints = ();
 }
 
 parameter_types! {
@@ -117,5 +117,13 @@ pub mod benchmarks {
         set_commission(RawOrigin::Signed(nominator), arbitrary_pair, commission);
     }
 
+    #[benchmark]
+    fn set_insurance_fund_cut() {
+        let cut = UnsignedFixedPoint::<T>::one();
+
+        #[extrinsic_call]
+        set_insurance_fund_cut(RawOrigin::Root, cut);
+    }
+
     impl_benchmark_test_suite! { Fee, crate::mock::ExtBuilder::build(), crate::mock::Test }
 }
@@ -42,6 +42,7 @@ pub trait WeightInfo {
 	fn set_punishment_fee() -> Weight;
 	fn set_replace_griefing_collateral() -> Weight;
 	fn set_commission() -> Weight;
+	fn set_insurance_fund_cut() -> Weight;
 }
 
 /// Weights for fee using the Substrate node and recommended hardware.
@@ -107,6 +108,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(2_835_000 as u64, 0u64)
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+
+	// Storage: Fee InsuranceFundCut (r:0 w:1)
+	fn set_insurance_fund_cut() -> Weight {
+		Weight::from_parts(2_835_000 as u64, 0u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -171,5 +178,11 @@ impl WeightInfo for () {
 		Weight::from_parts(2_835_000 as u64, 0u64)
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+
+	// Storage: Fee InsuranceFundCut (r:0 w:1)
+	fn set_insurance_fund_cut() -> Weight {
+		Weight::from_parts(2_835_000 as u64, 0u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
 }
 
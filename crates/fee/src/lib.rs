@@ -175,6 +175,12 @@ pub mod pallet {
     pub(super) type Commission<T: Config> =
         StorageMap<_, Blake2_128Concat, DefaultVaultId<T>, UnsignedFixedPoint<T>, OptionQuery>;
 
+    /// The fraction of vault rewards in the wrapped currency that is paid into the insurance
+    /// fund, which compensates nominators for slashed collateral.
+    #[pallet::storage]
+    #[pallet::getter(fn insurance_fund_cut)]
+    pub type InsuranceFundCut<T: Config> = StorageValue<_, UnsignedFixedPoint<T>, ValueQuery>;
+
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
@@ -351,6 +357,25 @@ pub mod pallet {
             Commission::<T>::insert(vault_id, commission);
             Ok(().into())
         }
+
+        /// Changes the share of vault rewards paid into the insurance fund (only executable by the Root account)
+        ///
+        /// Only rewards in the wrapped currency are insured, since claims are paid in the wrapped
+        /// currency. Rewards in the native currency are distributed in full.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - signing account
+        /// * `cut` - the new share
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_insurance_fund_cut())]
+        #[transactional]
+        pub fn set_insurance_fund_cut(origin: OriginFor<T>, cut: UnsignedFixedPoint<T>) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(cut <= UnsignedFixedPoint::<T>::one(), Error::<T>::AboveMaxExpectedValue);
+            InsuranceFundCut::<T>::put(cut);
+            Ok(().into())
+        }
     }
 }
 
@@ -368,6 +393,12 @@ impl<T: Config> Pallet<T> {
         <T as Config>::FeePalletId::get().into_account_truncating()
     }
 
+    /// The account ID of the insurance fund, which is paid a share of the vault rewards in the
+    /// wrapped currency.
+    pub fn insurance_fund_account_id() -> T::AccountId {
+        <T as Config>::FeePalletId::get().into_sub_account_truncating(b"insurance")
+    }
+
     pub fn get_max_expected_value() -> UnsignedFixedPoint<T> {
         <T as Config>::MaxExpectedValue::get()
     }
@@ -489,7 +520,7 @@ impl<T: Config> Pallet<T> {
     // Private functions internal to this pallet
 
     /// Withdraw rewards from a pool and transfer to `account_id`.
-    fn withdraw_vault_rewards(
+    pub fn withdraw_vault_rewards(
         vault_id: &DefaultVaultId<T>,
         nominator_id: &T::AccountId,
         index: Option<T::Nonce>,
@@ -526,7 +557,18 @@ impl<T: Config> Pallet<T> {
         // push rewards based on vault's contribution to that capacity
         let reward = T::VaultRewards::withdraw_reward(&collateral_id, vault_id, currency_id)?;
 
-        let full_amount = Amount::<T>::new(reward, currency_id);
+        let reward_amount = Amount::<T>::new(reward, currency_id);
+
+        // set aside the share of the insurance fund before any commission is paid. Claims are
+        // paid in the wrapped currency, so only rewards in that currency are insured.
+        let insurance = if currency_id == vault_id.wrapped_currency() {
+            reward_amount.checked_mul(&Self::insurance_fund_cut())?
+        } else {
+            Amount::zero(currency_id)
+        };
+        insurance.transfer(&Self::fee_pool_account_id(), &Self::insurance_fund_account_id())?;
+
+        let full_amount = reward_amount.checked_sub(&insurance)?;
 
         let commission_rate = Self::get_commission_rate(vault_id);
         let commission = full_amount.checked_mul(&commission_rate)?;
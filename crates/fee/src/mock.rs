@@ -114,6 +114,8 @@ impl reward::Config<CapacityRewardsInstance> for Test {
     type StakeId = CurrencyId;
     type CurrencyId = CurrencyId;
     type MaxRewardCurrencies = ConstU32<10>;
+    type CheckpointInterval = ();
+    type MaxCheckpoints = ();
 }
 
 type VaultRewardsInstance = reward::Instance2;
@@ -125,6 +127,8 @@ impl reward::Config<VaultRewardsInstance> for Test {
     type StakeId = VaultId<AccountId, CurrencyId>;
     type CurrencyId = CurrencyId;
     type MaxRewardCurrencies = ConstU32<10>;
+    type CheckpointInterval = ();
+    type MaxCheckpoints = ();
 }
 
 impl staking::Config for Test {
@@ -1,4 +1,4 @@
-use crate::{mock::*, Commission, IssueFee};
+use crate::{mock::*, Commission, InsuranceFundCut, IssueFee};
 use currency::Amount;
 use frame_support::{assert_noop, assert_ok, dispatch::DispatchResultWithPostInfo};
 use primitives::VaultId;
@@ -67,6 +67,87 @@ fn should_set_replace_griefing_collateral() {
     test_setter(Fee::set_replace_griefing_collateral, Fee::replace_griefing_collateral);
 }
 
+#[test]
+fn should_set_insurance_fund_cut() {
+    test_setter(Fee::set_insurance_fund_cut, Fee::insurance_fund_cut);
+}
+
+/// Distributes 1000 units of `reward_currency` to a vault with a 25% insurance fund cut and a
+/// 10% commission
+fn distribute_vault_rewards_with_insurance(reward_currency: CurrencyId) -> VaultId<AccountId, CurrencyId> {
+    let vault_id = VaultId {
+        account_id: 1,
+        currencies: primitives::VaultCurrencyPair {
+            collateral: Token(KSM),
+            wrapped: Token(KBTC),
+        },
+    };
+
+    CapacityRewards::set_stake(&(), &Token(KSM), 1000u128).unwrap();
+    VaultRewards::set_stake(&Token(KSM), &vault_id, 1000u128).unwrap();
+    VaultStaking::set_stake(&(None, vault_id.clone()), &2, 1000u128).unwrap();
+
+    InsuranceFundCut::<Test>::put(FixedU128::from_inner(FixedU128::DIV / 4));
+    Commission::<Test>::set(&vault_id, Some(FixedU128::from_inner(FixedU128::DIV / 10)));
+
+    let distributed_reward = Amount::new(1_000, reward_currency);
+    Tokens::set_balance(
+        RuntimeOrigin::root(),
+        Fee::fee_pool_account_id(),
+        distributed_reward.currency(),
+        distributed_reward.amount(),
+        0,
+    )
+    .unwrap();
+    CapacityRewards::distribute_reward(
+        &(),
+        distributed_reward.currency(),
+        FixedI128::from(i128::try_from(distributed_reward.amount()).unwrap()),
+    )
+    .unwrap();
+
+    assert_ok!(Fee::distribute_vault_rewards(&vault_id, reward_currency));
+    vault_id
+}
+
+#[test]
+fn distribute_vault_rewards_pays_insurance_fund() {
+    run_test(|| {
+        let reward_currency = Token(KBTC);
+        let vault_id = distribute_vault_rewards_with_insurance(reward_currency);
+        assert_eq!(
+            currency::get_free_balance::<Test>(reward_currency, &Fee::insurance_fund_account_id()),
+            Amount::new(250, reward_currency)
+        );
+        assert_eq!(
+            currency::get_free_balance::<Test>(reward_currency, &vault_id.account_id),
+            Amount::new(75, reward_currency)
+        );
+        assert_eq!(
+            Fee::compute_vault_rewards(&vault_id, &2, reward_currency).unwrap(),
+            Amount::new(675, reward_currency)
+        );
+    })
+}
+
+#[test]
+fn distribute_vault_rewards_only_insures_wrapped_currency() {
+    run_test(|| {
+        // claims are paid in the wrapped currency, so native rewards are not insured
+        let reward_currency = Token(KINT);
+        let vault_id = distribute_vault_rewards_with_insurance(reward_currency);
+        assert!(currency::get_free_balance::<Test>(reward_currency, &Fee::insurance_fund_account_id()).is_zero());
+        assert_eq!(
+            currency::get_free_balance::<Test>(reward_currency, &vault_id.account_id),
+            Amount::new(100, reward_currency)
+        );
+        assert_eq!(
+            Fee::compute_vault_rewards(&vault_id, &2, reward_currency).unwrap(),
+            Amount::new(900, reward_currency)
+        );
+    })
+}
+
 #[test]
 fn compute_vault_rewards_works_with_commission() {
     run_test(|| {
@@ -149,3 +149,13 @@ pub(crate) mod fee {
         <fee::Pallet<T>>::distribute_rewards(amount)
     }
 }
+
+#[cfg_attr(test, mockable)]
+pub(crate) mod oracle {
+    use frame_support::dispatch::DispatchResult;
+    use oracle::CurrencyId;
+
+    pub fn ensure_price_feed_not_paused<T: crate::Config>(currency_id: CurrencyId) -> DispatchResult {
+        <oracle::Pallet<T>>::ensure_price_feed_not_paused(currency_id)
+    }
+}
@@ -317,6 +317,9 @@ impl<T: Config> Pallet<T> {
         // Check that the vault is currently not banned
         ext::vault_registry::ensure_not_banned::<T>(&vault_id)?;
 
+        // don't issue against a collateral whose price feed is paused
+        ext::oracle::ensure_price_feed_not_paused::<T>(vault_id.collateral_currency())?;
+
         // calculate griefing collateral based on the total amount of tokens to be issued
         let amount_collateral = amount_requested.convert_to(griefing_currency)?;
         let griefing_collateral = ext::fee::get_issue_griefing_collateral::<T>(&amount_collateral)?;
@@ -18,7 +18,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::Codec;
-use primitives::{CurrencyId, Liquidity, Rate, Ratio, Shortfall};
+use primitives::{AccountPosition, CurrencyId, Liquidity, Rate, Ratio, Shortfall};
 use sp_runtime::{DispatchError, FixedU128};
 
 sp_api::decl_runtime_apis! {
@@ -28,5 +28,6 @@ sp_api::decl_runtime_apis! {
         fn get_account_liquidity(account: AccountId) -> Result<(Liquidity, Shortfall), DispatchError>;
         fn get_market_status(asset_id: CurrencyId) -> Result<(Rate, Rate, Rate, Ratio, Balance, Balance, FixedU128), DispatchError>;
         fn get_liquidation_threshold_liquidity(account: AccountId) -> Result<(Liquidity, Shortfall), DispatchError>;
+        fn get_account_position(account: AccountId) -> Result<AccountPosition<Balance>, DispatchError>;
     }
 }
@@ -25,7 +25,7 @@ use jsonrpsee::{
     proc_macros::rpc,
     types::error::{CallError, ErrorCode, ErrorObject},
 };
-use primitives::{CurrencyId, Liquidity, Rate, Ratio, Shortfall};
+use primitives::{AccountPosition, CurrencyId, FixedTermPosition, Liquidity, MarketPosition, Rate, Ratio, Shortfall};
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
 use sp_rpc::number::NumberOrHex;
@@ -50,6 +50,12 @@ where
         account: AccountId,
         at: Option<BlockHash>,
     ) -> RpcResult<(Liquidity, Shortfall)>;
+    #[method(name = "loans_getAccountPosition")]
+    fn get_account_position(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<AccountPosition<NumberOrHex>>;
 }
 
 /// A struct that implements the [`LoansApi`].
@@ -72,6 +78,7 @@ pub enum Error {
     RuntimeError,
     AccountLiquidityError,
     MarketStatusError,
+    AccountPositionError,
 }
 
 impl From<Error> for i32 {
@@ -80,6 +87,7 @@ impl From<Error> for i32 {
             Error::RuntimeError => 1,
             Error::AccountLiquidityError => 2,
             Error::MarketStatusError => 3,
+            Error::AccountPositionError => 4,
         }
     }
 }
@@ -149,6 +157,50 @@ where
             .map_err(runtime_error_into_rpc_error)?
             .map_err(account_liquidity_error_into_rpc_error)
     }
+
+    fn get_account_position(
+        &self,
+        account: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<AccountPosition<NumberOrHex>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        );
+        let position = api
+            .get_account_position(at, account)
+            .map_err(runtime_error_into_rpc_error)?
+            .map_err(account_position_error_into_rpc_error)?;
+        Ok(AccountPosition {
+            markets: position
+                .markets
+                .into_iter()
+                .map(|market| {
+                    Ok(MarketPosition {
+                        currency_id: market.currency_id,
+                        supplied: try_into_rpc_balance(market.supplied)?,
+                        lend_tokens: try_into_rpc_balance(market.lend_tokens)?,
+                        is_collateral: market.is_collateral,
+                        borrowed: try_into_rpc_balance(market.borrowed)?,
+                    })
+                })
+                .collect::<RpcResult<_>>()?,
+            fixed_term_borrows: position
+                .fixed_term_borrows
+                .into_iter()
+                .map(|borrow| {
+                    Ok(FixedTermPosition {
+                        currency_id: borrow.currency_id,
+                        maturity: borrow.maturity,
+                        borrowed: try_into_rpc_balance(borrow.borrowed)?,
+                    })
+                })
+                .collect::<RpcResult<_>>()?,
+            health_factor: position.health_factor,
+            liquidation_price_drop: position.liquidation_price_drop,
+        })
+    }
 }
 
 /// Converts a runtime trap into an RPC error.
@@ -178,6 +230,15 @@ fn market_status_error_into_rpc_error(err: impl std::fmt::Debug) -> JsonRpseeErr
     )))
 }
 
+/// Converts an account position error into an RPC error.
+fn account_position_error_into_rpc_error(err: impl std::fmt::Debug) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+        Error::AccountPositionError.into(),
+        "Not able to get account position",
+        Some(format!("{:?}", err)),
+    )))
+}
+
 fn try_into_rpc_balance<T: std::fmt::Display + Copy + TryInto<NumberOrHex>>(value: T) -> RpcResult<NumberOrHex> {
     value.try_into().map_err(|_| {
         JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
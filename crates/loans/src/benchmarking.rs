@@ -29,6 +29,7 @@ const DOT: CurrencyId = Token(DOT_CURRENCY);
 const LEND_DOT: CurrencyId = LendToken(1);
 const INTR: CurrencyId = Token(INTR_CURRENCY);
 const KINT: CurrencyId = Token(KINT_CURRENCY);
+const MATURITY: Timestamp = 4_000_000_000;
 
 const RATE_MODEL_MOCK: InterestRateModel = InterestRateModel::Jump(JumpModel {
     base_rate: Rate::from_inner(Rate::DIV / 100 * 2),
@@ -58,6 +59,30 @@ fn market_mock<T: Config>() -> Market<BalanceOf<T>> {
     }
 }
 
+fn efficiency_group_mock() -> EfficiencyGroup {
+    EfficiencyGroup {
+        collateral_factor: Ratio::from_percent(90),
+        liquidation_threshold: Ratio::from_percent(93),
+    }
+}
+
+fn liquidation_rate_limit_mock<T: Config>() -> LiquidationRateLimit<BlockNumberFor<T>> {
+    LiquidationRateLimit {
+        max_liquidations_per_block: 10,
+        borrower_cooldown: 10u32.into(),
+    }
+}
+
+fn liquidation_scaling_mock() -> LiquidationScaling {
+    LiquidationScaling {
+        min_close_factor: Ratio::from_percent(20),
+        max_close_factor: Ratio::from_percent(100),
+        min_liquidate_incentive: Rate::from_inner(Rate::DIV / 100 * 102),
+        max_liquidate_incentive: Rate::from_inner(Rate::DIV / 100 * 115),
+        critical_health_factor: Ratio::from_percent(90),
+    }
+}
+
 fn pending_market_mock<T: Config>(lend_token_id: CurrencyId) -> Market<BalanceOf<T>> {
     let mut market = market_mock::<T>();
     market.state = MarketState::Pending;
@@ -138,12 +163,36 @@ fn set_account_borrows<T: Config>(who: T::AccountId, asset_id: CurrencyId, borro
     amount.burn_from(&who).unwrap();
 }
 
+fn fixed_term_bucket_mock<T: Config>() -> FixedTermBucket<BalanceOf<T>> {
+    FixedTermBucket {
+        rate: Rate::from_inner(Rate::DIV / 100 * 5),
+        early_repayment_penalty: Ratio::from_percent(50),
+        overdue_penalty_rate: Rate::from_inner(Rate::DIV / 100 * 20),
+        total_shares: Zero::zero(),
+        cash: Zero::zero(),
+        total_debt: Zero::zero(),
+    }
+}
+
+fn open_fixed_term_bucket<T: Config>(asset_id: CurrencyId, maturity: Timestamp) {
+    let bucket = fixed_term_bucket_mock::<T>();
+    assert_ok!(Loans::<T>::open_fixed_term_bucket(
+        SystemOrigin::Root.into(),
+        asset_id,
+        maturity,
+        bucket.rate,
+        bucket.early_repayment_penalty,
+        bucket.overdue_penalty_rate
+    ));
+}
+
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
     frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
 #[benchmarks(where
-            T: orml_tokens::Config<CurrencyId = CurrencyId, Balance = Balance>, T: security::Config, T: oracle::Config)
+            T: orml_tokens::Config<CurrencyId = CurrencyId, Balance = Balance>, T: security::Config, T: oracle::Config,
+            <T as Config>::RuntimeCall: From<frame_system::Call<T>>)
             ]
 pub mod benchmarks {
     use frame_benchmarking::v2::extrinsic_call;
@@ -803,6 +852,823 @@ pub mod benchmarks {
         );
     }
 
+    #[benchmark]
+    pub fn set_flash_loan_fee() {
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        #[extrinsic_call]
+        Loans::set_flash_loan_fee(SystemOrigin::Root, KBTC, Some(Ratio::from_parts(900)));
+        assert_eq!(Loans::<T>::flash_loan_fee(KBTC), Some(Ratio::from_parts(900)));
+    }
+
+    #[benchmark]
+    pub fn flash_loan() {
+        initialize::<T>();
+
+        let caller: T::AccountId = whitelisted_caller();
+        transfer_initial_balance::<T>(caller.clone());
+        let deposit_amount: u32 = 200_000_000;
+        let loan_amount: u32 = 100_000_000;
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), KBTC));
+        assert_ok!(Loans::<T>::set_flash_loan_fee(
+            SystemOrigin::Root.into(),
+            KBTC,
+            Some(Ratio::from_parts(900))
+        ));
+        assert_ok!(Loans::<T>::mint(
+            SystemOrigin::Signed(caller.clone()).into(),
+            KBTC,
+            deposit_amount.into()
+        ));
+        let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark { remark: vec![] }.into();
+        #[extrinsic_call]
+        Loans::flash_loan(
+            SystemOrigin::Signed(caller.clone()),
+            KBTC,
+            loan_amount.into(),
+            Box::new(call),
+        );
+        assert_last_event::<T>(
+            Event::<T>::FlashLoan {
+                account_id: caller,
+                currency_id: KBTC,
+                amount: loan_amount.into(),
+                fee: Ratio::from_parts(900).mul_ceil(loan_amount).into(),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn set_isolation_mode() {
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        let debt_ceiling: BalanceOf<T> = 1_000_000u32.into();
+        #[extrinsic_call]
+        Loans::set_isolation_mode(SystemOrigin::Root, KBTC, Some(debt_ceiling));
+        assert_eq!(Loans::<T>::isolation_debt_ceiling(KBTC), Some(debt_ceiling));
+    }
+
+    #[benchmark]
+    pub fn set_borrowable_in_isolation() {
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        #[extrinsic_call]
+        Loans::set_borrowable_in_isolation(SystemOrigin::Root, KBTC, true);
+        assert!(Loans::<T>::borrowable_in_isolation(KBTC));
+    }
+
+    #[benchmark]
+    pub fn set_efficiency_group() {
+        #[extrinsic_call]
+        Loans::set_efficiency_group(SystemOrigin::Root, 1, Some(efficiency_group_mock()));
+        assert_eq!(Loans::<T>::efficiency_group(1), Some(efficiency_group_mock()));
+    }
+
+    #[benchmark]
+    pub fn set_market_efficiency_group() {
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        assert_ok!(Loans::<T>::set_efficiency_group(
+            SystemOrigin::Root.into(),
+            1,
+            Some(efficiency_group_mock())
+        ));
+        #[extrinsic_call]
+        Loans::set_market_efficiency_group(SystemOrigin::Root, KBTC, Some(1));
+        assert_eq!(Loans::<T>::market_efficiency_group(KBTC), Some(1));
+    }
+
+    #[benchmark]
+    pub fn enter_efficiency_group() {
+        initialize::<T>();
+
+        let caller: T::AccountId = whitelisted_caller();
+        transfer_initial_balance::<T>(caller.clone());
+        let deposit_amount: u32 = 200_000_000;
+        let borrow_amount: u32 = 100_000_000;
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), KBTC));
+        assert_ok!(Loans::<T>::set_efficiency_group(
+            SystemOrigin::Root.into(),
+            1,
+            Some(efficiency_group_mock())
+        ));
+        assert_ok!(Loans::<T>::set_market_efficiency_group(
+            SystemOrigin::Root.into(),
+            KBTC,
+            Some(1)
+        ));
+        assert_ok!(Loans::<T>::mint(
+            SystemOrigin::Signed(caller.clone()).into(),
+            KBTC,
+            deposit_amount.into()
+        ));
+        assert_ok!(Loans::<T>::deposit_all_collateral(
+            SystemOrigin::Signed(caller.clone()).into(),
+            KBTC
+        ));
+        assert_ok!(Loans::<T>::borrow(
+            SystemOrigin::Signed(caller.clone()).into(),
+            KBTC,
+            borrow_amount.into()
+        ));
+        #[extrinsic_call]
+        Loans::enter_efficiency_group(SystemOrigin::Signed(caller.clone()), Some(1));
+        assert_eq!(Loans::<T>::account_efficiency_group(caller), Some(1));
+    }
+
+    #[benchmark]
+    pub fn set_protocol_liquidation_rate_limit() {
+        #[extrinsic_call]
+        Loans::set_protocol_liquidation_rate_limit(SystemOrigin::Root, Some(liquidation_rate_limit_mock::<T>()));
+        assert_eq!(
+            Loans::<T>::protocol_liquidation_rate_limit(),
+            Some(liquidation_rate_limit_mock::<T>())
+        );
+    }
+
+    #[benchmark]
+    pub fn liquidate_borrow_unsigned() {
+        initialize::<T>();
+
+        Security::<T>::set_active_block_number(1u32.into());
+        let alice: T::AccountId = account("Sample", 100, SEED);
+        let bob: T::AccountId = account("Sample", 101, SEED);
+        transfer_initial_balance::<T>(alice.clone());
+        transfer_initial_balance::<T>(bob.clone());
+        let deposit_amount: u32 = 200_000_000;
+        let borrowed_amount: u32 = 200_000_000;
+        let liquidate_amount: u32 = 100_000_000;
+        let redeemed_amount: u32 = 107_000_000;
+        assert_ok!(Oracle::<T>::_set_exchange_rate(DOT, UnsignedFixedPoint::one()));
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            DOT,
+            pending_market_mock::<T>(LEND_DOT)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), DOT));
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), KBTC));
+        assert_ok!(Loans::<T>::mint(
+            SystemOrigin::Signed(bob.clone()).into(),
+            KBTC,
+            deposit_amount.into()
+        ));
+        assert_ok!(Loans::<T>::add_reserves(
+            SystemOrigin::Root.into(),
+            T::Lookup::unlookup(bob),
+            KBTC,
+            liquidate_amount.into()
+        ));
+        assert_ok!(Loans::<T>::mint(
+            SystemOrigin::Signed(alice.clone()).into(),
+            DOT,
+            deposit_amount.into()
+        ));
+        assert_ok!(Loans::<T>::deposit_all_collateral(
+            SystemOrigin::Signed(alice.clone()).into(),
+            DOT
+        ));
+        set_account_borrows::<T>(alice.clone(), KBTC, borrowed_amount.into());
+        assert_ok!(Loans::<T>::set_protocol_liquidation_rate_limit(
+            SystemOrigin::Root.into(),
+            Some(liquidation_rate_limit_mock::<T>())
+        ));
+        #[extrinsic_call]
+        Loans::liquidate_borrow_unsigned(SystemOrigin::None, alice.clone(), KBTC, liquidate_amount.into(), DOT);
+        // the seized collateral, minus the reserved incentive, is redeemed into the reserves
+        assert_last_event::<T>(
+            Event::<T>::ReservesAdded {
+                payer: Loans::<T>::protocol_liquidator_account_id(),
+                currency_id: DOT,
+                amount: redeemed_amount.into(),
+                new_reserve_amount: redeemed_amount.into(),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn set_liquidation_scaling() {
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        #[extrinsic_call]
+        Loans::set_liquidation_scaling(SystemOrigin::Root, KBTC, Some(liquidation_scaling_mock()));
+        assert_eq!(Loans::<T>::liquidation_scaling(KBTC), Some(liquidation_scaling_mock()));
+    }
+
+    #[benchmark]
+    pub fn write_off_bad_debt() {
+        initialize::<T>();
+
+        let alice: T::AccountId = account("Sample", 100, SEED);
+        let bob: T::AccountId = account("Sample", 101, SEED);
+        let caller: T::AccountId = whitelisted_caller();
+        transfer_initial_balance::<T>(alice.clone());
+        transfer_initial_balance::<T>(bob.clone());
+        let borrowed_amount: u32 = 200_000_000;
+        let reserves_amount: u32 = 100_000_000;
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            DOT,
+            pending_market_mock::<T>(LEND_DOT)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), DOT));
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), KBTC));
+        assert_ok!(Loans::<T>::add_reserves(
+            SystemOrigin::Root.into(),
+            T::Lookup::unlookup(bob),
+            KBTC,
+            reserves_amount.into()
+        ));
+        set_account_borrows::<T>(alice.clone(), KBTC, borrowed_amount.into());
+        #[extrinsic_call]
+        Loans::write_off_bad_debt(SystemOrigin::Signed(caller), alice.clone(), KBTC);
+        assert_last_event::<T>(
+            Event::<T>::BadDebtWrittenOff {
+                borrower: alice,
+                underlying_currency_id: KBTC,
+                amount: borrowed_amount.into(),
+                reserves_used: reserves_amount.into(),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn approve_delegation() {
+        let caller: T::AccountId = whitelisted_caller();
+        let delegatee: T::AccountId = account("Sample", 100, SEED);
+        let allowance: BalanceOf<T> = 100_000_000u32.into();
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        #[extrinsic_call]
+        Loans::approve_delegation(SystemOrigin::Signed(caller.clone()), delegatee.clone(), KBTC, allowance);
+        assert_eq!(Loans::<T>::borrow_allowance(caller, (delegatee, KBTC)), allowance);
+    }
+
+    #[benchmark]
+    pub fn borrow_on_behalf() {
+        initialize::<T>();
+
+        let owner: T::AccountId = account("Sample", 100, SEED);
+        let caller: T::AccountId = whitelisted_caller();
+        transfer_initial_balance::<T>(owner.clone());
+        let deposit_amount: u32 = 200_000_000;
+        let borrowed_amount: u32 = 100_000_000;
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), KBTC));
+        assert_ok!(Loans::<T>::mint(
+            SystemOrigin::Signed(owner.clone()).into(),
+            KBTC,
+            deposit_amount.into()
+        ));
+        assert_ok!(Loans::<T>::deposit_all_collateral(
+            SystemOrigin::Signed(owner.clone()).into(),
+            KBTC
+        ));
+        assert_ok!(Loans::<T>::approve_delegation(
+            SystemOrigin::Signed(owner.clone()).into(),
+            caller.clone(),
+            KBTC,
+            borrowed_amount.into()
+        ));
+        #[extrinsic_call]
+        Loans::borrow_on_behalf(
+            SystemOrigin::Signed(caller.clone()),
+            owner.clone(),
+            KBTC,
+            borrowed_amount.into(),
+        );
+        assert_last_event::<T>(
+            Event::<T>::DelegatedBorrow {
+                owner,
+                delegatee: caller,
+                currency_id: KBTC,
+                amount: borrowed_amount.into(),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn repay_borrow_on_behalf() {
+        initialize::<T>();
+
+        let borrower: T::AccountId = account("Sample", 100, SEED);
+        let caller: T::AccountId = whitelisted_caller();
+        transfer_initial_balance::<T>(borrower.clone());
+        transfer_initial_balance::<T>(caller.clone());
+        let deposit_amount: u32 = 200_000_000;
+        let borrowed_amount: u32 = 100_000_000;
+        let repay_amount: u32 = 100;
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), KBTC));
+        assert_ok!(Loans::<T>::mint(
+            SystemOrigin::Signed(borrower.clone()).into(),
+            KBTC,
+            deposit_amount.into()
+        ));
+        assert_ok!(Loans::<T>::deposit_all_collateral(
+            SystemOrigin::Signed(borrower.clone()).into(),
+            KBTC
+        ));
+        assert_ok!(Loans::<T>::borrow(
+            SystemOrigin::Signed(borrower.clone()).into(),
+            KBTC,
+            borrowed_amount.into()
+        ));
+        #[extrinsic_call]
+        Loans::repay_borrow_on_behalf(
+            SystemOrigin::Signed(caller),
+            borrower.clone(),
+            KBTC,
+            repay_amount.into(),
+        );
+        assert_last_event::<T>(
+            Event::<T>::RepaidBorrow {
+                account_id: borrower,
+                currency_id: KBTC,
+                amount: repay_amount.into(),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn set_reserve_strategy() {
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KSM,
+            pending_market_mock::<T>(LEND_KSM)
+        ));
+        let cap: u32 = 1000;
+        #[extrinsic_call]
+        Loans::set_reserve_strategy(
+            SystemOrigin::Root,
+            KBTC,
+            ReserveStrategy::SupplyMarket(KSM),
+            Some(cap.into()),
+        );
+        assert_eq!(
+            Loans::<T>::reserve_strategy(KBTC, ReserveStrategy::SupplyMarket(KSM)).map(|info| info.cap),
+            Some(cap.into())
+        );
+    }
+
+    #[benchmark]
+    pub fn deploy_reserves() {
+        initialize::<T>();
+
+        let caller: T::AccountId = whitelisted_caller();
+        let payer = T::Lookup::unlookup(caller.clone());
+        transfer_initial_balance::<T>(caller);
+        let add_amount: u32 = 2000;
+        let deploy_amount: u32 = 1000;
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), KBTC));
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KSM,
+            pending_market_mock::<T>(LEND_KSM)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), KSM));
+        assert_ok!(Loans::<T>::add_reserves(
+            SystemOrigin::Root.into(),
+            payer,
+            KBTC,
+            add_amount.into()
+        ));
+        assert_ok!(Loans::<T>::set_reserve_strategy(
+            SystemOrigin::Root.into(),
+            KBTC,
+            ReserveStrategy::SupplyMarket(KSM),
+            Some(add_amount.into())
+        ));
+        #[extrinsic_call]
+        Loans::deploy_reserves(
+            SystemOrigin::Root,
+            KBTC,
+            ReserveStrategy::SupplyMarket(KSM),
+            deploy_amount.into(),
+            0u32.into(),
+        );
+        assert_eq!(
+            Loans::<T>::reserve_strategy(KBTC, ReserveStrategy::SupplyMarket(KSM)).map(|info| info.deployed),
+            Some(deploy_amount.into())
+        );
+    }
+
+    #[benchmark]
+    pub fn withdraw_reserves() {
+        initialize::<T>();
+
+        let caller: T::AccountId = whitelisted_caller();
+        let payer = T::Lookup::unlookup(caller.clone());
+        transfer_initial_balance::<T>(caller);
+        let amount: u32 = 2000;
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), KBTC));
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KSM,
+            pending_market_mock::<T>(LEND_KSM)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), KSM));
+        assert_ok!(Loans::<T>::add_reserves(
+            SystemOrigin::Root.into(),
+            payer,
+            KBTC,
+            amount.into()
+        ));
+        assert_ok!(Loans::<T>::set_reserve_strategy(
+            SystemOrigin::Root.into(),
+            KBTC,
+            ReserveStrategy::SupplyMarket(KSM),
+            Some(amount.into())
+        ));
+        assert_ok!(Loans::<T>::deploy_reserves(
+            SystemOrigin::Root.into(),
+            KBTC,
+            ReserveStrategy::SupplyMarket(KSM),
+            amount.into(),
+            0u32.into()
+        ));
+        let position = Loans::<T>::reserve_strategy(KBTC, ReserveStrategy::SupplyMarket(KSM))
+            .unwrap()
+            .position;
+        #[extrinsic_call]
+        Loans::withdraw_reserves(
+            SystemOrigin::Root,
+            KBTC,
+            ReserveStrategy::SupplyMarket(KSM),
+            position,
+            0u32.into(),
+        );
+        assert_eq!(
+            Loans::<T>::reserve_strategy(KBTC, ReserveStrategy::SupplyMarket(KSM)).map(|info| info.position),
+            Some(0u32.into())
+        );
+    }
+
+    #[benchmark]
+    pub fn open_fixed_term_bucket() {
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        let bucket = fixed_term_bucket_mock::<T>();
+        #[extrinsic_call]
+        Loans::open_fixed_term_bucket(
+            SystemOrigin::Root,
+            KBTC,
+            MATURITY,
+            bucket.rate,
+            bucket.early_repayment_penalty,
+            bucket.overdue_penalty_rate,
+        );
+        assert_eq!(Loans::<T>::fixed_term_bucket(KBTC, MATURITY), Some(bucket));
+    }
+
+    #[benchmark]
+    pub fn deposit_fixed_term() {
+        let caller: T::AccountId = whitelisted_caller();
+        transfer_initial_balance::<T>(caller.clone());
+        let amount: u32 = 200_000_000;
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        open_fixed_term_bucket::<T>(KBTC, MATURITY);
+        #[extrinsic_call]
+        Loans::deposit_fixed_term(SystemOrigin::Signed(caller.clone()), KBTC, MATURITY, amount.into());
+        assert_eq!(Loans::<T>::fixed_term_deposit((KBTC, MATURITY), caller), amount.into());
+    }
+
+    #[benchmark]
+    pub fn withdraw_fixed_term() {
+        let caller: T::AccountId = whitelisted_caller();
+        let amount: u32 = 200_000_000;
+        // a bucket that matured at genesis
+        let mut bucket = fixed_term_bucket_mock::<T>();
+        bucket.total_shares = amount.into();
+        bucket.cash = amount.into();
+        FixedTermBuckets::<T>::insert(KBTC, 0, bucket);
+        FixedTermDeposits::<T>::insert((KBTC, 0), &caller, BalanceOf::<T>::from(amount));
+        assert_ok!(Amount::<T>::new(amount.into(), KBTC).mint_to(&Loans::<T>::fixed_term_account_id()));
+        #[extrinsic_call]
+        Loans::withdraw_fixed_term(SystemOrigin::Signed(caller.clone()), KBTC, 0, amount.into());
+        assert_eq!(Loans::<T>::fixed_term_deposit((KBTC, 0), caller), 0u32.into());
+    }
+
+    #[benchmark]
+    pub fn borrow_fixed_term() {
+        initialize::<T>();
+
+        let lender: T::AccountId = account("Sample", 100, SEED);
+        let caller: T::AccountId = whitelisted_caller();
+        transfer_initial_balance::<T>(lender.clone());
+        transfer_initial_balance::<T>(caller.clone());
+        let deposit_amount: u32 = 200_000_000;
+        let borrowed_amount: u32 = 100_000_000;
+        assert_ok!(Oracle::<T>::_set_exchange_rate(DOT, UnsignedFixedPoint::one()));
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            DOT,
+            pending_market_mock::<T>(LEND_DOT)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), DOT));
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), KBTC));
+        assert_ok!(Loans::<T>::mint(
+            SystemOrigin::Signed(caller.clone()).into(),
+            DOT,
+            deposit_amount.into()
+        ));
+        assert_ok!(Loans::<T>::deposit_all_collateral(
+            SystemOrigin::Signed(caller.clone()).into(),
+            DOT
+        ));
+        open_fixed_term_bucket::<T>(KBTC, MATURITY);
+        assert_ok!(Loans::<T>::deposit_fixed_term(
+            SystemOrigin::Signed(lender).into(),
+            KBTC,
+            MATURITY,
+            deposit_amount.into()
+        ));
+        #[extrinsic_call]
+        Loans::borrow_fixed_term(
+            SystemOrigin::Signed(caller.clone()),
+            KBTC,
+            MATURITY,
+            borrowed_amount.into(),
+        );
+        assert_eq!(
+            Loans::<T>::fixed_term_borrow(caller, (KBTC, MATURITY)).map(|loan| loan.principal),
+            Some(borrowed_amount.into())
+        );
+    }
+
+    #[benchmark]
+    pub fn repay_fixed_term() {
+        let caller: T::AccountId = whitelisted_caller();
+        transfer_initial_balance::<T>(caller.clone());
+        let borrowed_amount: u32 = 100_000_000;
+        let repay_amount: u32 = 50_000_000;
+        let mut bucket = fixed_term_bucket_mock::<T>();
+        bucket.total_debt = borrowed_amount.into();
+        FixedTermBuckets::<T>::insert(KBTC, MATURITY, bucket);
+        FixedTermBorrows::<T>::insert(
+            &caller,
+            (KBTC, MATURITY),
+            FixedTermLoan {
+                principal: borrowed_amount.into(),
+                interest: 0u32.into(),
+            },
+        );
+        #[extrinsic_call]
+        Loans::repay_fixed_term(
+            SystemOrigin::Signed(caller.clone()),
+            KBTC,
+            MATURITY,
+            repay_amount.into(),
+        );
+        assert_eq!(
+            Loans::<T>::fixed_term_borrow(caller, (KBTC, MATURITY)).map(|loan| loan.principal),
+            Some((borrowed_amount - repay_amount).into())
+        );
+    }
+
+    #[benchmark]
+    pub fn liquidate_fixed_term_borrow() {
+        initialize::<T>();
+
+        let alice: T::AccountId = account("Sample", 100, SEED);
+        let bob: T::AccountId = account("Sample", 101, SEED);
+        transfer_initial_balance::<T>(alice.clone());
+        transfer_initial_balance::<T>(bob.clone());
+        let deposit_amount: u32 = 200_000_000;
+        let borrowed_amount: u32 = 200_000_000;
+        let liquidate_amount: u32 = 100_000_000;
+        let incentive_amount: u32 = 110_000_000;
+        assert_ok!(Oracle::<T>::_set_exchange_rate(DOT, UnsignedFixedPoint::one()));
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            DOT,
+            pending_market_mock::<T>(LEND_DOT)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), DOT));
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), KBTC));
+        assert_ok!(Loans::<T>::mint(
+            SystemOrigin::Signed(alice.clone()).into(),
+            DOT,
+            deposit_amount.into()
+        ));
+        assert_ok!(Loans::<T>::deposit_all_collateral(
+            SystemOrigin::Signed(alice.clone()).into(),
+            DOT
+        ));
+        let mut bucket = fixed_term_bucket_mock::<T>();
+        bucket.total_debt = borrowed_amount.into();
+        FixedTermBuckets::<T>::insert(KBTC, MATURITY, bucket);
+        FixedTermBorrows::<T>::insert(
+            &alice,
+            (KBTC, MATURITY),
+            FixedTermLoan {
+                principal: borrowed_amount.into(),
+                interest: 0u32.into(),
+            },
+        );
+        #[extrinsic_call]
+        Loans::liquidate_fixed_term_borrow(
+            SystemOrigin::Signed(bob.clone()),
+            alice.clone(),
+            KBTC,
+            MATURITY,
+            liquidate_amount.into(),
+            DOT,
+        );
+        assert_last_event::<T>(
+            Event::<T>::FixedTermBorrowLiquidated {
+                liquidator: bob,
+                borrower: alice,
+                currency_id: KBTC,
+                maturity: MATURITY,
+                collateral_currency_id: DOT,
+                repay_amount: liquidate_amount.into(),
+                collateral_underlying_amount: incentive_amount.into(),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn set_market_value_caps() {
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        let caps = ValueCaps {
+            supply_cap: Some(1_000_000_000u32.into()),
+            borrow_cap: Some(500_000_000u32.into()),
+        };
+        #[extrinsic_call]
+        Loans::set_market_value_caps(SystemOrigin::Root, KBTC, Some(caps));
+        assert_eq!(Loans::<T>::market_value_caps(KBTC), Some(caps));
+    }
+
+    #[benchmark]
+    pub fn set_outflow_limit() {
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        let limit = OutflowLimit {
+            max_net_outflow: 100_000_000u32.into(),
+            window: 100u32.into(),
+        };
+        #[extrinsic_call]
+        Loans::set_outflow_limit(SystemOrigin::Root, KBTC, Some(limit));
+        assert_eq!(Loans::<T>::outflow_limit(KBTC), Some(limit));
+    }
+
+    #[benchmark]
+    pub fn reset_outflow_window() {
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        OutflowWindows::<T>::insert(
+            KBTC,
+            OutflowWindow {
+                start: 1u32.into(),
+                net_outflow: 100_000_000u32.into(),
+            },
+        );
+        #[extrinsic_call]
+        Loans::reset_outflow_window(SystemOrigin::Root, KBTC);
+        assert!(Loans::<T>::outflow_window(KBTC).net_outflow.is_zero());
+    }
+
+    #[benchmark]
+    pub fn write_off_fixed_term_bad_debt() {
+        initialize::<T>();
+
+        let alice: T::AccountId = account("Sample", 100, SEED);
+        let bob: T::AccountId = account("Sample", 101, SEED);
+        let caller: T::AccountId = whitelisted_caller();
+        transfer_initial_balance::<T>(bob.clone());
+        let borrowed_amount: u32 = 200_000_000;
+        let reserves_amount: u32 = 100_000_000;
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), KBTC));
+        assert_ok!(Loans::<T>::add_reserves(
+            SystemOrigin::Root.into(),
+            T::Lookup::unlookup(bob),
+            KBTC,
+            reserves_amount.into()
+        ));
+        let mut bucket = fixed_term_bucket_mock::<T>();
+        bucket.total_debt = borrowed_amount.into();
+        FixedTermBuckets::<T>::insert(KBTC, MATURITY, bucket);
+        FixedTermBorrows::<T>::insert(
+            &alice,
+            (KBTC, MATURITY),
+            FixedTermLoan {
+                principal: borrowed_amount.into(),
+                interest: 0u32.into(),
+            },
+        );
+        #[extrinsic_call]
+        Loans::write_off_fixed_term_bad_debt(SystemOrigin::Signed(caller), alice.clone(), KBTC, MATURITY);
+        assert_last_event::<T>(
+            Event::<T>::FixedTermBadDebtWrittenOff {
+                borrower: alice,
+                currency_id: KBTC,
+                maturity: MATURITY,
+                amount: borrowed_amount.into(),
+                reserves_used: reserves_amount.into(),
+            }
+            .into(),
+        );
+    }
+
     impl_benchmark_test_suite!(Loans, crate::mock::new_test_ext_no_markets(), crate::mock::Test);
 }
 
@@ -58,6 +58,33 @@ pub trait WeightInfo {
 	fn reduce_incentive_reserves() -> Weight;
 	fn add_reserves() -> Weight;
 	fn reduce_reserves() -> Weight;
+	fn set_flash_loan_fee() -> Weight;
+	fn flash_loan() -> Weight;
+	fn set_isolation_mode() -> Weight;
+	fn set_borrowable_in_isolation() -> Weight;
+	fn set_efficiency_group() -> Weight;
+	fn set_market_efficiency_group() -> Weight;
+	fn enter_efficiency_group() -> Weight;
+	fn set_protocol_liquidation_rate_limit() -> Weight;
+	fn liquidate_borrow_unsigned() -> Weight;
+	fn set_liquidation_scaling() -> Weight;
+	fn write_off_bad_debt() -> Weight;
+	fn approve_delegation() -> Weight;
+	fn borrow_on_behalf() -> Weight;
+	fn repay_borrow_on_behalf() -> Weight;
+	fn set_reserve_strategy() -> Weight;
+	fn deploy_reserves() -> Weight;
+	fn withdraw_reserves() -> Weight;
+	fn open_fixed_term_bucket() -> Weight;
+	fn deposit_fixed_term() -> Weight;
+	fn withdraw_fixed_term() -> Weight;
+	fn borrow_fixed_term() -> Weight;
+	fn repay_fixed_term() -> Weight;
+	fn liquidate_fixed_term_borrow() -> Weight;
+	fn set_market_value_caps() -> Weight;
+	fn set_outflow_limit() -> Weight;
+	fn reset_outflow_window() -> Weight;
+	fn write_off_fixed_term_bad_debt() -> Weight;
 }
 
 /// Weights for loans using the Substrate node and recommended hardware.
@@ -652,6 +679,659 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FlashLoanFee (r:0 w:1)
+	/// Proof Skipped: Loans FlashLoanFee (max_values: None, max_size: None, mode: Measured)
+	fn set_flash_loan_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1469`
+		//  Estimated: `3944`
+		// Minimum execution time: 31_283_000 picoseconds.
+		Weight::from_parts(32_107_000, 3944)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans ActiveFlashLoan (r:1 w:1)
+	/// Proof Skipped: Loans ActiveFlashLoan (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FlashLoanFee (r:1 w:0)
+	/// Proof Skipped: Loans FlashLoanFee (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Loans TotalReserves (r:1 w:1)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	fn flash_loan() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2803`
+		//  Estimated: `30317`
+		// Minimum execution time: 142_638_000 picoseconds.
+		Weight::from_parts(144_921_000, 30317)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans IsolationDebtCeiling (r:0 w:1)
+	/// Proof Skipped: Loans IsolationDebtCeiling (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans IsolationDebt (r:0 w:1)
+	/// Proof Skipped: Loans IsolationDebt (max_values: None, max_size: None, mode: Measured)
+	fn set_isolation_mode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1469`
+		//  Estimated: `3944`
+		// Minimum execution time: 32_514_000 picoseconds.
+		Weight::from_parts(33_362_000, 3944)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowableInIsolation (r:0 w:1)
+	/// Proof Skipped: Loans BorrowableInIsolation (max_values: None, max_size: None, mode: Measured)
+	fn set_borrowable_in_isolation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1469`
+		//  Estimated: `3944`
+		// Minimum execution time: 30_951_000 picoseconds.
+		Weight::from_parts(31_807_000, 3944)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans EfficiencyGroups (r:0 w:1)
+	/// Proof Skipped: Loans EfficiencyGroups (max_values: None, max_size: None, mode: Measured)
+	fn set_efficiency_group() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 17_224_000 picoseconds.
+		Weight::from_parts(17_861_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans EfficiencyGroups (r:1 w:0)
+	/// Proof Skipped: Loans EfficiencyGroups (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MarketEfficiencyGroup (r:0 w:1)
+	/// Proof Skipped: Loans MarketEfficiencyGroup (max_values: None, max_size: None, mode: Measured)
+	fn set_market_efficiency_group() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1512`
+		//  Estimated: `7974`
+		// Minimum execution time: 36_402_000 picoseconds.
+		Weight::from_parts(37_218_000, 7974)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans EfficiencyGroups (r:2 w:0)
+	/// Proof Skipped: Loans EfficiencyGroups (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:1 w:0)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountEfficiencyGroup (r:1 w:1)
+	/// Proof Skipped: Loans AccountEfficiencyGroup (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MarketEfficiencyGroup (r:2 w:0)
+	/// Proof Skipped: Loans MarketEfficiencyGroup (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:1 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:1 w:0)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle Aggregate (r:2 w:0)
+	/// Proof Skipped: Oracle Aggregate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalSupply (r:1 w:0)
+	/// Proof Skipped: Loans TotalSupply (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalBorrows (r:1 w:0)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalReserves (r:1 w:0)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	fn enter_efficiency_group() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3417`
+		//  Estimated: `47211`
+		// Minimum execution time: 128_307_000 picoseconds.
+		Weight::from_parts(130_482_000, 47211)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans ProtocolLiquidationRateLimit (r:0 w:1)
+	/// Proof Skipped: Loans ProtocolLiquidationRateLimit (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_protocol_liquidation_rate_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 16_538_000 picoseconds.
+		Weight::from_parts(17_102_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans ProtocolLiquidationRateLimit (r:1 w:0)
+	/// Proof Skipped: Loans ProtocolLiquidationRateLimit (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans ProtocolLiquidationsInBlock (r:1 w:1)
+	/// Proof Skipped: Loans ProtocolLiquidationsInBlock (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans LastProtocolLiquidation (r:1 w:1)
+	/// Proof Skipped: Loans LastProtocolLiquidation (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:2 w:2)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans Markets (r:3 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:4 w:1)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
+	/// Proof Skipped: Loans UnderlyingAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:6 w:5)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:4 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Loans TotalBorrows (r:2 w:1)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalReserves (r:1 w:1)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MinExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MinExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans MaxExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MaxExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Security ParachainStatus (r:1 w:0)
+	/// Proof: Security ParachainStatus (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:1 w:0)
+	/// Proof Skipped: Oracle Aggregate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:3 w:1)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:1 w:0)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowState (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowSpeed (r:1 w:0)
+	/// Proof Skipped: Loans RewardBorrowSpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowerIndex (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowerIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardAccrued (r:3 w:3)
+	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplyState (r:1 w:1)
+	/// Proof Skipped: Loans RewardSupplyState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplySpeed (r:1 w:0)
+	/// Proof Skipped: Loans RewardSupplySpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplierIndex (r:3 w:3)
+	/// Proof Skipped: Loans RewardSupplierIndex (max_values: None, max_size: None, mode: Measured)
+	fn liquidate_borrow_unsigned() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5341`
+		//  Estimated: `213864`
+		// Minimum execution time: 798_412_000 picoseconds.
+		Weight::from_parts(803_627_000, 213864)
+			.saturating_add(T::DbWeight::get().reads(47_u64))
+			.saturating_add(T::DbWeight::get().writes(24_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans LiquidationScalings (r:0 w:1)
+	/// Proof Skipped: Loans LiquidationScalings (max_values: None, max_size: None, mode: Measured)
+	fn set_liquidation_scaling() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1612`
+		//  Estimated: `5077`
+		// Minimum execution time: 27_304_000 picoseconds.
+		Weight::from_parts(28_011_000, 5077)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:4 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:3 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:1 w:1)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:1 w:0)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowState (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowSpeed (r:1 w:0)
+	/// Proof Skipped: Loans RewardBorrowSpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowerIndex (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowerIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardAccrued (r:1 w:1)
+	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalBorrows (r:1 w:1)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalReserves (r:1 w:1)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	fn write_off_bad_debt() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2871`
+		//  Estimated: `46137`
+		// Minimum execution time: 112_804_000 picoseconds.
+		Weight::from_parts(114_921_000, 46137)
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowAllowances (r:0 w:1)
+	/// Proof Skipped: Loans BorrowAllowances (max_values: None, max_size: None, mode: Measured)
+	fn approve_delegation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1612`
+		//  Estimated: `5077`
+		// Minimum execution time: 29_118_000 picoseconds.
+		Weight::from_parts(29_904_000, 5077)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans BorrowAllowances (r:1 w:1)
+	/// Proof Skipped: Loans BorrowAllowances (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalBorrows (r:1 w:1)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Loans TotalReserves (r:1 w:0)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:1 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
+	/// Proof Skipped: Loans UnderlyingAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Loans MinExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MinExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans MaxExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MaxExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:1 w:1)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowState (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowSpeed (r:1 w:0)
+	/// Proof Skipped: Loans RewardBorrowSpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowerIndex (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowerIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardAccrued (r:1 w:1)
+	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:1 w:0)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	fn borrow_on_behalf() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3471`
+		//  Estimated: `94452`
+		// Minimum execution time: 271_340_000 picoseconds.
+		Weight::from_parts(274_015_000, 94452)
+			.saturating_add(T::DbWeight::get().reads(21_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:1 w:1)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:1 w:0)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowState (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowSpeed (r:1 w:0)
+	/// Proof Skipped: Loans RewardBorrowSpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowerIndex (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowerIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardAccrued (r:1 w:1)
+	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Loans TotalBorrows (r:1 w:1)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	fn repay_borrow_on_behalf() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3512`
+		//  Estimated: `61201`
+		// Minimum execution time: 183_407_000 picoseconds.
+		Weight::from_parts(185_663_000, 61201)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans ReserveStrategies (r:1 w:1)
+	/// Proof Skipped: Loans ReserveStrategies (max_values: None, max_size: None, mode: Measured)
+	fn set_reserve_strategy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1612`
+		//  Estimated: `8164`
+		// Minimum execution time: 31_052_000 picoseconds.
+		Weight::from_parts(31_844_000, 8164)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans ReserveStrategies (r:1 w:1)
+	/// Proof Skipped: Loans ReserveStrategies (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalReserves (r:1 w:1)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
+	/// Proof Skipped: Loans UnderlyingAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplyState (r:1 w:1)
+	/// Proof Skipped: Loans RewardSupplyState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplySpeed (r:1 w:0)
+	/// Proof Skipped: Loans RewardSupplySpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplierIndex (r:1 w:1)
+	/// Proof Skipped: Loans RewardSupplierIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardAccrued (r:1 w:1)
+	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Loans TotalBorrows (r:1 w:0)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MinExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MinExchangeRate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:1 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	fn deploy_reserves() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3718`
+		//  Estimated: `92264`
+		// Minimum execution time: 318_407_000 picoseconds.
+		Weight::from_parts(320_115_000, 92264)
+			.saturating_add(T::DbWeight::get().reads(21_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans ReserveStrategies (r:1 w:1)
+	/// Proof Skipped: Loans ReserveStrategies (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
+	/// Proof Skipped: Loans UnderlyingAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:1 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Loans TotalBorrows (r:1 w:0)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalReserves (r:1 w:1)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MinExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MinExchangeRate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MaxExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MaxExchangeRate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplyState (r:1 w:1)
+	/// Proof Skipped: Loans RewardSupplyState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplySpeed (r:1 w:0)
+	/// Proof Skipped: Loans RewardSupplySpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplierIndex (r:1 w:1)
+	/// Proof Skipped: Loans RewardSupplierIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardAccrued (r:1 w:1)
+	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
+	fn withdraw_reserves() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3794`
+		//  Estimated: `96181`
+		// Minimum execution time: 361_728_000 picoseconds.
+		Weight::from_parts(363_502_000, 96181)
+			.saturating_add(T::DbWeight::get().reads(22_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans FixedTermBuckets (r:1 w:1)
+	/// Proof Skipped: Loans FixedTermBuckets (max_values: None, max_size: None, mode: Measured)
+	fn open_fixed_term_bucket() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1612`
+		//  Estimated: `9653`
+		// Minimum execution time: 33_417_000 picoseconds.
+		Weight::from_parts(34_102_000, 9653)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans FixedTermBuckets (r:1 w:1)
+	/// Proof Skipped: Loans FixedTermBuckets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Loans FixedTermDeposits (r:1 w:1)
+	/// Proof Skipped: Loans FixedTermDeposits (max_values: None, max_size: None, mode: Measured)
+	fn deposit_fixed_term() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2137`
+		//  Estimated: `17794`
+		// Minimum execution time: 71_566_000 picoseconds.
+		Weight::from_parts(72_480_000, 17794)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Loans FixedTermBuckets (r:1 w:1)
+	/// Proof Skipped: Loans FixedTermBuckets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans FixedTermDeposits (r:1 w:1)
+	/// Proof Skipped: Loans FixedTermDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw_fixed_term() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2489`
+		//  Estimated: `18146`
+		// Minimum execution time: 74_903_000 picoseconds.
+		Weight::from_parts(75_871_000, 18146)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Loans Markets (r:6 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FixedTermBuckets (r:2 w:1)
+	/// Proof Skipped: Loans FixedTermBuckets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans AccountEfficiencyGroup (r:1 w:0)
+	/// Proof Skipped: Loans AccountEfficiencyGroup (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans IsolationDebtCeiling (r:5 w:0)
+	/// Proof Skipped: Loans IsolationDebtCeiling (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:10 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
+	/// Proof Skipped: Loans UnderlyingAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalBorrows (r:1 w:0)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalReserves (r:1 w:0)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MinExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MinExchangeRate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MaxExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MaxExchangeRate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:5 w:0)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FixedTermBorrows (r:2 w:1)
+	/// Proof Skipped: Loans FixedTermBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle Aggregate (r:2 w:0)
+	/// Proof Skipped: Oracle Aggregate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn borrow_fixed_term() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4187`
+		//  Estimated: `142387`
+		// Minimum execution time: 301_558_000 picoseconds.
+		Weight::from_parts(303_266_000, 142387)
+			.saturating_add(T::DbWeight::get().reads(42_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Loans FixedTermBuckets (r:1 w:1)
+	/// Proof Skipped: Loans FixedTermBuckets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FixedTermBorrows (r:1 w:1)
+	/// Proof Skipped: Loans FixedTermBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn repay_fixed_term() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2671`
+		//  Estimated: `18328`
+		// Minimum execution time: 72_184_000 picoseconds.
+		Weight::from_parts(73_005_000, 18328)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Loans Markets (r:7 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans LiquidationScalings (r:1 w:0)
+	/// Proof Skipped: Loans LiquidationScalings (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FixedTermBuckets (r:2 w:1)
+	/// Proof Skipped: Loans FixedTermBuckets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FixedTermBorrows (r:2 w:1)
+	/// Proof Skipped: Loans FixedTermBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:6 w:1)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:5 w:0)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
+	/// Proof Skipped: Loans UnderlyingAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalBorrows (r:1 w:0)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalReserves (r:1 w:0)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MinExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MinExchangeRate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MaxExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MaxExchangeRate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle Aggregate (r:2 w:0)
+	/// Proof Skipped: Oracle Aggregate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:5 w:5)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Loans RewardSupplyState (r:1 w:1)
+	/// Proof Skipped: Loans RewardSupplyState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplySpeed (r:1 w:0)
+	/// Proof Skipped: Loans RewardSupplySpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplierIndex (r:3 w:3)
+	/// Proof Skipped: Loans RewardSupplierIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardAccrued (r:3 w:3)
+	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
+	fn liquidate_fixed_term_borrow() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5316`
+		//  Estimated: `174955`
+		// Minimum execution time: 412_907_000 picoseconds.
+		Weight::from_parts(415_362_000, 174955)
+			.saturating_add(T::DbWeight::get().reads(46_u64))
+			.saturating_add(T::DbWeight::get().writes(17_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MarketValueCaps (r:0 w:1)
+	/// Proof Skipped: Loans MarketValueCaps (max_values: None, max_size: None, mode: Measured)
+	fn set_market_value_caps() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1469`
+		//  Estimated: `3944`
+		// Minimum execution time: 30_917_000 picoseconds.
+		Weight::from_parts(31_688_000, 3944)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans OutflowLimits (r:0 w:1)
+	/// Proof Skipped: Loans OutflowLimits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans OutflowWindows (r:0 w:1)
+	/// Proof Skipped: Loans OutflowWindows (max_values: None, max_size: None, mode: Measured)
+	fn set_outflow_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1469`
+		//  Estimated: `3944`
+		// Minimum execution time: 33_406_000 picoseconds.
+		Weight::from_parts(34_211_000, 3944)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans OutflowWindows (r:0 w:1)
+	/// Proof Skipped: Loans OutflowWindows (max_values: None, max_size: None, mode: Measured)
+	fn reset_outflow_window() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1469`
+		//  Estimated: `3944`
+		// Minimum execution time: 29_874_000 picoseconds.
+		Weight::from_parts(30_602_000, 3944)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FixedTermBuckets (r:1 w:1)
+	/// Proof Skipped: Loans FixedTermBuckets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FixedTermBorrows (r:1 w:1)
+	/// Proof Skipped: Loans FixedTermBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FixedTermDeposits (r:1 w:0)
+	/// Proof Skipped: Loans FixedTermDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalReserves (r:1 w:1)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans ActiveFlashLoan (r:1 w:0)
+	/// Proof Skipped: Loans ActiveFlashLoan (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:4 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	fn write_off_fixed_term_bad_debt() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3412`
+		//  Estimated: `48207`
+		// Minimum execution time: 98_516_000 picoseconds.
+		Weight::from_parts(100_233_000, 48207)
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -880,50 +1560,301 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Loans TotalReserves (r:1 w:0)
 	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Loans AccountDeposits (r:1 w:0)
-	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
-	/// Proof Skipped: Loans UnderlyingAssetId (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Tokens TotalIssuance (r:1 w:0)
-	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Loans AccountDeposits (r:1 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
+	/// Proof Skipped: Loans UnderlyingAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Loans MinExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MinExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans MaxExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MaxExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:1 w:1)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowState (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowSpeed (r:1 w:0)
+	/// Proof Skipped: Loans RewardBorrowSpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowerIndex (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowerIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardAccrued (r:1 w:1)
+	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:1 w:0)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	fn borrow() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3312`
+		//  Estimated: `90329`
+		// Minimum execution time: 262_856_000 picoseconds.
+		Weight::from_parts(265_230_000, 90329)
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
+	/// Proof Skipped: Loans UnderlyingAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Loans TotalBorrows (r:1 w:0)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalReserves (r:1 w:0)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MinExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MinExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans MaxExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MaxExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:1 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplyState (r:1 w:1)
+	/// Proof Skipped: Loans RewardSupplyState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplySpeed (r:1 w:0)
+	/// Proof Skipped: Loans RewardSupplySpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplierIndex (r:1 w:1)
+	/// Proof Skipped: Loans RewardSupplierIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardAccrued (r:1 w:1)
+	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
+	fn redeem() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3368`
+		//  Estimated: `82017`
+		// Minimum execution time: 324_119_000 picoseconds.
+		Weight::from_parts(324_981_000, 82017)
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
+	/// Proof Skipped: Loans UnderlyingAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Loans TotalBorrows (r:1 w:0)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalReserves (r:1 w:0)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MinExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MinExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans MaxExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MaxExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:1 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplyState (r:1 w:1)
+	/// Proof Skipped: Loans RewardSupplyState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplySpeed (r:1 w:0)
+	/// Proof Skipped: Loans RewardSupplySpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplierIndex (r:1 w:1)
+	/// Proof Skipped: Loans RewardSupplierIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardAccrued (r:1 w:1)
+	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
+	fn redeem_all() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3368`
+		//  Estimated: `82017`
+		// Minimum execution time: 325_041_000 picoseconds.
+		Weight::from_parts(327_495_000, 82017)
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:1 w:1)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:1 w:0)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowState (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowSpeed (r:1 w:0)
+	/// Proof Skipped: Loans RewardBorrowSpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowerIndex (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowerIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardAccrued (r:1 w:1)
+	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Loans TotalBorrows (r:1 w:1)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	fn repay_borrow() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3407`
+		//  Estimated: `61096`
+		// Minimum execution time: 179_189_000 picoseconds.
+		Weight::from_parts(180_952_000, 61096)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:1 w:1)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:1 w:0)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowState (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowSpeed (r:1 w:0)
+	/// Proof Skipped: Loans RewardBorrowSpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowerIndex (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowerIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardAccrued (r:1 w:1)
+	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Loans TotalBorrows (r:1 w:1)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	fn repay_borrow_all() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3407`
+		//  Estimated: `61096`
+		// Minimum execution time: 197_625_000 picoseconds.
+		Weight::from_parts(198_568_000, 61096)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
+	/// Proof Skipped: Loans UnderlyingAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:1 w:1)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	fn deposit_all_collateral() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2295`
+		//  Estimated: `19375`
+		// Minimum execution time: 102_335_000 picoseconds.
+		Weight::from_parts(103_146_000, 19375)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:1 w:1)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
+	/// Proof Skipped: Loans UnderlyingAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Loans TotalBorrows (r:1 w:0)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalReserves (r:1 w:0)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MinExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MinExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans MaxExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MaxExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:1 w:0)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	fn withdraw_all_collateral() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3242`
+		//  Estimated: `60764`
+		// Minimum execution time: 225_110_000 picoseconds.
+		Weight::from_parts(226_403_000, 60764)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:2 w:2)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans Markets (r:3 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:4 w:1)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
+	/// Proof Skipped: Loans UnderlyingAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:6 w:5)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:4 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Loans TotalBorrows (r:2 w:1)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalReserves (r:1 w:0)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Loans MinExchangeRate (r:1 w:0)
 	/// Proof Skipped: Loans MinExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Loans MaxExchangeRate (r:1 w:0)
 	/// Proof Skipped: Loans MaxExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: Loans AccountBorrows (r:1 w:1)
+	/// Storage: Security ParachainStatus (r:1 w:0)
+	/// Proof Skipped: Security ParachainStatus (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Oracle Aggregate (r:1 w:0)
+	/// Proof Skipped: Oracle Aggregate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:3 w:1)
 	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:1 w:0)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Loans RewardBorrowState (r:1 w:1)
 	/// Proof Skipped: Loans RewardBorrowState (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Loans RewardBorrowSpeed (r:1 w:0)
 	/// Proof Skipped: Loans RewardBorrowSpeed (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Loans RewardBorrowerIndex (r:1 w:1)
 	/// Proof Skipped: Loans RewardBorrowerIndex (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Loans RewardAccrued (r:1 w:1)
+	/// Storage: Loans RewardAccrued (r:3 w:3)
 	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Loans BorrowIndex (r:1 w:0)
-	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
-	fn borrow() -> Weight {
+	/// Storage: Loans RewardSupplyState (r:1 w:1)
+	/// Proof Skipped: Loans RewardSupplyState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplySpeed (r:1 w:0)
+	/// Proof Skipped: Loans RewardSupplySpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplierIndex (r:3 w:3)
+	/// Proof Skipped: Loans RewardSupplierIndex (max_values: None, max_size: None, mode: Measured)
+	fn liquidate_borrow() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `3312`
-		//  Estimated: `90329`
-		// Minimum execution time: 262_856_000 picoseconds.
-		Weight::from_parts(265_230_000, 90329)
-			.saturating_add(RocksDbWeight::get().reads(20_u64))
-			.saturating_add(RocksDbWeight::get().writes(8_u64))
+		//  Measured:  `5226`
+		//  Estimated: `201519`
+		// Minimum execution time: 762_145_000 picoseconds.
+		Weight::from_parts(766_944_000, 201519)
+			.saturating_add(RocksDbWeight::get().reads(44_u64))
+			.saturating_add(RocksDbWeight::get().writes(21_u64))
 	}
 	/// Storage: Loans Markets (r:2 w:0)
 	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
-	/// Proof Skipped: Loans UnderlyingAssetId (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
 	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
+	/// Proof Skipped: Loans UnderlyingAssetId (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Tokens TotalIssuance (r:1 w:1)
 	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
-	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Storage: Tokens Accounts (r:4 w:4)
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:0)
+	/// Storage: System Account (r:2 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Loans TotalBorrows (r:1 w:0)
 	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
@@ -943,62 +1874,271 @@ impl WeightInfo for () {
 	/// Proof Skipped: Loans RewardSupplierIndex (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Loans RewardAccrued (r:1 w:1)
 	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
-	fn redeem() -> Weight {
+	fn reduce_incentive_reserves() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `3368`
-		//  Estimated: `82017`
-		// Minimum execution time: 324_119_000 picoseconds.
-		Weight::from_parts(324_981_000, 82017)
-			.saturating_add(RocksDbWeight::get().reads(19_u64))
-			.saturating_add(RocksDbWeight::get().writes(8_u64))
+		//  Measured:  `4362`
+		//  Estimated: `99138`
+		// Minimum execution time: 413_086_000 picoseconds.
+		Weight::from_parts(417_575_000, 99138)
+			.saturating_add(RocksDbWeight::get().reads(21_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 	/// Storage: Loans Markets (r:2 w:0)
 	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
-	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
-	/// Proof Skipped: Loans UnderlyingAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Loans TotalReserves (r:1 w:1)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	fn add_reserves() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2768`
+		//  Estimated: `26490`
+		// Minimum execution time: 131_052_000 picoseconds.
+		Weight::from_parts(132_405_000, 26490)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
 	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Tokens TotalIssuance (r:1 w:1)
-	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Loans TotalReserves (r:1 w:1)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:0)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn reduce_reserves() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2857`
+		//  Estimated: `26757`
+		// Minimum execution time: 115_421_000 picoseconds.
+		Weight::from_parts(116_053_000, 26757)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FlashLoanFee (r:0 w:1)
+	/// Proof Skipped: Loans FlashLoanFee (max_values: None, max_size: None, mode: Measured)
+	fn set_flash_loan_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1469`
+		//  Estimated: `3944`
+		// Minimum execution time: 31_283_000 picoseconds.
+		Weight::from_parts(32_107_000, 3944)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans ActiveFlashLoan (r:1 w:1)
+	/// Proof Skipped: Loans ActiveFlashLoan (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FlashLoanFee (r:1 w:0)
+	/// Proof Skipped: Loans FlashLoanFee (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Loans TotalReserves (r:1 w:1)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	fn flash_loan() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2803`
+		//  Estimated: `30317`
+		// Minimum execution time: 142_638_000 picoseconds.
+		Weight::from_parts(144_921_000, 30317)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans IsolationDebtCeiling (r:0 w:1)
+	/// Proof Skipped: Loans IsolationDebtCeiling (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans IsolationDebt (r:0 w:1)
+	/// Proof Skipped: Loans IsolationDebt (max_values: None, max_size: None, mode: Measured)
+	fn set_isolation_mode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1469`
+		//  Estimated: `3944`
+		// Minimum execution time: 32_514_000 picoseconds.
+		Weight::from_parts(33_362_000, 3944)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowableInIsolation (r:0 w:1)
+	/// Proof Skipped: Loans BorrowableInIsolation (max_values: None, max_size: None, mode: Measured)
+	fn set_borrowable_in_isolation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1469`
+		//  Estimated: `3944`
+		// Minimum execution time: 30_951_000 picoseconds.
+		Weight::from_parts(31_807_000, 3944)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans EfficiencyGroups (r:0 w:1)
+	/// Proof Skipped: Loans EfficiencyGroups (max_values: None, max_size: None, mode: Measured)
+	fn set_efficiency_group() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 17_224_000 picoseconds.
+		Weight::from_parts(17_861_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans EfficiencyGroups (r:1 w:0)
+	/// Proof Skipped: Loans EfficiencyGroups (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MarketEfficiencyGroup (r:0 w:1)
+	/// Proof Skipped: Loans MarketEfficiencyGroup (max_values: None, max_size: None, mode: Measured)
+	fn set_market_efficiency_group() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1512`
+		//  Estimated: `7974`
+		// Minimum execution time: 36_402_000 picoseconds.
+		Weight::from_parts(37_218_000, 7974)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans EfficiencyGroups (r:2 w:0)
+	/// Proof Skipped: Loans EfficiencyGroups (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:1 w:0)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountEfficiencyGroup (r:1 w:1)
+	/// Proof Skipped: Loans AccountEfficiencyGroup (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MarketEfficiencyGroup (r:2 w:0)
+	/// Proof Skipped: Loans MarketEfficiencyGroup (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:1 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:1 w:0)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle Aggregate (r:2 w:0)
+	/// Proof Skipped: Oracle Aggregate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalSupply (r:1 w:0)
+	/// Proof Skipped: Loans TotalSupply (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Loans TotalBorrows (r:1 w:0)
 	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Loans TotalReserves (r:1 w:0)
 	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	fn enter_efficiency_group() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3417`
+		//  Estimated: `47211`
+		// Minimum execution time: 128_307_000 picoseconds.
+		Weight::from_parts(130_482_000, 47211)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans ProtocolLiquidationRateLimit (r:0 w:1)
+	/// Proof Skipped: Loans ProtocolLiquidationRateLimit (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_protocol_liquidation_rate_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 16_538_000 picoseconds.
+		Weight::from_parts(17_102_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans ProtocolLiquidationRateLimit (r:1 w:0)
+	/// Proof Skipped: Loans ProtocolLiquidationRateLimit (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans ProtocolLiquidationsInBlock (r:1 w:1)
+	/// Proof Skipped: Loans ProtocolLiquidationsInBlock (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans LastProtocolLiquidation (r:1 w:1)
+	/// Proof Skipped: Loans LastProtocolLiquidation (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:2 w:2)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans Markets (r:3 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:4 w:1)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
+	/// Proof Skipped: Loans UnderlyingAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:6 w:5)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:4 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Loans TotalBorrows (r:2 w:1)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalReserves (r:1 w:1)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Loans MinExchangeRate (r:1 w:0)
 	/// Proof Skipped: Loans MinExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Loans MaxExchangeRate (r:1 w:0)
 	/// Proof Skipped: Loans MaxExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: Loans AccountDeposits (r:1 w:0)
-	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Security ParachainStatus (r:1 w:0)
+	/// Proof: Security ParachainStatus (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:1 w:0)
+	/// Proof Skipped: Oracle Aggregate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:3 w:1)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:1 w:0)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowState (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowSpeed (r:1 w:0)
+	/// Proof Skipped: Loans RewardBorrowSpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowerIndex (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowerIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardAccrued (r:3 w:3)
+	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Loans RewardSupplyState (r:1 w:1)
 	/// Proof Skipped: Loans RewardSupplyState (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Loans RewardSupplySpeed (r:1 w:0)
 	/// Proof Skipped: Loans RewardSupplySpeed (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Loans RewardSupplierIndex (r:1 w:1)
+	/// Storage: Loans RewardSupplierIndex (r:3 w:3)
 	/// Proof Skipped: Loans RewardSupplierIndex (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Loans RewardAccrued (r:1 w:1)
-	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
-	fn redeem_all() -> Weight {
+	fn liquidate_borrow_unsigned() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `3368`
-		//  Estimated: `82017`
-		// Minimum execution time: 325_041_000 picoseconds.
-		Weight::from_parts(327_495_000, 82017)
-			.saturating_add(RocksDbWeight::get().reads(19_u64))
-			.saturating_add(RocksDbWeight::get().writes(8_u64))
+		//  Measured:  `5341`
+		//  Estimated: `213864`
+		// Minimum execution time: 798_412_000 picoseconds.
+		Weight::from_parts(803_627_000, 213864)
+			.saturating_add(RocksDbWeight::get().reads(47_u64))
+			.saturating_add(RocksDbWeight::get().writes(24_u64))
 	}
-	/// Storage: Loans Markets (r:2 w:0)
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans LiquidationScalings (r:0 w:1)
+	/// Proof Skipped: Loans LiquidationScalings (max_values: None, max_size: None, mode: Measured)
+	fn set_liquidation_scaling() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1612`
+		//  Estimated: `5077`
+		// Minimum execution time: 27_304_000 picoseconds.
+		Weight::from_parts(28_011_000, 5077)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:4 w:0)
 	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
 	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:3 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Loans AccountBorrows (r:1 w:1)
 	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Loans BorrowIndex (r:1 w:0)
@@ -1011,18 +2151,78 @@ impl WeightInfo for () {
 	/// Proof Skipped: Loans RewardBorrowerIndex (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Loans RewardAccrued (r:1 w:1)
 	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Tokens Accounts (r:2 w:2)
-	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
 	/// Storage: Loans TotalBorrows (r:1 w:1)
 	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
-	fn repay_borrow() -> Weight {
+	/// Storage: Loans TotalReserves (r:1 w:1)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	fn write_off_bad_debt() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2871`
+		//  Estimated: `46137`
+		// Minimum execution time: 112_804_000 picoseconds.
+		Weight::from_parts(114_921_000, 46137)
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowAllowances (r:0 w:1)
+	/// Proof Skipped: Loans BorrowAllowances (max_values: None, max_size: None, mode: Measured)
+	fn approve_delegation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1612`
+		//  Estimated: `5077`
+		// Minimum execution time: 29_118_000 picoseconds.
+		Weight::from_parts(29_904_000, 5077)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans BorrowAllowances (r:1 w:1)
+	/// Proof Skipped: Loans BorrowAllowances (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalBorrows (r:1 w:1)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Loans TotalReserves (r:1 w:0)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:1 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
+	/// Proof Skipped: Loans UnderlyingAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Loans MinExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MinExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans MaxExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MaxExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:1 w:1)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowState (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowSpeed (r:1 w:0)
+	/// Proof Skipped: Loans RewardBorrowSpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowerIndex (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowerIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardAccrued (r:1 w:1)
+	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:1 w:0)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	fn borrow_on_behalf() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `3407`
-		//  Estimated: `61096`
-		// Minimum execution time: 179_189_000 picoseconds.
-		Weight::from_parts(180_952_000, 61096)
-			.saturating_add(RocksDbWeight::get().reads(13_u64))
-			.saturating_add(RocksDbWeight::get().writes(8_u64))
+		//  Measured:  `3471`
+		//  Estimated: `94452`
+		// Minimum execution time: 271_340_000 picoseconds.
+		Weight::from_parts(274_015_000, 94452)
+			.saturating_add(RocksDbWeight::get().reads(21_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: Loans Markets (r:2 w:0)
 	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
@@ -1046,121 +2246,68 @@ impl WeightInfo for () {
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
 	/// Storage: Loans TotalBorrows (r:1 w:1)
 	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
-	fn repay_borrow_all() -> Weight {
+	fn repay_borrow_on_behalf() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `3407`
-		//  Estimated: `61096`
-		// Minimum execution time: 197_625_000 picoseconds.
-		Weight::from_parts(198_568_000, 61096)
+		//  Measured:  `3512`
+		//  Estimated: `61201`
+		// Minimum execution time: 183_407_000 picoseconds.
+		Weight::from_parts(185_663_000, 61201)
 			.saturating_add(RocksDbWeight::get().reads(13_u64))
 			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
-	/// Storage: Loans Markets (r:2 w:0)
+	/// Storage: Loans Markets (r:1 w:0)
 	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Tokens Accounts (r:1 w:1)
-	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
-	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
-	/// Proof Skipped: Loans UnderlyingAssetId (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Loans AccountDeposits (r:1 w:1)
-	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
-	fn deposit_all_collateral() -> Weight {
+	/// Storage: Loans ReserveStrategies (r:1 w:1)
+	/// Proof Skipped: Loans ReserveStrategies (max_values: None, max_size: None, mode: Measured)
+	fn set_reserve_strategy() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2295`
-		//  Estimated: `19375`
-		// Minimum execution time: 102_335_000 picoseconds.
-		Weight::from_parts(103_146_000, 19375)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		//  Measured:  `1612`
+		//  Estimated: `8164`
+		// Minimum execution time: 31_052_000 picoseconds.
+		Weight::from_parts(31_844_000, 8164)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Loans Markets (r:2 w:0)
 	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Loans AccountDeposits (r:1 w:1)
-	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
-	/// Proof Skipped: Loans UnderlyingAssetId (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
 	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Tokens TotalIssuance (r:1 w:0)
-	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
-	/// Storage: Tokens Accounts (r:2 w:1)
+	/// Storage: Loans ReserveStrategies (r:1 w:1)
+	/// Proof Skipped: Loans ReserveStrategies (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalReserves (r:1 w:1)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:3 w:3)
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:0)
+	/// Storage: System Account (r:2 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Loans TotalBorrows (r:1 w:0)
-	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Loans TotalReserves (r:1 w:0)
-	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Loans MinExchangeRate (r:1 w:0)
-	/// Proof Skipped: Loans MinExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: Loans MaxExchangeRate (r:1 w:0)
-	/// Proof Skipped: Loans MaxExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: Loans AccountBorrows (r:1 w:0)
-	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
-	fn withdraw_all_collateral() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `3242`
-		//  Estimated: `60764`
-		// Minimum execution time: 225_110_000 picoseconds.
-		Weight::from_parts(226_403_000, 60764)
-			.saturating_add(RocksDbWeight::get().reads(15_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
-	}
-	/// Storage: Timestamp Now (r:1 w:0)
-	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
-	/// Storage: Loans LastAccruedInterestTime (r:2 w:2)
-	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Loans Markets (r:3 w:0)
-	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Loans AccountDeposits (r:4 w:1)
-	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
 	/// Proof Skipped: Loans UnderlyingAssetId (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Tokens TotalIssuance (r:1 w:0)
-	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
-	/// Storage: Tokens Accounts (r:6 w:5)
-	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
-	/// Storage: System Account (r:4 w:2)
-	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Loans TotalBorrows (r:2 w:1)
-	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Loans TotalReserves (r:1 w:0)
-	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Loans MinExchangeRate (r:1 w:0)
-	/// Proof Skipped: Loans MinExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: Loans MaxExchangeRate (r:1 w:0)
-	/// Proof Skipped: Loans MaxExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: Security ParachainStatus (r:1 w:0)
-	/// Proof Skipped: Security ParachainStatus (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: Oracle Aggregate (r:1 w:0)
-	/// Proof Skipped: Oracle Aggregate (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Loans AccountBorrows (r:3 w:1)
-	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Loans BorrowIndex (r:1 w:0)
-	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Loans RewardBorrowState (r:1 w:1)
-	/// Proof Skipped: Loans RewardBorrowState (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Loans RewardBorrowSpeed (r:1 w:0)
-	/// Proof Skipped: Loans RewardBorrowSpeed (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Loans RewardBorrowerIndex (r:1 w:1)
-	/// Proof Skipped: Loans RewardBorrowerIndex (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Loans RewardAccrued (r:3 w:3)
-	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Loans RewardSupplyState (r:1 w:1)
 	/// Proof Skipped: Loans RewardSupplyState (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Loans RewardSupplySpeed (r:1 w:0)
 	/// Proof Skipped: Loans RewardSupplySpeed (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Loans RewardSupplierIndex (r:3 w:3)
+	/// Storage: Loans RewardSupplierIndex (r:1 w:1)
 	/// Proof Skipped: Loans RewardSupplierIndex (max_values: None, max_size: None, mode: Measured)
-	fn liquidate_borrow() -> Weight {
+	/// Storage: Loans RewardAccrued (r:1 w:1)
+	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Loans TotalBorrows (r:1 w:0)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MinExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MinExchangeRate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:1 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	fn deploy_reserves() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `5226`
-		//  Estimated: `201519`
-		// Minimum execution time: 762_145_000 picoseconds.
-		Weight::from_parts(766_944_000, 201519)
-			.saturating_add(RocksDbWeight::get().reads(44_u64))
-			.saturating_add(RocksDbWeight::get().writes(21_u64))
+		//  Measured:  `3718`
+		//  Estimated: `92264`
+		// Minimum execution time: 318_407_000 picoseconds.
+		Weight::from_parts(320_115_000, 92264)
+			.saturating_add(RocksDbWeight::get().reads(21_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
 	}
 	/// Storage: Loans Markets (r:2 w:0)
 	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
@@ -1168,24 +2315,26 @@ impl WeightInfo for () {
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
 	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans ReserveStrategies (r:1 w:1)
+	/// Proof Skipped: Loans ReserveStrategies (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
 	/// Proof Skipped: Loans UnderlyingAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:1 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Tokens TotalIssuance (r:1 w:1)
 	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
-	/// Storage: Tokens Accounts (r:4 w:4)
+	/// Storage: Tokens Accounts (r:3 w:3)
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
 	/// Storage: System Account (r:2 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Loans TotalBorrows (r:1 w:0)
 	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Loans TotalReserves (r:1 w:0)
+	/// Storage: Loans TotalReserves (r:1 w:1)
 	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Loans MinExchangeRate (r:1 w:0)
-	/// Proof Skipped: Loans MinExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
+	/// Proof Skipped: Loans MinExchangeRate (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Loans MaxExchangeRate (r:1 w:0)
-	/// Proof Skipped: Loans MaxExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: Loans AccountDeposits (r:1 w:0)
-	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Proof Skipped: Loans MaxExchangeRate (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Loans RewardSupplyState (r:1 w:1)
 	/// Proof Skipped: Loans RewardSupplyState (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Loans RewardSupplySpeed (r:1 w:0)
@@ -1194,55 +2343,239 @@ impl WeightInfo for () {
 	/// Proof Skipped: Loans RewardSupplierIndex (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Loans RewardAccrued (r:1 w:1)
 	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
-	fn reduce_incentive_reserves() -> Weight {
+	fn withdraw_reserves() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `4362`
-		//  Estimated: `99138`
-		// Minimum execution time: 413_086_000 picoseconds.
-		Weight::from_parts(417_575_000, 99138)
-			.saturating_add(RocksDbWeight::get().reads(21_u64))
-			.saturating_add(RocksDbWeight::get().writes(10_u64))
+		//  Measured:  `3794`
+		//  Estimated: `96181`
+		// Minimum execution time: 361_728_000 picoseconds.
+		Weight::from_parts(363_502_000, 96181)
+			.saturating_add(RocksDbWeight::get().reads(22_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
 	}
-	/// Storage: Loans Markets (r:2 w:0)
+	/// Storage: Loans Markets (r:1 w:0)
 	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
-	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
-	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FixedTermBuckets (r:1 w:1)
+	/// Proof Skipped: Loans FixedTermBuckets (max_values: None, max_size: None, mode: Measured)
+	fn open_fixed_term_bucket() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1612`
+		//  Estimated: `9653`
+		// Minimum execution time: 33_417_000 picoseconds.
+		Weight::from_parts(34_102_000, 9653)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans FixedTermBuckets (r:1 w:1)
+	/// Proof Skipped: Loans FixedTermBuckets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: Tokens Accounts (r:2 w:2)
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Loans TotalReserves (r:1 w:1)
-	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
-	fn add_reserves() -> Weight {
+	/// Storage: Loans FixedTermDeposits (r:1 w:1)
+	/// Proof Skipped: Loans FixedTermDeposits (max_values: None, max_size: None, mode: Measured)
+	fn deposit_fixed_term() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2768`
-		//  Estimated: `26490`
-		// Minimum execution time: 131_052_000 picoseconds.
-		Weight::from_parts(132_405_000, 26490)
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
+		//  Measured:  `2137`
+		//  Estimated: `17794`
+		// Minimum execution time: 71_566_000 picoseconds.
+		Weight::from_parts(72_480_000, 17794)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
-	/// Storage: Loans Markets (r:2 w:0)
+	/// Storage: Loans FixedTermBuckets (r:1 w:1)
+	/// Proof Skipped: Loans FixedTermBuckets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans FixedTermDeposits (r:1 w:1)
+	/// Proof Skipped: Loans FixedTermDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw_fixed_term() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2489`
+		//  Estimated: `18146`
+		// Minimum execution time: 74_903_000 picoseconds.
+		Weight::from_parts(75_871_000, 18146)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Loans Markets (r:6 w:0)
 	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FixedTermBuckets (r:2 w:1)
+	/// Proof Skipped: Loans FixedTermBuckets (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
-	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
-	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Loans TotalReserves (r:1 w:1)
+	/// Storage: Loans AccountEfficiencyGroup (r:1 w:0)
+	/// Proof Skipped: Loans AccountEfficiencyGroup (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans IsolationDebtCeiling (r:5 w:0)
+	/// Proof Skipped: Loans IsolationDebtCeiling (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:10 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
+	/// Proof Skipped: Loans UnderlyingAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalBorrows (r:1 w:0)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalReserves (r:1 w:0)
 	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MinExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MinExchangeRate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MaxExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MaxExchangeRate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:5 w:0)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FixedTermBorrows (r:2 w:1)
+	/// Proof Skipped: Loans FixedTermBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle Aggregate (r:2 w:0)
+	/// Proof Skipped: Oracle Aggregate (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Tokens Accounts (r:2 w:2)
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:0)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	fn reduce_reserves() -> Weight {
+	fn borrow_fixed_term() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2857`
-		//  Estimated: `26757`
-		// Minimum execution time: 115_421_000 picoseconds.
-		Weight::from_parts(116_053_000, 26757)
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
+		//  Measured:  `4187`
+		//  Estimated: `142387`
+		// Minimum execution time: 301_558_000 picoseconds.
+		Weight::from_parts(303_266_000, 142387)
+			.saturating_add(RocksDbWeight::get().reads(42_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Loans FixedTermBuckets (r:1 w:1)
+	/// Proof Skipped: Loans FixedTermBuckets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FixedTermBorrows (r:1 w:1)
+	/// Proof Skipped: Loans FixedTermBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn repay_fixed_term() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2671`
+		//  Estimated: `18328`
+		// Minimum execution time: 72_184_000 picoseconds.
+		Weight::from_parts(73_005_000, 18328)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Storage: Loans Markets (r:7 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans LiquidationScalings (r:1 w:0)
+	/// Proof Skipped: Loans LiquidationScalings (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FixedTermBuckets (r:2 w:1)
+	/// Proof Skipped: Loans FixedTermBuckets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FixedTermBorrows (r:2 w:1)
+	/// Proof Skipped: Loans FixedTermBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:6 w:1)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:5 w:0)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans UnderlyingAssetId (r:1 w:0)
+	/// Proof Skipped: Loans UnderlyingAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalBorrows (r:1 w:0)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalReserves (r:1 w:0)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MinExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MinExchangeRate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MaxExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MaxExchangeRate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle Aggregate (r:2 w:0)
+	/// Proof Skipped: Oracle Aggregate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:5 w:5)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Loans RewardSupplyState (r:1 w:1)
+	/// Proof Skipped: Loans RewardSupplyState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplySpeed (r:1 w:0)
+	/// Proof Skipped: Loans RewardSupplySpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplierIndex (r:3 w:3)
+	/// Proof Skipped: Loans RewardSupplierIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardAccrued (r:3 w:3)
+	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
+	fn liquidate_fixed_term_borrow() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5316`
+		//  Estimated: `174955`
+		// Minimum execution time: 412_907_000 picoseconds.
+		Weight::from_parts(415_362_000, 174955)
+			.saturating_add(RocksDbWeight::get().reads(46_u64))
+			.saturating_add(RocksDbWeight::get().writes(17_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MarketValueCaps (r:0 w:1)
+	/// Proof Skipped: Loans MarketValueCaps (max_values: None, max_size: None, mode: Measured)
+	fn set_market_value_caps() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1469`
+		//  Estimated: `3944`
+		// Minimum execution time: 30_917_000 picoseconds.
+		Weight::from_parts(31_688_000, 3944)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans OutflowLimits (r:0 w:1)
+	/// Proof Skipped: Loans OutflowLimits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans OutflowWindows (r:0 w:1)
+	/// Proof Skipped: Loans OutflowWindows (max_values: None, max_size: None, mode: Measured)
+	fn set_outflow_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1469`
+		//  Estimated: `3944`
+		// Minimum execution time: 33_406_000 picoseconds.
+		Weight::from_parts(34_211_000, 3944)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans OutflowWindows (r:0 w:1)
+	/// Proof Skipped: Loans OutflowWindows (max_values: None, max_size: None, mode: Measured)
+	fn reset_outflow_window() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1469`
+		//  Estimated: `3944`
+		// Minimum execution time: 29_874_000 picoseconds.
+		Weight::from_parts(30_602_000, 3944)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FixedTermBuckets (r:1 w:1)
+	/// Proof Skipped: Loans FixedTermBuckets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FixedTermBorrows (r:1 w:1)
+	/// Proof Skipped: Loans FixedTermBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FixedTermDeposits (r:1 w:0)
+	/// Proof Skipped: Loans FixedTermDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalReserves (r:1 w:1)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans ActiveFlashLoan (r:1 w:0)
+	/// Proof Skipped: Loans ActiveFlashLoan (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:4 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	fn write_off_fixed_term_bad_debt() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3412`
+		//  Estimated: `48207`
+		// Minimum execution time: 98_516_000 picoseconds.
+		Weight::from_parts(100_233_000, 48207)
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
 }
@@ -0,0 +1,382 @@
+// Copyright 2022 Interlay.
+// This file is part of Interlay.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fixed-term lending. Lenders deposit into the bucket of a market with a given maturity, and
+//! borrowers lock the rate of the bucket until maturity. Fixed-term debt counts towards the
+//! liquidity of the borrower, so it is backed by the same collateral as variable-rate debt.
+
+use primitives::{Timestamp, SECONDS_PER_YEAR};
+use sp_runtime::{helpers_128bit::multiply_by_rational_with_rounding, DispatchResult};
+
+use crate::*;
+
+impl<T: Config> Pallet<T> {
+    /// Interest on `principal` at the annual `rate` between `from` and `to`
+    fn fixed_term_interest(
+        principal: &Amount<T>,
+        rate: Rate,
+        from: Timestamp,
+        to: Timestamp,
+        rounding: Rounding,
+    ) -> Result<Amount<T>, DispatchError> {
+        let period = Rate::saturating_from_rational(to.saturating_sub(from), SECONDS_PER_YEAR);
+        principal.checked_rounded_mul(&rate.saturating_mul(period), rounding)
+    }
+
+    /// Amount required to repay `loan` at `now`. Before maturity, the interest of the remaining
+    /// term is waived except for the early repayment penalty. After maturity, the overdue
+    /// penalty accrues on the principal and interest.
+    pub fn fixed_term_debt(
+        loan: &FixedTermLoan<BalanceOf<T>>,
+        bucket: &FixedTermBucket<BalanceOf<T>>,
+        asset_id: CurrencyId<T>,
+        maturity: Timestamp,
+        now: Timestamp,
+    ) -> Result<Amount<T>, DispatchError> {
+        let principal = Amount::new(loan.principal, asset_id);
+        let interest = Amount::new(loan.interest, asset_id);
+        let due = principal.checked_add(&interest)?;
+        if now < maturity {
+            let remaining_interest =
+                Self::fixed_term_interest(&principal, bucket.rate, now, maturity, Rounding::Down)?.min(&interest)?;
+            let waived =
+                remaining_interest.checked_sub(&remaining_interest.mul_ratio_floor(bucket.early_repayment_penalty))?;
+            due.checked_sub(&waived)
+        } else {
+            let overdue = Self::fixed_term_interest(&due, bucket.overdue_penalty_rate, maturity, now, Rounding::Up)?;
+            due.checked_add(&overdue)
+        }
+    }
+
+    /// Value of the fixed-term debt of `borrower` in the reference currency
+    pub(crate) fn total_fixed_term_borrowed_value(borrower: &T::AccountId) -> Result<Amount<T>, DispatchError> {
+        let now = T::UnixTime::now().as_secs();
+        let mut total_borrow_value = Amount::<T>::zero(T::ReferenceAssetId::get());
+        for ((asset_id, maturity), loan) in FixedTermBorrows::<T>::iter_prefix(borrower) {
+            let bucket = Self::fixed_term_bucket(asset_id, maturity).ok_or(Error::<T>::FixedTermBucketNotFound)?;
+            let debt = Self::fixed_term_debt(&loan, &bucket, asset_id, maturity, now)?;
+            total_borrow_value.checked_accrue(&Self::get_asset_value(&debt)?)?;
+        }
+        Ok(total_borrow_value)
+    }
+
+    /// Fixed-term borrows of `borrower`, with the amount required to repay them now
+    pub(crate) fn fixed_term_positions(
+        borrower: &T::AccountId,
+    ) -> Result<Vec<FixedTermPosition<BalanceOf<T>>>, DispatchError> {
+        let now = T::UnixTime::now().as_secs();
+        FixedTermBorrows::<T>::iter_prefix(borrower)
+            .map(|((asset_id, maturity), loan)| {
+                let bucket = Self::fixed_term_bucket(asset_id, maturity).ok_or(Error::<T>::FixedTermBucketNotFound)?;
+                let debt = Self::fixed_term_debt(&loan, &bucket, asset_id, maturity, now)?;
+                Ok(FixedTermPosition {
+                    currency_id: asset_id,
+                    maturity,
+                    borrowed: debt.amount(),
+                })
+            })
+            .collect()
+    }
+
+    fn ensure_fixed_term_bucket_open(
+        asset_id: CurrencyId<T>,
+        maturity: Timestamp,
+    ) -> Result<FixedTermBucket<BalanceOf<T>>, DispatchError> {
+        let bucket = Self::fixed_term_bucket(asset_id, maturity).ok_or(Error::<T>::FixedTermBucketNotFound)?;
+        ensure!(
+            T::UnixTime::now().as_secs() < maturity,
+            Error::<T>::FixedTermBucketMatured
+        );
+        Ok(bucket)
+    }
+
+    pub(crate) fn do_deposit_fixed_term(
+        lender: &T::AccountId,
+        amount: &Amount<T>,
+        maturity: Timestamp,
+    ) -> DispatchResult {
+        let asset_id = amount.currency();
+        let mut bucket = Self::ensure_fixed_term_bucket_open(asset_id, maturity)?;
+
+        let shares = if bucket.total_shares.is_zero() || bucket.value().is_zero() {
+            amount.amount()
+        } else {
+            multiply_by_rational_with_rounding(amount.amount(), bucket.total_shares, bucket.value(), Rounding::Down)
+                .ok_or(ArithmeticError::Overflow)?
+        };
+        ensure!(!shares.is_zero(), Error::<T>::InvalidAmount);
+
+        amount.transfer(lender, &Self::fixed_term_account_id())?;
+        bucket.cash = bucket
+            .cash
+            .checked_add(amount.amount())
+            .ok_or(ArithmeticError::Overflow)?;
+        bucket.total_shares = bucket
+            .total_shares
+            .checked_add(shares)
+            .ok_or(ArithmeticError::Overflow)?;
+        FixedTermBuckets::<T>::insert(asset_id, maturity, bucket);
+        FixedTermDeposits::<T>::try_mutate((asset_id, maturity), lender, |deposit| -> DispatchResult {
+            *deposit = deposit.checked_add(shares).ok_or(ArithmeticError::Overflow)?;
+            Ok(())
+        })?;
+
+        Self::deposit_event(Event::<T>::FixedTermDeposited {
+            account_id: lender.clone(),
+            currency_id: asset_id,
+            maturity,
+            amount: amount.amount(),
+            shares,
+        });
+        Ok(())
+    }
+
+    pub(crate) fn do_withdraw_fixed_term(
+        lender: &T::AccountId,
+        amount: &Amount<T>,
+        maturity: Timestamp,
+    ) -> DispatchResult {
+        let asset_id = amount.currency();
+        let mut bucket = Self::fixed_term_bucket(asset_id, maturity).ok_or(Error::<T>::FixedTermBucketNotFound)?;
+        ensure!(
+            T::UnixTime::now().as_secs() >= maturity,
+            Error::<T>::FixedTermBucketNotMatured
+        );
+        // lenders can only withdraw what has been repaid so far
+        ensure!(amount.amount() <= bucket.cash, Error::<T>::InsufficientCash);
+
+        let shares =
+            multiply_by_rational_with_rounding(amount.amount(), bucket.total_shares, bucket.value(), Rounding::Up)
+                .ok_or(ArithmeticError::Overflow)?;
+        FixedTermDeposits::<T>::try_mutate_exists((asset_id, maturity), lender, |deposit| -> DispatchResult {
+            let remaining = deposit
+                .unwrap_or_default()
+                .checked_sub(shares)
+                .ok_or(Error::<T>::InsufficientDeposit)?;
+            *deposit = Some(remaining).filter(|remaining| !remaining.is_zero());
+            Ok(())
+        })?;
+        bucket.cash = bucket.cash.saturating_sub(amount.amount());
+        bucket.total_shares = bucket.total_shares.saturating_sub(shares);
+        FixedTermBuckets::<T>::insert(asset_id, maturity, bucket);
+        amount.transfer(&Self::fixed_term_account_id(), lender)?;
+
+        Self::deposit_event(Event::<T>::FixedTermWithdrawn {
+            account_id: lender.clone(),
+            currency_id: asset_id,
+            maturity,
+            amount: amount.amount(),
+            shares,
+        });
+        Ok(())
+    }
+
+    pub(crate) fn do_borrow_fixed_term(
+        borrower: &T::AccountId,
+        borrow: &Amount<T>,
+        maturity: Timestamp,
+    ) -> DispatchResult {
+        let asset_id = borrow.currency();
+        Self::ensure_active_market(asset_id)?;
+        let mut bucket = Self::ensure_fixed_term_bucket_open(asset_id, maturity)?;
+
+        if let Some(group_id) = Self::account_efficiency_group(borrower) {
+            ensure!(
+                Self::market_efficiency_group(asset_id) == Some(group_id),
+                Error::<T>::AssetNotInEfficiencyGroup
+            );
+        }
+        // fixed-term debt does not count towards the debt ceiling of isolated collateral
+        ensure!(
+            Self::isolated_collateral(borrower)?.is_none(),
+            Error::<T>::AssetNotBorrowableInIsolation
+        );
+        ensure!(borrow.amount() <= bucket.cash, Error::<T>::InsufficientCash);
+        Self::ensure_liquidity(borrower, Self::get_asset_value(borrow)?)?;
+
+        let now = T::UnixTime::now().as_secs();
+        let interest = Self::fixed_term_interest(borrow, bucket.rate, now, maturity, Rounding::Up)?;
+        FixedTermBorrows::<T>::try_mutate(borrower, (asset_id, maturity), |loan| -> DispatchResult {
+            let loan = loan.get_or_insert_with(Default::default);
+            loan.principal = loan
+                .principal
+                .checked_add(borrow.amount())
+                .ok_or(ArithmeticError::Overflow)?;
+            loan.interest = loan
+                .interest
+                .checked_add(interest.amount())
+                .ok_or(ArithmeticError::Overflow)?;
+            Ok(())
+        })?;
+        bucket.cash = bucket.cash.saturating_sub(borrow.amount());
+        bucket.total_debt = bucket
+            .total_debt
+            .checked_add(borrow.checked_add(&interest)?.amount())
+            .ok_or(ArithmeticError::Overflow)?;
+        FixedTermBuckets::<T>::insert(asset_id, maturity, bucket);
+        borrow.transfer(&Self::fixed_term_account_id(), borrower)?;
+
+        Self::deposit_event(Event::<T>::FixedTermBorrowed {
+            account_id: borrower.clone(),
+            currency_id: asset_id,
+            maturity,
+            amount: borrow.amount(),
+            interest: interest.amount(),
+        });
+        Ok(())
+    }
+
+    /// Repays `repayment` of the fixed-term debt of `borrower` with the funds of `payer`. The
+    /// principal and interest of the loan are reduced by the repaid share of the debt.
+    pub(crate) fn do_repay_fixed_term_from(
+        payer: &T::AccountId,
+        borrower: &T::AccountId,
+        repayment: &Amount<T>,
+        maturity: Timestamp,
+    ) -> DispatchResult {
+        let asset_id = repayment.currency();
+        let mut bucket = Self::fixed_term_bucket(asset_id, maturity).ok_or(Error::<T>::FixedTermBucketNotFound)?;
+        let loan = Self::fixed_term_borrow(borrower, (asset_id, maturity)).ok_or(Error::<T>::NoFixedTermBorrow)?;
+        let debt = Self::fixed_term_debt(&loan, &bucket, asset_id, maturity, T::UnixTime::now().as_secs())?;
+        if debt.lt(repayment)? {
+            return Err(Error::<T>::TooMuchRepay.into());
+        }
+
+        let repaid_share = |x: BalanceOf<T>| {
+            multiply_by_rational_with_rounding(x, repayment.amount(), debt.amount(), Rounding::Down)
+                .ok_or(ArithmeticError::Overflow)
+        };
+        let loan_new = FixedTermLoan {
+            principal: loan.principal.saturating_sub(repaid_share(loan.principal)?),
+            interest: loan.interest.saturating_sub(repaid_share(loan.interest)?),
+        };
+        if repayment.eq(&debt)? {
+            FixedTermBorrows::<T>::remove(borrower, (asset_id, maturity));
+        } else {
+            FixedTermBorrows::<T>::insert(borrower, (asset_id, maturity), loan_new);
+        }
+        let debt_repaid = loan
+            .principal
+            .saturating_add(loan.interest)
+            .saturating_sub(loan_new.principal.saturating_add(loan_new.interest));
+        bucket.total_debt = bucket.total_debt.saturating_sub(debt_repaid);
+        bucket.cash = bucket
+            .cash
+            .checked_add(repayment.amount())
+            .ok_or(ArithmeticError::Overflow)?;
+        FixedTermBuckets::<T>::insert(asset_id, maturity, bucket);
+        repayment.transfer(payer, &Self::fixed_term_account_id())?;
+
+        Self::deposit_event(Event::<T>::FixedTermRepaid {
+            account_id: borrower.clone(),
+            currency_id: asset_id,
+            maturity,
+            amount: repayment.amount(),
+        });
+        Ok(())
+    }
+
+    /// Liquidates a fixed-term borrow like `do_liquidate_borrow` liquidates a variable-rate
+    /// borrow: subject to the same close factor and incentive, seizing the same collateral.
+    /// Overdue borrows can also be liquidated when the borrower has no shortfall.
+    #[require_transactional]
+    pub fn do_liquidate_fixed_term_borrow(
+        liquidator: T::AccountId,
+        borrower: T::AccountId,
+        repayment: &Amount<T>,
+        maturity: Timestamp,
+        collateral_asset_id: CurrencyId<T>,
+    ) -> DispatchResult {
+        let asset_id = repayment.currency();
+        Self::ensure_active_market(asset_id)?;
+        Self::ensure_active_market(collateral_asset_id)?;
+        ensure!(borrower != liquidator, Error::<T>::LiquidatorIsBorrower);
+        Self::ensure_price_feeds_not_paused(&borrower)?;
+
+        let market = Self::market(asset_id)?;
+        let (close_factor, liquidate_incentive) = Self::liquidation_parameters(&borrower, asset_id, &market)?;
+        let bucket = Self::fixed_term_bucket(asset_id, maturity).ok_or(Error::<T>::FixedTermBucketNotFound)?;
+        let loan = Self::fixed_term_borrow(&borrower, (asset_id, maturity)).ok_or(Error::<T>::NoFixedTermBorrow)?;
+        let now = T::UnixTime::now().as_secs();
+        if now < maturity
+            && Self::get_account_liquidation_threshold_liquidity(&borrower)?
+                .shortfall()
+                .is_zero()
+        {
+            return Err(Error::<T>::InsufficientShortfall.into());
+        }
+        let debt = Self::fixed_term_debt(&loan, &bucket, asset_id, maturity, now)?;
+        if debt.map(|x| close_factor.mul_ceil(x)).lt(repayment)? {
+            return Err(Error::<T>::TooMuchRepay.into());
+        }
+
+        let collateral_underlying =
+            Self::liquidation_collateral(&borrower, repayment, collateral_asset_id, liquidate_incentive)?;
+        Self::do_repay_fixed_term_from(&liquidator, &borrower, repayment, maturity)?;
+        Self::seize_collateral(
+            &liquidator,
+            &borrower,
+            &collateral_underlying,
+            &market,
+            liquidate_incentive,
+        )?;
+
+        Self::deposit_event(Event::<T>::FixedTermBorrowLiquidated {
+            liquidator,
+            borrower,
+            currency_id: asset_id,
+            maturity,
+            collateral_currency_id: collateral_asset_id,
+            repay_amount: repayment.amount(),
+            collateral_underlying_amount: collateral_underlying.amount(),
+        });
+        Ok(())
+    }
+    /// Writes off the fixed-term debt of `borrower` like `do_write_off_bad_debt` writes off
+    /// variable-rate debt. The reserves of the market are moved into the bucket to cover the
+    /// debt as far as possible, the rest reduces the value of the bucket for its lenders.
+    #[require_transactional]
+    pub(crate) fn do_write_off_fixed_term_bad_debt(
+        borrower: &T::AccountId,
+        asset_id: CurrencyId<T>,
+        maturity: Timestamp,
+    ) -> DispatchResult {
+        let mut bucket = Self::fixed_term_bucket(asset_id, maturity).ok_or(Error::<T>::FixedTermBucketNotFound)?;
+        let loan = FixedTermBorrows::<T>::take(borrower, (asset_id, maturity)).ok_or(Error::<T>::NoBadDebt)?;
+        let debt = Self::fixed_term_debt(&loan, &bucket, asset_id, maturity, T::UnixTime::now().as_secs())?;
+
+        let total_reserves = Self::total_reserves(asset_id);
+        let reserves_used = debt.min(&total_reserves)?.min(&Self::get_available_cash(asset_id)?)?;
+        TotalReserves::<T>::insert(asset_id, total_reserves.checked_sub(&reserves_used)?.amount());
+        reserves_used.transfer(&Self::account_id(), &Self::fixed_term_account_id())?;
+
+        bucket.total_debt = bucket
+            .total_debt
+            .saturating_sub(loan.principal.saturating_add(loan.interest));
+        bucket.cash = bucket
+            .cash
+            .checked_add(reserves_used.amount())
+            .ok_or(ArithmeticError::Overflow)?;
+        FixedTermBuckets::<T>::insert(asset_id, maturity, bucket);
+
+        Self::deposit_event(Event::<T>::FixedTermBadDebtWrittenOff {
+            borrower: borrower.clone(),
+            currency_id: asset_id,
+            maturity,
+            amount: debt.amount(),
+            reserves_used: reserves_used.amount(),
+        });
+        Ok(())
+    }
+}
@@ -38,6 +38,16 @@ impl<T: Config> Pallet<T> {
         let (borrow_rate, supply_rate, exchange_rate, util, total_borrows_new, total_reserves_new, borrow_index_new) =
             Self::get_market_status(asset_id)?;
 
+        // adaptive models move their rate over time, persist the adapted model
+        let rate_model = Self::adapted_rate_model(asset_id, util, now)?;
+        if let InterestRateModel::Adaptive(_) = rate_model {
+            Markets::<T>::try_mutate(asset_id, |market| -> DispatchResult {
+                let market = market.as_mut().ok_or(Error::<T>::MarketDoesNotExist)?;
+                market.rate_model = rate_model;
+                Ok(())
+            })?;
+        }
+
         Self::update_last_accrued_interest_time(asset_id, now)?;
         TotalBorrows::<T>::insert(asset_id, total_borrows_new);
         TotalReserves::<T>::insert(asset_id, total_reserves_new);
@@ -64,6 +74,25 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// The interest rate model of the market as of `now`, given the utilization since the last
+    /// accrual.
+    fn adapted_rate_model(
+        asset_id: CurrencyId<T>,
+        util: Ratio,
+        now: Timestamp,
+    ) -> Result<InterestRateModel, DispatchError> {
+        let market = Self::market(asset_id)?;
+        let last_accrued_interest_time = Self::last_accrued_interest_time(asset_id);
+        if last_accrued_interest_time.is_zero() {
+            return Ok(market.rate_model);
+        }
+        let delta_time = now.saturating_sub(last_accrued_interest_time);
+        Ok(market
+            .rate_model
+            .adapt(util, delta_time)
+            .ok_or(ArithmeticError::Overflow)?)
+    }
+
     pub fn get_market_status(
         asset_id: CurrencyId<T>,
     ) -> Result<(Rate, Rate, Rate, Ratio, BalanceOf<T>, BalanceOf<T>, FixedU128), DispatchError> {
@@ -76,14 +105,13 @@ impl<T: Config> Pallet<T> {
         let mut borrow_index_new = borrow_index;
 
         let util = Self::calc_utilization_ratio(&total_cash, &total_borrows, &total_reserves)?;
-        let borrow_rate = market
-            .rate_model
+        let now = T::UnixTime::now().as_secs();
+        let last_accrued_interest_time = Self::last_accrued_interest_time(asset_id);
+        let borrow_rate = Self::adapted_rate_model(asset_id, util, now)?
             .get_borrow_rate(util)
             .ok_or(ArithmeticError::Overflow)?;
         let supply_rate = InterestRateModel::get_supply_rate(borrow_rate, util, market.reserve_factor);
 
-        let now = T::UnixTime::now().as_secs();
-        let last_accrued_interest_time = Self::last_accrued_interest_time(asset_id);
         if now > last_accrued_interest_time {
             let delta_time = now
                 .checked_sub(last_accrued_interest_time)
@@ -31,6 +31,7 @@ use crate::types::AccountLiquidity;
 
 use currency::{Amount, Rounding};
 use frame_support::{
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
     log,
     pallet_prelude::*,
     require_transactional,
@@ -40,27 +41,35 @@ use frame_support::{
     },
     transactional, PalletId,
 };
-use frame_system::pallet_prelude::*;
+use frame_system::{
+    offchain::{SendTransactionTypes, SubmitTransaction},
+    pallet_prelude::*,
+};
 use num_traits::cast::ToPrimitive;
 use orml_traits::{MultiCurrency, MultiReservableCurrency};
 pub use pallet::*;
-use primitives::{Balance, Rate, Ratio, Timestamp};
+use primitives::{AccountPosition, Balance, FixedTermPosition, MarketPosition, Rate, Ratio, Timestamp};
 use sp_runtime::{
     traits::{
-        AccountIdConversion, CheckedAdd, CheckedDiv, CheckedMul, One, SaturatedConversion, Saturating, StaticLookup,
-        Zero,
+        AccountIdConversion, CheckedAdd, CheckedDiv, CheckedMul, Dispatchable, One, SaturatedConversion, Saturating,
+        StaticLookup, Zero,
     },
     ArithmeticError, FixedPointNumber, FixedU128,
 };
-use sp_std::{marker, result::Result};
+use sp_std::{boxed::Box, collections::btree_set::BTreeSet, marker, result::Result, vec::Vec};
 
 use traits::{
     ConvertToBigUint, LoansApi as LoansTrait, LoansMarketDataProvider, MarketInfo, MarketStatus, OnExchangeRateChange,
+    PriceFeedStatusApi, StableLiquidityApi, SwapApi,
 };
 
 pub use default_weights::WeightInfo;
 pub use orml_traits::currency::{OnDeposit, OnSlash, OnTransfer};
-pub use types::{BorrowSnapshot, EarnedSnapshot, Market, MarketState, RewardMarketState};
+pub use types::{
+    BorrowSnapshot, EarnedSnapshot, EfficiencyGroup, FixedTermBucket, FixedTermLoan, LiquidationRateLimit,
+    LiquidationScaling, Market, MarketState, OutflowLimit, OutflowWindow, ReserveStrategy, ReserveStrategyInfo,
+    RewardMarketState, ValueCaps,
+};
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
@@ -71,6 +80,7 @@ mod mock;
 mod tests;
 
 mod farming;
+mod fixed_term;
 mod interest;
 #[cfg(test)]
 mod lend_token;
@@ -84,6 +94,11 @@ use mocktopus::macros::mockable;
 
 pub const REWARD_SUB_ACCOUNT: &[u8; 7] = b"farming";
 pub const INCENTIVE_SUB_ACCOUNT: &[u8; 9] = b"incentive";
+pub const LIQUIDATOR_SUB_ACCOUNT: &[u8; 10] = b"liquidator";
+pub const RESERVE_STRATEGY_SUB_ACCOUNT: &[u8; 8] = b"strategy";
+pub const FIXED_TERM_SUB_ACCOUNT: &[u8; 9] = b"fixedterm";
+
+pub const UNSIGNED_TXS_PRIORITY: u64 = 100;
 
 pub const DEFAULT_MAX_EXCHANGE_RATE: u128 = 1_000_000_000_000_000_000; // 1
 pub const DEFAULT_MIN_EXCHANGE_RATE: u128 = 20_000_000_000_000_000; // 0.02
@@ -224,7 +239,9 @@ pub mod pallet {
 
     #[pallet::config]
     pub trait Config:
-        frame_system::Config + currency::Config<Balance = Balance, UnsignedFixedPoint = FixedU128>
+        frame_system::Config
+        + SendTransactionTypes<Call<Self>>
+        + currency::Config<Balance = Balance, UnsignedFixedPoint = FixedU128>
     {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -255,6 +272,20 @@ pub mod pallet {
 
         /// Hook for exchangerate changes.
         type OnExchangeRateChange: OnExchangeRateChange<CurrencyId<Self>>;
+
+        /// The overarching call type, dispatched by flash loans.
+        type RuntimeCall: Parameter
+            + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+            + GetDispatchInfo;
+
+        /// Swaps reserves deployed into the supply-market and buy-back-and-burn strategies.
+        type Swap: SwapApi<Self::AccountId, CurrencyId<Self>, BalanceOf<Self>>;
+
+        /// Adds reserves deployed into stable pool strategies as liquidity.
+        type StableLiquidity: StableLiquidityApi<Self::AccountId, CurrencyId<Self>, BalanceOf<Self>>;
+
+        /// Reports price feeds paused by the oracle, which must not be used for liquidations.
+        type PriceFeeds: PriceFeedStatusApi<CurrencyId<Self>>;
     }
 
     #[pallet::error]
@@ -312,6 +343,68 @@ pub mod pallet {
         TokensAlreadyLocked,
         /// Only free lend tokens are redeemable
         LockedTokensCannotBeRedeemed,
+        /// Flash loans are not enabled for the market
+        FlashLoansDisabled,
+        /// Flash loans cannot be nested
+        FlashLoanInProgress,
+        /// The flash loan and its fee were not repaid
+        FlashLoanNotRepaid,
+        /// Invalid efficiency group parameters
+        InvalidEfficiencyGroup,
+        /// Efficiency group does not exist
+        EfficiencyGroupDoesNotExist,
+        /// The asset is not part of the efficiency group of the account
+        AssetNotInEfficiencyGroup,
+        /// Isolated collateral cannot be deposited together with other collateral
+        IsolatedCollateralCannotBeCombined,
+        /// The asset cannot be borrowed against isolated collateral
+        AssetNotBorrowableInIsolation,
+        /// The debt backed by the isolated collateral would exceed its ceiling
+        IsolationDebtCeilingExceeded,
+        /// Protocol liquidations are disabled
+        ProtocolLiquidationDisabled,
+        /// Too many protocol liquidations in this block, or of this borrower recently
+        ProtocolLiquidationRateLimited,
+        /// Invalid liquidation scaling parameters
+        InvalidLiquidationScaling,
+        /// Debt can only be written off if the borrower has no lend tokens or fixed-term deposits left
+        BorrowerHasAssets,
+        /// The borrower has no debt in this market
+        NoBadDebt,
+        /// An account cannot delegate credit to itself
+        SelfDelegation,
+        /// The borrow exceeds the allowance granted by the collateral owner
+        BorrowAllowanceExceeded,
+        /// The reserve strategy is not whitelisted for this market
+        ReserveStrategyNotWhitelisted,
+        /// The reserve strategy cannot be used for this market
+        InvalidReserveStrategy,
+        /// Deploying the reserves would exceed the cap of the strategy
+        ReserveStrategyCapExceeded,
+        /// The strategy still holds a position and cannot be removed
+        ReserveStrategyInUse,
+        /// The strategy holds fewer lend tokens or LP tokens than requested
+        InsufficientReserveStrategyPosition,
+        /// Reserves deployed into this strategy cannot be withdrawn
+        ReserveStrategyNotWithdrawable,
+        /// The strategy returned less than the requested minimum
+        ReserveStrategySlippage,
+        /// A fixed-term bucket with this maturity already exists
+        FixedTermBucketAlreadyExists,
+        /// The fixed-term bucket does not exist
+        FixedTermBucketNotFound,
+        /// The maturity must be in the future
+        InvalidMaturity,
+        /// The fixed-term bucket matured, so it no longer accepts deposits and borrows
+        FixedTermBucketMatured,
+        /// Fixed-term deposits can only be withdrawn once the bucket matured
+        FixedTermBucketNotMatured,
+        /// The account has no fixed-term borrow in this bucket
+        NoFixedTermBorrow,
+        /// The outflow limit of the window must span at least one block
+        InvalidOutflowLimit,
+        /// The net outflow of the market in the current window reached its limit
+        OutflowLimitExceeded,
     }
 
     #[pallet::event]
@@ -426,6 +519,157 @@ pub mod pallet {
             currency_id: CurrencyId<T>,
             amount: BalanceOf<T>,
         },
+        /// Event emitted when a flash loan is repaid
+        FlashLoan {
+            account_id: T::AccountId,
+            currency_id: CurrencyId<T>,
+            amount: BalanceOf<T>,
+            fee: BalanceOf<T>,
+        },
+        /// Event emitted when the flash loan fee of a market is updated
+        FlashLoanFeeUpdated {
+            underlying_currency_id: CurrencyId<T>,
+            fee: Option<Ratio>,
+        },
+        /// Event emitted when a market is isolated or no longer isolated
+        IsolationModeUpdated {
+            underlying_currency_id: CurrencyId<T>,
+            debt_ceiling: Option<BalanceOf<T>>,
+        },
+        /// Event emitted when an asset can or can no longer be borrowed against isolated collateral
+        BorrowableInIsolationUpdated {
+            underlying_currency_id: CurrencyId<T>,
+            borrowable: bool,
+        },
+        /// Event emitted when an efficiency group is created, updated or removed
+        EfficiencyGroupUpdated {
+            group_id: u8,
+            group: Option<EfficiencyGroup>,
+        },
+        /// Event emitted when a market joins or leaves an efficiency group
+        MarketEfficiencyGroupUpdated {
+            underlying_currency_id: CurrencyId<T>,
+            group_id: Option<u8>,
+        },
+        /// Event emitted when an account enters or leaves an efficiency group
+        AccountEfficiencyGroupUpdated {
+            account_id: T::AccountId,
+            group_id: Option<u8>,
+        },
+        /// Event emitted when the rate limit of protocol liquidations is updated
+        ProtocolLiquidationRateLimitUpdated {
+            rate_limit: Option<LiquidationRateLimit<BlockNumberFor<T>>>,
+        },
+        /// Event emitted when the liquidation scaling of a market is updated
+        LiquidationScalingUpdated {
+            underlying_currency_id: CurrencyId<T>,
+            scaling: Option<LiquidationScaling>,
+        },
+        /// Event emitted when a collateral owner updates the borrow allowance of a delegatee
+        BorrowAllowanceUpdated {
+            owner: T::AccountId,
+            delegatee: T::AccountId,
+            currency_id: CurrencyId<T>,
+            allowance: BalanceOf<T>,
+        },
+        /// Event emitted when a delegatee borrows against the collateral of its owner
+        DelegatedBorrow {
+            owner: T::AccountId,
+            delegatee: T::AccountId,
+            currency_id: CurrencyId<T>,
+            amount: BalanceOf<T>,
+        },
+        /// Event emitted when a reserve strategy is whitelisted, its cap is updated or it is removed
+        ReserveStrategyUpdated {
+            currency_id: CurrencyId<T>,
+            strategy: ReserveStrategy,
+            cap: Option<BalanceOf<T>>,
+        },
+        /// Event emitted when reserves are deployed into a strategy, in exchange for `received`
+        /// lend tokens, LP tokens or burned native currency
+        ReservesDeployed {
+            currency_id: CurrencyId<T>,
+            strategy: ReserveStrategy,
+            amount: BalanceOf<T>,
+            received: BalanceOf<T>,
+            new_reserve_amount: BalanceOf<T>,
+        },
+        /// Event emitted when `amount` lend tokens or LP tokens of a strategy are unwound,
+        /// returning `returned` to the reserves
+        ReservesWithdrawn {
+            currency_id: CurrencyId<T>,
+            strategy: ReserveStrategy,
+            amount: BalanceOf<T>,
+            returned: BalanceOf<T>,
+            new_reserve_amount: BalanceOf<T>,
+        },
+        /// Event emitted when a fixed-term bucket is opened
+        FixedTermBucketOpened {
+            currency_id: CurrencyId<T>,
+            maturity: Timestamp,
+            rate: Rate,
+            early_repayment_penalty: Ratio,
+            overdue_penalty_rate: Rate,
+        },
+        /// Event emitted when a lender deposits into a fixed-term bucket
+        FixedTermDeposited {
+            account_id: T::AccountId,
+            currency_id: CurrencyId<T>,
+            maturity: Timestamp,
+            amount: BalanceOf<T>,
+            shares: BalanceOf<T>,
+        },
+        /// Event emitted when a lender withdraws from a matured fixed-term bucket
+        FixedTermWithdrawn {
+            account_id: T::AccountId,
+            currency_id: CurrencyId<T>,
+            maturity: Timestamp,
+            amount: BalanceOf<T>,
+            shares: BalanceOf<T>,
+        },
+        /// Event emitted when an account borrows from a fixed-term bucket, owing `interest` at
+        /// maturity
+        FixedTermBorrowed {
+            account_id: T::AccountId,
+            currency_id: CurrencyId<T>,
+            maturity: Timestamp,
+            amount: BalanceOf<T>,
+            interest: BalanceOf<T>,
+        },
+        /// Event emitted when a fixed-term borrow is repaid, including any penalty
+        FixedTermRepaid {
+            account_id: T::AccountId,
+            currency_id: CurrencyId<T>,
+            maturity: Timestamp,
+            amount: BalanceOf<T>,
+        },
+        /// Event emitted when a fixed-term borrow is liquidated
+        FixedTermBorrowLiquidated {
+            liquidator: T::AccountId,
+            borrower: T::AccountId,
+            currency_id: CurrencyId<T>,
+            maturity: Timestamp,
+            collateral_currency_id: CurrencyId<T>,
+            repay_amount: BalanceOf<T>,
+            collateral_underlying_amount: BalanceOf<T>,
+        },
+        /// Event emitted when the debt of a borrower without collateral is written off. The
+        /// reserves absorb up to `reserves_used`, the rest is socialised among suppliers.
+        BadDebtWrittenOff {
+            borrower: T::AccountId,
+            underlying_currency_id: CurrencyId<T>,
+            amount: BalanceOf<T>,
+            reserves_used: BalanceOf<T>,
+        },
+        /// Event emitted when the fixed-term debt of a borrower without assets is written off. The
+        /// reserves absorb up to `reserves_used`, the rest is socialised among the bucket's lenders.
+        FixedTermBadDebtWrittenOff {
+            borrower: T::AccountId,
+            currency_id: CurrencyId<T>,
+            maturity: Timestamp,
+            amount: BalanceOf<T>,
+            reserves_used: BalanceOf<T>,
+        },
         /// Event emitted when interest has been accrued for a market
         InterestAccrued {
             underlying_currency_id: CurrencyId<T>,
@@ -437,6 +681,19 @@ pub mod pallet {
             supply_rate: Rate,
             exchange_rate: Rate,
         },
+        /// Event emitted when the caps of a market in the reference currency are updated
+        MarketValueCapsUpdated {
+            underlying_currency_id: CurrencyId<T>,
+            caps: Option<ValueCaps<BalanceOf<T>>>,
+        },
+        /// Event emitted when the outflow limit of a market is updated
+        OutflowLimitUpdated {
+            underlying_currency_id: CurrencyId<T>,
+            limit: Option<OutflowLimit<BalanceOf<T>, BlockNumberFor<T>>>,
+        },
+        /// Event emitted when governance resets the outflow window of a market, which lifts
+        /// the pause of outflows that reached the limit
+        OutflowWindowReset { underlying_currency_id: CurrencyId<T> },
     }
 
     /// The timestamp of the last calculation of accrued interest
@@ -566,6 +823,157 @@ pub mod pallet {
     #[pallet::getter(fn min_exchange_rate)]
     pub type MinExchangeRate<T: Config> = StorageValue<_, Rate, ValueQuery>;
 
+    /// Fee charged on flash loans of a market, added to its reserves. Flash loans are
+    /// disabled for markets without a fee.
+    #[pallet::storage]
+    #[pallet::getter(fn flash_loan_fee)]
+    pub type FlashLoanFee<T: Config> = StorageMap<_, Blake2_128Concat, CurrencyId<T>, Ratio>;
+
+    /// Market and amount of the flash loan being executed, used to prevent nested flash loans
+    /// and to account for the lent cash until it is repaid.
+    #[pallet::storage]
+    pub type ActiveFlashLoan<T: Config> = StorageValue<_, (CurrencyId<T>, BalanceOf<T>)>;
+
+    /// Ceiling on the debt, valued in `ReferenceAssetId`, that can be backed by the collateral of
+    /// an isolated market. Markets without a ceiling are not isolated.
+    #[pallet::storage]
+    #[pallet::getter(fn isolation_debt_ceiling)]
+    pub type IsolationDebtCeiling<T: Config> = StorageMap<_, Blake2_128Concat, CurrencyId<T>, BalanceOf<T>>;
+
+    /// Debt backed by the collateral of an isolated market, per borrowed asset, in units of the
+    /// borrowed asset. The snapshot accrues interest with the borrow index of the borrowed asset.
+    /// Isolated CurrencyId -> Borrowed CurrencyId -> BorrowSnapshot
+    #[pallet::storage]
+    #[pallet::getter(fn isolation_debt_snapshot)]
+    pub type IsolationDebt<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        CurrencyId<T>,
+        Blake2_128Concat,
+        CurrencyId<T>,
+        BorrowSnapshot<BalanceOf<T>>,
+        ValueQuery,
+    >;
+
+    /// Whether an asset can be borrowed against the collateral of isolated markets.
+    #[pallet::storage]
+    #[pallet::getter(fn borrowable_in_isolation)]
+    pub type BorrowableInIsolation<T: Config> = StorageMap<_, Blake2_128Concat, CurrencyId<T>, bool, ValueQuery>;
+
+    /// Efficiency groups, indexed by their id.
+    #[pallet::storage]
+    #[pallet::getter(fn efficiency_group)]
+    pub type EfficiencyGroups<T: Config> = StorageMap<_, Twox64Concat, u8, EfficiencyGroup>;
+
+    /// Efficiency group of a market.
+    #[pallet::storage]
+    #[pallet::getter(fn market_efficiency_group)]
+    pub type MarketEfficiencyGroup<T: Config> = StorageMap<_, Blake2_128Concat, CurrencyId<T>, u8>;
+
+    /// Efficiency group entered by an account.
+    #[pallet::storage]
+    #[pallet::getter(fn account_efficiency_group)]
+    pub type AccountEfficiencyGroup<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u8>;
+
+    /// Rate limit of the liquidations submitted by the offchain worker. Protocol liquidations
+    /// are disabled if unset.
+    #[pallet::storage]
+    #[pallet::getter(fn protocol_liquidation_rate_limit)]
+    pub type ProtocolLiquidationRateLimit<T: Config> = StorageValue<_, LiquidationRateLimit<BlockNumberFor<T>>>;
+
+    /// Block of the last protocol liquidations and their number in that block.
+    #[pallet::storage]
+    pub type ProtocolLiquidationsInBlock<T: Config> = StorageValue<_, (BlockNumberFor<T>, u32), ValueQuery>;
+
+    /// Block of the last protocol liquidation of a borrower.
+    #[pallet::storage]
+    #[pallet::getter(fn last_protocol_liquidation)]
+    pub type LastProtocolLiquidation<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>>;
+
+    /// Amount a delegatee may still borrow against the collateral of an owner
+    /// Owner -> (Delegatee, CurrencyId) -> Balance
+    #[pallet::storage]
+    #[pallet::getter(fn borrow_allowance)]
+    pub type BorrowAllowances<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        (T::AccountId, CurrencyId<T>),
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Liquidation parameters of a market that scale with the health factor of the borrower.
+    /// If unset, the fixed `close_factor` and `liquidate_incentive` of the market apply.
+    #[pallet::storage]
+    #[pallet::getter(fn liquidation_scaling)]
+    pub type LiquidationScalings<T: Config> = StorageMap<_, Blake2_128Concat, CurrencyId<T>, LiquidationScaling>;
+
+    /// Strategies whitelisted for the reserves of a market, with their cap and accounting
+    /// CurrencyId -> ReserveStrategy -> ReserveStrategyInfo
+    #[pallet::storage]
+    #[pallet::getter(fn reserve_strategy)]
+    pub type ReserveStrategies<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        CurrencyId<T>,
+        Blake2_128Concat,
+        ReserveStrategy,
+        ReserveStrategyInfo<BalanceOf<T>>,
+    >;
+
+    /// Fixed-term buckets of a market, by maturity
+    /// CurrencyId -> Maturity -> FixedTermBucket
+    #[pallet::storage]
+    #[pallet::getter(fn fixed_term_bucket)]
+    pub type FixedTermBuckets<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, CurrencyId<T>, Twox64Concat, Timestamp, FixedTermBucket<BalanceOf<T>>>;
+
+    /// Shares of the lenders in a fixed-term bucket
+    /// (CurrencyId, Maturity) -> Lender -> Shares
+    #[pallet::storage]
+    #[pallet::getter(fn fixed_term_deposit)]
+    pub type FixedTermDeposits<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (CurrencyId<T>, Timestamp),
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Fixed-term loans of an account
+    /// Borrower -> (CurrencyId, Maturity) -> FixedTermLoan
+    #[pallet::storage]
+    #[pallet::getter(fn fixed_term_borrow)]
+    pub type FixedTermBorrows<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        (CurrencyId<T>, Timestamp),
+        FixedTermLoan<BalanceOf<T>>,
+    >;
+
+    /// Caps of a market denominated in the reference currency
+    #[pallet::storage]
+    #[pallet::getter(fn market_value_caps)]
+    pub type MarketValueCaps<T: Config> = StorageMap<_, Blake2_128Concat, CurrencyId<T>, ValueCaps<BalanceOf<T>>>;
+
+    /// Limit on the net outflow of a market per window. Unlimited if unset.
+    #[pallet::storage]
+    #[pallet::getter(fn outflow_limit)]
+    pub type OutflowLimits<T: Config> =
+        StorageMap<_, Blake2_128Concat, CurrencyId<T>, OutflowLimit<BalanceOf<T>, BlockNumberFor<T>>>;
+
+    /// Net outflow of a market in the current window of its outflow limit
+    #[pallet::storage]
+    #[pallet::getter(fn outflow_window)]
+    pub type OutflowWindows<T: Config> =
+        StorageMap<_, Blake2_128Concat, CurrencyId<T>, OutflowWindow<BalanceOf<T>, BlockNumberFor<T>>, ValueQuery>;
+
     /// DefaultVersion is using for initialize the StorageVersion
     #[pallet::type_value]
     pub(super) fn DefaultVersion<T: Config>() -> Versions {
@@ -595,6 +1003,48 @@ pub mod pallet {
     #[pallet::pallet]
     pub struct Pallet<T>(PhantomData<T>);
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn offchain_worker(n: BlockNumberFor<T>) {
+            log::info!("Off-chain worker started on block {:?}", n);
+            Self::_offchain_worker();
+        }
+    }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config> frame_support::unsigned::ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        fn validate_unsigned(source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            match source {
+                TransactionSource::External => {
+                    // receiving unsigned transaction from network - disallow
+                    return InvalidTransaction::Call.into();
+                }
+                TransactionSource::Local => {}   // produced by off-chain worker
+                TransactionSource::InBlock => {} // some other node included it in a block
+            };
+
+            match call {
+                Call::liquidate_borrow_unsigned { borrower, .. } => {
+                    if Self::ensure_protocol_liquidation_allowed(borrower).is_err() {
+                        return InvalidTransaction::ExhaustsResources.into();
+                    }
+                    if !Self::has_shortfall(borrower) || Self::ensure_price_feeds_not_paused(borrower).is_err() {
+                        return InvalidTransaction::Stale.into();
+                    }
+                    ValidTransaction::with_tag_prefix("loans")
+                        .priority(UNSIGNED_TXS_PRIORITY)
+                        .and_provides((b"liquidate_borrow_unsigned", borrower))
+                        .longevity(3)
+                        .propagate(false)
+                        .build()
+                }
+                _ => InvalidTransaction::Call.into(),
+            }
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Creates a new lending market for a given currency. Returns `Err` if a market already
@@ -1141,10 +1591,8 @@ pub mod pallet {
             Ok(().into())
         }
 
-        /// Add reserves by transferring from payer.
-        /// TODO: This extrinsic currently does nothing useful. See the TODO comment
-        /// of the `ensure_enough_cash` function for more details. Based on that
-        /// TODO, decide whether this extrinsic should be kept.
+        /// Add reserves by transferring from payer. The reserves are not lent out unless they are
+        /// deployed into a `SupplyMarket` strategy.
         ///
         /// May only be called from `T::ReserveOrigin`.
         ///
@@ -1258,90 +1706,1077 @@ pub mod pallet {
             });
             Ok(().into())
         }
-    }
-}
-
-impl<T: Config> Pallet<T> {
-    #[cfg_attr(any(test, feature = "integration-tests"), visibility::make(pub))]
-    fn account_deposits(lend_token_id: CurrencyId<T>, supplier: &T::AccountId) -> Amount<T> {
-        Amount::new(AccountDeposits::<T>::get(lend_token_id, supplier), lend_token_id)
-    }
-
-    #[cfg_attr(any(test, feature = "integration-tests"), visibility::make(pub))]
-    fn total_borrows(asset_id: CurrencyId<T>) -> Amount<T> {
-        Amount::new(TotalBorrows::<T>::get(asset_id), asset_id)
-    }
-
-    #[cfg_attr(any(test, feature = "integration-tests"), visibility::make(pub))]
-    fn total_reserves(asset_id: CurrencyId<T>) -> Amount<T> {
-        Amount::new(TotalReserves::<T>::get(asset_id), asset_id)
-    }
-
-    pub fn account_id() -> T::AccountId {
-        T::PalletId::get().into_account_truncating()
-    }
-
-    pub fn get_account_liquidity(account: &T::AccountId) -> Result<AccountLiquidity<T>, DispatchError> {
-        let total_collateral_value = Self::total_collateral_value(account)?;
-        let total_borrow_value = Self::total_borrowed_value(account)?;
-        log::trace!(
-            target: "loans::get_account_liquidity",
-            "account: {:?}, total_borrow_value: {:?}, total_collateral_value: {:?}",
-            account,
-            total_borrow_value.amount(),
-            total_collateral_value.amount(),
-        );
-        AccountLiquidity::from_collateral_and_debt(total_collateral_value, total_borrow_value)
-    }
-
-    pub fn get_account_liquidation_threshold_liquidity(
-        account: &T::AccountId,
-    ) -> Result<AccountLiquidity<T>, DispatchError> {
-        let total_collateral_value = Self::total_liquidation_threshold_value(account)?;
-        let total_borrow_value = Self::total_borrowed_value(account)?;
-        log::trace!(
-            target: "loans::get_account_liquidation_threshold_liquidity",
-            "account: {:?}, total_borrow_value: {:?}, total_collateral_value: {:?}",
-            account,
-            total_borrow_value.amount(),
-            total_collateral_value.amount(),
-        );
-        AccountLiquidity::from_collateral_and_debt(total_collateral_value, total_borrow_value)
-    }
 
-    fn total_borrowed_value(borrower: &T::AccountId) -> Result<Amount<T>, DispatchError> {
-        let mut total_borrow_value = Amount::<T>::zero(T::ReferenceAssetId::get());
-        for (asset_id, _) in Self::active_markets() {
-            let borrow = Self::current_borrow_balance(borrower, asset_id)?;
-            if borrow.is_zero() {
-                continue;
+        /// Sets the fee charged on flash loans of a market. `None` disables flash loans.
+        ///
+        /// May only be called from `T::UpdateOrigin`.
+        ///
+        /// - `asset_id`: Market related currency
+        /// - `fee`: the share of the flash loan paid to the reserves
+        #[pallet::call_index(21)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_flash_loan_fee())]
+        #[transactional]
+        pub fn set_flash_loan_fee(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId<T>,
+            fee: Option<Ratio>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::market(asset_id)?;
+            match fee {
+                Some(fee) => FlashLoanFee::<T>::insert(asset_id, fee),
+                None => FlashLoanFee::<T>::remove(asset_id),
             }
-            let value = Self::get_asset_value(&borrow)?;
-            total_borrow_value.checked_accrue(&value)?;
+            Self::deposit_event(Event::<T>::FlashLoanFeeUpdated {
+                underlying_currency_id: asset_id,
+                fee,
+            });
+            Ok(().into())
         }
 
-        Ok(total_borrow_value)
-    }
-
-    fn collateral_amount_value(voucher: &Amount<T>) -> Result<Amount<T>, DispatchError> {
-        let underlying = voucher.to_underlying()?;
-        let market = Self::market(underlying.currency())?;
-        let effects = underlying.map(|x| market.collateral_factor.mul_ceil(x));
+        /// Lends `amount` from the cash of a market to the sender for the duration of `call`,
+        /// which is dispatched with the sender's origin. The amount plus the market's flash loan
+        /// fee is transferred back from the sender afterwards, otherwise everything is reverted.
+        ///
+        /// - `asset_id`: the asset to be borrowed.
+        /// - `amount`: the amount to be borrowed.
+        /// - `call`: the call using the borrowed amount.
+        #[pallet::call_index(22)]
+        #[pallet::weight({
+            let dispatch_info = call.get_dispatch_info();
+            (<T as Config>::WeightInfo::flash_loan().saturating_add(dispatch_info.weight), dispatch_info.class)
+        })]
+        #[transactional]
+        pub fn flash_loan(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId<T>,
+            #[pallet::compact] amount: BalanceOf<T>,
+            call: Box<<T as Config>::RuntimeCall>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin.clone())?;
+            ensure!(!ActiveFlashLoan::<T>::exists(), Error::<T>::FlashLoanInProgress);
+            Self::ensure_active_market(asset_id)?;
+            let fee_ratio = Self::flash_loan_fee(asset_id).ok_or(Error::<T>::FlashLoansDisabled)?;
+            Self::accrue_interest(asset_id)?;
 
-        Self::get_asset_value(&effects)
-    }
+            let loan = Amount::<T>::new(amount, asset_id);
+            ensure!(!loan.is_zero(), Error::<T>::InvalidAmount);
+            Self::ensure_enough_cash(&loan)?;
+            let fee = Amount::<T>::new(fee_ratio.mul_ceil(amount), asset_id);
+
+            ActiveFlashLoan::<T>::put((asset_id, amount));
+            loan.transfer(&Self::account_id(), &who)?;
+            let result = call.dispatch(origin);
+            ActiveFlashLoan::<T>::kill();
+            let post_info = result.map_err(|err| err.error)?;
+
+            loan.checked_add(&fee)?
+                .transfer(&who, &Self::account_id())
+                .map_err(|_| Error::<T>::FlashLoanNotRepaid)?;
+            let total_reserves_new = Self::total_reserves(asset_id).checked_add(&fee)?;
+            TotalReserves::<T>::insert(asset_id, total_reserves_new.amount());
 
-    fn collateral_asset_value(supplier: &T::AccountId, asset_id: CurrencyId<T>) -> Result<Amount<T>, DispatchError> {
-        let lend_token_id = Self::lend_token_id(asset_id)?;
-        let deposits = Self::account_deposits(lend_token_id, supplier);
-        if deposits.is_zero() {
-            return Ok(Amount::<T>::zero(T::ReferenceAssetId::get()));
+            Self::deposit_event(Event::<T>::FlashLoan {
+                account_id: who,
+                currency_id: asset_id,
+                amount,
+                fee: fee.amount(),
+            });
+            Ok(PostDispatchInfo {
+                actual_weight: post_info
+                    .actual_weight
+                    .map(|weight| weight.saturating_add(<T as Config>::WeightInfo::flash_loan())),
+                pays_fee: post_info.pays_fee,
+            })
         }
-        Self::collateral_amount_value(&deposits)
-    }
 
-    fn liquidation_threshold_asset_value(
-        borrower: &T::AccountId,
+        /// Isolates a market, so that its collateral can only back up to `debt_ceiling` of debt,
+        /// valued in `ReferenceAssetId`, in assets that are borrowable in isolation, and cannot be
+        /// combined with other collateral. `None` removes the isolation.
+        ///
+        /// Isolating a market accounts the existing debt of its suppliers towards the ceiling, and
+        /// fails if any of them combines the market's collateral with other collateral.
+        ///
+        /// May only be called from `T::UpdateOrigin`.
+        ///
+        /// - `asset_id`: Market related currency
+        /// - `debt_ceiling`: the maximum debt backed by the market's collateral
+        #[pallet::call_index(23)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_isolation_mode())]
+        #[transactional]
+        pub fn set_isolation_mode(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId<T>,
+            debt_ceiling: Option<BalanceOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::market(asset_id)?;
+            match debt_ceiling {
+                Some(debt_ceiling) => {
+                    let newly_isolated = !IsolationDebtCeiling::<T>::contains_key(asset_id);
+                    IsolationDebtCeiling::<T>::insert(asset_id, debt_ceiling);
+                    if newly_isolated {
+                        Self::isolate_market(asset_id)?;
+                    }
+                }
+                None => {
+                    IsolationDebtCeiling::<T>::remove(asset_id);
+                    let _ = IsolationDebt::<T>::clear_prefix(asset_id, u32::MAX, None);
+                }
+            }
+            Self::deposit_event(Event::<T>::IsolationModeUpdated {
+                underlying_currency_id: asset_id,
+                debt_ceiling,
+            });
+            Ok(().into())
+        }
+
+        /// Sets whether an asset can be borrowed against the collateral of isolated markets.
+        ///
+        /// May only be called from `T::UpdateOrigin`.
+        ///
+        /// - `asset_id`: Market related currency
+        /// - `borrowable`: whether the asset is borrowable in isolation
+        #[pallet::call_index(24)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_borrowable_in_isolation())]
+        #[transactional]
+        pub fn set_borrowable_in_isolation(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId<T>,
+            borrowable: bool,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::market(asset_id)?;
+            BorrowableInIsolation::<T>::insert(asset_id, borrowable);
+            Self::deposit_event(Event::<T>::BorrowableInIsolationUpdated {
+                underlying_currency_id: asset_id,
+                borrowable,
+            });
+            Ok(().into())
+        }
+
+        /// Creates, updates or removes an efficiency group.
+        ///
+        /// May only be called from `T::UpdateOrigin`.
+        ///
+        /// - `group_id`: the id of the group
+        /// - `group`: the collateral parameters of the group, `None` to remove it
+        #[pallet::call_index(25)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_efficiency_group())]
+        #[transactional]
+        pub fn set_efficiency_group(
+            origin: OriginFor<T>,
+            group_id: u8,
+            group: Option<EfficiencyGroup>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            match group {
+                Some(group) => {
+                    ensure!(
+                        group.collateral_factor < Ratio::one()
+                            && group.liquidation_threshold >= group.collateral_factor
+                            && group.liquidation_threshold < Ratio::one(),
+                        Error::<T>::InvalidEfficiencyGroup
+                    );
+                    EfficiencyGroups::<T>::insert(group_id, group);
+                }
+                None => EfficiencyGroups::<T>::remove(group_id),
+            }
+            Self::deposit_event(Event::<T>::EfficiencyGroupUpdated { group_id, group });
+            Ok(().into())
+        }
+
+        /// Adds a market to an efficiency group, or removes it with `None`.
+        ///
+        /// May only be called from `T::UpdateOrigin`.
+        ///
+        /// - `asset_id`: Market related currency
+        /// - `group_id`: the id of the group
+        #[pallet::call_index(26)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_market_efficiency_group())]
+        #[transactional]
+        pub fn set_market_efficiency_group(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId<T>,
+            group_id: Option<u8>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::market(asset_id)?;
+            match group_id {
+                Some(group_id) => {
+                    ensure!(
+                        EfficiencyGroups::<T>::contains_key(group_id),
+                        Error::<T>::EfficiencyGroupDoesNotExist
+                    );
+                    MarketEfficiencyGroup::<T>::insert(asset_id, group_id);
+                }
+                None => MarketEfficiencyGroup::<T>::remove(asset_id),
+            }
+            Self::deposit_event(Event::<T>::MarketEfficiencyGroupUpdated {
+                underlying_currency_id: asset_id,
+                group_id,
+            });
+            Ok(().into())
+        }
+
+        /// Enters an efficiency group, or leaves it with `None`. While in a group, the caller
+        /// can only borrow assets of the group, and collateral in the group uses the group's
+        /// collateral factor and liquidation threshold.
+        ///
+        /// - `group_id`: the id of the group
+        #[pallet::call_index(27)]
+        #[pallet::weight(<T as Config>::WeightInfo::enter_efficiency_group())]
+        #[transactional]
+        pub fn enter_efficiency_group(origin: OriginFor<T>, group_id: Option<u8>) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            match group_id {
+                Some(group_id) => {
+                    ensure!(
+                        EfficiencyGroups::<T>::contains_key(group_id),
+                        Error::<T>::EfficiencyGroupDoesNotExist
+                    );
+                    for (asset_id, _) in Self::active_markets() {
+                        if !Self::current_borrow_balance(&who, asset_id)?.is_zero() {
+                            ensure!(
+                                Self::market_efficiency_group(asset_id) == Some(group_id),
+                                Error::<T>::AssetNotInEfficiencyGroup
+                            );
+                        }
+                    }
+                    AccountEfficiencyGroup::<T>::insert(&who, group_id);
+                }
+                None => AccountEfficiencyGroup::<T>::remove(&who),
+            }
+            // leaving or switching groups may lower the collateral value of the account
+            ensure!(
+                Self::get_account_liquidity(&who)?.shortfall().is_zero(),
+                Error::<T>::InsufficientLiquidity
+            );
+            Self::deposit_event(Event::<T>::AccountEfficiencyGroupUpdated {
+                account_id: who,
+                group_id,
+            });
+            Ok(().into())
+        }
+
+        /// Sets the rate limit of the liquidations submitted by the offchain worker. `None`
+        /// disables protocol liquidations.
+        ///
+        /// May only be called from `T::UpdateOrigin`.
+        ///
+        /// - `rate_limit`: the maximum number of liquidations per block and per borrower
+        #[pallet::call_index(28)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_protocol_liquidation_rate_limit())]
+        #[transactional]
+        pub fn set_protocol_liquidation_rate_limit(
+            origin: OriginFor<T>,
+            rate_limit: Option<LiquidationRateLimit<BlockNumberFor<T>>>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ProtocolLiquidationRateLimit::<T>::set(rate_limit);
+            Self::deposit_event(Event::<T>::ProtocolLiquidationRateLimitUpdated { rate_limit });
+            Ok(().into())
+        }
+
+        /// Liquidates a borrower on behalf of the protocol, submitted by the offchain worker.
+        /// The debt is repaid from the reserves of `liquidation_asset_id`, and the seized
+        /// collateral is redeemed into the reserves of `collateral_asset_id`.
+        ///
+        /// - `borrower`: the borrower to be liquidated.
+        /// - `liquidation_asset_id`: the asset to be liquidated.
+        /// - `repay_amount`: the amount to be repaid from the reserves.
+        /// - `collateral_asset_id`: The underlying currency whose lend tokens to seize from the borrower.
+        #[pallet::call_index(29)]
+        #[pallet::weight(<T as Config>::WeightInfo::liquidate_borrow_unsigned())]
+        #[transactional]
+        pub fn liquidate_borrow_unsigned(
+            origin: OriginFor<T>,
+            borrower: T::AccountId,
+            liquidation_asset_id: CurrencyId<T>,
+            #[pallet::compact] repay_amount: BalanceOf<T>,
+            collateral_asset_id: CurrencyId<T>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_none(origin)?;
+            Self::ensure_protocol_liquidation_allowed(&borrower)?;
+            Self::accrue_interest(liquidation_asset_id)?;
+            Self::accrue_interest(collateral_asset_id)?;
+            ensure!(!repay_amount.is_zero(), Error::<T>::InvalidAmount);
+
+            let repayment = Amount::new(repay_amount, liquidation_asset_id);
+            let total_reserves = Self::total_reserves(liquidation_asset_id);
+            if repayment.gt(&total_reserves)? {
+                return Err(Error::<T>::InsufficientReserves.into());
+            }
+            TotalReserves::<T>::insert(liquidation_asset_id, total_reserves.checked_sub(&repayment)?.amount());
+
+            let liquidator = Self::protocol_liquidator_account_id();
+            repayment.transfer(&Self::account_id(), &liquidator)?;
+            Self::do_liquidate_borrow(liquidator.clone(), borrower.clone(), &repayment, collateral_asset_id)?;
+            Self::redeem_seized_collateral(&liquidator, collateral_asset_id)?;
+            Self::record_protocol_liquidation(&borrower);
+            Ok(().into())
+        }
+
+        /// Makes the close factor and liquidation incentive of a market scale with the health
+        /// factor of the borrower. `None` restores the fixed parameters of the market.
+        ///
+        /// May only be called from `T::UpdateOrigin`.
+        ///
+        /// - `asset_id`: Market related currency
+        /// - `scaling`: the ranges of the close factor and liquidation incentive
+        #[pallet::call_index(30)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_liquidation_scaling())]
+        #[transactional]
+        pub fn set_liquidation_scaling(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId<T>,
+            scaling: Option<LiquidationScaling>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::market(asset_id)?;
+            match scaling {
+                Some(scaling) => {
+                    ensure!(scaling.is_valid(), Error::<T>::InvalidLiquidationScaling);
+                    LiquidationScalings::<T>::insert(asset_id, scaling);
+                }
+                None => LiquidationScalings::<T>::remove(asset_id),
+            }
+            Self::deposit_event(Event::<T>::LiquidationScalingUpdated {
+                underlying_currency_id: asset_id,
+                scaling,
+            });
+            Ok(().into())
+        }
+
+        /// Writes off the debt of a borrower that has no lend tokens or fixed-term deposits left,
+        /// which can no longer be liquidated. The debt is covered by the reserves of the market as far as possible,
+        /// any remainder is socialised among suppliers through the exchange rate.
+        ///
+        /// - `borrower`: the borrower whose debt to write off
+        /// - `asset_id`: the borrowed asset
+        #[pallet::call_index(31)]
+        #[pallet::weight(<T as Config>::WeightInfo::write_off_bad_debt())]
+        #[transactional]
+        pub fn write_off_bad_debt(
+            origin: OriginFor<T>,
+            borrower: T::AccountId,
+            asset_id: CurrencyId<T>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            Self::market(asset_id)?;
+            Self::accrue_interest(asset_id)?;
+            ensure!(!Self::has_assets(&borrower), Error::<T>::BorrowerHasAssets);
+            Self::do_write_off_bad_debt(&borrower, asset_id)?;
+            Ok(().into())
+        }
+
+        /// The caller allows `delegatee` to borrow up to `allowance` of `asset_id` against the
+        /// caller's collateral. The debt is owed by the caller and counts against their
+        /// liquidity, and their collateral is liquidated if it becomes unhealthy. Setting the
+        /// allowance replaces the previous one, zero revokes the delegation.
+        ///
+        /// - `delegatee`: the account allowed to borrow
+        /// - `asset_id`: the asset that may be borrowed
+        /// - `allowance`: the maximum amount that may be borrowed
+        #[pallet::call_index(32)]
+        #[pallet::weight(<T as Config>::WeightInfo::approve_delegation())]
+        #[transactional]
+        pub fn approve_delegation(
+            origin: OriginFor<T>,
+            delegatee: T::AccountId,
+            asset_id: CurrencyId<T>,
+            #[pallet::compact] allowance: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let owner = ensure_signed(origin)?;
+            ensure!(owner != delegatee, Error::<T>::SelfDelegation);
+            Self::market(asset_id)?;
+            if allowance.is_zero() {
+                BorrowAllowances::<T>::remove(&owner, (&delegatee, asset_id));
+            } else {
+                BorrowAllowances::<T>::insert(&owner, (&delegatee, asset_id), allowance);
+            }
+            Self::deposit_event(Event::<T>::BorrowAllowanceUpdated {
+                owner,
+                delegatee,
+                currency_id: asset_id,
+                allowance,
+            });
+            Ok(().into())
+        }
+
+        /// The caller borrows `borrow_amount` of `asset_id` against the collateral of `owner`,
+        /// within the allowance `owner` approved. The debt is owed by `owner`.
+        ///
+        /// - `owner`: the account whose collateral backs the borrow
+        /// - `asset_id`: the asset to be borrowed
+        /// - `borrow_amount`: the amount to be borrowed
+        #[pallet::call_index(33)]
+        #[pallet::weight(<T as Config>::WeightInfo::borrow_on_behalf())]
+        #[transactional]
+        pub fn borrow_on_behalf(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            asset_id: CurrencyId<T>,
+            #[pallet::compact] borrow_amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let delegatee = ensure_signed(origin)?;
+            ensure!(!borrow_amount.is_zero(), Error::<T>::InvalidAmount);
+            BorrowAllowances::<T>::try_mutate(&owner, (&delegatee, asset_id), |allowance| -> DispatchResult {
+                *allowance = allowance
+                    .checked_sub(&borrow_amount)
+                    .ok_or(Error::<T>::BorrowAllowanceExceeded)?;
+                Ok(())
+            })?;
+            Self::do_borrow_to(&owner, &delegatee, &Amount::new(borrow_amount, asset_id))?;
+            Self::deposit_event(Event::<T>::DelegatedBorrow {
+                owner,
+                delegatee,
+                currency_id: asset_id,
+                amount: borrow_amount,
+            });
+            Ok(().into())
+        }
+
+        /// The caller repays some of the debt of `borrower`.
+        ///
+        /// - `borrower`: the account whose debt is repaid
+        /// - `asset_id`: the asset to be repaid
+        /// - `repay_amount`: the amount to be repaid, in the underlying currency (`asset_id`)
+        #[pallet::call_index(34)]
+        #[pallet::weight(<T as Config>::WeightInfo::repay_borrow_on_behalf())]
+        #[transactional]
+        pub fn repay_borrow_on_behalf(
+            origin: OriginFor<T>,
+            borrower: T::AccountId,
+            asset_id: CurrencyId<T>,
+            #[pallet::compact] repay_amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let payer = ensure_signed(origin)?;
+            ensure!(!repay_amount.is_zero(), Error::<T>::InvalidAmount);
+            Self::do_repay_borrow_from(&payer, &borrower, &Amount::new(repay_amount, asset_id))?;
+            Ok(().into())
+        }
+
+        /// Whitelists a strategy for the reserves of a market or updates its cap. `None` removes
+        /// the strategy, which requires it not to hold a position anymore.
+        ///
+        /// May only be called from `T::UpdateOrigin`.
+        ///
+        /// - `asset_id`: the market whose reserves may be deployed
+        /// - `strategy`: the strategy to whitelist
+        /// - `cap`: the maximum amount of reserves deployed into the strategy
+        #[pallet::call_index(35)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_reserve_strategy())]
+        #[transactional]
+        pub fn set_reserve_strategy(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId<T>,
+            strategy: ReserveStrategy,
+            cap: Option<BalanceOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::market(asset_id)?;
+            match strategy {
+                ReserveStrategy::StablePool(lp_currency_id) => {
+                    ensure!(lp_currency_id != asset_id, Error::<T>::InvalidReserveStrategy)
+                }
+                ReserveStrategy::BuyBackAndBurn => ensure!(
+                    asset_id != currency::getters::get_native_currency_id::<T>(),
+                    Error::<T>::InvalidReserveStrategy
+                ),
+                ReserveStrategy::SupplyMarket(market_id) => {
+                    // supplying the reserves back to their own market would lend them out twice
+                    ensure!(market_id != asset_id, Error::<T>::InvalidReserveStrategy);
+                    Self::market(market_id)?;
+                }
+            }
+            match cap {
+                Some(cap) => ReserveStrategies::<T>::mutate(asset_id, strategy, |info| {
+                    info.get_or_insert_with(Default::default).cap = cap;
+                }),
+                None => {
+                    if let Some(info) = Self::reserve_strategy(asset_id, strategy) {
+                        ensure!(info.position.is_zero(), Error::<T>::ReserveStrategyInUse);
+                    }
+                    ReserveStrategies::<T>::remove(asset_id, strategy);
+                }
+            }
+            Self::deposit_event(Event::<T>::ReserveStrategyUpdated {
+                currency_id: asset_id,
+                strategy,
+                cap,
+            });
+            Ok(().into())
+        }
+
+        /// Deploys reserves of a market into a whitelisted strategy.
+        ///
+        /// May only be called from `T::ReserveOrigin`.
+        ///
+        /// - `asset_id`: the market whose reserves are deployed
+        /// - `strategy`: the strategy to deploy the reserves into
+        /// - `amount`: the amount of reserves to deploy
+        /// - `min_received`: the minimum amount of lend tokens, LP tokens or native currency
+        /// the reserves must be exchanged for
+        #[pallet::call_index(36)]
+        #[pallet::weight(<T as Config>::WeightInfo::deploy_reserves())]
+        #[transactional]
+        pub fn deploy_reserves(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId<T>,
+            strategy: ReserveStrategy,
+            #[pallet::compact] amount: BalanceOf<T>,
+            #[pallet::compact] min_received: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            T::ReserveOrigin::ensure_origin(origin)?;
+            ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+            Self::ensure_active_market(asset_id)?;
+            Self::accrue_interest(asset_id)?;
+            Self::do_deploy_reserves(&Amount::new(amount, asset_id), strategy, min_received)?;
+            Ok(().into())
+        }
+
+        /// Unwinds lend tokens or LP tokens of a reserve strategy, returning the proceeds to
+        /// the reserves of the market.
+        ///
+        /// May only be called from `T::ReserveOrigin`.
+        ///
+        /// - `asset_id`: the market whose reserves were deployed
+        /// - `strategy`: the strategy to withdraw from
+        /// - `amount`: the amount of lend tokens or LP tokens to unwind
+        /// - `min_returned`: the minimum amount of `asset_id` returned to the reserves
+        #[pallet::call_index(37)]
+        #[pallet::weight(<T as Config>::WeightInfo::withdraw_reserves())]
+        #[transactional]
+        pub fn withdraw_reserves(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId<T>,
+            strategy: ReserveStrategy,
+            #[pallet::compact] amount: BalanceOf<T>,
+            #[pallet::compact] min_returned: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            T::ReserveOrigin::ensure_origin(origin)?;
+            ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+            Self::ensure_active_market(asset_id)?;
+            Self::accrue_interest(asset_id)?;
+            Self::do_withdraw_reserves(asset_id, strategy, amount, min_returned)?;
+            Ok(().into())
+        }
+
+        /// Opens a fixed-term bucket of a market. Borrowers lock `rate` until `maturity`, and
+        /// lenders share the repayments, penalties included.
+        ///
+        /// May only be called from `T::UpdateOrigin`.
+        ///
+        /// - `asset_id`: the market to lend and borrow in
+        /// - `maturity`: the unix time in seconds at which the loans are due
+        /// - `rate`: the annual interest rate of the loans
+        /// - `early_repayment_penalty`: the share of the interest of the remaining term charged
+        /// on early repayment
+        /// - `overdue_penalty_rate`: the annual interest rate charged on overdue debt
+        #[pallet::call_index(38)]
+        #[pallet::weight(<T as Config>::WeightInfo::open_fixed_term_bucket())]
+        #[transactional]
+        pub fn open_fixed_term_bucket(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId<T>,
+            maturity: Timestamp,
+            rate: Rate,
+            early_repayment_penalty: Ratio,
+            overdue_penalty_rate: Rate,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::market(asset_id)?;
+            ensure!(maturity > T::UnixTime::now().as_secs(), Error::<T>::InvalidMaturity);
+            ensure!(
+                !FixedTermBuckets::<T>::contains_key(asset_id, maturity),
+                Error::<T>::FixedTermBucketAlreadyExists
+            );
+            FixedTermBuckets::<T>::insert(
+                asset_id,
+                maturity,
+                FixedTermBucket {
+                    rate,
+                    early_repayment_penalty,
+                    overdue_penalty_rate,
+                    total_shares: Zero::zero(),
+                    cash: Zero::zero(),
+                    total_debt: Zero::zero(),
+                },
+            );
+            Self::deposit_event(Event::<T>::FixedTermBucketOpened {
+                currency_id: asset_id,
+                maturity,
+                rate,
+                early_repayment_penalty,
+                overdue_penalty_rate,
+            });
+            Ok(().into())
+        }
+
+        /// Sender deposits into a fixed-term bucket, which can be withdrawn with the earned
+        /// interest once it matured.
+        ///
+        /// - `asset_id`: the asset to be deposited
+        /// - `maturity`: the maturity of the bucket
+        /// - `amount`: the amount to be deposited
+        #[pallet::call_index(39)]
+        #[pallet::weight(<T as Config>::WeightInfo::deposit_fixed_term())]
+        #[transactional]
+        pub fn deposit_fixed_term(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId<T>,
+            maturity: Timestamp,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+            Self::do_deposit_fixed_term(&who, &Amount::new(amount, asset_id), maturity)?;
+            Ok(().into())
+        }
+
+        /// Sender withdraws from a matured fixed-term bucket, up to the amount repaid so far.
+        ///
+        /// - `asset_id`: the asset to be withdrawn
+        /// - `maturity`: the maturity of the bucket
+        /// - `amount`: the amount to be withdrawn
+        #[pallet::call_index(40)]
+        #[pallet::weight(<T as Config>::WeightInfo::withdraw_fixed_term())]
+        #[transactional]
+        pub fn withdraw_fixed_term(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId<T>,
+            maturity: Timestamp,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+            Self::do_withdraw_fixed_term(&who, &Amount::new(amount, asset_id), maturity)?;
+            Ok(().into())
+        }
+
+        /// Sender borrows from a fixed-term bucket at its rate until maturity, using their
+        /// supplied assets as collateral.
+        ///
+        /// - `asset_id`: the asset to be borrowed
+        /// - `maturity`: the maturity of the bucket
+        /// - `amount`: the amount to be borrowed
+        #[pallet::call_index(41)]
+        #[pallet::weight(<T as Config>::WeightInfo::borrow_fixed_term())]
+        #[transactional]
+        pub fn borrow_fixed_term(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId<T>,
+            maturity: Timestamp,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+            Self::do_borrow_fixed_term(&who, &Amount::new(amount, asset_id), maturity)?;
+            Ok(().into())
+        }
+
+        /// Sender repays some of their fixed-term debt, including the early repayment or
+        /// overdue penalty.
+        ///
+        /// - `asset_id`: the asset to be repaid
+        /// - `maturity`: the maturity of the bucket
+        /// - `amount`: the amount to be repaid
+        #[pallet::call_index(42)]
+        #[pallet::weight(<T as Config>::WeightInfo::repay_fixed_term())]
+        #[transactional]
+        pub fn repay_fixed_term(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId<T>,
+            maturity: Timestamp,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+            Self::do_repay_fixed_term_from(&who, &who, &Amount::new(amount, asset_id), maturity)?;
+            Ok(().into())
+        }
+
+        /// The sender liquidates the fixed-term borrow of a borrower that is undercollateralized
+        /// or overdue, receiving the collateral of the borrower with the liquidation incentive.
+        ///
+        /// - `borrower`: the borrower to be liquidated
+        /// - `asset_id`: the asset of the fixed-term borrow
+        /// - `maturity`: the maturity of the bucket
+        /// - `repay_amount`: the amount to be repaid on behalf of the borrower
+        /// - `collateral_asset_id`: the collateral to seize from the borrower
+        #[pallet::call_index(43)]
+        #[pallet::weight(<T as Config>::WeightInfo::liquidate_fixed_term_borrow())]
+        #[transactional]
+        pub fn liquidate_fixed_term_borrow(
+            origin: OriginFor<T>,
+            borrower: T::AccountId,
+            asset_id: CurrencyId<T>,
+            maturity: Timestamp,
+            #[pallet::compact] repay_amount: BalanceOf<T>,
+            collateral_asset_id: CurrencyId<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(!repay_amount.is_zero(), Error::<T>::InvalidAmount);
+            Self::accrue_interest(collateral_asset_id)?;
+            Self::do_liquidate_fixed_term_borrow(
+                who,
+                borrower,
+                &Amount::new(repay_amount, asset_id),
+                maturity,
+                collateral_asset_id,
+            )?;
+            Ok(().into())
+        }
+
+        /// Caps the value of the cash and total borrows of a market in the reference currency,
+        /// in addition to its native `supply_cap` and `borrow_cap`. `None` removes the caps.
+        ///
+        /// May only be called from `T::UpdateOrigin`.
+        ///
+        /// - `asset_id`: Market related currency
+        /// - `caps`: the supply and borrow caps in the reference currency
+        #[pallet::call_index(44)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_market_value_caps())]
+        #[transactional]
+        pub fn set_market_value_caps(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId<T>,
+            caps: Option<ValueCaps<BalanceOf<T>>>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::market(asset_id)?;
+            MarketValueCaps::<T>::set(asset_id, caps);
+            Self::deposit_event(Event::<T>::MarketValueCapsUpdated {
+                underlying_currency_id: asset_id,
+                caps,
+            });
+            Ok(().into())
+        }
+
+        /// Limits the net amount that can be redeemed and borrowed from a market per window of
+        /// blocks. Once the limit is reached, further outflows fail until the window ends or
+        /// governance resets it. `None` removes the limit.
+        ///
+        /// May only be called from `T::UpdateOrigin`.
+        ///
+        /// - `asset_id`: Market related currency
+        /// - `limit`: the maximum net outflow and the length of the window
+        #[pallet::call_index(45)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_outflow_limit())]
+        #[transactional]
+        pub fn set_outflow_limit(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId<T>,
+            limit: Option<OutflowLimit<BalanceOf<T>, BlockNumberFor<T>>>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::market(asset_id)?;
+            match limit {
+                Some(limit) => {
+                    ensure!(!limit.window.is_zero(), Error::<T>::InvalidOutflowLimit);
+                    OutflowLimits::<T>::insert(asset_id, limit);
+                }
+                None => OutflowLimits::<T>::remove(asset_id),
+            }
+            OutflowWindows::<T>::remove(asset_id);
+            Self::deposit_event(Event::<T>::OutflowLimitUpdated {
+                underlying_currency_id: asset_id,
+                limit,
+            });
+            Ok(().into())
+        }
+
+        /// Starts a new outflow window for a market, which resumes outflows paused by the
+        /// outflow limit.
+        ///
+        /// May only be called from `T::UpdateOrigin`.
+        ///
+        /// - `asset_id`: Market related currency
+        #[pallet::call_index(46)]
+        #[pallet::weight(<T as Config>::WeightInfo::reset_outflow_window())]
+        #[transactional]
+        pub fn reset_outflow_window(origin: OriginFor<T>, asset_id: CurrencyId<T>) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::market(asset_id)?;
+            OutflowWindows::<T>::insert(
+                asset_id,
+                OutflowWindow {
+                    start: frame_system::Pallet::<T>::block_number(),
+                    net_outflow: Zero::zero(),
+                },
+            );
+            Self::deposit_event(Event::<T>::OutflowWindowReset {
+                underlying_currency_id: asset_id,
+            });
+            Ok(().into())
+        }
+
+        /// Writes off the fixed-term debt of a borrower that has no lend tokens or fixed-term
+        /// deposits left, like `write_off_bad_debt` does for variable-rate debt. The debt is
+        /// covered by the reserves of the market as far as possible, any remainder is
+        /// socialised among the lenders of the bucket.
+        ///
+        /// - `borrower`: the borrower whose debt to write off
+        /// - `asset_id`: the asset of the fixed-term borrow
+        /// - `maturity`: the maturity of the bucket
+        #[pallet::call_index(47)]
+        #[pallet::weight(<T as Config>::WeightInfo::write_off_fixed_term_bad_debt())]
+        #[transactional]
+        pub fn write_off_fixed_term_bad_debt(
+            origin: OriginFor<T>,
+            borrower: T::AccountId,
+            asset_id: CurrencyId<T>,
+            maturity: Timestamp,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            Self::market(asset_id)?;
+            Self::accrue_interest(asset_id)?;
+            ensure!(!Self::has_assets(&borrower), Error::<T>::BorrowerHasAssets);
+            Self::do_write_off_fixed_term_bad_debt(&borrower, asset_id, maturity)?;
+            Ok(().into())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    fn _offchain_worker() {
+        let rate_limit = match Self::protocol_liquidation_rate_limit() {
+            Some(rate_limit) => rate_limit,
+            None => return,
+        };
+        let mut submitted = 0;
+        for borrower in Self::undercollateralized_borrowers() {
+            if submitted >= rate_limit.max_liquidations_per_block {
+                break;
+            }
+            if Self::ensure_protocol_liquidation_allowed(&borrower).is_err() {
+                continue;
+            }
+            match Self::protocol_liquidation(&borrower) {
+                Ok(Some((repayment, collateral_asset_id))) => {
+                    log::info!("Liquidating borrower {:?}", borrower);
+                    let call = Call::liquidate_borrow_unsigned {
+                        borrower,
+                        liquidation_asset_id: repayment.currency(),
+                        repay_amount: repayment.amount(),
+                        collateral_asset_id,
+                    };
+                    let _ = SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into());
+                    submitted += 1;
+                }
+                Ok(None) => {}
+                Err(err) => log::warn!("Failed to compute liquidation of {:?}: {:?}", borrower, err),
+            }
+        }
+    }
+
+    /// Borrowers whose debt exceeds the liquidation threshold of their collateral
+    fn undercollateralized_borrowers() -> Vec<T::AccountId> {
+        AccountBorrows::<T>::iter()
+            .filter(|(_, _, snapshot)| !snapshot.principal.is_zero())
+            .map(|(_, borrower, _)| borrower)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .filter(Self::has_shortfall)
+            .filter(|borrower| Self::ensure_price_feeds_not_paused(borrower).is_ok())
+            .collect()
+    }
+
+    /// Fails if the price feed of any asset `borrower` supplies or borrows is paused by the
+    /// oracle, in which case its positions can't be valued reliably and must not be liquidated.
+    pub(crate) fn ensure_price_feeds_not_paused(borrower: &T::AccountId) -> DispatchResult {
+        for (asset_id, market) in Self::active_markets() {
+            if !Self::account_deposits(market.lend_token_id, borrower).is_zero()
+                || !Self::account_borrows(asset_id, borrower).principal.is_zero()
+            {
+                T::PriceFeeds::ensure_price_feed_not_paused(&asset_id)?;
+            }
+        }
+        for ((asset_id, _), _) in FixedTermBorrows::<T>::iter_prefix(borrower) {
+            T::PriceFeeds::ensure_price_feed_not_paused(&asset_id)?;
+        }
+        Ok(())
+    }
+
+    fn has_shortfall(borrower: &T::AccountId) -> bool {
+        Self::get_account_liquidation_threshold_liquidity(borrower)
+            .map(|liquidity| !liquidity.shortfall().is_zero())
+            .unwrap_or(false)
+    }
+
+    /// Computes the largest liquidation of `borrower` that the reserves can fund: the largest
+    /// debt is repaid up to the close factor, in exchange for the largest collateral.
+    fn protocol_liquidation(borrower: &T::AccountId) -> Result<Option<(Amount<T>, CurrencyId<T>)>, DispatchError> {
+        let mut debt: Option<(Amount<T>, Amount<T>)> = None;
+        let mut collateral: Option<(CurrencyId<T>, Amount<T>)> = None;
+        for (asset_id, _) in Self::active_markets() {
+            let borrow = Self::current_borrow_balance(borrower, asset_id)?;
+            if !borrow.is_zero() {
+                let value = Self::get_asset_value(&borrow)?;
+                if debt.as_ref().map_or(Ok(true), |(_, largest)| value.gt(largest))? {
+                    debt = Some((borrow, value));
+                }
+            }
+            let deposits = Self::account_deposits(Self::lend_token_id(asset_id)?, borrower);
+            if !deposits.is_zero() {
+                let value = Self::get_asset_value(&deposits.to_underlying()?)?;
+                if collateral.as_ref().map_or(Ok(true), |(_, largest)| value.gt(largest))? {
+                    collateral = Some((asset_id, value));
+                }
+            }
+        }
+        let ((borrow, _), (collateral_asset_id, collateral_value)) = match (debt, collateral) {
+            (Some(debt), Some(collateral)) => (debt, collateral),
+            _ => return Ok(None),
+        };
+
+        let market = Self::market(borrow.currency())?;
+        let (close_factor, liquidate_incentive) = Self::liquidation_parameters(borrower, borrow.currency(), &market)?;
+        let repayment = borrow
+            .map(|x| close_factor.mul_floor(x))
+            .min(
+                &collateral_value
+                    .checked_div(&liquidate_incentive)?
+                    .convert_to(borrow.currency())?,
+            )?
+            .min(&Self::total_reserves(borrow.currency()))?;
+        if repayment.is_zero() {
+            return Ok(None);
+        }
+        Ok(Some((repayment, collateral_asset_id)))
+    }
+
+    fn ensure_protocol_liquidation_allowed(borrower: &T::AccountId) -> DispatchResult {
+        let rate_limit = Self::protocol_liquidation_rate_limit().ok_or(Error::<T>::ProtocolLiquidationDisabled)?;
+        let now = frame_system::Pallet::<T>::block_number();
+        let (block, count) = ProtocolLiquidationsInBlock::<T>::get();
+        ensure!(
+            block != now || count < rate_limit.max_liquidations_per_block,
+            Error::<T>::ProtocolLiquidationRateLimited
+        );
+        if let Some(last) = Self::last_protocol_liquidation(borrower) {
+            ensure!(
+                now >= last.saturating_add(rate_limit.borrower_cooldown),
+                Error::<T>::ProtocolLiquidationRateLimited
+            );
+        }
+        Ok(())
+    }
+
+    fn record_protocol_liquidation(borrower: &T::AccountId) {
+        let now = frame_system::Pallet::<T>::block_number();
+        ProtocolLiquidationsInBlock::<T>::mutate(|(block, count)| {
+            if *block != now {
+                *block = now;
+                *count = 0;
+            }
+            *count = count.saturating_add(1);
+        });
+        LastProtocolLiquidation::<T>::insert(borrower, now);
+    }
+
+    #[cfg_attr(any(test, feature = "integration-tests"), visibility::make(pub))]
+    fn account_deposits(lend_token_id: CurrencyId<T>, supplier: &T::AccountId) -> Amount<T> {
+        Amount::new(AccountDeposits::<T>::get(lend_token_id, supplier), lend_token_id)
+    }
+
+    #[cfg_attr(any(test, feature = "integration-tests"), visibility::make(pub))]
+    fn total_borrows(asset_id: CurrencyId<T>) -> Amount<T> {
+        Amount::new(TotalBorrows::<T>::get(asset_id), asset_id)
+    }
+
+    #[cfg_attr(any(test, feature = "integration-tests"), visibility::make(pub))]
+    fn total_reserves(asset_id: CurrencyId<T>) -> Amount<T> {
+        Amount::new(TotalReserves::<T>::get(asset_id), asset_id)
+    }
+
+    pub fn account_id() -> T::AccountId {
+        T::PalletId::get().into_account_truncating()
+    }
+
+    pub fn get_account_liquidity(account: &T::AccountId) -> Result<AccountLiquidity<T>, DispatchError> {
+        let total_collateral_value = Self::total_collateral_value(account)?;
+        let total_borrow_value = Self::total_borrowed_value(account)?;
+        log::trace!(
+            target: "loans::get_account_liquidity",
+            "account: {:?}, total_borrow_value: {:?}, total_collateral_value: {:?}",
+            account,
+            total_borrow_value.amount(),
+            total_collateral_value.amount(),
+        );
+        AccountLiquidity::from_collateral_and_debt(total_collateral_value, total_borrow_value)
+    }
+
+    pub fn get_account_liquidation_threshold_liquidity(
+        account: &T::AccountId,
+    ) -> Result<AccountLiquidity<T>, DispatchError> {
+        let total_collateral_value = Self::total_liquidation_threshold_value(account)?;
+        let total_borrow_value = Self::total_borrowed_value(account)?;
+        log::trace!(
+            target: "loans::get_account_liquidation_threshold_liquidity",
+            "account: {:?}, total_borrow_value: {:?}, total_collateral_value: {:?}",
+            account,
+            total_borrow_value.amount(),
+            total_collateral_value.amount(),
+        );
+        AccountLiquidity::from_collateral_and_debt(total_collateral_value, total_borrow_value)
+    }
+
+    fn total_borrowed_value(borrower: &T::AccountId) -> Result<Amount<T>, DispatchError> {
+        let mut total_borrow_value = Amount::<T>::zero(T::ReferenceAssetId::get());
+        for (asset_id, _) in Self::active_markets() {
+            let borrow = Self::current_borrow_balance(borrower, asset_id)?;
+            if borrow.is_zero() {
+                continue;
+            }
+            let value = Self::get_asset_value(&borrow)?;
+            total_borrow_value.checked_accrue(&value)?;
+        }
+        total_borrow_value.checked_accrue(&Self::total_fixed_term_borrowed_value(borrower)?)?;
+
+        Ok(total_borrow_value)
+    }
+
+    /// Collateral factor and liquidation threshold applied to the `asset_id` collateral of
+    /// `account`, which are those of the efficiency group if both are part of the same group.
+    fn collateral_parameters(
+        account: &T::AccountId,
+        asset_id: CurrencyId<T>,
+        market: &Market<BalanceOf<T>>,
+    ) -> (Ratio, Ratio) {
+        let group = Self::account_efficiency_group(account)
+            .filter(|group_id| Self::market_efficiency_group(asset_id) == Some(*group_id))
+            .and_then(Self::efficiency_group);
+        match group {
+            Some(group) => (
+                group.collateral_factor.max(market.collateral_factor),
+                group.liquidation_threshold.max(market.liquidation_threshold),
+            ),
+            None => (market.collateral_factor, market.liquidation_threshold),
+        }
+    }
+
+    fn collateral_amount_value(account: &T::AccountId, voucher: &Amount<T>) -> Result<Amount<T>, DispatchError> {
+        let underlying = voucher.to_underlying()?;
+        let market = Self::market(underlying.currency())?;
+        let (collateral_factor, _) = Self::collateral_parameters(account, underlying.currency(), &market);
+        let effects = underlying.map(|x| collateral_factor.mul_ceil(x));
+
+        Self::get_asset_value(&effects)
+    }
+
+    fn collateral_asset_value(supplier: &T::AccountId, asset_id: CurrencyId<T>) -> Result<Amount<T>, DispatchError> {
+        let lend_token_id = Self::lend_token_id(asset_id)?;
+        let deposits = Self::account_deposits(lend_token_id, supplier);
+        if deposits.is_zero() {
+            return Ok(Amount::<T>::zero(T::ReferenceAssetId::get()));
+        }
+        Self::collateral_amount_value(supplier, &deposits)
+    }
+
+    fn liquidation_threshold_asset_value(
+        borrower: &T::AccountId,
         asset_id: CurrencyId<T>,
     ) -> Result<Amount<T>, DispatchError> {
         let lend_token_id = Self::lend_token_id(asset_id)?;
@@ -1354,7 +2789,8 @@ impl<T: Config> Pallet<T> {
         }
         let underlying_amount = deposits.to_underlying()?;
         let market = Self::market(asset_id)?;
-        let effects_amount = underlying_amount.map(|x| market.liquidation_threshold.mul_ceil(x));
+        let (_, liquidation_threshold) = Self::collateral_parameters(borrower, asset_id, &market);
+        let effects_amount = underlying_amount.map(|x| liquidation_threshold.mul_ceil(x));
 
         Self::get_asset_value(&effects_amount)
     }
@@ -1411,6 +2847,12 @@ impl<T: Config> Pallet<T> {
 
     /// Borrower shouldn't borrow more than their total collateral value allows
     fn borrow_allowed(borrower: &T::AccountId, borrow: &Amount<T>) -> DispatchResult {
+        if let Some(group_id) = Self::account_efficiency_group(borrower) {
+            ensure!(
+                Self::market_efficiency_group(borrow.currency()) == Some(group_id),
+                Error::<T>::AssetNotInEfficiencyGroup
+            );
+        }
         Self::ensure_under_borrow_cap(borrow)?;
         Self::ensure_enough_cash(borrow)?;
         let borrow_value = Self::get_asset_value(borrow)?;
@@ -1421,6 +2863,7 @@ impl<T: Config> Pallet<T> {
 
     #[require_transactional]
     fn do_repay_borrow_with_amount(
+        payer: &T::AccountId,
         borrower: &T::AccountId,
         asset_id: CurrencyId<T>,
         account_borrows: &Amount<T>,
@@ -1432,7 +2875,9 @@ impl<T: Config> Pallet<T> {
         Self::update_reward_borrow_index(asset_id)?;
         Self::distribute_borrower_reward(asset_id, borrower)?;
 
-        repay_amount.transfer(borrower, &Self::account_id())?;
+        repay_amount.transfer(payer, &Self::account_id())?;
+        Self::record_inflow(repay_amount);
+        Self::decrease_isolation_debt(borrower, repay_amount)?;
 
         let account_borrows_new = account_borrows.checked_sub(&repay_amount)?;
         let total_borrows = Self::total_borrows(asset_id);
@@ -1486,6 +2931,7 @@ impl<T: Config> Pallet<T> {
         borrower: &T::AccountId,
         underlying: &Amount<T>,
         market: &Market<BalanceOf<T>>,
+        close_factor: Ratio,
     ) -> DispatchResult {
         log::trace!(
             target: "loans::liquidate_borrow_allowed",
@@ -1509,7 +2955,7 @@ impl<T: Config> Pallet<T> {
         let repay_value = Self::get_asset_value(&underlying)?;
 
         if account_borrows_value
-            .map(|x| market.close_factor.mul_ceil(x))
+            .map(|x| close_factor.mul_ceil(x))
             .lt(&repay_value)?
         {
             return Err(Error::<T>::TooMuchRepay.into());
@@ -1543,30 +2989,76 @@ impl<T: Config> Pallet<T> {
         if borrower == liquidator {
             return Err(Error::<T>::LiquidatorIsBorrower.into());
         }
-        Self::liquidate_borrow_allowed(&borrower, repayment_underlying, &market)?;
+        Self::ensure_price_feeds_not_paused(&borrower)?;
+        let (close_factor, liquidate_incentive) =
+            Self::liquidation_parameters(&borrower, liquidation_asset_id, &market)?;
+        Self::liquidate_borrow_allowed(&borrower, repayment_underlying, &market, close_factor)?;
+
+        let real_collateral_underlying_amount = Self::liquidation_collateral(
+            &borrower,
+            repayment_underlying,
+            collateral_asset_id,
+            liquidate_incentive,
+        )?;
+        Self::liquidated_transfer(
+            &liquidator,
+            &borrower,
+            &repayment_underlying,
+            &real_collateral_underlying_amount,
+            &market,
+            liquidate_incentive,
+        )?;
+
+        Ok(())
+    }
 
+    /// Amount of `collateral_asset_id` seized from `borrower` when `repayment` of its debt is
+    /// repaid, including the premium of the liquidator.
+    fn liquidation_collateral(
+        borrower: &T::AccountId,
+        repayment: &Amount<T>,
+        collateral_asset_id: CurrencyId<T>,
+        liquidate_incentive: Rate,
+    ) -> Result<Amount<T>, DispatchError> {
         let lend_token_id = Self::lend_token_id(collateral_asset_id)?;
-        let deposits = Self::account_deposits(lend_token_id, &borrower);
+        let deposits = Self::account_deposits(lend_token_id, borrower);
         ensure!(!deposits.is_zero(), Error::<T>::DepositsAreNotCollateral);
         let borrower_deposits = deposits.to_underlying()?;
 
         let collateral_value = Self::get_asset_value(&borrower_deposits)?;
         // liquidate_value includes the premium of the liquidator
-        let liquidate_value = Self::get_asset_value(repayment_underlying)?.checked_mul(&market.liquidate_incentive)?;
+        let liquidate_value = Self::get_asset_value(repayment)?.checked_mul(&liquidate_incentive)?;
         if collateral_value.lt(&liquidate_value)? {
             return Err(Error::<T>::InsufficientCollateral.into());
         }
 
         // Calculate the collateral amount to seize from the borrower
-        let real_collateral_underlying_amount = liquidate_value.convert_to(collateral_asset_id)?;
-        Self::liquidated_transfer(
-            &liquidator,
-            &borrower,
-            &repayment_underlying,
-            &real_collateral_underlying_amount,
-            &market,
-        )?;
+        liquidate_value.convert_to(collateral_asset_id)
+    }
+
+    /// Burns the lend tokens seized by a protocol liquidation and adds their underlying value to
+    /// the reserves of the collateral market. The underlying never leaves the pallet account, so
+    /// the exchange rate of the market is unaffected.
+    fn redeem_seized_collateral(liquidator: &T::AccountId, collateral_asset_id: CurrencyId<T>) -> DispatchResult {
+        let voucher = Self::free_lend_tokens(collateral_asset_id, liquidator)?;
+        if voucher.is_zero() {
+            return Ok(());
+        }
+        let underlying = voucher.to_underlying()?;
 
+        Self::update_reward_supply_index(collateral_asset_id)?;
+        Self::distribute_supplier_reward(collateral_asset_id, liquidator)?;
+        voucher.lock_on(liquidator)?;
+        voucher.burn_from(liquidator)?;
+
+        let total_reserves_new = Self::total_reserves(collateral_asset_id).checked_add(&underlying)?;
+        TotalReserves::<T>::insert(collateral_asset_id, total_reserves_new.amount());
+        Self::deposit_event(Event::<T>::ReservesAdded {
+            payer: liquidator.clone(),
+            currency_id: collateral_asset_id,
+            amount: underlying.amount(),
+            new_reserve_amount: total_reserves_new.amount(),
+        });
         Ok(())
     }
 
@@ -1577,6 +3069,7 @@ impl<T: Config> Pallet<T> {
         repayment: &Amount<T>,
         collateral_underlying: &Amount<T>,
         market: &Market<BalanceOf<T>>,
+        liquidate_incentive: Rate,
     ) -> DispatchResult {
         let liquidation_asset_id = repayment.currency();
         let collateral_asset_id = collateral_underlying.currency();
@@ -1600,6 +3093,7 @@ impl<T: Config> Pallet<T> {
         // 1.liquidator repays borrower's debt,
         // transfer from liquidator to module account
         repayment.transfer(liquidator, &Self::account_id())?;
+        Self::decrease_isolation_debt(borrower, repayment)?;
 
         // 2.the system reduces borrower's debt
         let account_borrows_new =
@@ -1615,6 +3109,32 @@ impl<T: Config> Pallet<T> {
         );
         TotalBorrows::<T>::insert(liquidation_asset_id, total_borrows_new.amount());
 
+        Self::seize_collateral(liquidator, borrower, collateral_underlying, market, liquidate_incentive)?;
+
+        Self::deposit_event(Event::<T>::LiquidatedBorrow {
+            liquidator: liquidator.clone(),
+            borrower: borrower.clone(),
+            liquidation_currency_id: liquidation_asset_id,
+            collateral_currency_id: collateral_asset_id,
+            repay_amount: repayment.amount(),
+            collateral_underlying_amount: collateral_underlying.amount(),
+        });
+
+        Ok(())
+    }
+
+    /// Transfers `collateral_underlying` worth of the collateral of `borrower` to `liquidator`,
+    /// minus the share of the incentive that goes to the incentive reserves.
+    #[require_transactional]
+    fn seize_collateral(
+        liquidator: &T::AccountId,
+        borrower: &T::AccountId,
+        collateral_underlying: &Amount<T>,
+        market: &Market<BalanceOf<T>>,
+        liquidate_incentive: Rate,
+    ) -> DispatchResult {
+        let collateral_asset_id = collateral_underlying.currency();
+
         // update supply index before modify supply balance.
         Self::update_reward_supply_index(collateral_asset_id)?;
         Self::distribute_supplier_reward(collateral_asset_id, liquidator)?;
@@ -1642,7 +3162,7 @@ impl<T: Config> Pallet<T> {
         amount_to_liquidate.unlock_on(borrower)?;
 
         let incentive_reserved = amount_to_liquidate
-            .checked_div(&market.liquidate_incentive)?
+            .checked_div(&liquidate_incentive)?
             .mul_ratio_floor(market.liquidate_incentive_reserved_factor);
 
         // increase liquidator's voucher_balance
@@ -1652,15 +3172,311 @@ impl<T: Config> Pallet<T> {
         // increase reserve's voucher_balance
         incentive_reserved.transfer(borrower, &Self::incentive_reward_account_id())?;
 
-        Self::deposit_event(Event::<T>::LiquidatedBorrow {
-            liquidator: liquidator.clone(),
+        Ok(())
+    }
+
+    /// Ratio of the liquidation threshold value of the collateral of `account` to the value of
+    /// its debt. The account can be liquidated when it is below one.
+    pub fn health_factor(account: &T::AccountId) -> Result<Rate, DispatchError> {
+        let total_borrow_value = Self::total_borrowed_value(account)?;
+        if total_borrow_value.is_zero() {
+            return Ok(Rate::from_inner(u128::MAX));
+        }
+        let total_collateral_value = Self::total_liquidation_threshold_value(account)?;
+        Ok(
+            Rate::checked_from_rational(total_collateral_value.amount(), total_borrow_value.amount())
+                .ok_or(ArithmeticError::Overflow)?,
+        )
+    }
+
+    /// Positions of `account` in every active market it supplied to or borrowed from, together
+    /// with its health factor. Accrues interest first, so this is meant to be called from the
+    /// runtime API where the changes are discarded.
+    pub fn get_account_position(account: &T::AccountId) -> Result<AccountPosition<BalanceOf<T>>, DispatchError> {
+        let mut markets = Vec::new();
+        for (asset_id, market) in Self::active_markets() {
+            Self::accrue_interest(asset_id)?;
+            let lend_tokens = Self::balance(market.lend_token_id, account);
+            let borrowed = Self::current_borrow_balance(account, asset_id)?;
+            if lend_tokens.is_zero() && borrowed.is_zero() {
+                continue;
+            }
+            let supplied = lend_tokens
+                .checked_mul(&Self::exchange_rate_stored(asset_id)?)?
+                .set_currency(asset_id);
+            markets.push(MarketPosition {
+                currency_id: asset_id,
+                supplied: supplied.amount(),
+                lend_tokens: lend_tokens.amount(),
+                is_collateral: !Self::account_deposits(market.lend_token_id, account).is_zero(),
+                borrowed: borrowed.amount(),
+            });
+        }
+        let fixed_term_borrows = Self::fixed_term_positions(account)?;
+
+        if Self::total_borrowed_value(account)?.is_zero() {
+            return Ok(AccountPosition {
+                markets,
+                fixed_term_borrows,
+                health_factor: None,
+                liquidation_price_drop: None,
+            });
+        }
+        let health_factor = Self::health_factor(account)?;
+        // the collateral value can drop by `1 - 1 / health_factor` before it equals the debt
+        let liquidation_price_drop = Ratio::from_rational(
+            health_factor.saturating_sub(Rate::one()).into_inner(),
+            health_factor.into_inner(),
+        );
+        Ok(AccountPosition {
+            markets,
+            fixed_term_borrows,
+            health_factor: Some(health_factor),
+            liquidation_price_drop: Some(liquidation_price_drop),
+        })
+    }
+
+    /// Close factor and liquidation incentive that apply when liquidating the `asset_id` debt
+    /// of `borrower`
+    pub fn liquidation_parameters(
+        borrower: &T::AccountId,
+        asset_id: CurrencyId<T>,
+        market: &Market<BalanceOf<T>>,
+    ) -> Result<(Ratio, Rate), DispatchError> {
+        match Self::liquidation_scaling(asset_id) {
+            Some(scaling) => Ok(scaling.parameters(Self::health_factor(borrower)?)),
+            None => Ok((market.close_factor, market.liquidate_incentive)),
+        }
+    }
+
+    /// Borrows `borrow` against the collateral of `borrower`, who owes the debt, and sends the
+    /// borrowed funds to `recipient`.
+    fn do_borrow_to(borrower: &T::AccountId, recipient: &T::AccountId, borrow: &Amount<T>) -> DispatchResult {
+        let asset_id = borrow.currency();
+        Self::ensure_active_market(asset_id)?;
+
+        Self::accrue_interest(asset_id)?;
+        Self::borrow_allowed(borrower, &borrow)?;
+        Self::record_outflow(borrow)?;
+        Self::increase_isolation_debt(borrower, &borrow)?;
+
+        // update borrow index after accrue interest.
+        Self::update_reward_borrow_index(asset_id)?;
+        Self::distribute_borrower_reward(asset_id, borrower)?;
+
+        let account_borrows = Self::current_borrow_balance(borrower, asset_id)?;
+        let account_borrows_new = account_borrows.checked_add(borrow)?;
+        let total_borrows = Self::total_borrows(asset_id);
+        let total_borrows_new = total_borrows.checked_add(&borrow)?;
+        AccountBorrows::<T>::insert(
+            asset_id,
+            borrower,
+            BorrowSnapshot {
+                principal: account_borrows_new.amount(),
+                borrow_index: Self::borrow_index(asset_id),
+            },
+        );
+        TotalBorrows::<T>::insert(asset_id, total_borrows_new.amount());
+        borrow.transfer(&Self::account_id(), recipient)?;
+
+        Self::deposit_event(Event::<T>::Borrowed {
+            account_id: borrower.clone(),
+            currency_id: asset_id,
+            amount: borrow.amount(),
+        });
+        Ok(())
+    }
+
+    /// Repays `borrow` of the debt of `borrower` with the funds of `payer`.
+    fn do_repay_borrow_from(payer: &T::AccountId, borrower: &T::AccountId, borrow: &Amount<T>) -> DispatchResult {
+        let asset_id = borrow.currency();
+        Self::ensure_active_market(asset_id)?;
+        Self::accrue_interest(asset_id)?;
+        let account_borrows = Self::current_borrow_balance(borrower, asset_id)?;
+        Self::do_repay_borrow_with_amount(payer, borrower, asset_id, &account_borrows, &borrow)?;
+        Self::deposit_event(Event::<T>::RepaidBorrow {
+            account_id: borrower.clone(),
+            currency_id: asset_id,
+            amount: borrow.amount(),
+        });
+        Ok(())
+    }
+
+    /// Whether `account` holds lend tokens, as collateral or not, or fixed-term deposits that
+    /// could still cover its debt
+    fn has_assets(account: &T::AccountId) -> bool {
+        Markets::<T>::iter_values().any(|market| !Self::balance(market.lend_token_id, account).is_zero())
+            || FixedTermBuckets::<T>::iter_keys()
+                .any(|(asset_id, maturity)| FixedTermDeposits::<T>::contains_key((asset_id, maturity), account))
+    }
+
+    #[require_transactional]
+    fn do_write_off_bad_debt(borrower: &T::AccountId, asset_id: CurrencyId<T>) -> DispatchResult {
+        let debt = Self::current_borrow_balance(borrower, asset_id)?;
+        ensure!(!debt.is_zero(), Error::<T>::NoBadDebt);
+
+        Self::update_reward_borrow_index(asset_id)?;
+        Self::distribute_borrower_reward(asset_id, borrower)?;
+
+        AccountBorrows::<T>::remove(asset_id, borrower);
+        // `total_borrows` is rounded down, so it may be slightly lower than the debt
+        let total_borrows = Self::total_borrows(asset_id);
+        TotalBorrows::<T>::insert(asset_id, total_borrows.saturating_sub(&debt)?.amount());
+
+        let total_reserves = Self::total_reserves(asset_id);
+        let reserves_used = debt.min(&total_reserves)?;
+        TotalReserves::<T>::insert(asset_id, total_reserves.checked_sub(&reserves_used)?.amount());
+
+        Self::deposit_event(Event::<T>::BadDebtWrittenOff {
             borrower: borrower.clone(),
-            liquidation_currency_id: liquidation_asset_id,
-            collateral_currency_id: collateral_asset_id,
-            repay_amount: repayment.amount(),
-            collateral_underlying_amount: collateral_underlying.amount(),
+            underlying_currency_id: asset_id,
+            amount: debt.amount(),
+            reserves_used: reserves_used.amount(),
+        });
+        Ok(())
+    }
+
+    fn do_deploy_reserves(amount: &Amount<T>, strategy: ReserveStrategy, min_received: BalanceOf<T>) -> DispatchResult {
+        let asset_id = amount.currency();
+        let mut info = Self::reserve_strategy(asset_id, strategy).ok_or(Error::<T>::ReserveStrategyNotWhitelisted)?;
+        let deployed = info
+            .deployed
+            .checked_add(amount.amount())
+            .ok_or(ArithmeticError::Overflow)?;
+        ensure!(deployed <= info.cap, Error::<T>::ReserveStrategyCapExceeded);
+
+        let total_reserves = Self::total_reserves(asset_id);
+        if amount.gt(&total_reserves)? {
+            return Err(Error::<T>::InsufficientReserves.into());
+        }
+        let total_reserves_new = total_reserves.checked_sub(amount)?;
+        TotalReserves::<T>::insert(asset_id, total_reserves_new.amount());
+
+        let strategy_account = Self::reserve_strategy_account_id();
+        amount.transfer(&Self::account_id(), &strategy_account)?;
+        let received = match strategy {
+            ReserveStrategy::SupplyMarket(market_id) => {
+                let supplied = T::Swap::swap_exact_tokens_for_tokens(
+                    &strategy_account,
+                    asset_id,
+                    amount.amount(),
+                    market_id,
+                    Zero::zero(),
+                )?;
+                let lend_token_id = Self::lend_token_id(market_id)?;
+                let lend_tokens_before = Self::balance(lend_token_id, &strategy_account);
+                Self::do_mint(&strategy_account, &Amount::new(supplied, market_id))?;
+                let received = Self::balance(lend_token_id, &strategy_account).checked_sub(&lend_tokens_before)?;
+                ensure!(
+                    received.ge(&Amount::new(min_received, lend_token_id))?,
+                    Error::<T>::ReserveStrategySlippage
+                );
+                received.amount()
+            }
+            ReserveStrategy::StablePool(lp_currency_id) => T::StableLiquidity::add_liquidity_one_currency(
+                &strategy_account,
+                lp_currency_id,
+                asset_id,
+                amount.amount(),
+                min_received,
+            )?,
+            ReserveStrategy::BuyBackAndBurn => {
+                let native_currency_id = currency::getters::get_native_currency_id::<T>();
+                let received = T::Swap::swap_exact_tokens_for_tokens(
+                    &strategy_account,
+                    asset_id,
+                    amount.amount(),
+                    native_currency_id,
+                    min_received,
+                )?;
+                Amount::<T>::new(received, native_currency_id).burn_from(&strategy_account)?;
+                received
+            }
+        };
+
+        info.deployed = deployed;
+        info.total_deployed = info.total_deployed.saturating_add(amount.amount());
+        if strategy != ReserveStrategy::BuyBackAndBurn {
+            info.position = info.position.checked_add(received).ok_or(ArithmeticError::Overflow)?;
+        }
+        ReserveStrategies::<T>::insert(asset_id, strategy, info);
+
+        Self::deposit_event(Event::<T>::ReservesDeployed {
+            currency_id: asset_id,
+            strategy,
+            amount: amount.amount(),
+            received,
+            new_reserve_amount: total_reserves_new.amount(),
         });
+        Ok(())
+    }
+
+    fn do_withdraw_reserves(
+        asset_id: CurrencyId<T>,
+        strategy: ReserveStrategy,
+        amount: BalanceOf<T>,
+        min_returned: BalanceOf<T>,
+    ) -> DispatchResult {
+        let mut info = Self::reserve_strategy(asset_id, strategy).ok_or(Error::<T>::ReserveStrategyNotWhitelisted)?;
+        // burned reserves are never returned
+        ensure!(
+            strategy != ReserveStrategy::BuyBackAndBurn,
+            Error::<T>::ReserveStrategyNotWithdrawable
+        );
+        ensure!(amount <= info.position, Error::<T>::InsufficientReserveStrategyPosition);
+
+        let strategy_account = Self::reserve_strategy_account_id();
+        let returned = match strategy {
+            ReserveStrategy::SupplyMarket(market_id) => {
+                Self::accrue_interest(market_id)?;
+                let voucher = Amount::new(amount, Self::lend_token_id(market_id)?);
+                let underlying = voucher.to_underlying()?;
+                Self::do_redeem(&strategy_account, &underlying, &voucher)?;
+                Amount::new(
+                    T::Swap::swap_exact_tokens_for_tokens(
+                        &strategy_account,
+                        market_id,
+                        underlying.amount(),
+                        asset_id,
+                        min_returned,
+                    )?,
+                    asset_id,
+                )
+            }
+            ReserveStrategy::StablePool(lp_currency_id) => Amount::new(
+                T::StableLiquidity::remove_liquidity_one_currency(
+                    &strategy_account,
+                    lp_currency_id,
+                    asset_id,
+                    amount,
+                    min_returned,
+                )?,
+                asset_id,
+            ),
+            ReserveStrategy::BuyBackAndBurn => return Err(Error::<T>::ReserveStrategyNotWithdrawable.into()),
+        };
+        ensure!(
+            returned.ge(&Amount::new(min_returned, asset_id))?,
+            Error::<T>::ReserveStrategySlippage
+        );
+
+        returned.transfer(&strategy_account, &Self::account_id())?;
+        let total_reserves_new = Self::total_reserves(asset_id).checked_add(&returned)?;
+        TotalReserves::<T>::insert(asset_id, total_reserves_new.amount());
 
+        info.position = info.position.saturating_sub(amount);
+        // earnings returned on top of the deployed reserves are not deducted further
+        info.deployed = info.deployed.saturating_sub(returned.amount());
+        info.total_returned = info.total_returned.saturating_add(returned.amount());
+        ReserveStrategies::<T>::insert(asset_id, strategy, info);
+
+        Self::deposit_event(Event::<T>::ReservesWithdrawn {
+            currency_id: asset_id,
+            strategy,
+            amount,
+            returned: returned.amount(),
+            new_reserve_amount: total_reserves_new.amount(),
+        });
         Ok(())
     }
 
@@ -1689,12 +3505,18 @@ impl<T: Config> Pallet<T> {
 
         let market = Self::market(asset_id)?;
         // Assets holded by market currently.
-        let current_cash = Self::balance(asset_id, &Self::account_id());
+        let current_cash = Self::balance(asset_id, &Self::account_id()).checked_add(&Self::flash_loaned(asset_id))?;
         let total_cash = current_cash.checked_add(&asset)?;
         ensure!(
             total_cash.amount() <= market.supply_cap,
             Error::<T>::SupplyCapacityExceeded
         );
+        if let Some(cap) = Self::market_value_caps(asset_id).and_then(|caps| caps.supply_cap) {
+            ensure!(
+                Self::get_asset_value(&total_cash)?.amount() <= cap,
+                Error::<T>::SupplyCapacityExceeded
+            );
+        }
 
         Ok(())
     }
@@ -1709,17 +3531,71 @@ impl<T: Config> Pallet<T> {
             new_total_borrows.amount() <= market.borrow_cap,
             Error::<T>::BorrowCapacityExceeded
         );
+        if let Some(cap) = Self::market_value_caps(asset_id).and_then(|caps| caps.borrow_cap) {
+            ensure!(
+                Self::get_asset_value(&new_total_borrows)?.amount() <= cap,
+                Error::<T>::BorrowCapacityExceeded
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Net outflow of the current window of the outflow limit of a market, which starts a new
+    /// window once the previous one ended.
+    fn current_outflow_window(
+        asset_id: CurrencyId<T>,
+        limit: &OutflowLimit<BalanceOf<T>, BlockNumberFor<T>>,
+    ) -> OutflowWindow<BalanceOf<T>, BlockNumberFor<T>> {
+        let now = frame_system::Pallet::<T>::block_number();
+        let window = Self::outflow_window(asset_id);
+        if now >= window.start.saturating_add(limit.window) {
+            OutflowWindow {
+                start: now,
+                net_outflow: Zero::zero(),
+            }
+        } else {
+            window
+        }
+    }
 
+    /// Records the redemption or borrow of `amount`, failing if the net outflow of the market
+    /// would exceed its limit in the current window.
+    fn record_outflow(amount: &Amount<T>) -> DispatchResult {
+        let asset_id = amount.currency();
+        let limit = match Self::outflow_limit(asset_id) {
+            Some(limit) => limit,
+            None => return Ok(()),
+        };
+        let mut window = Self::current_outflow_window(asset_id, &limit);
+        window.net_outflow = window
+            .net_outflow
+            .checked_add(amount.amount())
+            .ok_or(ArithmeticError::Overflow)?;
+        ensure!(
+            window.net_outflow <= limit.max_net_outflow,
+            Error::<T>::OutflowLimitExceeded
+        );
+        OutflowWindows::<T>::insert(asset_id, window);
         Ok(())
     }
 
+    /// Records the mint or repayment of `amount`, which offsets the outflow of the current
+    /// window.
+    fn record_inflow(amount: &Amount<T>) {
+        let asset_id = amount.currency();
+        if let Some(limit) = Self::outflow_limit(asset_id) {
+            let mut window = Self::current_outflow_window(asset_id, &limit);
+            window.net_outflow = window.net_outflow.saturating_sub(amount.amount());
+            OutflowWindows::<T>::insert(asset_id, window);
+        }
+    }
+
     /// Make sure there is enough cash available in the pool
-    /// TODO: Compared to Compound's implementation, it seems like
-    /// this function should not subtract the total reserves from the total cash,
-    /// possibly to allow the treasury to deposit liquidity and enable users to exit
-    /// their lend token positions in case of 100% utilization in the market.
-    /// Decide if this function needs to be modified as such, or remove the `add_reserves`
-    /// extrinsic since it currently does nothing useful.
+    /// Compared to Compound's implementation, this function subtracts the total reserves
+    /// from the total cash. Reserves can instead be made available to users, e.g. to exit
+    /// their lend token positions in case of 100% utilization in the market, by deploying
+    /// them into the `SupplyMarket` reserve strategy.
     ///
     /// See the redeem check in Compound V2 (also the borrow check):
     /// - `getCashPrior() > redeemAmount`:
@@ -1728,7 +3604,7 @@ impl<T: Config> Pallet<T> {
     /// https://github.com/compound-finance/compound-protocol/blob/a3214f67b73310d547e00fc578e8355911c9d376/contracts/CToken.sol#L1125
     fn ensure_enough_cash(amount: &Amount<T>) -> DispatchResult {
         let reducible_cash =
-            Self::get_total_cash(amount.currency()).checked_sub(&Self::total_reserves(amount.currency()))?;
+            Self::get_available_cash(amount.currency())?.checked_sub(&Self::total_reserves(amount.currency()))?;
         if reducible_cash.lt(&amount)? {
             return Err(Error::<T>::InsufficientCash.into());
         }
@@ -1764,20 +3640,150 @@ impl<T: Config> Pallet<T> {
         Err(Error::<T>::InsufficientLiquidity.into())
     }
 
+    /// Returns the isolated market used as collateral by `account`, if any
+    fn isolated_collateral(account: &T::AccountId) -> Result<Option<CurrencyId<T>>, DispatchError> {
+        for (asset_id, _) in Self::active_markets() {
+            if IsolationDebtCeiling::<T>::contains_key(asset_id)
+                && !Self::account_deposits(Self::lend_token_id(asset_id)?, account).is_zero()
+            {
+                return Ok(Some(asset_id));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Ensures that collateral of isolated markets is never combined with other collateral
+    fn ensure_collateral_can_be_combined(account: &T::AccountId, asset_id: CurrencyId<T>) -> DispatchResult {
+        let isolated = IsolationDebtCeiling::<T>::contains_key(asset_id);
+        for (other_id, _) in Self::active_markets() {
+            if other_id == asset_id || Self::account_deposits(Self::lend_token_id(other_id)?, account).is_zero() {
+                continue;
+            }
+            ensure!(
+                !isolated && !IsolationDebtCeiling::<T>::contains_key(other_id),
+                Error::<T>::IsolatedCollateralCannotBeCombined
+            );
+        }
+        Ok(())
+    }
+
+    /// Accounts the current borrows of the suppliers of the newly isolated market `collateral_id`
+    /// towards its debt ceiling, since the debt is not tracked while the market isn't isolated.
+    fn isolate_market(collateral_id: CurrencyId<T>) -> DispatchResult {
+        let lend_token_id = Self::lend_token_id(collateral_id)?;
+        for (account, deposits) in AccountDeposits::<T>::iter_prefix(lend_token_id) {
+            if deposits.is_zero() {
+                continue;
+            }
+            Self::ensure_collateral_can_be_combined(&account, collateral_id)?;
+            for (asset_id, _) in Self::active_markets() {
+                let borrow = Self::current_borrow_balance(&account, asset_id)?;
+                if !borrow.is_zero() {
+                    let debt = Self::isolation_debt(collateral_id, asset_id)?.checked_add(&borrow)?;
+                    Self::set_isolation_debt(collateral_id, &debt);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the debt in `asset_id` backed by the collateral of the isolated market
+    /// `collateral_id`, including accrued interest
+    pub fn isolation_debt(collateral_id: CurrencyId<T>, asset_id: CurrencyId<T>) -> Result<Amount<T>, DispatchError> {
+        let snapshot = Self::isolation_debt_snapshot(collateral_id, asset_id);
+        if snapshot.principal.is_zero() || snapshot.borrow_index.is_zero() {
+            return Ok(Amount::zero(asset_id));
+        }
+        Self::borrow_balance_from_old_and_new_index(
+            &snapshot.borrow_index,
+            &Self::borrow_index(asset_id),
+            Amount::<T>::new(snapshot.principal, asset_id),
+            Rounding::Up,
+        )
+    }
+
+    /// Stores `debt` as the debt backed by the collateral of the isolated market `collateral_id`
+    fn set_isolation_debt(collateral_id: CurrencyId<T>, debt: &Amount<T>) {
+        IsolationDebt::<T>::insert(
+            collateral_id,
+            debt.currency(),
+            BorrowSnapshot {
+                principal: debt.amount(),
+                borrow_index: Self::borrow_index(debt.currency()),
+            },
+        );
+    }
+
+    /// Accounts `borrow` towards the debt ceiling of the isolated collateral of `borrower`, if any.
+    /// The debt is valued at current prices, so the ceiling follows both prices and interest.
+    fn increase_isolation_debt(borrower: &T::AccountId, borrow: &Amount<T>) -> DispatchResult {
+        let collateral_id = match Self::isolated_collateral(borrower)? {
+            Some(collateral_id) => collateral_id,
+            None => return Ok(()),
+        };
+        ensure!(
+            Self::borrowable_in_isolation(borrow.currency()),
+            Error::<T>::AssetNotBorrowableInIsolation
+        );
+        let debt_ceiling = Amount::<T>::new(
+            Self::isolation_debt_ceiling(collateral_id).unwrap_or_default(),
+            T::ReferenceAssetId::get(),
+        );
+        let mut total_debt_value = Self::get_asset_value(borrow)?;
+        for (asset_id, _) in IsolationDebt::<T>::iter_prefix(collateral_id) {
+            let debt = Self::isolation_debt(collateral_id, asset_id)?;
+            total_debt_value = total_debt_value.checked_add(&Self::get_asset_value(&debt)?)?;
+        }
+        ensure!(
+            total_debt_value.le(&debt_ceiling)?,
+            Error::<T>::IsolationDebtCeilingExceeded
+        );
+        let debt = Self::isolation_debt(collateral_id, borrow.currency())?.checked_add(borrow)?;
+        Self::set_isolation_debt(collateral_id, &debt);
+        Ok(())
+    }
+
+    /// Releases `repayment` from the debt ceiling of the isolated collateral of `borrower`, if any
+    fn decrease_isolation_debt(borrower: &T::AccountId, repayment: &Amount<T>) -> DispatchResult {
+        if let Some(collateral_id) = Self::isolated_collateral(borrower)? {
+            let debt = Self::isolation_debt(collateral_id, repayment.currency())?.saturating_sub(repayment)?;
+            if debt.is_zero() {
+                IsolationDebt::<T>::remove(collateral_id, repayment.currency());
+            } else {
+                Self::set_isolation_debt(collateral_id, &debt);
+            }
+        }
+        Ok(())
+    }
+
     /// Transferrable balance in the pallet account (`free - frozen`)
     fn get_total_cash(asset_id: CurrencyId<T>) -> Amount<T> {
         // for the reducible_balance arguments, see:
         // - https://docs.rs/frame-support/latest/frame_support/traits/tokens/enum.Preservation.html
         // - https://docs.rs/frame-support/latest/frame_support/traits/tokens/enum.Fortitude.html
-        Amount::new(
-            orml_tokens::Pallet::<T>::reducible_balance(
-                asset_id,
-                &Self::account_id(),
-                Preservation::Expendable, // We don’t care if the account gets killed by this operation.
-                Fortitude::Polite,        // "The operation should execute with regular privilege", i.e. not forced
-            ),
+        let cash = orml_tokens::Pallet::<T>::reducible_balance(
             asset_id,
-        )
+            &Self::account_id(),
+            Preservation::Expendable, // We don’t care if the account gets killed by this operation.
+            Fortitude::Polite,        // "The operation should execute with regular privilege", i.e. not forced
+        );
+        // cash lent by a flash loan still counts towards the market, so that the exchange rate
+        // cannot be manipulated while the loan is outstanding
+        Amount::new(cash.saturating_add(Self::flash_loaned(asset_id).amount()), asset_id)
+    }
+
+    /// Cash that can currently be transferred out of the pallet account, which excludes the
+    /// cash lent by the flash loan being executed
+    fn get_available_cash(asset_id: CurrencyId<T>) -> Result<Amount<T>, DispatchError> {
+        Self::get_total_cash(asset_id).checked_sub(&Self::flash_loaned(asset_id))
+    }
+
+    /// Amount of the market's cash lent by the flash loan being executed
+    fn flash_loaned(asset_id: CurrencyId<T>) -> Amount<T> {
+        match ActiveFlashLoan::<T>::get() {
+            Some((currency_id, amount)) if currency_id == asset_id => Amount::new(amount, asset_id),
+            _ => Amount::zero(asset_id),
+        }
     }
 
     /// Get the total balance of `who`.
@@ -1865,6 +3871,21 @@ impl<T: Config> Pallet<T> {
     pub fn incentive_reward_account_id() -> T::AccountId {
         T::PalletId::get().into_sub_account_truncating(INCENTIVE_SUB_ACCOUNT)
     }
+
+    /// Account through which protocol liquidations repay debt and seize collateral
+    pub fn protocol_liquidator_account_id() -> T::AccountId {
+        T::PalletId::get().into_sub_account_truncating(LIQUIDATOR_SUB_ACCOUNT)
+    }
+
+    /// Account holding the positions of the reserve strategies
+    pub fn reserve_strategy_account_id() -> T::AccountId {
+        T::PalletId::get().into_sub_account_truncating(RESERVE_STRATEGY_SUB_ACCOUNT)
+    }
+
+    /// Account holding the liquidity of the fixed-term buckets
+    pub fn fixed_term_account_id() -> T::AccountId {
+        T::PalletId::get().into_sub_account_truncating(FIXED_TERM_SUB_ACCOUNT)
+    }
 }
 
 impl<T: Config> LoansTrait<CurrencyId<T>, AccountIdOf<T>, Amount<T>> for Pallet<T> {
@@ -1883,6 +3904,7 @@ impl<T: Config> LoansTrait<CurrencyId<T>, AccountIdOf<T>, Amount<T>> for Pallet<
         ensure!(!voucher.is_zero(), Error::<T>::InvalidExchangeRate);
 
         amount.transfer(supplier, &Self::account_id())?;
+        Self::record_inflow(amount);
 
         voucher.mint_to(supplier)?;
 
@@ -1895,43 +3917,14 @@ impl<T: Config> LoansTrait<CurrencyId<T>, AccountIdOf<T>, Amount<T>> for Pallet<
     }
 
     fn do_borrow(borrower: &AccountIdOf<T>, borrow: &Amount<T>) -> Result<(), DispatchError> {
-        let asset_id = borrow.currency();
-        Self::ensure_active_market(asset_id)?;
-
-        Self::accrue_interest(asset_id)?;
-        Self::borrow_allowed(borrower, &borrow)?;
-
-        // update borrow index after accrue interest.
-        Self::update_reward_borrow_index(asset_id)?;
-        Self::distribute_borrower_reward(asset_id, borrower)?;
-
-        let account_borrows = Self::current_borrow_balance(borrower, asset_id)?;
-        let account_borrows_new = account_borrows.checked_add(borrow)?;
-        let total_borrows = Self::total_borrows(asset_id);
-        let total_borrows_new = total_borrows.checked_add(&borrow)?;
-        AccountBorrows::<T>::insert(
-            asset_id,
-            borrower,
-            BorrowSnapshot {
-                principal: account_borrows_new.amount(),
-                borrow_index: Self::borrow_index(asset_id),
-            },
-        );
-        TotalBorrows::<T>::insert(asset_id, total_borrows_new.amount());
-        borrow.transfer(&Self::account_id(), borrower)?;
-
-        Self::deposit_event(Event::<T>::Borrowed {
-            account_id: borrower.clone(),
-            currency_id: asset_id,
-            amount: borrow.amount(),
-        });
-        Ok(())
+        Self::do_borrow_to(borrower, borrower, borrow)
     }
 
     fn do_deposit_collateral(supplier: &AccountIdOf<T>, lend_token_amount: &Amount<T>) -> Result<(), DispatchError> {
         // If the given asset_id is not a valid lend_token, fetching the underlying will fail
         let underlying_id = Self::underlying_id(lend_token_amount.currency())?;
         Self::ensure_active_market(underlying_id)?;
+        Self::ensure_collateral_can_be_combined(supplier, underlying_id)?;
 
         // Will fail if supplier has insufficient free tokens
         lend_token_amount.lock_on(supplier)?;
@@ -1955,7 +3948,7 @@ impl<T: Config> LoansTrait<CurrencyId<T>, AccountIdOf<T>, Amount<T>> for Pallet<
         Self::ensure_active_market(underlying_id)?;
 
         let total_collateral_value = Self::total_collateral_value(supplier)?;
-        let collateral_amount_value = Self::collateral_amount_value(&voucher)?;
+        let collateral_amount_value = Self::collateral_amount_value(supplier, &voucher)?;
         let total_borrowed_value = Self::total_borrowed_value(supplier)?;
         log::trace!(
             target: "loans::collateral_asset",
@@ -1995,17 +3988,7 @@ impl<T: Config> LoansTrait<CurrencyId<T>, AccountIdOf<T>, Amount<T>> for Pallet<
     }
 
     fn do_repay_borrow(borrower: &AccountIdOf<T>, borrow: &Amount<T>) -> Result<(), DispatchError> {
-        let asset_id = borrow.currency();
-        Self::ensure_active_market(asset_id)?;
-        Self::accrue_interest(asset_id)?;
-        let account_borrows = Self::current_borrow_balance(borrower, asset_id)?;
-        Self::do_repay_borrow_with_amount(borrower, asset_id, &account_borrows, &borrow)?;
-        Self::deposit_event(Event::<T>::RepaidBorrow {
-            account_id: borrower.clone(),
-            currency_id: asset_id,
-            amount: borrow.amount(),
-        });
-        Ok(())
+        Self::do_repay_borrow_from(borrower, borrower, borrow)
     }
 
     fn do_redeem(supplier: &AccountIdOf<T>, underlying: &Amount<T>, voucher: &Amount<T>) -> Result<(), DispatchError> {
@@ -2023,6 +4006,7 @@ impl<T: Config> LoansTrait<CurrencyId<T>, AccountIdOf<T>, Amount<T>> for Pallet<
         Self::accrue_interest(asset_id)?;
 
         Self::redeem_allowed(supplier, &voucher)?;
+        Self::record_outflow(underlying)?;
 
         Self::update_reward_supply_index(asset_id)?;
         Self::distribute_supplier_reward(asset_id, supplier)?;
@@ -2107,14 +4091,7 @@ impl<T: Config> LoansMarketDataProvider<CurrencyId<T>, BalanceOf<T>> for Pallet<
     }
 
     fn get_full_interest_rate(asset_id: CurrencyId<T>) -> Option<Rate> {
-        if let Ok(market) = Self::market(asset_id) {
-            let rate = match market.rate_model {
-                InterestRateModel::Jump(jump) => Some(jump.full_rate),
-                _ => None,
-            };
-            return rate;
-        }
-        None
+        Self::market(asset_id).ok()?.rate_model.get_full_rate()
     }
 }
 
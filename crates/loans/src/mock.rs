@@ -34,16 +34,17 @@ use primitives::{
     DOT, IBTC, INTR, KBTC, KINT, KSM,
 };
 use sp_core::H256;
-use sp_runtime::{traits::IdentityLookup, AccountId32, BuildStorage, FixedI128};
+use sp_runtime::{testing::TestXt, traits::IdentityLookup, AccountId32, BuildStorage, FixedI128};
 use traits::OracleApi;
 
+pub(crate) type Extrinsic = TestXt<RuntimeCall, ()>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
 construct_runtime!(
     pub enum Test
     {
         System: frame_system::{Pallet, Call, Storage, Config<T>, Event<T>},
-        Loans: loans::{Pallet, Call, Storage, Event<T>, Config<T>},
+        Loans: loans::{Pallet, Call, Storage, Event<T>, Config<T>, ValidateUnsigned},
         TimestampPallet: pallet_timestamp::{Pallet, Call, Storage, Inherent},
         Tokens: orml_tokens::{Pallet, Call, Storage, Config<T>, Event<T>},
         Currency: currency::{Pallet},
@@ -206,11 +207,20 @@ impl pallet_utility::Config for Test {
     type WeightInfo = ();
 }
 
+parameter_types! {
+    pub const OracleTreasuryAccountId: AccountId = AccountId32::new([100u8; 32]);
+}
+
 impl oracle::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type OnExchangeRateChange = ();
+    type DexPriceCumulative = ();
+    type DexStableLpPrice = ();
+    type DexPairReserves = ();
+    type TreasuryAccountId = OracleTreasuryAccountId;
     type WeightInfo = ();
     type MaxNameLength = ConstU32<255>;
+    type MaxPriceHistoryLength = ConstU32<10>;
 }
 
 impl security::Config for Test {
@@ -226,6 +236,64 @@ parameter_types! {
     pub const LoansPalletId: PalletId = PalletId(*b"par/loan");
 }
 
+/// Swaps at a 1:1 rate
+pub struct MockSwap;
+
+impl traits::SwapApi<AccountId, CurrencyId, Balance> for MockSwap {
+    fn swap_exact_tokens_for_tokens(
+        who: &AccountId,
+        currency_in: CurrencyId,
+        amount_in: Balance,
+        currency_out: CurrencyId,
+        amount_out_min: Balance,
+    ) -> Result<Balance, DispatchError> {
+        ensure!(
+            amount_in >= amount_out_min,
+            DispatchError::Other("Insufficient target amount")
+        );
+        <Tokens as MultiCurrency<AccountId>>::withdraw(currency_in, who, amount_in)?;
+        <Tokens as MultiCurrency<AccountId>>::deposit(currency_out, who, amount_in)?;
+        Ok(amount_in)
+    }
+}
+
+/// Mints one LP token per deposited token
+pub struct MockStableLiquidity;
+
+impl traits::StableLiquidityApi<AccountId, CurrencyId, Balance> for MockStableLiquidity {
+    fn add_liquidity_one_currency(
+        who: &AccountId,
+        lp_currency_id: CurrencyId,
+        currency_id: CurrencyId,
+        amount: Balance,
+        min_mint_amount: Balance,
+    ) -> Result<Balance, DispatchError> {
+        ensure!(
+            amount >= min_mint_amount,
+            DispatchError::Other("Insufficient mint amount")
+        );
+        <Tokens as MultiCurrency<AccountId>>::withdraw(currency_id, who, amount)?;
+        <Tokens as MultiCurrency<AccountId>>::deposit(lp_currency_id, who, amount)?;
+        Ok(amount)
+    }
+
+    fn remove_liquidity_one_currency(
+        who: &AccountId,
+        lp_currency_id: CurrencyId,
+        currency_id: CurrencyId,
+        lp_amount: Balance,
+        min_amount: Balance,
+    ) -> Result<Balance, DispatchError> {
+        ensure!(
+            lp_amount >= min_amount,
+            DispatchError::Other("Insufficient target amount")
+        );
+        <Tokens as MultiCurrency<AccountId>>::withdraw(lp_currency_id, who, lp_amount)?;
+        <Tokens as MultiCurrency<AccountId>>::deposit(currency_id, who, lp_amount)?;
+        Ok(lp_amount)
+    }
+}
+
 impl Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type PalletId = LoansPalletId;
@@ -236,6 +304,18 @@ impl Config for Test {
     type RewardAssetId = GetNativeCurrencyId;
     type ReferenceAssetId = GetWrappedCurrencyId;
     type OnExchangeRateChange = ();
+    type RuntimeCall = RuntimeCall;
+    type Swap = MockSwap;
+    type StableLiquidity = MockStableLiquidity;
+    type PriceFeeds = Oracle;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    type OverarchingCall = RuntimeCall;
+    type Extrinsic = Extrinsic;
 }
 
 pub const LEND_DOT: CurrencyId = LendToken(1);
@@ -336,7 +416,7 @@ pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
         Loans::add_market(RuntimeOrigin::root(), Token(IBTC), market_mock(LEND_IBTC)).unwrap();
         Loans::activate_market(RuntimeOrigin::root(), Token(IBTC)).unwrap();
 
-        System::set_block_number(0);
+        System::set_block_number(1);
         Security::set_active_block_number(1);
         TimestampPallet::set_timestamp(6000);
     });
@@ -365,7 +445,7 @@ pub(crate) fn new_test_ext_no_markets() -> sp_io::TestExternalities {
         // Set exchange rate with the reference currency to the default value
         CurrencyConvert::convert.mock_safe(with_price(None));
 
-        System::set_block_number(0);
+        System::set_block_number(1);
         TimestampPallet::set_timestamp(6000);
     });
     ext
@@ -16,9 +16,9 @@
 // limitations under the License.
 
 use codec::MaxEncodedLen;
-use primitives::{Rate, Ratio};
+use primitives::{Rate, Ratio, Timestamp, SECONDS_PER_YEAR};
 use scale_info::TypeInfo;
-use sp_runtime::traits::{CheckedAdd, CheckedDiv, CheckedSub, Saturating};
+use sp_runtime::traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Saturating};
 
 use crate::*;
 
@@ -39,6 +39,8 @@ use crate::*;
 pub enum InterestRateModel {
     Jump(JumpModel),
     Curve(CurveModel),
+    Kinked(KinkedModel),
+    Adaptive(AdaptiveModel),
 }
 
 impl Default for InterestRateModel {
@@ -65,6 +67,8 @@ impl InterestRateModel {
         match self {
             Self::Jump(jump) => jump.check_model(),
             Self::Curve(curve) => curve.check_model(),
+            Self::Kinked(kinked) => kinked.check_model(),
+            Self::Adaptive(adaptive) => adaptive.check_model(),
         }
     }
 
@@ -73,6 +77,27 @@ impl InterestRateModel {
         match self {
             Self::Jump(jump) => jump.get_borrow_rate(utilization),
             Self::Curve(curve) => curve.get_borrow_rate(utilization),
+            Self::Kinked(kinked) => kinked.get_borrow_rate(utilization),
+            Self::Adaptive(adaptive) => adaptive.get_borrow_rate(utilization),
+        }
+    }
+
+    /// Returns the model after `delta_time` seconds at `utilization`. Only the adaptive model
+    /// changes over time.
+    pub fn adapt(&self, utilization: Ratio, delta_time: Timestamp) -> Option<Self> {
+        match self {
+            Self::Adaptive(adaptive) => Some(Self::Adaptive(adaptive.adapt(utilization, delta_time)?)),
+            model => Some(*model),
+        }
+    }
+
+    /// The borrow interest rate at full utilization, if known
+    pub fn get_full_rate(&self) -> Option<Rate> {
+        match self {
+            Self::Jump(jump) => Some(jump.full_rate),
+            Self::Curve(_) => None,
+            Self::Kinked(kinked) => Some(kinked.full_rate),
+            Self::Adaptive(adaptive) => adaptive.get_borrow_rate(Ratio::one()),
         }
     }
 
@@ -207,6 +232,237 @@ impl CurveModel {
     }
 }
 
+/// Breakpoint of the kinked interest rate model
+#[derive(
+    serde::Deserialize,
+    serde::Serialize,
+    Encode,
+    Decode,
+    Eq,
+    PartialEq,
+    Copy,
+    Clone,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct Kink {
+    /// The utilization of the breakpoint
+    pub utilization: Ratio,
+    /// The interest rate at the breakpoint
+    pub rate: Rate,
+}
+
+/// The maximum number of breakpoints of the kinked interest rate model
+pub const MAX_KINKS: usize = 6;
+
+/// The kinked interest rate model: a piecewise-linear curve from `base_rate` at 0% utilization
+/// to `full_rate` at 100% utilization, through an arbitrary number of breakpoints.
+#[derive(
+    serde::Deserialize,
+    serde::Serialize,
+    Encode,
+    Decode,
+    Eq,
+    PartialEq,
+    Copy,
+    Clone,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct KinkedModel {
+    /// The base interest rate when utilization rate is 0
+    pub base_rate: Rate,
+    /// The breakpoints in increasing order of utilization. Unused breakpoints are `None` and
+    /// must come after all used ones.
+    pub kinks: [Option<Kink>; MAX_KINKS],
+    /// The max interest rate when utilization rate is 100%
+    pub full_rate: Rate,
+}
+
+impl KinkedModel {
+    pub const MAX_BASE_RATE: Rate = Rate::from_inner(100_000_000_000_000_000); // 10%
+    pub const MAX_FULL_RATE: Rate = Rate::from_inner(5_000_000_000_000_000_000); // 500%
+
+    /// Create a new kinked model. Fails if there are more than `MAX_KINKS` breakpoints.
+    pub fn new_model(base_rate: Rate, kinks: &[Kink], full_rate: Rate) -> Option<KinkedModel> {
+        if kinks.len() > MAX_KINKS {
+            return None;
+        }
+        let mut model = Self {
+            base_rate,
+            kinks: [None; MAX_KINKS],
+            full_rate,
+        };
+        for (slot, kink) in model.kinks.iter_mut().zip(kinks) {
+            *slot = Some(*kink);
+        }
+        Some(model)
+    }
+
+    /// All points of the curve, including 0% and 100% utilization
+    fn points(&self) -> impl Iterator<Item = (Ratio, Rate)> + '_ {
+        sp_std::iter::once((Ratio::zero(), self.base_rate))
+            .chain(self.kinks.iter().flatten().map(|kink| (kink.utilization, kink.rate)))
+            .chain(sp_std::iter::once((Ratio::one(), self.full_rate)))
+    }
+
+    /// Check the kinked model for sanity
+    pub fn check_model(&self) -> bool {
+        if self.base_rate > Self::MAX_BASE_RATE || self.full_rate > Self::MAX_FULL_RATE {
+            return false;
+        }
+        // used breakpoints must come first
+        if self
+            .kinks
+            .iter()
+            .skip_while(|kink| kink.is_some())
+            .any(|kink| kink.is_some())
+        {
+            return false;
+        }
+        // utilization must be strictly increasing and rates must not decrease
+        let points = self.points().collect::<Vec<_>>();
+        points
+            .windows(2)
+            .all(|pair| pair[0].0 < pair[1].0 && pair[0].1 <= pair[1].1)
+    }
+
+    /// Calculates the borrow interest rate of kinked model
+    pub fn get_borrow_rate(&self, utilization: Ratio) -> Option<Rate> {
+        let mut lower = (Ratio::zero(), self.base_rate);
+        for upper in self.points().skip(1) {
+            if utilization <= upper.0 {
+                // (utilization - lower_utilization) * (upper_rate - lower_rate) / (upper_utilization -
+                // lower_utilization) + lower_rate
+                let result = upper
+                    .1
+                    .checked_sub(&lower.1)?
+                    .saturating_mul(utilization.saturating_sub(lower.0).into())
+                    .checked_div(&upper.0.saturating_sub(lower.0).into())?
+                    .checked_add(&lower.1)?;
+                return Some(result);
+            }
+            lower = upper;
+        }
+        Some(self.full_rate)
+    }
+}
+
+/// The adaptive interest rate model. The rate at the target utilization continuously moves
+/// towards the rate that brings utilization back to the target: it increases while utilization
+/// is above the target and decreases while it is below, the faster the larger the deviation.
+#[derive(
+    serde::Deserialize,
+    serde::Serialize,
+    Encode,
+    Decode,
+    Eq,
+    PartialEq,
+    Copy,
+    Clone,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct AdaptiveModel {
+    /// The utilization the model steers towards
+    pub target_utilization: Ratio,
+    /// The borrow rate at 100% utilization is `curve_steepness` times the rate at target, the
+    /// borrow rate at 0% utilization is the rate at target divided by `curve_steepness`
+    pub curve_steepness: Rate,
+    /// The yearly relative change of the rate at target when utilization is 100%
+    pub adjustment_speed: Rate,
+    /// The current interest rate at the target utilization
+    pub rate_at_target: Rate,
+    /// Lower bound of the rate at target
+    pub min_rate_at_target: Rate,
+    /// Upper bound of the rate at target
+    pub max_rate_at_target: Rate,
+}
+
+impl AdaptiveModel {
+    pub const MAX_CURVE_STEEPNESS: Rate = Rate::from_inner(100_000_000_000_000_000_000); // 100x
+    pub const MAX_ADJUSTMENT_SPEED: Rate = Rate::from_inner(1_000_000_000_000_000_000_000); // 1000 per year
+    pub const MAX_FULL_RATE: Rate = Rate::from_inner(5_000_000_000_000_000_000); // 500%
+
+    /// Check the adaptive model for sanity
+    pub fn check_model(&self) -> bool {
+        if self.target_utilization.is_zero() || self.target_utilization >= Ratio::one() {
+            return false;
+        }
+        if self.curve_steepness < Rate::one() || self.curve_steepness > Self::MAX_CURVE_STEEPNESS {
+            return false;
+        }
+        if self.adjustment_speed > Self::MAX_ADJUSTMENT_SPEED {
+            return false;
+        }
+        if self.min_rate_at_target > self.rate_at_target || self.rate_at_target > self.max_rate_at_target {
+            return false;
+        }
+        match self.max_rate_at_target.checked_mul(&self.curve_steepness) {
+            Some(max_full_rate) => max_full_rate <= Self::MAX_FULL_RATE,
+            None => false,
+        }
+    }
+
+    /// Distance of `utilization` from the target, normalized to [0, 1] in either direction.
+    /// Returns whether utilization is above the target, and the distance.
+    fn error(&self, utilization: Ratio) -> Option<(bool, Rate)> {
+        if utilization > self.target_utilization {
+            let error = Rate::from(utilization.saturating_sub(self.target_utilization))
+                .checked_div(&Ratio::one().saturating_sub(self.target_utilization).into())?;
+            Some((true, error))
+        } else {
+            let error = Rate::from(self.target_utilization.saturating_sub(utilization))
+                .checked_div(&self.target_utilization.into())?;
+            Some((false, error))
+        }
+    }
+
+    /// Calculates the borrow interest rate of adaptive model
+    pub fn get_borrow_rate(&self, utilization: Ratio) -> Option<Rate> {
+        let (above_target, error) = self.error(utilization)?;
+        let factor = if above_target {
+            // 1 + (curve_steepness - 1) * error
+            self.curve_steepness
+                .checked_sub(&Rate::one())?
+                .checked_mul(&error)?
+                .checked_add(&Rate::one())?
+        } else {
+            // 1 - (1 - 1 / curve_steepness) * error
+            let flattening = Rate::one().checked_sub(&Rate::one().checked_div(&self.curve_steepness)?)?;
+            Rate::one().checked_sub(&flattening.checked_mul(&error)?)?
+        };
+        self.rate_at_target.checked_mul(&factor)
+    }
+
+    /// Moves the rate at target after `delta_time` seconds at `utilization`:
+    /// rate_at_target * (1 +/- adjustment_speed * error / SECONDS_PER_YEAR) ^ delta_time
+    pub fn adapt(&self, utilization: Ratio, delta_time: Timestamp) -> Option<AdaptiveModel> {
+        let (above_target, error) = self.error(utilization)?;
+        let speed_per_second = self
+            .adjustment_speed
+            .checked_mul(&error)?
+            .checked_div(&Rate::saturating_from_integer(SECONDS_PER_YEAR))?;
+        let step = if above_target {
+            Rate::one().checked_add(&speed_per_second)?
+        } else {
+            Rate::one().saturating_sub(speed_per_second)
+        };
+        let rate_at_target = self
+            .rate_at_target
+            .saturating_mul(step.saturating_pow(delta_time.try_into().ok()?))
+            .max(self.min_rate_at_target)
+            .min(self.max_rate_at_target);
+        Some(AdaptiveModel {
+            rate_at_target,
+            ..*self
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -15,11 +15,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod credit_delegation;
 mod edge_cases;
+mod fixed_term;
+mod flash_loan;
 mod interest_rate;
 pub mod lend_tokens;
 mod liquidate_borrow;
 mod market;
+mod market_categories;
+mod market_limits;
+mod protocol_liquidation;
+mod reserve_strategies;
 
 use currency::Amount;
 use frame_support::{assert_noop, assert_ok};
@@ -565,6 +572,51 @@ fn get_account_liquidation_threshold_liquidity_works() {
     })
 }
 
+#[test]
+fn get_account_position_works() {
+    new_test_ext().execute_with(|| {
+        Loans::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)).unwrap();
+        Loans::mint(RuntimeOrigin::signed(ALICE), IBTC, unit(200)).unwrap();
+        Loans::deposit_all_collateral(RuntimeOrigin::signed(ALICE), IBTC).unwrap();
+        Loans::mint(RuntimeOrigin::signed(ALICE), USDT, unit(200)).unwrap();
+
+        // no debt, so no health factor
+        let position = Loans::get_account_position(&ALICE).unwrap();
+        assert_eq!(position.markets.len(), 2);
+        assert_eq!(position.health_factor, None);
+        assert_eq!(position.liquidation_price_drop, None);
+
+        Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(88)).unwrap();
+        let position = Loans::get_account_position(&ALICE).unwrap();
+        let market_position = |currency_id| {
+            position
+                .markets
+                .iter()
+                .find(|market| market.currency_id == currency_id)
+                .cloned()
+                .unwrap()
+        };
+        assert_eq!(
+            market_position(IBTC),
+            MarketPosition {
+                currency_id: IBTC,
+                supplied: unit(200),
+                lend_tokens: Loans::free_lend_tokens(IBTC, &ALICE).unwrap().amount()
+                    + Loans::reserved_lend_tokens(IBTC, &ALICE).unwrap().amount(),
+                is_collateral: true,
+                borrowed: 0,
+            }
+        );
+        assert!(!market_position(USDT).is_collateral);
+        assert_eq!(market_position(USDT).supplied, unit(200));
+        assert_eq!(market_position(KSM).borrowed, unit(88));
+
+        // liquidation threshold value of the collateral: 200 * 55% = 110
+        assert_eq!(position.health_factor, Some(Rate::saturating_from_rational(110, 88)));
+        assert_eq!(position.liquidation_price_drop, Some(Permill::from_percent(20)));
+    })
+}
+
 #[test]
 fn borrow_works() {
     new_test_ext().execute_with(|| {
@@ -0,0 +1,187 @@
+use super::*;
+use crate::{mock::*, tests::Loans, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use mocktopus::mocking::Mockable;
+use primitives::{
+    CurrencyId::{self, Token},
+    DOT, INTR, KSM,
+};
+
+fn ksm_balance(who: &AccountId) -> u128 {
+    <Tokens as MultiCurrency<_>>::free_balance(Token(KSM), who)
+}
+
+/// Alice supplies $200 of DOT collateral, allowing her to borrow $100
+fn alice_supplies_collateral() {
+    assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), Token(KSM), unit(200)));
+    assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), Token(DOT), unit(200)));
+    assert_ok!(Loans::deposit_all_collateral(RuntimeOrigin::signed(ALICE), Token(DOT)));
+}
+
+fn alice_delegates_to_charlie(asset_id: CurrencyId, allowance: u128) {
+    assert_ok!(Loans::approve_delegation(
+        RuntimeOrigin::signed(ALICE),
+        CHARLIE,
+        asset_id,
+        allowance
+    ));
+}
+
+#[test]
+fn approve_delegation_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Loans::approve_delegation(RuntimeOrigin::signed(ALICE), ALICE, Token(KSM), unit(50)),
+            Error::<Test>::SelfDelegation
+        );
+        assert_noop!(
+            Loans::approve_delegation(RuntimeOrigin::signed(ALICE), CHARLIE, Token(INTR), unit(50)),
+            Error::<Test>::MarketDoesNotExist
+        );
+
+        alice_delegates_to_charlie(Token(KSM), unit(50));
+        assert_eq!(Loans::borrow_allowance(ALICE, (CHARLIE, Token(KSM))), unit(50));
+        System::assert_last_event(RuntimeEvent::Loans(Event::BorrowAllowanceUpdated {
+            owner: ALICE,
+            delegatee: CHARLIE,
+            currency_id: Token(KSM),
+            allowance: unit(50),
+        }));
+
+        // a zero allowance revokes the delegation
+        alice_delegates_to_charlie(Token(KSM), 0);
+        assert!(!BorrowAllowances::<Test>::contains_key(ALICE, (CHARLIE, Token(KSM))));
+    })
+}
+
+#[test]
+fn delegatee_borrows_against_owner_collateral() {
+    new_test_ext().execute_with(|| {
+        alice_supplies_collateral();
+        alice_delegates_to_charlie(Token(KSM), unit(50));
+        let alice_ksm = ksm_balance(&ALICE);
+
+        assert_ok!(Loans::borrow_on_behalf(
+            RuntimeOrigin::signed(CHARLIE),
+            ALICE,
+            Token(KSM),
+            unit(30)
+        ));
+        System::assert_last_event(RuntimeEvent::Loans(Event::DelegatedBorrow {
+            owner: ALICE,
+            delegatee: CHARLIE,
+            currency_id: Token(KSM),
+            amount: unit(30),
+        }));
+
+        // the funds go to the delegatee, the debt to the owner
+        assert_eq!(ksm_balance(&CHARLIE), unit(30));
+        assert_eq!(ksm_balance(&ALICE), alice_ksm);
+        assert_eq!(Loans::account_borrows(Token(KSM), ALICE).principal, unit(30));
+        assert_eq!(Loans::account_borrows(Token(KSM), CHARLIE).principal, 0);
+        assert_eq!(
+            Loans::get_account_liquidity(&ALICE).unwrap().liquidity().amount(),
+            unit(70)
+        );
+        assert_eq!(Loans::borrow_allowance(ALICE, (CHARLIE, Token(KSM))), unit(20));
+
+        // the allowance is per asset and per delegatee
+        assert_noop!(
+            Loans::borrow_on_behalf(RuntimeOrigin::signed(CHARLIE), ALICE, Token(KSM), unit(21)),
+            Error::<Test>::BorrowAllowanceExceeded
+        );
+        assert_noop!(
+            Loans::borrow_on_behalf(RuntimeOrigin::signed(CHARLIE), ALICE, Token(DOT), 1),
+            Error::<Test>::BorrowAllowanceExceeded
+        );
+        assert_noop!(
+            Loans::borrow_on_behalf(RuntimeOrigin::signed(DAVE), ALICE, Token(KSM), 1),
+            Error::<Test>::BorrowAllowanceExceeded
+        );
+        assert_noop!(
+            Loans::borrow_on_behalf(RuntimeOrigin::signed(CHARLIE), ALICE, Token(KSM), 0),
+            Error::<Test>::InvalidAmount
+        );
+    })
+}
+
+#[test]
+fn delegated_borrow_is_limited_by_owner_liquidity() {
+    new_test_ext().execute_with(|| {
+        alice_supplies_collateral();
+        alice_delegates_to_charlie(Token(KSM), unit(150));
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), Token(KSM), unit(60)));
+
+        assert_noop!(
+            Loans::borrow_on_behalf(RuntimeOrigin::signed(CHARLIE), ALICE, Token(KSM), unit(41)),
+            Error::<Test>::InsufficientLiquidity
+        );
+        assert_ok!(Loans::borrow_on_behalf(
+            RuntimeOrigin::signed(CHARLIE),
+            ALICE,
+            Token(KSM),
+            unit(40)
+        ));
+        assert_eq!(Loans::account_borrows(Token(KSM), ALICE).principal, unit(100));
+    })
+}
+
+#[test]
+fn anyone_can_repay_on_behalf_of_borrower() {
+    new_test_ext().execute_with(|| {
+        alice_supplies_collateral();
+        alice_delegates_to_charlie(Token(KSM), unit(50));
+        assert_ok!(Loans::borrow_on_behalf(
+            RuntimeOrigin::signed(CHARLIE),
+            ALICE,
+            Token(KSM),
+            unit(30)
+        ));
+        let bob_ksm = ksm_balance(&BOB);
+
+        assert_noop!(
+            Loans::repay_borrow_on_behalf(RuntimeOrigin::signed(BOB), ALICE, Token(KSM), unit(31)),
+            Error::<Test>::TooMuchRepay
+        );
+        assert_ok!(Loans::repay_borrow_on_behalf(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            Token(KSM),
+            unit(10)
+        ));
+        assert_eq!(ksm_balance(&BOB), bob_ksm - unit(10));
+        assert_eq!(ksm_balance(&CHARLIE), unit(30));
+        assert_eq!(Loans::account_borrows(Token(KSM), ALICE).principal, unit(20));
+    })
+}
+
+#[test]
+fn delegated_debt_liquidates_owner_collateral() {
+    new_test_ext().execute_with(|| {
+        alice_supplies_collateral();
+        alice_delegates_to_charlie(Token(KSM), unit(50));
+        assert_ok!(Loans::borrow_on_behalf(
+            RuntimeOrigin::signed(CHARLIE),
+            ALICE,
+            Token(KSM),
+            unit(30)
+        ));
+
+        // Collateral   Loans
+        // DOT $110     KSM $120
+        CurrencyConvert::convert.mock_safe(with_price(Some((KSM, 4.into()))));
+        assert!(!Loans::get_account_liquidation_threshold_liquidity(&ALICE)
+            .unwrap()
+            .shortfall()
+            .is_zero());
+        assert_ok!(Loans::liquidate_borrow(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            Token(KSM),
+            unit(15),
+            Token(DOT)
+        ));
+        assert_eq!(Loans::account_borrows(Token(KSM), ALICE).principal, unit(15));
+        assert_eq!(ksm_balance(&CHARLIE), unit(30));
+    })
+}
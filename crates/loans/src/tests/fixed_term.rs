@@ -0,0 +1,359 @@
+use super::*;
+use crate::{mock::*, tests::Loans, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use mocktopus::mocking::Mockable;
+use primitives::{CurrencyId::Token, FixedTermPosition, Rate, Ratio, DOT, INTR, KSM, SECONDS_PER_YEAR};
+use sp_runtime::{traits::Zero, DispatchError, FixedPointNumber};
+
+/// The mock starts at 6 seconds, so the bucket matures after exactly one year
+const MATURITY: u64 = 6 + SECONDS_PER_YEAR;
+
+fn ksm_balance(who: &AccountId) -> u128 {
+    <Tokens as MultiCurrency<_>>::free_balance(Token(KSM), who)
+}
+
+fn set_time(seconds: u64) {
+    TimestampPallet::set_timestamp(seconds * 1000);
+}
+
+/// Opens a KSM bucket at 10% a year, with an early repayment penalty of half the waived
+/// interest and an overdue penalty of 10% a year, into which Bob deposits 100 KSM
+fn open_bucket() {
+    assert_ok!(Loans::open_fixed_term_bucket(
+        RuntimeOrigin::root(),
+        Token(KSM),
+        MATURITY,
+        Rate::saturating_from_rational(10, 100),
+        Ratio::from_percent(50),
+        Rate::saturating_from_rational(10, 100),
+    ));
+    assert_ok!(Loans::deposit_fixed_term(
+        RuntimeOrigin::signed(BOB),
+        Token(KSM),
+        MATURITY,
+        unit(100)
+    ));
+}
+
+/// Alice supplies $200 of DOT collateral and borrows `amount` KSM from the bucket
+fn alice_borrows_fixed_term(amount: u128) {
+    assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), Token(DOT), unit(200)));
+    assert_ok!(Loans::deposit_all_collateral(RuntimeOrigin::signed(ALICE), Token(DOT)));
+    assert_ok!(Loans::borrow_fixed_term(
+        RuntimeOrigin::signed(ALICE),
+        Token(KSM),
+        MATURITY,
+        amount
+    ));
+}
+
+fn alice_debt() -> u128 {
+    let loan = Loans::fixed_term_borrow(ALICE, (Token(KSM), MATURITY)).unwrap();
+    let bucket = Loans::fixed_term_bucket(Token(KSM), MATURITY).unwrap();
+    Loans::fixed_term_debt(&loan, &bucket, Token(KSM), MATURITY, TimestampPallet::now() / 1000)
+        .unwrap()
+        .amount()
+}
+
+#[test]
+fn open_fixed_term_bucket_works() {
+    new_test_ext().execute_with(|| {
+        let open = |origin, asset_id, maturity| {
+            Loans::open_fixed_term_bucket(origin, asset_id, maturity, Rate::zero(), Ratio::zero(), Rate::zero())
+        };
+        assert_noop!(
+            open(RuntimeOrigin::signed(ALICE), Token(KSM), MATURITY),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            open(RuntimeOrigin::root(), Token(INTR), MATURITY),
+            Error::<Test>::MarketDoesNotExist
+        );
+        assert_noop!(
+            open(RuntimeOrigin::root(), Token(KSM), 6),
+            Error::<Test>::InvalidMaturity
+        );
+
+        assert_ok!(open(RuntimeOrigin::root(), Token(KSM), MATURITY));
+        System::assert_last_event(RuntimeEvent::Loans(Event::FixedTermBucketOpened {
+            currency_id: Token(KSM),
+            maturity: MATURITY,
+            rate: Rate::zero(),
+            early_repayment_penalty: Ratio::zero(),
+            overdue_penalty_rate: Rate::zero(),
+        }));
+        assert_noop!(
+            open(RuntimeOrigin::root(), Token(KSM), MATURITY),
+            Error::<Test>::FixedTermBucketAlreadyExists
+        );
+    })
+}
+
+#[test]
+fn fixed_term_lending_works() {
+    new_test_ext().execute_with(|| {
+        open_bucket();
+        alice_borrows_fixed_term(unit(50));
+        System::assert_last_event(RuntimeEvent::Loans(Event::FixedTermBorrowed {
+            account_id: ALICE,
+            currency_id: Token(KSM),
+            maturity: MATURITY,
+            amount: unit(50),
+            interest: unit(5),
+        }));
+        let bucket = Loans::fixed_term_bucket(Token(KSM), MATURITY).unwrap();
+        assert_eq!(bucket.cash, unit(50));
+        assert_eq!(bucket.total_debt, unit(55));
+
+        assert_noop!(
+            Loans::borrow_fixed_term(RuntimeOrigin::signed(ALICE), Token(KSM), MATURITY, unit(51)),
+            Error::<Test>::InsufficientCash
+        );
+        assert_noop!(
+            Loans::withdraw_fixed_term(RuntimeOrigin::signed(BOB), Token(KSM), MATURITY, unit(10)),
+            Error::<Test>::FixedTermBucketNotMatured
+        );
+
+        set_time(MATURITY);
+        assert_noop!(
+            Loans::deposit_fixed_term(RuntimeOrigin::signed(BOB), Token(KSM), MATURITY, unit(10)),
+            Error::<Test>::FixedTermBucketMatured
+        );
+        assert_eq!(alice_debt(), unit(55));
+        assert_ok!(Loans::repay_fixed_term(
+            RuntimeOrigin::signed(ALICE),
+            Token(KSM),
+            MATURITY,
+            unit(55)
+        ));
+        assert_eq!(Loans::fixed_term_borrow(ALICE, (Token(KSM), MATURITY)), None);
+
+        // the lender earns the interest paid by the borrower
+        let bob_balance = ksm_balance(&BOB);
+        assert_ok!(Loans::withdraw_fixed_term(
+            RuntimeOrigin::signed(BOB),
+            Token(KSM),
+            MATURITY,
+            unit(105)
+        ));
+        assert_eq!(ksm_balance(&BOB), bob_balance + unit(105));
+        assert_eq!(Loans::fixed_term_deposit((Token(KSM), MATURITY), BOB), 0);
+    })
+}
+
+#[test]
+fn early_repayment_waives_part_of_the_remaining_interest() {
+    new_test_ext().execute_with(|| {
+        open_bucket();
+        alice_borrows_fixed_term(unit(50));
+
+        // half of the 2.5 KSM interest of the remaining half year is waived
+        set_time(6 + SECONDS_PER_YEAR / 2);
+        assert_eq!(alice_debt(), unit(5375) / 100);
+        assert_noop!(
+            Loans::repay_fixed_term(RuntimeOrigin::signed(ALICE), Token(KSM), MATURITY, unit(54)),
+            Error::<Test>::TooMuchRepay
+        );
+        assert_ok!(Loans::repay_fixed_term(
+            RuntimeOrigin::signed(ALICE),
+            Token(KSM),
+            MATURITY,
+            unit(5375) / 100
+        ));
+        assert_eq!(Loans::fixed_term_borrow(ALICE, (Token(KSM), MATURITY)), None);
+        let bucket = Loans::fixed_term_bucket(Token(KSM), MATURITY).unwrap();
+        assert_eq!(bucket.cash, unit(10375) / 100);
+        assert_eq!(bucket.total_debt, 0);
+    })
+}
+
+#[test]
+fn overdue_debt_accrues_penalty() {
+    new_test_ext().execute_with(|| {
+        open_bucket();
+        alice_borrows_fixed_term(unit(50));
+
+        set_time(MATURITY + SECONDS_PER_YEAR / 2);
+        assert_eq!(alice_debt(), unit(5775) / 100);
+
+        // repaying half of the debt halves the principal and interest
+        assert_ok!(Loans::repay_fixed_term(
+            RuntimeOrigin::signed(ALICE),
+            Token(KSM),
+            MATURITY,
+            unit(57750) / 2000
+        ));
+        assert_eq!(
+            Loans::fixed_term_borrow(ALICE, (Token(KSM), MATURITY)),
+            Some(FixedTermLoan {
+                principal: unit(25),
+                interest: unit(25) / 10,
+            })
+        );
+    })
+}
+
+#[test]
+fn fixed_term_debt_counts_towards_liquidity() {
+    new_test_ext().execute_with(|| {
+        open_bucket();
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), Token(KSM), unit(100)));
+        // the $200 of DOT collateral allows borrowing $100, of which the fixed-term debt
+        // takes $63 including the early repayment penalty
+        alice_borrows_fixed_term(unit(60));
+        assert_noop!(
+            Loans::borrow(RuntimeOrigin::signed(ALICE), Token(KSM), unit(40)),
+            Error::<Test>::InsufficientLiquidity
+        );
+        assert_noop!(
+            Loans::borrow_fixed_term(RuntimeOrigin::signed(ALICE), Token(KSM), MATURITY, unit(40)),
+            Error::<Test>::InsufficientLiquidity
+        );
+    })
+}
+
+#[test]
+fn account_position_reports_fixed_term_borrows() {
+    new_test_ext().execute_with(|| {
+        open_bucket();
+        alice_borrows_fixed_term(unit(60));
+
+        let position = Loans::get_account_position(&ALICE).unwrap();
+        assert_eq!(
+            position.fixed_term_borrows,
+            vec![FixedTermPosition {
+                currency_id: Token(KSM),
+                maturity: MATURITY,
+                borrowed: alice_debt(),
+            }]
+        );
+        assert!(position.markets.iter().all(|market| market.borrowed.is_zero()));
+        assert!(position.health_factor.is_some());
+    })
+}
+
+#[test]
+fn liquidate_fixed_term_borrow_works() {
+    new_test_ext().execute_with(|| {
+        open_bucket();
+        alice_borrows_fixed_term(unit(90));
+        let debt = alice_debt();
+
+        assert_noop!(
+            Loans::liquidate_fixed_term_borrow(
+                RuntimeOrigin::signed(BOB),
+                ALICE,
+                Token(KSM),
+                MATURITY,
+                unit(10),
+                Token(DOT)
+            ),
+            Error::<Test>::InsufficientShortfall
+        );
+
+        // the KSM price doubles, so the $110 liquidation threshold of the collateral no longer
+        // covers the debt
+        CurrencyConvert::convert.mock_safe(with_price(Some((Token(KSM), 2.into()))));
+        assert_noop!(
+            Loans::liquidate_fixed_term_borrow(
+                RuntimeOrigin::signed(BOB),
+                ALICE,
+                Token(KSM),
+                MATURITY,
+                debt / 2 + 1,
+                Token(DOT)
+            ),
+            Error::<Test>::TooMuchRepay
+        );
+        assert_ok!(Loans::liquidate_fixed_term_borrow(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            Token(KSM),
+            MATURITY,
+            unit(40),
+            Token(DOT)
+        ));
+        System::assert_last_event(RuntimeEvent::Loans(Event::FixedTermBorrowLiquidated {
+            liquidator: BOB,
+            borrower: ALICE,
+            currency_id: Token(KSM),
+            maturity: MATURITY,
+            collateral_currency_id: Token(DOT),
+            repay_amount: unit(40),
+            collateral_underlying_amount: unit(88),
+        }));
+        assert_eq!(Loans::fixed_term_bucket(Token(KSM), MATURITY).unwrap().cash, unit(50));
+        assert!(alice_debt() < debt);
+        assert!(!Loans::free_lend_tokens(Token(DOT), &BOB).unwrap().is_zero());
+    })
+}
+
+#[test]
+fn fixed_term_bad_debt_is_written_off_against_reserves() {
+    new_test_ext().execute_with(|| {
+        open_bucket();
+        alice_borrows_fixed_term(unit(90));
+        assert_ok!(Loans::add_reserves(RuntimeOrigin::root(), BOB, Token(KSM), unit(20)));
+        assert_noop!(
+            Loans::write_off_fixed_term_bad_debt(RuntimeOrigin::signed(BOB), ALICE, Token(KSM), MATURITY),
+            Error::<Test>::BorrowerHasAssets
+        );
+
+        // seize all of Alice's collateral: 40 KSM * $4 * 1.25 = $200
+        CurrencyConvert::convert.mock_safe(with_price(Some((Token(KSM), 4.into()))));
+        Loans::mutate_market(Token(KSM), |market| {
+            market.liquidate_incentive = Rate::saturating_from_rational(125, 100);
+            Ok(market.clone())
+        })
+        .unwrap();
+        assert_ok!(Loans::liquidate_fixed_term_borrow(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            Token(KSM),
+            MATURITY,
+            unit(40),
+            Token(DOT)
+        ));
+        let debt = alice_debt();
+
+        assert_ok!(Loans::write_off_fixed_term_bad_debt(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            Token(KSM),
+            MATURITY
+        ));
+        System::assert_last_event(RuntimeEvent::Loans(Event::FixedTermBadDebtWrittenOff {
+            borrower: ALICE,
+            currency_id: Token(KSM),
+            maturity: MATURITY,
+            amount: debt,
+            reserves_used: unit(20),
+        }));
+        assert!(Loans::fixed_term_borrow(ALICE, (Token(KSM), MATURITY)).is_none());
+        assert_eq!(Loans::total_reserves(Token(KSM)).amount(), 0);
+        // the reserves are moved into the bucket, the rest of the debt is lost by the lenders
+        let bucket = Loans::fixed_term_bucket(Token(KSM), MATURITY).unwrap();
+        assert_eq!(bucket.cash, unit(70));
+        assert_eq!(bucket.total_debt, 0);
+    })
+}
+
+#[test]
+fn overdue_fixed_term_borrow_can_be_liquidated() {
+    new_test_ext().execute_with(|| {
+        open_bucket();
+        alice_borrows_fixed_term(unit(10));
+
+        // Alice is healthy, but her loan is overdue
+        set_time(MATURITY + 1);
+        assert_ok!(Loans::liquidate_fixed_term_borrow(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            Token(KSM),
+            MATURITY,
+            unit(5),
+            Token(DOT)
+        ));
+        assert_eq!(Loans::fixed_term_bucket(Token(KSM), MATURITY).unwrap().cash, unit(95));
+    })
+}
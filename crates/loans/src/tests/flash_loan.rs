@@ -0,0 +1,224 @@
+use super::*;
+use crate::{mock::*, tests::Loans, Error, LendingAmountExt};
+use currency::Amount;
+use frame_support::{assert_noop, assert_ok};
+use primitives::{
+    CurrencyId::{self, Token},
+    Ratio, DOT, INTR, KSM,
+};
+use sp_runtime::DispatchError;
+
+const FLASH_LOAN_FEE: Ratio = Ratio::from_parts(900); // 0.09%
+
+fn enable_flash_loans(asset_id: CurrencyId) {
+    assert_ok!(Loans::set_flash_loan_fee(
+        RuntimeOrigin::root(),
+        asset_id,
+        Some(FLASH_LOAN_FEE)
+    ));
+}
+
+fn remark_call() -> Box<RuntimeCall> {
+    Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![] }))
+}
+
+fn transfer_call(dest: AccountId, currency_id: CurrencyId, amount: u128) -> Box<RuntimeCall> {
+    Box::new(RuntimeCall::Tokens(orml_tokens::Call::transfer {
+        dest,
+        currency_id,
+        amount,
+    }))
+}
+
+#[test]
+fn set_flash_loan_fee_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Loans::set_flash_loan_fee(RuntimeOrigin::signed(ALICE), Token(DOT), Some(FLASH_LOAN_FEE)),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Loans::set_flash_loan_fee(RuntimeOrigin::root(), Token(INTR), Some(FLASH_LOAN_FEE)),
+            Error::<Test>::MarketDoesNotExist
+        );
+
+        enable_flash_loans(Token(DOT));
+        assert_eq!(Loans::flash_loan_fee(Token(DOT)), Some(FLASH_LOAN_FEE));
+
+        assert_ok!(Loans::set_flash_loan_fee(RuntimeOrigin::root(), Token(DOT), None));
+        assert_eq!(Loans::flash_loan_fee(Token(DOT)), None);
+    })
+}
+
+#[test]
+fn flash_loan_adds_fee_to_reserves() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), Token(DOT), unit(1000)));
+        enable_flash_loans(Token(DOT));
+        let alice_balance = <Tokens as MultiCurrency<_>>::free_balance(Token(DOT), &ALICE);
+        let total_cash = Loans::get_total_cash(Token(DOT));
+
+        assert_ok!(Loans::flash_loan(
+            RuntimeOrigin::signed(ALICE),
+            Token(DOT),
+            unit(500),
+            remark_call()
+        ));
+
+        let fee = FLASH_LOAN_FEE.mul_ceil(unit(500));
+        assert_eq!(
+            <Tokens as MultiCurrency<_>>::free_balance(Token(DOT), &ALICE),
+            alice_balance - fee
+        );
+        assert_eq!(Loans::total_reserves(Token(DOT)).amount(), fee);
+        assert_eq!(Loans::get_total_cash(Token(DOT)).amount(), total_cash.amount() + fee);
+        assert_eq!(crate::ActiveFlashLoan::<Test>::get(), None);
+        System::assert_last_event(RuntimeEvent::Loans(crate::Event::FlashLoan {
+            account_id: ALICE,
+            currency_id: Token(DOT),
+            amount: unit(500),
+            fee,
+        }));
+    })
+}
+
+#[test]
+fn flash_loan_fails_if_not_repaid() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), Token(DOT), unit(1000)));
+        enable_flash_loans(Token(DOT));
+        // Alice moves everything she owns elsewhere, so neither the loan nor the fee can be returned
+        let alice_balance = <Tokens as MultiCurrency<_>>::free_balance(Token(DOT), &ALICE);
+        assert_noop!(
+            Loans::flash_loan(
+                RuntimeOrigin::signed(ALICE),
+                Token(DOT),
+                unit(500),
+                transfer_call(CHARLIE, Token(DOT), alice_balance + unit(500))
+            ),
+            Error::<Test>::FlashLoanNotRepaid
+        );
+    })
+}
+
+#[test]
+fn flash_loan_fails_if_fee_cannot_be_paid() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), Token(DOT), unit(1000)));
+        enable_flash_loans(Token(DOT));
+        // EVE holds no DOT, so she can return the principal but not the fee
+        assert_noop!(
+            Loans::flash_loan(RuntimeOrigin::signed(EVE), Token(DOT), unit(500), remark_call()),
+            Error::<Test>::FlashLoanNotRepaid
+        );
+    })
+}
+
+#[test]
+fn flash_loan_propagates_nested_call_errors() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), Token(DOT), unit(1000)));
+        enable_flash_loans(Token(DOT));
+        assert_noop!(
+            Loans::flash_loan(
+                RuntimeOrigin::signed(EVE),
+                Token(DOT),
+                unit(500),
+                transfer_call(CHARLIE, Token(DOT), unit(501))
+            ),
+            orml_tokens::Error::<Test>::BalanceTooLow
+        );
+    })
+}
+
+#[test]
+fn flash_loan_cannot_be_nested() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), Token(DOT), unit(1000)));
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), Token(KSM), unit(1000)));
+        enable_flash_loans(Token(DOT));
+        enable_flash_loans(Token(KSM));
+        let nested = Box::new(RuntimeCall::Loans(crate::Call::flash_loan {
+            asset_id: Token(KSM),
+            amount: unit(100),
+            call: remark_call(),
+        }));
+        assert_noop!(
+            Loans::flash_loan(RuntimeOrigin::signed(ALICE), Token(DOT), unit(500), nested),
+            Error::<Test>::FlashLoanInProgress
+        );
+    })
+}
+
+#[test]
+fn flash_loan_requires_enabled_market_and_enough_cash() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), Token(DOT), unit(1000)));
+        assert_noop!(
+            Loans::flash_loan(RuntimeOrigin::signed(ALICE), Token(DOT), unit(500), remark_call()),
+            Error::<Test>::FlashLoansDisabled
+        );
+
+        enable_flash_loans(Token(DOT));
+        assert_noop!(
+            Loans::flash_loan(RuntimeOrigin::signed(ALICE), Token(DOT), 0, remark_call()),
+            Error::<Test>::InvalidAmount
+        );
+        assert_noop!(
+            Loans::flash_loan(RuntimeOrigin::signed(ALICE), Token(DOT), unit(1001), remark_call()),
+            Error::<Test>::InsufficientCash
+        );
+    })
+}
+
+#[test]
+fn flash_loaned_cash_cannot_be_borrowed() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), Token(DOT), unit(500)));
+        assert_ok!(Loans::deposit_all_collateral(RuntimeOrigin::signed(ALICE), Token(DOT)));
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), Token(DOT), unit(1000)));
+        enable_flash_loans(Token(DOT));
+
+        // only 100 DOT are left in the market while the flash loan is outstanding
+        let borrow_call = Box::new(RuntimeCall::Loans(crate::Call::borrow {
+            asset_id: Token(DOT),
+            borrow_amount: unit(200),
+        }));
+        assert_noop!(
+            Loans::flash_loan(RuntimeOrigin::signed(ALICE), Token(DOT), unit(1400), borrow_call),
+            Error::<Test>::InsufficientCash
+        );
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), Token(DOT), unit(200)));
+    })
+}
+
+#[test]
+fn flash_loan_does_not_change_exchange_rate() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), Token(DOT), unit(1000)));
+        assert_ok!(Loans::deposit_all_collateral(RuntimeOrigin::signed(BOB), Token(DOT)));
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(BOB), Token(DOT), unit(200)));
+        accrue_interest_per_block(Token(DOT), 100, 10);
+        enable_flash_loans(Token(DOT));
+        let exchange_rate = Loans::exchange_rate_stored(Token(DOT)).unwrap();
+        let expected_lend_tokens = Amount::<Test>::new(unit(500), Token(DOT)).to_lend_token().unwrap();
+
+        // Alice supplies the borrowed cash, which must be priced as if the loan was never taken
+        let mint_call = Box::new(RuntimeCall::Loans(crate::Call::mint {
+            asset_id: Token(DOT),
+            mint_amount: unit(500),
+        }));
+        assert_ok!(Loans::flash_loan(
+            RuntimeOrigin::signed(ALICE),
+            Token(DOT),
+            unit(500),
+            mint_call
+        ));
+
+        assert_eq!(
+            <Tokens as MultiCurrency<_>>::free_balance(LEND_DOT, &ALICE),
+            expected_lend_tokens.amount()
+        );
+        assert_eq!(Loans::exchange_rate_stored(Token(DOT)).unwrap(), exchange_rate);
+    })
+}
@@ -537,3 +537,184 @@ fn a_market_can_only_accrue_interest_once_in_a_block() {
         assert_eq!(Loans::borrow_index(Token(DOT)), Rate::from_inner(1000000003805175038),);
     })
 }
+
+fn percent(x: u128) -> Rate {
+    Rate::saturating_from_rational(x, 100)
+}
+
+fn kinked_model() -> KinkedModel {
+    KinkedModel::new_model(
+        percent(2),
+        &[
+            Kink {
+                utilization: Ratio::from_percent(50),
+                rate: percent(10),
+            },
+            Kink {
+                utilization: Ratio::from_percent(80),
+                rate: percent(30),
+            },
+        ],
+        percent(200),
+    )
+    .unwrap()
+}
+
+fn adaptive_model() -> AdaptiveModel {
+    AdaptiveModel {
+        target_utilization: Ratio::from_percent(90),
+        curve_steepness: Rate::saturating_from_integer(4),
+        adjustment_speed: Rate::saturating_from_integer(50),
+        rate_at_target: percent(4),
+        min_rate_at_target: percent(1),
+        max_rate_at_target: percent(50),
+    }
+}
+
+#[test]
+fn kinked_model_interpolates_between_breakpoints() {
+    let model = kinked_model();
+    assert!(model.check_model());
+
+    let rate = |x| model.get_borrow_rate(Ratio::from_percent(x)).unwrap();
+    assert_eq!(rate(0), percent(2));
+    assert_eq!(rate(25), percent(6));
+    assert_eq!(rate(50), percent(10));
+    assert_eq!(rate(65), percent(20));
+    assert_eq!(rate(80), percent(30));
+    assert_eq!(rate(90), percent(115));
+    assert_eq!(rate(100), percent(200));
+}
+
+#[test]
+fn kinked_model_rejects_invalid_breakpoints() {
+    let kink = |utilization, rate| Kink {
+        utilization: Ratio::from_percent(utilization),
+        rate: percent(rate),
+    };
+
+    // too many breakpoints
+    assert!(KinkedModel::new_model(percent(2), &[kink(10, 10); MAX_KINKS + 1], percent(200)).is_none());
+    // utilization must be strictly increasing
+    let model = KinkedModel::new_model(percent(2), &[kink(50, 10), kink(50, 30)], percent(200)).unwrap();
+    assert!(!model.check_model());
+    let model = KinkedModel::new_model(percent(2), &[kink(80, 10), kink(50, 30)], percent(200)).unwrap();
+    assert!(!model.check_model());
+    // breakpoints must be strictly between 0% and 100%
+    let model = KinkedModel::new_model(percent(2), &[kink(0, 10)], percent(200)).unwrap();
+    assert!(!model.check_model());
+    let model = KinkedModel::new_model(percent(2), &[kink(100, 10)], percent(200)).unwrap();
+    assert!(!model.check_model());
+    // rates must not decrease
+    let model = KinkedModel::new_model(percent(2), &[kink(50, 30), kink(80, 10)], percent(200)).unwrap();
+    assert!(!model.check_model());
+    let model = KinkedModel::new_model(percent(2), &[kink(50, 10)], percent(5)).unwrap();
+    assert!(!model.check_model());
+    // rates are capped
+    let model = KinkedModel::new_model(percent(11), &[kink(50, 20)], percent(200)).unwrap();
+    assert!(!model.check_model());
+    let model = KinkedModel::new_model(percent(2), &[kink(50, 20)], percent(501)).unwrap();
+    assert!(!model.check_model());
+    // unused breakpoints must come last
+    let mut model = kinked_model();
+    model.kinks.swap(1, 2);
+    assert!(!model.check_model());
+}
+
+#[test]
+fn adaptive_model_rate_follows_curve_around_target() {
+    let model = adaptive_model();
+    assert!(model.check_model());
+
+    let rate = |x| model.get_borrow_rate(Ratio::from_percent(x)).unwrap();
+    assert_eq!(rate(90), percent(4));
+    assert_eq!(rate(100), percent(16));
+    assert_eq!(rate(95), percent(10));
+    assert_eq!(rate(0), percent(1));
+    assert_eq!(rate(45), Rate::saturating_from_rational(25, 1000));
+}
+
+#[test]
+fn adaptive_model_rejects_invalid_parameters() {
+    let invalid = |f: fn(&mut AdaptiveModel)| {
+        let mut model = adaptive_model();
+        f(&mut model);
+        !model.check_model()
+    };
+    assert!(invalid(|m| m.target_utilization = Ratio::zero()));
+    assert!(invalid(|m| m.target_utilization = Ratio::one()));
+    assert!(invalid(|m| m.curve_steepness = percent(50)));
+    assert!(invalid(|m| m.adjustment_speed = Rate::saturating_from_integer(1001)));
+    assert!(invalid(|m| m.rate_at_target = percent(51)));
+    assert!(invalid(|m| m.rate_at_target = percent(0)));
+    // the rate at full utilization would exceed 500%
+    assert!(invalid(|m| m.max_rate_at_target = percent(200)));
+}
+
+#[test]
+fn adaptive_model_adapts_rate_at_target() {
+    let model = adaptive_model();
+    let day = 24 * 60 * 60;
+
+    // at target the rate does not move
+    assert_eq!(model.adapt(Ratio::from_percent(90), day).unwrap(), model);
+
+    // above target the rate increases, the faster the further utilization is from the target
+    let slightly_above = model.adapt(Ratio::from_percent(95), day).unwrap();
+    let far_above = model.adapt(Ratio::from_percent(100), day).unwrap();
+    assert!(slightly_above.rate_at_target > model.rate_at_target);
+    assert!(far_above.rate_at_target > slightly_above.rate_at_target);
+
+    // below target the rate decreases
+    let below = model.adapt(Ratio::from_percent(45), day).unwrap();
+    assert!(below.rate_at_target < model.rate_at_target);
+
+    // the rate at target is bounded
+    let year = 365 * day;
+    assert_eq!(
+        model.adapt(Ratio::from_percent(100), year).unwrap().rate_at_target,
+        model.max_rate_at_target
+    );
+    assert_eq!(
+        model.adapt(Ratio::zero(), year).unwrap().rate_at_target,
+        model.min_rate_at_target
+    );
+
+    // other models do not change over time
+    let kinked = InterestRateModel::Kinked(kinked_model());
+    assert_eq!(kinked.adapt(Ratio::from_percent(100), year).unwrap(), kinked);
+}
+
+#[test]
+fn accrue_interest_updates_adaptive_rate_at_target() {
+    new_test_ext().execute_with(|| {
+        let model = AdaptiveModel {
+            target_utilization: Ratio::from_percent(10),
+            ..adaptive_model()
+        };
+        assert_ok!(Loans::update_rate_model(
+            RuntimeOrigin::root(),
+            Token(DOT),
+            InterestRateModel::Adaptive(model)
+        ));
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), Token(DOT), unit(200)));
+        assert_ok!(Loans::deposit_all_collateral(RuntimeOrigin::signed(ALICE), Token(DOT)));
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), Token(DOT), unit(100)));
+
+        TimestampPallet::set_timestamp(24 * 60 * 60 * 1000);
+        let (borrow_rate, ..) = Loans::get_market_status(Token(DOT)).unwrap();
+        assert_ok!(Loans::accrue_interest(Token(DOT)));
+
+        // utilization is above the target, so the rate at target increased
+        let adapted = match Loans::market(Token(DOT)).unwrap().rate_model {
+            InterestRateModel::Adaptive(adapted) => adapted,
+            _ => panic!("rate model changed"),
+        };
+        assert!(adapted.rate_at_target > model.rate_at_target);
+        assert_eq!(
+            Loans::borrow_rate(Token(DOT)),
+            adapted.get_borrow_rate(Ratio::from_percent(50)).unwrap()
+        );
+        assert_eq!(Loans::borrow_rate(Token(DOT)), borrow_rate);
+    })
+}
@@ -1,10 +1,11 @@
 use crate::{
     mock::{
-        new_test_ext, with_price, CurrencyConvert, Loans, RuntimeOrigin, Test, Tokens, _run_to_block, market_mock,
-        new_test_ext_no_markets, RuntimeCall, ALICE, BOB, DEFAULT_WRAPPED_CURRENCY, LEND_KBTC, LEND_KSM,
+        new_test_ext, with_price, CurrencyConvert, Loans, RuntimeOrigin, System, Test, Tokens, _run_to_block,
+        market_mock, new_test_ext_no_markets, RuntimeCall, ALICE, BOB, CHARLIE, DEFAULT_WRAPPED_CURRENCY, LEND_KBTC,
+        LEND_KSM,
     },
     tests::unit,
-    Amount, Error, Market, MarketState,
+    Amount, Error, Event, LiquidationScaling, Market, MarketState,
 };
 use frame_support::{assert_noop, assert_ok, dispatch::Dispatchable};
 use mocktopus::mocking::Mockable;
@@ -14,7 +15,10 @@ use primitives::{
     CurrencyId::{self, Token},
     Rate, Ratio, DOT as DOT_CURRENCY, KBTC as KBTC_CURRENCY, KSM as KSM_CURRENCY,
 };
-use sp_runtime::FixedPointNumber;
+use sp_runtime::{
+    traits::{One, Zero},
+    FixedPointNumber,
+};
 use traits::{LoansApi, OracleApi};
 
 const DOT: CurrencyId = Token(DOT_CURRENCY);
@@ -27,7 +31,7 @@ fn liquidate_borrow_allowed_works() {
         // Borrower should have a positive shortfall
         let dot_market = Loans::market(DOT).unwrap();
         assert_noop!(
-            Loans::liquidate_borrow_allowed(&ALICE, &Amount::new(100, DOT), &dot_market),
+            Loans::liquidate_borrow_allowed(&ALICE, &Amount::new(100, DOT), &dot_market, dot_market.close_factor),
             Error::<Test>::InsufficientShortfall
         );
         initial_setup();
@@ -39,13 +43,19 @@ fn liquidate_borrow_allowed_works() {
         // Collateral   Loans
         // USDT $110    KSM $200
         assert_noop!(
-            Loans::liquidate_borrow_allowed(&ALICE, &Amount::new(unit(51), KSM), &ksm_market),
+            Loans::liquidate_borrow_allowed(
+                &ALICE,
+                &Amount::new(unit(51), KSM),
+                &ksm_market,
+                ksm_market.close_factor
+            ),
             Error::<Test>::TooMuchRepay
         );
         assert_ok!(Loans::liquidate_borrow_allowed(
             &ALICE,
             &Amount::new(unit(50), KSM),
-            &ksm_market
+            &ksm_market,
+            ksm_market.close_factor
         ));
     })
 }
@@ -59,7 +69,7 @@ fn deposit_of_borrower_must_be_collateral() {
         CurrencyConvert::convert.mock_safe(with_price(Some((KSM, 2.into()))));
         let market = Loans::market(KSM).unwrap();
         assert_noop!(
-            Loans::liquidate_borrow_allowed(&ALICE, &Amount::new(unit(51), KSM), &market),
+            Loans::liquidate_borrow_allowed(&ALICE, &Amount::new(unit(51), KSM), &market, market.close_factor),
             Error::<Test>::TooMuchRepay
         );
 
@@ -70,6 +80,29 @@ fn deposit_of_borrower_must_be_collateral() {
     })
 }
 
+#[test]
+fn liquidation_fails_while_price_feed_is_paused() {
+    new_test_ext().execute_with(|| {
+        initial_setup();
+        alice_borrows_100_ksm();
+        CurrencyConvert::convert.mock_safe(with_price(Some((KSM, 2.into()))));
+        oracle::PausedPriceFeeds::<Test>::insert(oracle::OracleKey::ExchangeRate(KSM), ());
+        assert_noop!(
+            Loans::liquidate_borrow(RuntimeOrigin::signed(BOB), ALICE, KSM, unit(50), KBTC),
+            oracle::Error::<Test>::PriceFeedPaused
+        );
+
+        oracle::PausedPriceFeeds::<Test>::remove(oracle::OracleKey::ExchangeRate(KSM));
+        assert_ok!(Loans::liquidate_borrow(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            KSM,
+            unit(50),
+            KBTC
+        ));
+    })
+}
+
 #[test]
 fn collateral_value_must_be_greater_than_liquidation_value() {
     new_test_ext().execute_with(|| {
@@ -494,6 +527,210 @@ fn liquidator_must_not_be_borrower() {
     })
 }
 
+fn liquidation_scaling() -> LiquidationScaling {
+    LiquidationScaling {
+        min_close_factor: Ratio::from_percent(20),
+        max_close_factor: Ratio::from_percent(100),
+        min_liquidate_incentive: Rate::saturating_from_rational(102, 100),
+        max_liquidate_incentive: Rate::saturating_from_rational(118, 100),
+        critical_health_factor: Ratio::from_percent(10),
+    }
+}
+
+#[test]
+fn liquidation_scaling_interpolates_between_bounds() {
+    let scaling = liquidation_scaling();
+    let min = (scaling.min_close_factor, scaling.min_liquidate_incentive);
+    let max = (scaling.max_close_factor, scaling.max_liquidate_incentive);
+    // healthy and barely unhealthy accounts get the minimum
+    assert_eq!(scaling.parameters(Rate::saturating_from_integer(2)), min);
+    assert_eq!(scaling.parameters(Rate::one()), min);
+    // halfway to the critical health factor
+    assert_eq!(
+        scaling.parameters(Rate::saturating_from_rational(55, 100)),
+        (Ratio::from_percent(60), Rate::saturating_from_rational(110, 100))
+    );
+    // critically unhealthy accounts get the maximum
+    assert_eq!(scaling.parameters(Rate::saturating_from_rational(10, 100)), max);
+    assert_eq!(scaling.parameters(Rate::zero()), max);
+}
+
+#[test]
+fn set_liquidation_scaling_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Loans::set_liquidation_scaling(RuntimeOrigin::signed(ALICE), KSM, Some(liquidation_scaling())),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Loans::set_liquidation_scaling(RuntimeOrigin::root(), CurrencyId::ForeignAsset(1), None),
+            Error::<Test>::MarketDoesNotExist
+        );
+        let invalid_scalings = [
+            LiquidationScaling {
+                min_close_factor: Ratio::zero(),
+                ..liquidation_scaling()
+            },
+            LiquidationScaling {
+                min_close_factor: Ratio::from_percent(60),
+                max_close_factor: Ratio::from_percent(50),
+                ..liquidation_scaling()
+            },
+            LiquidationScaling {
+                min_liquidate_incentive: Rate::saturating_from_rational(99, 100),
+                ..liquidation_scaling()
+            },
+            LiquidationScaling {
+                max_liquidate_incentive: Rate::one(),
+                ..liquidation_scaling()
+            },
+            LiquidationScaling {
+                critical_health_factor: Ratio::one(),
+                ..liquidation_scaling()
+            },
+        ];
+        for scaling in invalid_scalings {
+            assert_noop!(
+                Loans::set_liquidation_scaling(RuntimeOrigin::root(), KSM, Some(scaling)),
+                Error::<Test>::InvalidLiquidationScaling
+            );
+        }
+
+        assert_ok!(Loans::set_liquidation_scaling(
+            RuntimeOrigin::root(),
+            KSM,
+            Some(liquidation_scaling())
+        ));
+        assert_eq!(Loans::liquidation_scaling(KSM), Some(liquidation_scaling()));
+        assert_ok!(Loans::set_liquidation_scaling(RuntimeOrigin::root(), KSM, None));
+        assert_eq!(Loans::liquidation_scaling(KSM), None);
+    })
+}
+
+#[test]
+fn liquidation_parameters_scale_with_health_factor() {
+    new_test_ext().execute_with(|| {
+        initial_setup();
+        alice_borrows_100_ksm();
+        CurrencyConvert::convert.mock_safe(with_price(Some((KSM, 2.into()))));
+        assert_ok!(Loans::set_liquidation_scaling(
+            RuntimeOrigin::root(),
+            KSM,
+            Some(liquidation_scaling())
+        ));
+
+        // Collateral   Loans
+        // USDT $110    KSM $200
+        assert_eq!(
+            Loans::health_factor(&ALICE).unwrap(),
+            Rate::saturating_from_rational(55, 100)
+        );
+        let market = Loans::market(KSM).unwrap();
+        assert_eq!(
+            Loans::liquidation_parameters(&ALICE, KSM, &market).unwrap(),
+            (Ratio::from_percent(60), Rate::saturating_from_rational(110, 100))
+        );
+
+        // the close factor is 60% instead of the 50% of the market
+        assert_noop!(
+            Loans::liquidate_borrow(RuntimeOrigin::signed(BOB), ALICE, KSM, unit(61), KBTC),
+            Error::<Test>::TooMuchRepay
+        );
+        assert_ok!(Loans::liquidate_borrow(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            KSM,
+            unit(60),
+            KBTC
+        ));
+        // incentive = repay KSM value * 1.1 = (60 * 2) * 1.1 = 132
+        // Alice KBTC collateral: deposit - incentive = 200 - 132 = 68
+        assert_eq!(
+            Loans::exchange_rate(KBTC).saturating_mul_int(<Tokens as MultiCurrency<_>>::total_balance(
+                Loans::lend_token_id(KBTC).unwrap(),
+                &ALICE
+            )),
+            unit(68),
+        );
+        assert_eq!(Loans::account_borrows(KSM, ALICE).principal, unit(40));
+    })
+}
+
+#[test]
+fn bad_debt_without_collateral_is_written_off_against_reserves() {
+    new_test_ext().execute_with(|| {
+        initial_setup();
+        alice_borrows_100_ksm();
+        assert_ok!(Loans::add_reserves(RuntimeOrigin::root(), BOB, KSM, unit(20)));
+        assert_noop!(
+            Loans::write_off_bad_debt(RuntimeOrigin::signed(BOB), ALICE, KSM),
+            Error::<Test>::BorrowerHasAssets
+        );
+        assert_noop!(
+            Loans::write_off_bad_debt(RuntimeOrigin::signed(BOB), CHARLIE, KSM),
+            Error::<Test>::NoBadDebt
+        );
+
+        alice_loses_all_collateral();
+        assert_eq!(Loans::account_borrows(KSM, ALICE).principal, unit(60));
+
+        let (_, _, exchange_rate, ..) = Loans::get_market_status(KSM).unwrap();
+        assert_ok!(Loans::write_off_bad_debt(RuntimeOrigin::signed(BOB), ALICE, KSM));
+        System::assert_last_event(
+            Event::<Test>::BadDebtWrittenOff {
+                borrower: ALICE,
+                underlying_currency_id: KSM,
+                amount: unit(60),
+                reserves_used: unit(20),
+            }
+            .into(),
+        );
+        assert_eq!(Loans::account_borrows(KSM, ALICE).principal, 0);
+        assert_eq!(Loans::total_borrows(KSM).amount(), 0);
+        assert_eq!(Loans::total_reserves(KSM).amount(), 0);
+        // the debt not covered by the reserves is socialised among suppliers
+        let (_, _, exchange_rate_after, ..) = Loans::get_market_status(KSM).unwrap();
+        assert!(exchange_rate_after < exchange_rate);
+    })
+}
+
+#[test]
+fn bad_debt_is_not_written_off_while_borrower_holds_lend_tokens() {
+    new_test_ext().execute_with(|| {
+        initial_setup();
+        alice_borrows_100_ksm();
+        alice_loses_all_collateral();
+
+        // lend tokens that aren't collateral can still be redeemed to repay the debt
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), DOT, unit(10)));
+        assert_noop!(
+            Loans::write_off_bad_debt(RuntimeOrigin::signed(BOB), ALICE, KSM),
+            Error::<Test>::BorrowerHasAssets
+        );
+
+        assert_ok!(Loans::redeem_all(RuntimeOrigin::signed(ALICE), DOT));
+        assert_ok!(Loans::write_off_bad_debt(RuntimeOrigin::signed(BOB), ALICE, KSM));
+    })
+}
+
+/// Seizes all of Alice's collateral: 40 KSM * $4 * 1.25 = $200
+fn alice_loses_all_collateral() {
+    CurrencyConvert::convert.mock_safe(with_price(Some((KSM, 4.into()))));
+    Loans::mutate_market(KSM, |market| {
+        market.liquidate_incentive = Rate::saturating_from_rational(125, 100);
+        Ok(market.clone())
+    })
+    .unwrap();
+    assert_ok!(Loans::liquidate_borrow(
+        RuntimeOrigin::signed(BOB),
+        ALICE,
+        KSM,
+        unit(40),
+        KBTC
+    ));
+    assert_eq!(Loans::account_deposits(LEND_KBTC, &ALICE).amount(), 0);
+}
+
 fn alice_borrows_100_ksm() {
     assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(100)));
 }
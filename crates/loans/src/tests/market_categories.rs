@@ -0,0 +1,297 @@
+use super::*;
+use crate::{mock::*, tests::Loans, EfficiencyGroup, Error, IsolationDebt};
+use frame_support::{assert_noop, assert_ok};
+use mocktopus::mocking::Mockable;
+use primitives::{CurrencyId::Token, Ratio, DOT, KBTC, KSM, SECONDS_PER_YEAR};
+
+const GROUP_ID: u8 = 1;
+
+fn efficiency_group() -> EfficiencyGroup {
+    EfficiencyGroup {
+        collateral_factor: Ratio::from_percent(90),
+        liquidation_threshold: Ratio::from_percent(93),
+    }
+}
+
+fn supply_collateral(who: AccountId, asset_id: CurrencyId, amount: u128) {
+    assert_ok!(Loans::mint(RuntimeOrigin::signed(who.clone()), asset_id, amount));
+    assert_ok!(Loans::deposit_all_collateral(RuntimeOrigin::signed(who), asset_id));
+}
+
+fn setup_efficiency_group() {
+    assert_ok!(Loans::set_efficiency_group(
+        RuntimeOrigin::root(),
+        GROUP_ID,
+        Some(efficiency_group())
+    ));
+    assert_ok!(Loans::set_market_efficiency_group(
+        RuntimeOrigin::root(),
+        Token(DOT),
+        Some(GROUP_ID)
+    ));
+    assert_ok!(Loans::set_market_efficiency_group(
+        RuntimeOrigin::root(),
+        Token(KSM),
+        Some(GROUP_ID)
+    ));
+}
+
+fn isolation_debt() -> u128 {
+    Loans::isolation_debt(Token(KSM), Token(DOT)).unwrap().amount()
+}
+
+/// Isolates KSM with a $50 debt ceiling, against which DOT can be borrowed, and supplies $200 of
+/// KSM collateral for Alice
+fn setup_isolated_market() {
+    assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), Token(DOT), unit(500)));
+    assert_ok!(Loans::set_isolation_mode(
+        RuntimeOrigin::root(),
+        Token(KSM),
+        Some(unit(50))
+    ));
+    supply_collateral(ALICE, Token(KSM), unit(200));
+}
+
+#[test]
+fn isolated_collateral_cannot_be_combined() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Loans::set_isolation_mode(
+            RuntimeOrigin::root(),
+            Token(KSM),
+            Some(unit(100))
+        ));
+
+        // Isolated collateral cannot be added to existing collateral
+        supply_collateral(ALICE, Token(DOT), unit(100));
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), Token(KSM), unit(100)));
+        assert_noop!(
+            Loans::deposit_all_collateral(RuntimeOrigin::signed(ALICE), Token(KSM)),
+            Error::<Test>::IsolatedCollateralCannotBeCombined
+        );
+
+        // Nor can other collateral be added to isolated collateral
+        supply_collateral(BOB, Token(KSM), unit(100));
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), Token(DOT), unit(100)));
+        assert_noop!(
+            Loans::deposit_all_collateral(RuntimeOrigin::signed(BOB), Token(DOT)),
+            Error::<Test>::IsolatedCollateralCannotBeCombined
+        );
+
+        // Removing the isolation lifts the restriction
+        assert_ok!(Loans::set_isolation_mode(RuntimeOrigin::root(), Token(KSM), None));
+        assert_ok!(Loans::deposit_all_collateral(RuntimeOrigin::signed(BOB), Token(DOT)));
+    })
+}
+
+#[test]
+fn isolated_collateral_backs_capped_debt_in_borrowable_assets() {
+    new_test_ext().execute_with(|| {
+        setup_isolated_market();
+
+        assert_noop!(
+            Loans::borrow(RuntimeOrigin::signed(ALICE), Token(DOT), unit(10)),
+            Error::<Test>::AssetNotBorrowableInIsolation
+        );
+
+        assert_ok!(Loans::set_borrowable_in_isolation(
+            RuntimeOrigin::root(),
+            Token(DOT),
+            true
+        ));
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), Token(DOT), unit(40)));
+        assert_eq!(isolation_debt(), unit(40));
+
+        // Alice has enough liquidity, but the ceiling of the isolated market would be exceeded
+        assert_noop!(
+            Loans::borrow(RuntimeOrigin::signed(ALICE), Token(DOT), unit(20)),
+            Error::<Test>::IsolationDebtCeilingExceeded
+        );
+
+        assert_ok!(Loans::repay_borrow(RuntimeOrigin::signed(ALICE), Token(DOT), unit(30)));
+        assert_eq!(isolation_debt(), unit(10));
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), Token(DOT), unit(20)));
+        assert_eq!(isolation_debt(), unit(30));
+    })
+}
+
+#[test]
+fn isolating_market_accounts_existing_debt() {
+    new_test_ext().execute_with(|| {
+        setup_isolated_market();
+        assert_ok!(Loans::set_borrowable_in_isolation(
+            RuntimeOrigin::root(),
+            Token(DOT),
+            true
+        ));
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), Token(DOT), unit(40)));
+
+        // The debt keeps growing while the market isn't isolated
+        assert_ok!(Loans::set_isolation_mode(RuntimeOrigin::root(), Token(KSM), None));
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), Token(DOT), unit(20)));
+
+        // and is accounted towards the ceiling once the market is isolated again
+        assert_ok!(Loans::set_isolation_mode(
+            RuntimeOrigin::root(),
+            Token(KSM),
+            Some(unit(70))
+        ));
+        assert_eq!(isolation_debt(), unit(60));
+        assert_noop!(
+            Loans::borrow(RuntimeOrigin::signed(ALICE), Token(DOT), unit(20)),
+            Error::<Test>::IsolationDebtCeilingExceeded
+        );
+    })
+}
+
+#[test]
+fn market_with_combined_collateral_cannot_be_isolated() {
+    new_test_ext().execute_with(|| {
+        supply_collateral(ALICE, Token(KSM), unit(100));
+        supply_collateral(ALICE, Token(DOT), unit(100));
+        assert_noop!(
+            Loans::set_isolation_mode(RuntimeOrigin::root(), Token(KSM), Some(unit(100))),
+            Error::<Test>::IsolatedCollateralCannotBeCombined
+        );
+
+        assert_ok!(Loans::withdraw_all_collateral(RuntimeOrigin::signed(ALICE), Token(DOT)));
+        assert_ok!(Loans::set_isolation_mode(
+            RuntimeOrigin::root(),
+            Token(KSM),
+            Some(unit(100))
+        ));
+    })
+}
+
+#[test]
+fn isolation_debt_accrues_interest_and_follows_prices() {
+    new_test_ext().execute_with(|| {
+        setup_isolated_market();
+        assert_ok!(Loans::set_borrowable_in_isolation(
+            RuntimeOrigin::root(),
+            Token(DOT),
+            true
+        ));
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), Token(DOT), unit(40)));
+
+        // The debt backed by the isolated collateral includes the accrued interest
+        TimestampPallet::set_timestamp(SECONDS_PER_YEAR * 1000);
+        assert_ok!(Loans::accrue_interest(Token(DOT)));
+        let debt = Loans::current_borrow_balance(&ALICE, Token(DOT)).unwrap().amount();
+        assert!(debt > unit(40));
+        assert_eq!(isolation_debt(), debt);
+
+        // Repaying the debt with its interest releases it from the ceiling entirely
+        assert_ok!(Loans::repay_borrow_all(RuntimeOrigin::signed(ALICE), Token(DOT)));
+        assert!(!IsolationDebt::<Test>::contains_key(Token(KSM), Token(DOT)));
+
+        // The debt is valued at the current price of the borrowed asset
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), Token(DOT), unit(40)));
+        CurrencyConvert::convert.mock_safe(with_price(Some((Token(DOT), 2.into()))));
+        assert_noop!(
+            Loans::borrow(RuntimeOrigin::signed(ALICE), Token(DOT), unit(1)),
+            Error::<Test>::IsolationDebtCeilingExceeded
+        );
+        assert_ok!(Loans::repay_borrow(RuntimeOrigin::signed(ALICE), Token(DOT), unit(20)));
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), Token(DOT), unit(5)));
+        assert_eq!(isolation_debt(), unit(25));
+    })
+}
+
+#[test]
+fn set_efficiency_group_validates_parameters() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Loans::set_efficiency_group(
+                RuntimeOrigin::root(),
+                GROUP_ID,
+                Some(EfficiencyGroup {
+                    collateral_factor: Ratio::from_percent(90),
+                    liquidation_threshold: Ratio::from_percent(80),
+                })
+            ),
+            Error::<Test>::InvalidEfficiencyGroup
+        );
+        assert_noop!(
+            Loans::set_efficiency_group(
+                RuntimeOrigin::root(),
+                GROUP_ID,
+                Some(EfficiencyGroup {
+                    collateral_factor: Ratio::from_percent(90),
+                    liquidation_threshold: Ratio::one(),
+                })
+            ),
+            Error::<Test>::InvalidEfficiencyGroup
+        );
+        assert_noop!(
+            Loans::set_market_efficiency_group(RuntimeOrigin::root(), Token(DOT), Some(GROUP_ID)),
+            Error::<Test>::EfficiencyGroupDoesNotExist
+        );
+        assert_noop!(
+            Loans::enter_efficiency_group(RuntimeOrigin::signed(ALICE), Some(GROUP_ID)),
+            Error::<Test>::EfficiencyGroupDoesNotExist
+        );
+    })
+}
+
+#[test]
+fn efficiency_group_boosts_collateral_factor() {
+    new_test_ext().execute_with(|| {
+        setup_efficiency_group();
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), Token(DOT), unit(500)));
+        supply_collateral(ALICE, Token(KSM), unit(100));
+        assert_eq!(
+            Loans::get_account_liquidity(&ALICE).unwrap().liquidity().amount(),
+            unit(50)
+        );
+
+        assert_ok!(Loans::enter_efficiency_group(
+            RuntimeOrigin::signed(ALICE),
+            Some(GROUP_ID)
+        ));
+        assert_eq!(
+            Loans::get_account_liquidity(&ALICE).unwrap().liquidity().amount(),
+            unit(90)
+        );
+        assert_eq!(
+            Loans::get_account_liquidation_threshold_liquidity(&ALICE)
+                .unwrap()
+                .liquidity()
+                .amount(),
+            unit(93)
+        );
+
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), Token(DOT), unit(80)));
+        // Assets outside the group cannot be borrowed while in the group
+        assert_noop!(
+            Loans::borrow(RuntimeOrigin::signed(ALICE), Token(KBTC), unit(1)),
+            Error::<Test>::AssetNotInEfficiencyGroup
+        );
+        // Leaving the group would leave Alice undercollateralized
+        assert_noop!(
+            Loans::enter_efficiency_group(RuntimeOrigin::signed(ALICE), None),
+            Error::<Test>::InsufficientLiquidity
+        );
+    })
+}
+
+#[test]
+fn entering_efficiency_group_requires_borrows_in_group() {
+    new_test_ext().execute_with(|| {
+        setup_efficiency_group();
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), Token(KBTC), unit(500)));
+        supply_collateral(ALICE, Token(KSM), unit(100));
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), Token(KBTC), unit(10)));
+
+        assert_noop!(
+            Loans::enter_efficiency_group(RuntimeOrigin::signed(ALICE), Some(GROUP_ID)),
+            Error::<Test>::AssetNotInEfficiencyGroup
+        );
+
+        assert_ok!(Loans::repay_borrow_all(RuntimeOrigin::signed(ALICE), Token(KBTC)));
+        assert_ok!(Loans::enter_efficiency_group(
+            RuntimeOrigin::signed(ALICE),
+            Some(GROUP_ID)
+        ));
+        assert_eq!(Loans::account_efficiency_group(ALICE), Some(GROUP_ID));
+    })
+}
@@ -0,0 +1,200 @@
+use super::*;
+use crate::{mock::*, tests::Loans, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use mocktopus::mocking::Mockable;
+use primitives::{CurrencyId::Token, DOT, INTR};
+use sp_runtime::DispatchError;
+
+fn value_caps(supply_cap: Option<u128>, borrow_cap: Option<u128>) -> ValueCaps<u128> {
+    ValueCaps { supply_cap, borrow_cap }
+}
+
+/// At most 50 DOT may leave the market per window of 10 blocks
+fn outflow_limit() -> OutflowLimit<u128, BlockNumber> {
+    OutflowLimit {
+        max_net_outflow: unit(50),
+        window: 10,
+    }
+}
+
+#[test]
+fn set_market_value_caps_works() {
+    new_test_ext().execute_with(|| {
+        let caps = value_caps(Some(unit(150)), None);
+        assert_noop!(
+            Loans::set_market_value_caps(RuntimeOrigin::signed(ALICE), Token(DOT), Some(caps)),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Loans::set_market_value_caps(RuntimeOrigin::root(), Token(INTR), Some(caps)),
+            Error::<Test>::MarketDoesNotExist
+        );
+
+        assert_ok!(Loans::set_market_value_caps(
+            RuntimeOrigin::root(),
+            Token(DOT),
+            Some(caps)
+        ));
+        assert_eq!(Loans::market_value_caps(Token(DOT)), Some(caps));
+        System::assert_last_event(RuntimeEvent::Loans(Event::MarketValueCapsUpdated {
+            underlying_currency_id: Token(DOT),
+            caps: Some(caps),
+        }));
+
+        assert_ok!(Loans::set_market_value_caps(RuntimeOrigin::root(), Token(DOT), None));
+        assert_eq!(Loans::market_value_caps(Token(DOT)), None);
+    })
+}
+
+#[test]
+fn supply_value_cap_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Loans::set_market_value_caps(
+            RuntimeOrigin::root(),
+            Token(DOT),
+            Some(value_caps(Some(unit(150)), None))
+        ));
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), Token(DOT), unit(100)));
+        assert_noop!(
+            Loans::mint(RuntimeOrigin::signed(BOB), Token(DOT), unit(60)),
+            Error::<Test>::SupplyCapacityExceeded
+        );
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), Token(DOT), unit(50)));
+
+        // the cap is denominated in the reference currency, so it follows the price
+        CurrencyConvert::convert.mock_safe(with_price(Some((Token(DOT), 2.into()))));
+        assert_ok!(Loans::set_market_value_caps(
+            RuntimeOrigin::root(),
+            Token(DOT),
+            Some(value_caps(Some(unit(310)), None))
+        ));
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), Token(DOT), unit(5)));
+        assert_noop!(
+            Loans::mint(RuntimeOrigin::signed(BOB), Token(DOT), unit(1)),
+            Error::<Test>::SupplyCapacityExceeded
+        );
+    })
+}
+
+#[test]
+fn borrow_value_cap_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Loans::set_market_value_caps(
+            RuntimeOrigin::root(),
+            Token(DOT),
+            Some(value_caps(None, Some(unit(40))))
+        ));
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), Token(DOT), unit(200)));
+        assert_ok!(Loans::deposit_all_collateral(RuntimeOrigin::signed(ALICE), Token(DOT)));
+
+        assert_noop!(
+            Loans::borrow(RuntimeOrigin::signed(ALICE), Token(DOT), unit(41)),
+            Error::<Test>::BorrowCapacityExceeded
+        );
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), Token(DOT), unit(40)));
+        assert_noop!(
+            Loans::borrow(RuntimeOrigin::signed(ALICE), Token(DOT), 1),
+            Error::<Test>::BorrowCapacityExceeded
+        );
+    })
+}
+
+#[test]
+fn set_outflow_limit_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Loans::set_outflow_limit(RuntimeOrigin::signed(ALICE), Token(DOT), Some(outflow_limit())),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Loans::set_outflow_limit(
+                RuntimeOrigin::root(),
+                Token(DOT),
+                Some(OutflowLimit {
+                    max_net_outflow: unit(50),
+                    window: 0,
+                })
+            ),
+            Error::<Test>::InvalidOutflowLimit
+        );
+
+        assert_ok!(Loans::set_outflow_limit(
+            RuntimeOrigin::root(),
+            Token(DOT),
+            Some(outflow_limit())
+        ));
+        assert_eq!(Loans::outflow_limit(Token(DOT)), Some(outflow_limit()));
+        System::assert_last_event(RuntimeEvent::Loans(Event::OutflowLimitUpdated {
+            underlying_currency_id: Token(DOT),
+            limit: Some(outflow_limit()),
+        }));
+
+        assert_ok!(Loans::set_outflow_limit(RuntimeOrigin::root(), Token(DOT), None));
+        assert_eq!(Loans::outflow_limit(Token(DOT)), None);
+    })
+}
+
+#[test]
+fn outflow_limit_pauses_redeems_and_borrows() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), Token(DOT), unit(200)));
+        assert_ok!(Loans::deposit_all_collateral(RuntimeOrigin::signed(ALICE), Token(DOT)));
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), Token(DOT), unit(100)));
+        assert_ok!(Loans::set_outflow_limit(
+            RuntimeOrigin::root(),
+            Token(DOT),
+            Some(outflow_limit())
+        ));
+
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), Token(DOT), unit(30)));
+        assert_noop!(
+            Loans::redeem(RuntimeOrigin::signed(BOB), Token(DOT), unit(21)),
+            Error::<Test>::OutflowLimitExceeded
+        );
+        // repayments and mints offset the outflow
+        assert_ok!(Loans::repay_borrow(RuntimeOrigin::signed(ALICE), Token(DOT), unit(10)));
+        assert_ok!(Loans::redeem(RuntimeOrigin::signed(BOB), Token(DOT), unit(30)));
+        assert_eq!(
+            Loans::outflow_window(Token(DOT)),
+            OutflowWindow {
+                start: 0,
+                net_outflow: unit(50),
+            }
+        );
+        assert_noop!(
+            Loans::redeem(RuntimeOrigin::signed(BOB), Token(DOT), unit(1)),
+            Error::<Test>::OutflowLimitExceeded
+        );
+        assert_noop!(
+            Loans::borrow(RuntimeOrigin::signed(ALICE), Token(DOT), unit(1)),
+            Error::<Test>::OutflowLimitExceeded
+        );
+
+        // governance can resume outflows by starting a new window
+        assert_ok!(Loans::reset_outflow_window(RuntimeOrigin::root(), Token(DOT)));
+        System::assert_last_event(RuntimeEvent::Loans(Event::OutflowWindowReset {
+            underlying_currency_id: Token(DOT),
+        }));
+        assert_ok!(Loans::redeem(RuntimeOrigin::signed(BOB), Token(DOT), unit(50)));
+        assert_noop!(
+            Loans::redeem(RuntimeOrigin::signed(BOB), Token(DOT), unit(1)),
+            Error::<Test>::OutflowLimitExceeded
+        );
+
+        // outflows also resume in the next window
+        System::set_block_number(10);
+        assert_noop!(
+            Loans::redeem(RuntimeOrigin::signed(BOB), Token(DOT), unit(1)),
+            Error::<Test>::OutflowLimitExceeded
+        );
+        System::set_block_number(11);
+        assert_ok!(Loans::redeem(RuntimeOrigin::signed(BOB), Token(DOT), unit(1)));
+        assert_eq!(
+            Loans::outflow_window(Token(DOT)),
+            OutflowWindow {
+                start: 11,
+                net_outflow: unit(1),
+            }
+        );
+    })
+}
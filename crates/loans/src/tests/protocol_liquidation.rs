@@ -0,0 +1,237 @@
+use crate::{
+    mock::{
+        new_test_ext, with_price, CurrencyConvert, Extrinsic, Loans, RuntimeCall, RuntimeOrigin, System, Test, ALICE,
+        BOB, LEND_KBTC,
+    },
+    tests::unit,
+    Error, LiquidationRateLimit,
+};
+use codec::Decode;
+use frame_support::{assert_noop, assert_ok, unsigned::ValidateUnsigned};
+use mocktopus::mocking::Mockable;
+use orml_traits::MultiCurrency;
+use primitives::{
+    CurrencyId::{self, Token},
+    Rate, KBTC as KBTC_CURRENCY, KSM as KSM_CURRENCY,
+};
+use sp_core::offchain::{testing::TestTransactionPoolExt, TransactionPoolExt};
+use sp_runtime::{
+    transaction_validity::{InvalidTransaction, TransactionSource},
+    DispatchError, FixedPointNumber,
+};
+
+const KSM: CurrencyId = Token(KSM_CURRENCY);
+const KBTC: CurrencyId = Token(KBTC_CURRENCY);
+
+fn set_rate_limit(max_liquidations_per_block: u32, borrower_cooldown: u64) {
+    assert_ok!(Loans::set_protocol_liquidation_rate_limit(
+        RuntimeOrigin::root(),
+        Some(LiquidationRateLimit {
+            max_liquidations_per_block,
+            borrower_cooldown,
+        })
+    ));
+}
+
+fn liquidation_call(repay_amount: u128) -> crate::Call<Test> {
+    crate::Call::liquidate_borrow_unsigned {
+        borrower: ALICE,
+        liquidation_asset_id: KSM,
+        repay_amount,
+        collateral_asset_id: KBTC,
+    }
+}
+
+fn undercollateralized_setup(reserves: u128) {
+    // Bob deposits 200 KSM and funds the reserves
+    assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)));
+    assert_ok!(Loans::add_reserves(RuntimeOrigin::root(), BOB, KSM, reserves));
+    // Alice deposits 200 KBTC as collateral and borrows 100 KSM
+    assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), KBTC, unit(200)));
+    assert_ok!(Loans::deposit_all_collateral(RuntimeOrigin::signed(ALICE), KBTC));
+    assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(100)));
+    // Double the KSM price to make Alice undercollateralized
+    CurrencyConvert::convert.mock_safe(with_price(Some((KSM, 2.into()))));
+}
+
+#[test]
+fn offchain_worker_submits_protocol_liquidation() {
+    let mut externalities = new_test_ext();
+    let (pool, pool_state) = TestTransactionPoolExt::new();
+    externalities.register_extension(TransactionPoolExt::new(pool));
+
+    externalities.execute_with(|| {
+        undercollateralized_setup(unit(100));
+
+        // Nothing is submitted while protocol liquidations are disabled
+        Loans::_offchain_worker();
+        assert!(pool_state.read().transactions.is_empty());
+
+        set_rate_limit(5, 10);
+        Loans::_offchain_worker();
+
+        // The close factor limits the repayment to 50 of the 100 KSM borrowed
+        let tx = pool_state.write().transactions.pop().unwrap();
+        assert!(pool_state.read().transactions.is_empty());
+        let tx = Extrinsic::decode(&mut &*tx).unwrap();
+        assert_eq!(tx.signature, None);
+        assert_eq!(tx.call, RuntimeCall::Loans(liquidation_call(unit(50))));
+    })
+}
+
+#[test]
+fn offchain_worker_skips_borrowers_with_paused_price_feed() {
+    let mut externalities = new_test_ext();
+    let (pool, pool_state) = TestTransactionPoolExt::new();
+    externalities.register_extension(TransactionPoolExt::new(pool));
+
+    externalities.execute_with(|| {
+        undercollateralized_setup(unit(100));
+        set_rate_limit(5, 10);
+        oracle::PausedPriceFeeds::<Test>::insert(oracle::OracleKey::ExchangeRate(KBTC), ());
+        Loans::_offchain_worker();
+        assert!(pool_state.read().transactions.is_empty());
+        assert_eq!(
+            Loans::validate_unsigned(TransactionSource::Local, &liquidation_call(unit(50))),
+            InvalidTransaction::Stale.into()
+        );
+    })
+}
+
+#[test]
+fn offchain_worker_repayment_is_limited_by_reserves() {
+    let mut externalities = new_test_ext();
+    let (pool, pool_state) = TestTransactionPoolExt::new();
+    externalities.register_extension(TransactionPoolExt::new(pool));
+
+    externalities.execute_with(|| {
+        undercollateralized_setup(unit(20));
+        set_rate_limit(5, 10);
+        Loans::_offchain_worker();
+
+        let tx = pool_state.write().transactions.pop().unwrap();
+        let tx = Extrinsic::decode(&mut &*tx).unwrap();
+        assert_eq!(tx.call, RuntimeCall::Loans(liquidation_call(unit(20))));
+    })
+}
+
+#[test]
+fn liquidate_borrow_unsigned_repays_from_reserves() {
+    new_test_ext().execute_with(|| {
+        undercollateralized_setup(unit(100));
+        assert_noop!(
+            Loans::liquidate_borrow_unsigned(RuntimeOrigin::none(), ALICE, KSM, unit(50), KBTC),
+            Error::<Test>::ProtocolLiquidationDisabled
+        );
+        set_rate_limit(5, 10);
+        assert_noop!(
+            Loans::liquidate_borrow_unsigned(RuntimeOrigin::signed(BOB), ALICE, KSM, unit(50), KBTC),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Loans::liquidate_borrow_unsigned(RuntimeOrigin::none(), ALICE, KSM, unit(101), KBTC),
+            Error::<Test>::InsufficientReserves
+        );
+
+        assert_ok!(Loans::liquidate_borrow_unsigned(
+            RuntimeOrigin::none(),
+            ALICE,
+            KSM,
+            unit(50),
+            KBTC
+        ));
+        assert_eq!(Loans::total_reserves(KSM).amount(), unit(50));
+        assert_eq!(Loans::current_borrow_balance(&ALICE, KSM).unwrap().amount(), unit(50));
+        // The 110 KBTC of seized collateral, minus the 3 KBTC reserved incentive, is redeemed
+        // into the reserves
+        assert_eq!(Loans::total_reserves(KBTC).amount(), unit(107));
+        assert_eq!(
+            <orml_tokens::Pallet<Test> as MultiCurrency<_>>::free_balance(
+                LEND_KBTC,
+                &Loans::protocol_liquidator_account_id()
+            ),
+            0
+        );
+        assert_eq!(Loans::account_deposits(LEND_KBTC, &ALICE).amount(), unit(90) * 50);
+        assert_eq!(
+            Loans::exchange_rate_stored(KBTC).unwrap(),
+            Rate::saturating_from_rational(2, 100)
+        );
+        assert_eq!(Loans::last_protocol_liquidation(ALICE), Some(1));
+    })
+}
+
+#[test]
+fn protocol_liquidations_are_rate_limited() {
+    new_test_ext().execute_with(|| {
+        undercollateralized_setup(unit(100));
+        set_rate_limit(5, 10);
+        assert_ok!(Loans::liquidate_borrow_unsigned(
+            RuntimeOrigin::none(),
+            ALICE,
+            KSM,
+            unit(20),
+            KBTC
+        ));
+
+        // Alice is still undercollateralized, but within the cooldown
+        System::set_block_number(10);
+        assert_noop!(
+            Loans::liquidate_borrow_unsigned(RuntimeOrigin::none(), ALICE, KSM, unit(20), KBTC),
+            Error::<Test>::ProtocolLiquidationRateLimited
+        );
+        System::set_block_number(11);
+        assert_ok!(Loans::liquidate_borrow_unsigned(
+            RuntimeOrigin::none(),
+            ALICE,
+            KSM,
+            unit(20),
+            KBTC
+        ));
+
+        // Only one liquidation is allowed per block
+        set_rate_limit(1, 0);
+        assert_noop!(
+            Loans::liquidate_borrow_unsigned(RuntimeOrigin::none(), ALICE, KSM, unit(5), KBTC),
+            Error::<Test>::ProtocolLiquidationRateLimited
+        );
+        System::set_block_number(12);
+        assert_ok!(Loans::liquidate_borrow_unsigned(
+            RuntimeOrigin::none(),
+            ALICE,
+            KSM,
+            unit(5),
+            KBTC
+        ));
+    })
+}
+
+#[test]
+fn validate_unsigned_checks_liquidation() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)));
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), KBTC, unit(200)));
+        assert_ok!(Loans::deposit_all_collateral(RuntimeOrigin::signed(ALICE), KBTC));
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(100)));
+        let call = liquidation_call(unit(50));
+
+        assert_eq!(
+            Loans::validate_unsigned(TransactionSource::Local, &call),
+            InvalidTransaction::ExhaustsResources.into()
+        );
+        set_rate_limit(5, 10);
+        // Alice is not undercollateralized yet
+        assert_eq!(
+            Loans::validate_unsigned(TransactionSource::Local, &call),
+            InvalidTransaction::Stale.into()
+        );
+
+        CurrencyConvert::convert.mock_safe(with_price(Some((KSM, 2.into()))));
+        assert!(Loans::validate_unsigned(TransactionSource::Local, &call).is_ok());
+        assert!(Loans::validate_unsigned(TransactionSource::InBlock, &call).is_ok());
+        assert_eq!(
+            Loans::validate_unsigned(TransactionSource::External, &call),
+            InvalidTransaction::Call.into()
+        );
+    })
+}
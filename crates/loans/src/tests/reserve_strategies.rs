@@ -0,0 +1,273 @@
+use super::*;
+use crate::{mock::*, tests::Loans, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use primitives::{
+    CurrencyId::{self, StableLpToken, Token},
+    DOT, INTR, KSM,
+};
+use sp_runtime::DispatchError;
+
+const LP_TOKEN: CurrencyId = StableLpToken(0);
+const SUPPLY_KSM: ReserveStrategy = ReserveStrategy::SupplyMarket(Token(KSM));
+
+fn balance(currency_id: CurrencyId, who: &AccountId) -> u128 {
+    <Tokens as MultiCurrency<_>>::free_balance(currency_id, who)
+}
+
+/// Alice adds 100 DOT to the reserves and governance whitelists `strategy` with `cap`
+fn whitelist_strategy(strategy: ReserveStrategy, cap: u128) {
+    assert_ok!(Loans::add_reserves(RuntimeOrigin::root(), ALICE, Token(DOT), unit(100)));
+    assert_ok!(Loans::set_reserve_strategy(
+        RuntimeOrigin::root(),
+        Token(DOT),
+        strategy,
+        Some(cap)
+    ));
+}
+
+#[test]
+fn set_reserve_strategy_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Loans::set_reserve_strategy(RuntimeOrigin::signed(ALICE), Token(DOT), SUPPLY_KSM, Some(unit(50))),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Loans::set_reserve_strategy(RuntimeOrigin::root(), Token(INTR), SUPPLY_KSM, Some(unit(50))),
+            Error::<Test>::MarketDoesNotExist
+        );
+        assert_noop!(
+            Loans::set_reserve_strategy(
+                RuntimeOrigin::root(),
+                Token(DOT),
+                ReserveStrategy::StablePool(Token(DOT)),
+                Some(unit(50))
+            ),
+            Error::<Test>::InvalidReserveStrategy
+        );
+        assert_noop!(
+            Loans::set_reserve_strategy(
+                RuntimeOrigin::root(),
+                Token(DOT),
+                ReserveStrategy::SupplyMarket(Token(DOT)),
+                Some(unit(50))
+            ),
+            Error::<Test>::InvalidReserveStrategy
+        );
+        assert_noop!(
+            Loans::set_reserve_strategy(
+                RuntimeOrigin::root(),
+                Token(DOT),
+                ReserveStrategy::SupplyMarket(Token(INTR)),
+                Some(unit(50))
+            ),
+            Error::<Test>::MarketDoesNotExist
+        );
+
+        assert_ok!(Loans::set_reserve_strategy(
+            RuntimeOrigin::root(),
+            Token(DOT),
+            SUPPLY_KSM,
+            Some(unit(50))
+        ));
+        assert_eq!(
+            Loans::reserve_strategy(Token(DOT), SUPPLY_KSM).map(|info| info.cap),
+            Some(unit(50))
+        );
+        System::assert_last_event(RuntimeEvent::Loans(Event::ReserveStrategyUpdated {
+            currency_id: Token(DOT),
+            strategy: SUPPLY_KSM,
+            cap: Some(unit(50)),
+        }));
+
+        assert_ok!(Loans::set_reserve_strategy(
+            RuntimeOrigin::root(),
+            Token(DOT),
+            SUPPLY_KSM,
+            None
+        ));
+        assert_eq!(Loans::reserve_strategy(Token(DOT), SUPPLY_KSM), None);
+    })
+}
+
+#[test]
+fn deploy_reserves_respects_whitelist_cap_and_reserves() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Loans::add_reserves(RuntimeOrigin::root(), ALICE, Token(DOT), unit(100)));
+        assert_noop!(
+            Loans::deploy_reserves(RuntimeOrigin::root(), Token(DOT), SUPPLY_KSM, unit(10), 0),
+            Error::<Test>::ReserveStrategyNotWhitelisted
+        );
+
+        assert_ok!(Loans::set_reserve_strategy(
+            RuntimeOrigin::root(),
+            Token(DOT),
+            SUPPLY_KSM,
+            Some(unit(200))
+        ));
+        assert_noop!(
+            Loans::deploy_reserves(RuntimeOrigin::root(), Token(DOT), SUPPLY_KSM, unit(201), 0),
+            Error::<Test>::ReserveStrategyCapExceeded
+        );
+        assert_noop!(
+            Loans::deploy_reserves(RuntimeOrigin::root(), Token(DOT), SUPPLY_KSM, unit(101), 0),
+            Error::<Test>::InsufficientReserves
+        );
+    })
+}
+
+#[test]
+fn supply_market_strategy_works() {
+    new_test_ext().execute_with(|| {
+        whitelist_strategy(SUPPLY_KSM, unit(50));
+        let strategy_account = Loans::reserve_strategy_account_id();
+        let ksm_cash = Loans::get_total_cash(Token(KSM)).amount();
+
+        // the reserves are swapped for KSM and supplied to the KSM market
+        assert_ok!(Loans::deploy_reserves(
+            RuntimeOrigin::root(),
+            Token(DOT),
+            SUPPLY_KSM,
+            unit(50),
+            0
+        ));
+        assert_eq!(Loans::total_reserves(Token(DOT)).amount(), unit(50));
+        let info = Loans::reserve_strategy(Token(DOT), SUPPLY_KSM).unwrap();
+        assert_eq!(info.deployed, unit(50));
+        assert_eq!(info.total_deployed, unit(50));
+        assert_eq!(info.position, balance(LEND_KSM, &strategy_account));
+        assert_eq!(Loans::get_total_cash(Token(KSM)).amount(), ksm_cash + unit(50));
+        System::assert_last_event(RuntimeEvent::Loans(Event::ReservesDeployed {
+            currency_id: Token(DOT),
+            strategy: SUPPLY_KSM,
+            amount: unit(50),
+            received: info.position,
+            new_reserve_amount: unit(50),
+        }));
+
+        // the strategy cannot be removed while it holds lend tokens
+        assert_noop!(
+            Loans::set_reserve_strategy(RuntimeOrigin::root(), Token(DOT), SUPPLY_KSM, None),
+            Error::<Test>::ReserveStrategyInUse
+        );
+        assert_noop!(
+            Loans::withdraw_reserves(RuntimeOrigin::root(), Token(DOT), SUPPLY_KSM, info.position + 1, 0),
+            Error::<Test>::InsufficientReserveStrategyPosition
+        );
+        assert_noop!(
+            Loans::withdraw_reserves(RuntimeOrigin::root(), Token(DOT), SUPPLY_KSM, info.position, unit(51)),
+            DispatchError::Other("Insufficient target amount")
+        );
+
+        assert_ok!(Loans::withdraw_reserves(
+            RuntimeOrigin::root(),
+            Token(DOT),
+            SUPPLY_KSM,
+            info.position,
+            unit(50)
+        ));
+        assert_eq!(Loans::total_reserves(Token(DOT)).amount(), unit(100));
+        assert_eq!(balance(LEND_KSM, &strategy_account), 0);
+        assert_eq!(Loans::get_total_cash(Token(KSM)).amount(), ksm_cash);
+        let info = Loans::reserve_strategy(Token(DOT), SUPPLY_KSM).unwrap();
+        assert_eq!(info.position, 0);
+        assert_eq!(info.deployed, 0);
+        assert_eq!(info.total_returned, unit(50));
+    })
+}
+
+#[test]
+fn stable_pool_strategy_works() {
+    new_test_ext().execute_with(|| {
+        whitelist_strategy(ReserveStrategy::StablePool(LP_TOKEN), unit(50));
+        let strategy_account = Loans::reserve_strategy_account_id();
+
+        assert_ok!(Loans::deploy_reserves(
+            RuntimeOrigin::root(),
+            Token(DOT),
+            ReserveStrategy::StablePool(LP_TOKEN),
+            unit(40),
+            unit(40)
+        ));
+        assert_eq!(balance(LP_TOKEN, &strategy_account), unit(40));
+        let info = Loans::reserve_strategy(Token(DOT), ReserveStrategy::StablePool(LP_TOKEN)).unwrap();
+        assert_eq!(info.position, unit(40));
+        assert_eq!(info.deployed, unit(40));
+
+        assert_ok!(Loans::withdraw_reserves(
+            RuntimeOrigin::root(),
+            Token(DOT),
+            ReserveStrategy::StablePool(LP_TOKEN),
+            unit(15),
+            unit(15)
+        ));
+        assert_eq!(Loans::total_reserves(Token(DOT)).amount(), unit(75));
+        System::assert_last_event(RuntimeEvent::Loans(Event::ReservesWithdrawn {
+            currency_id: Token(DOT),
+            strategy: ReserveStrategy::StablePool(LP_TOKEN),
+            amount: unit(15),
+            returned: unit(15),
+            new_reserve_amount: unit(75),
+        }));
+        let info = Loans::reserve_strategy(Token(DOT), ReserveStrategy::StablePool(LP_TOKEN)).unwrap();
+        assert_eq!(info.position, unit(25));
+        assert_eq!(info.deployed, unit(25));
+        assert_eq!(info.total_deployed, unit(40));
+        assert_eq!(info.total_returned, unit(15));
+    })
+}
+
+#[test]
+fn buy_back_and_burn_strategy_works() {
+    new_test_ext().execute_with(|| {
+        whitelist_strategy(ReserveStrategy::BuyBackAndBurn, unit(50));
+        let strategy_account = Loans::reserve_strategy_account_id();
+        let native_issuance = <Tokens as MultiCurrency<_>>::total_issuance(Token(INTR));
+
+        assert_ok!(Loans::deploy_reserves(
+            RuntimeOrigin::root(),
+            Token(DOT),
+            ReserveStrategy::BuyBackAndBurn,
+            unit(30),
+            unit(30)
+        ));
+        System::assert_last_event(RuntimeEvent::Loans(Event::ReservesDeployed {
+            currency_id: Token(DOT),
+            strategy: ReserveStrategy::BuyBackAndBurn,
+            amount: unit(30),
+            received: unit(30),
+            new_reserve_amount: unit(70),
+        }));
+        // the bought native currency is burned
+        assert_eq!(balance(Token(INTR), &strategy_account), 0);
+        assert_eq!(
+            <Tokens as MultiCurrency<_>>::total_issuance(Token(INTR)),
+            native_issuance
+        );
+        let info = Loans::reserve_strategy(Token(DOT), ReserveStrategy::BuyBackAndBurn).unwrap();
+        assert_eq!(info.position, 0);
+        assert_eq!(info.deployed, unit(30));
+
+        // burned reserves are never returned, so the cap bounds the total amount burned
+        assert_noop!(
+            Loans::deploy_reserves(
+                RuntimeOrigin::root(),
+                Token(DOT),
+                ReserveStrategy::BuyBackAndBurn,
+                unit(30),
+                0
+            ),
+            Error::<Test>::ReserveStrategyCapExceeded
+        );
+        assert_noop!(
+            Loans::withdraw_reserves(
+                RuntimeOrigin::root(),
+                Token(DOT),
+                ReserveStrategy::BuyBackAndBurn,
+                unit(30),
+                0
+            ),
+            Error::<Test>::ReserveStrategyNotWithdrawable
+        );
+    })
+}
@@ -4,6 +4,10 @@ use currency::Amount;
 use frame_support::pallet_prelude::*;
 use primitives::{CurrencyId, Liquidity, Rate, Ratio, Shortfall};
 use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{One, Saturating},
+    FixedPointNumber,
+};
 
 // TODO: `cargo doc` crashes on this type, remove the `hidden` macro
 // when upgrading rustc in case that fixes it
@@ -56,6 +60,174 @@ impl<T: Config> AccountLiquidity<T> {
     }
 }
 
+/// Efficiency group of correlated markets. Accounts that entered the group and only borrow
+/// assets in it get these parameters for collateral in the group's markets.
+#[derive(
+    serde::Deserialize,
+    serde::Serialize,
+    Encode,
+    Decode,
+    Eq,
+    PartialEq,
+    Copy,
+    Clone,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct EfficiencyGroup {
+    /// The secure collateral ratio of collateral in the group
+    pub collateral_factor: Ratio,
+    /// The collateral ratio of collateral in the group when a borrower can be liquidated
+    pub liquidation_threshold: Ratio,
+}
+
+/// Rate limits of the liquidations submitted by the offchain worker.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, Default, TypeInfo, MaxEncodedLen)]
+pub struct LiquidationRateLimit<BlockNumber> {
+    /// Maximum number of protocol liquidations per block
+    pub max_liquidations_per_block: u32,
+    /// Minimum number of blocks between two protocol liquidations of the same borrower
+    pub borrower_cooldown: BlockNumber,
+}
+
+/// Ranges of the liquidation parameters of a market, which scale with the health factor of the
+/// borrower: from the minimum when the account just became liquidatable, to the maximum once
+/// its health factor dropped to `critical_health_factor`.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct LiquidationScaling {
+    /// Close factor of barely unhealthy accounts
+    pub min_close_factor: Ratio,
+    /// Close factor of critically unhealthy accounts
+    pub max_close_factor: Ratio,
+    /// Liquidation incentive of barely unhealthy accounts
+    pub min_liquidate_incentive: Rate,
+    /// Liquidation incentive of critically unhealthy accounts
+    pub max_liquidate_incentive: Rate,
+    /// Health factor from which the maximum close factor and incentive apply
+    pub critical_health_factor: Ratio,
+}
+
+impl LiquidationScaling {
+    pub fn is_valid(&self) -> bool {
+        self.min_close_factor > Ratio::zero()
+            && self.min_close_factor <= self.max_close_factor
+            && self.min_liquidate_incentive >= Rate::one()
+            && self.min_liquidate_incentive <= self.max_liquidate_incentive
+            && self.critical_health_factor < Ratio::one()
+    }
+
+    /// Close factor and liquidation incentive of an account with the given health factor
+    pub fn parameters(&self, health_factor: Rate) -> (Ratio, Rate) {
+        // how far the account is between the liquidation point and the critical health factor
+        let progress = Ratio::from_rational(
+            Rate::one().saturating_sub(health_factor).into_inner(),
+            Rate::one()
+                .saturating_sub(self.critical_health_factor.into())
+                .into_inner(),
+        );
+        let close_factor = self
+            .min_close_factor
+            .saturating_add(progress * self.max_close_factor.saturating_sub(self.min_close_factor));
+        let liquidate_incentive = self
+            .max_liquidate_incentive
+            .saturating_sub(self.min_liquidate_incentive)
+            .saturating_mul(progress.into())
+            .saturating_add(self.min_liquidate_incentive);
+        (close_factor, liquidate_incentive)
+    }
+}
+
+/// Strategy into which governance can deploy the reserves of a market
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ReserveStrategy {
+    /// Swap the reserves for the underlying of the given market and supply them to it, earning
+    /// the supply rate on the minted lend tokens
+    SupplyMarket(CurrencyId),
+    /// Add the reserves as single-sided liquidity to the `dex-stable` pool of the given LP token
+    StablePool(CurrencyId),
+    /// Swap the reserves for the native currency and burn it
+    BuyBackAndBurn,
+}
+
+/// Cap and accounting of the reserves of a market deployed into a whitelisted strategy
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ReserveStrategyInfo<Balance> {
+    /// Maximum amount of reserves deployed into the strategy at any time. Since burned reserves
+    /// are never returned, this caps the total amount bought back and burned.
+    pub cap: Balance,
+    /// Reserves currently deployed into the strategy
+    pub deployed: Balance,
+    /// Lend tokens or LP tokens held by the strategy
+    pub position: Balance,
+    /// Reserves ever deployed into the strategy
+    pub total_deployed: Balance,
+    /// Reserves ever returned by the strategy, including its earnings
+    pub total_returned: Balance,
+}
+
+/// Maturity bucket of fixed-term lending in a market. Lenders share the repayments of the
+/// borrowers pro rata to their shares and can only withdraw once the bucket matured.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct FixedTermBucket<Balance> {
+    /// Annual interest rate borrowers lock until maturity
+    pub rate: Rate,
+    /// Share of the interest of the remaining term charged on early repayment
+    pub early_repayment_penalty: Ratio,
+    /// Annual interest rate charged on debt that is overdue
+    pub overdue_penalty_rate: Rate,
+    /// Shares issued to lenders
+    pub total_shares: Balance,
+    /// Deposits that are not lent out, and repayments
+    pub cash: Balance,
+    /// Principal and interest of the outstanding loans, due at maturity
+    pub total_debt: Balance,
+}
+
+impl<Balance: Copy + Saturating> FixedTermBucket<Balance> {
+    /// Amount owed to the lenders, assuming the outstanding loans are repaid at maturity
+    pub fn value(&self) -> Balance {
+        self.cash.saturating_add(self.total_debt)
+    }
+}
+
+/// Fixed-term loan of an account in a maturity bucket
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct FixedTermLoan<Balance> {
+    /// Borrowed amount
+    pub principal: Balance,
+    /// Interest due at maturity
+    pub interest: Balance,
+}
+
+/// Caps of a market denominated in the reference currency, which apply in addition to the
+/// native `supply_cap` and `borrow_cap` of the market.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ValueCaps<Balance> {
+    /// Maximum value of the cash held by the market, if any
+    pub supply_cap: Option<Balance>,
+    /// Maximum value of the total borrows of the market, if any
+    pub borrow_cap: Option<Balance>,
+}
+
+/// Limit on the net amount of cash redeemed and borrowed from a market per window.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct OutflowLimit<Balance, BlockNumber> {
+    /// Maximum outflow net of mints and repayments within a window
+    pub max_net_outflow: Balance,
+    /// Length of a window in blocks
+    pub window: BlockNumber,
+}
+
+/// Net outflow of a market in the current window of its `OutflowLimit`.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct OutflowWindow<Balance, BlockNumber> {
+    /// Block in which the window started
+    pub start: BlockNumber,
+    /// Redeemed and borrowed amount net of mints and repayments since `start`
+    pub net_outflow: Balance,
+}
+
 /// Container for borrow balance information
 #[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, Default, TypeInfo, MaxEncodedLen)]
 pub struct BorrowSnapshot<Balance> {
@@ -1,12 +1,15 @@
 use super::*;
 use currency::getters::{get_relay_chain_currency_id as get_collateral_currency_id, *};
-use frame_benchmarking::v2::{account, benchmarks, impl_benchmark_test_suite};
-use frame_support::assert_ok;
+use frame_benchmarking::v2::{account, benchmarks, impl_benchmark_test_suite, Linear};
+use frame_support::{assert_ok, traits::Hooks};
 use frame_system::RawOrigin;
 use orml_traits::{MultiCurrency, MultiReservableCurrency};
 use primitives::CurrencyId;
 use sp_core::Get;
-use sp_runtime::traits::One;
+use sp_runtime::{
+    traits::{One, Zero},
+    FixedPointNumber,
+};
 use sp_std::vec;
 use vault_registry::{
     benchmarking::{activate_lending_and_get_vault_id, mint_lend_tokens},
@@ -195,6 +198,144 @@ pub mod benchmarks {
         assert_eq!(balance_before - amount, balance_after);
     }
 
+    #[benchmark]
+    pub fn set_insurance_payout_cap() {
+        #[extrinsic_call]
+        _(RawOrigin::Root, 1000u32.into());
+    }
+
+    #[benchmark]
+    pub fn claim_slashing_insurance() {
+        setup_exchange_rate::<T>();
+        <NominationEnabled<T>>::set(true);
+
+        let vault_id = activate_lending_and_get_vault_id::<T>();
+        register_vault::<T>(vault_id.clone());
+
+        <Vaults<T>>::insert(&vault_id, true);
+
+        Nomination::<T>::set_nomination_limit(
+            RawOrigin::Signed(vault_id.account_id.clone()).into(),
+            vault_id.currencies.clone(),
+            (1u32 << 31).into(),
+        )
+        .unwrap();
+
+        let nominator: T::AccountId = account("Nominator", 0, 0);
+        let collateral_currency = vault_id.collateral_currency();
+        if collateral_currency.is_lend_token() {
+            mint_lend_tokens::<T>(&nominator, collateral_currency);
+        } else {
+            mint_collateral::<T>(&nominator, (1u32 << 31).into());
+        }
+        let amount = 100_000_000u32.into();
+
+        assert_ok!(Nomination::<T>::_deposit_collateral(&vault_id, &nominator, amount));
+
+        // slash half of the backing collateral, nominated collateral included
+        let slashed = Amount::<T>::new(100_000_000u32.into(), collateral_currency);
+        assert_ok!(vault_registry::PoolManager::<T>::slash_collateral(&vault_id, &slashed));
+
+        <InsurancePayoutCap<T>>::put(BalanceOf::<T>::from(1u32 << 31));
+        deposit_tokens::<T>(
+            vault_id.wrapped_currency(),
+            &Fee::<T>::insurance_fund_account_id(),
+            (1u32 << 31).into(),
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(nominator.clone()), vault_id.clone(), None);
+
+        assert!(!<orml_tokens::Pallet<T>>::free_balance(vault_id.wrapped_currency(), &nominator).is_zero());
+    }
+
+    #[benchmark]
+    pub fn set_auto_compound() {
+        setup_exchange_rate::<T>();
+        <NominationEnabled<T>>::set(true);
+
+        let vault_id = activate_lending_and_get_vault_id::<T>();
+        register_vault::<T>(vault_id.clone());
+        <Vaults<T>>::insert(&vault_id, true);
+        Nomination::<T>::set_nomination_limit(
+            RawOrigin::Signed(vault_id.account_id.clone()).into(),
+            vault_id.currencies.clone(),
+            (1u32 << 31).into(),
+        )
+        .unwrap();
+
+        let nominator: T::AccountId = account("Nominator", 0, 0);
+        let collateral_currency = vault_id.collateral_currency();
+        if collateral_currency.is_lend_token() {
+            mint_lend_tokens::<T>(&nominator, collateral_currency);
+        } else {
+            mint_collateral::<T>(&nominator, (1u32 << 31).into());
+        }
+        assert_ok!(Nomination::<T>::_deposit_collateral(
+            &vault_id,
+            &nominator,
+            100u32.into()
+        ));
+        let max_slippage = UnsignedFixedPoint::<T>::checked_from_rational(1, 100).unwrap();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(nominator.clone()),
+            vault_id.clone(),
+            Some(max_slippage),
+        );
+
+        assert_eq!(<AutoCompounds<T>>::get().len(), 1);
+    }
+
+    #[benchmark]
+    pub fn on_initialize(n: Linear<0, 10>) {
+        setup_exchange_rate::<T>();
+        <NominationEnabled<T>>::set(true);
+
+        let vault_id = activate_lending_and_get_vault_id::<T>();
+        register_vault::<T>(vault_id.clone());
+
+        <Vaults<T>>::insert(&vault_id, true);
+
+        Nomination::<T>::set_nomination_limit(
+            RawOrigin::Signed(vault_id.account_id.clone()).into(),
+            vault_id.currencies.clone(),
+            (1u32 << 31).into(),
+        )
+        .unwrap();
+
+        let collateral_currency = vault_id.collateral_currency();
+        let max_slippage = UnsignedFixedPoint::<T>::checked_from_rational(1, 100).unwrap();
+        for i in 0..n {
+            let nominator: T::AccountId = account("Nominator", i, 0);
+            if collateral_currency.is_lend_token() {
+                mint_lend_tokens::<T>(&nominator, collateral_currency);
+            } else {
+                mint_collateral::<T>(&nominator, (1u32 << 31).into());
+            }
+            assert_ok!(Nomination::<T>::_deposit_collateral(
+                &vault_id,
+                &nominator,
+                100u32.into()
+            ));
+            assert_ok!(Nomination::<T>::_set_auto_compound(
+                &vault_id,
+                &nominator,
+                Some(max_slippage)
+            ));
+        }
+
+        // only two reward currencies supported
+        distribute_rewards::<T>(T::GetWrappedCurrencyId::get());
+        distribute_rewards::<T>(T::GetNativeCurrencyId::get());
+
+        #[block]
+        {
+            Nomination::<T>::on_initialize(T::AutoCompoundPeriod::get());
+        }
+    }
+
     impl_benchmark_test_suite!(
         Nomination,
         crate::mock::ExtBuilder::build_with(Default::default()),
@@ -71,7 +71,7 @@ pub(crate) mod vault_registry {
 pub(crate) mod staking {
     use crate::BalanceOf;
     use frame_support::dispatch::DispatchError;
-    use staking::{RewardsApi, StakingApi};
+    use staking::{RewardsApi, SlashingApi, StakingApi};
     use vault_registry::DefaultVaultId;
 
     pub fn nonce<T: crate::Config>(vault_id: &DefaultVaultId<T>) -> T::Nonce {
@@ -84,4 +84,54 @@ pub(crate) mod staking {
     ) -> Result<BalanceOf<T>, DispatchError> {
         T::VaultStaking::get_stake(&(None, vault_id.clone()), nominator_id)
     }
+
+    pub fn compute_total_slash<T: crate::Config>(
+        vault_id: &DefaultVaultId<T>,
+        nominator_id: &T::AccountId,
+        index: T::Nonce,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        T::VaultSlashing::get_total_slash(&(Some(index), vault_id.clone()), nominator_id)
+    }
+}
+
+#[cfg_attr(test, mockable)]
+pub(crate) mod fee {
+    use currency::{Amount, CurrencyId};
+    use frame_support::dispatch::DispatchError;
+    use vault_registry::DefaultVaultId;
+
+    pub fn insurance_fund_account_id<T: crate::Config>() -> T::AccountId {
+        <fee::Pallet<T>>::insurance_fund_account_id()
+    }
+
+    pub fn withdraw_vault_rewards<T: crate::Config>(
+        vault_id: &DefaultVaultId<T>,
+        nominator_id: &T::AccountId,
+        currency_id: CurrencyId<T>,
+    ) -> Result<Amount<T>, DispatchError> {
+        let rewards = <fee::Pallet<T>>::withdraw_vault_rewards(vault_id, nominator_id, None, currency_id)?;
+        Ok(Amount::new(rewards, currency_id))
+    }
+}
+
+#[cfg_attr(test, mockable)]
+pub(crate) mod swap {
+    use currency::Amount;
+    use frame_support::dispatch::DispatchError;
+    use traits::SwapApi;
+
+    pub fn swap_exact_tokens_for_tokens<T: crate::Config>(
+        account_id: &T::AccountId,
+        amount_in: &Amount<T>,
+        min_amount_out: &Amount<T>,
+    ) -> Result<Amount<T>, DispatchError> {
+        let amount_out = T::Swap::swap_exact_tokens_for_tokens(
+            account_id,
+            amount_in.currency(),
+            amount_in.amount(),
+            min_amount_out.currency(),
+            min_amount_out.amount(),
+        )?;
+        Ok(Amount::new(amount_out, min_amount_out.currency()))
+    }
 }
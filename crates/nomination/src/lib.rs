@@ -24,17 +24,23 @@ mod benchmarking;
 mod default_weights;
 pub use default_weights::WeightInfo;
 
-use currency::Amount;
+use currency::{Amount, CurrencyId};
 use frame_support::{
     dispatch::{DispatchError, DispatchResult},
-    ensure, transactional,
+    ensure,
+    traits::Get,
+    transactional,
 };
 use frame_system::{ensure_root, ensure_signed};
 pub use pallet::*;
 use primitives::VaultId;
+use sp_runtime::traits::{One, Saturating, Zero};
+use staking::SlashingApi;
 
 pub(crate) type BalanceOf<T> = <T as currency::Config>::Balance;
 
+pub(crate) type UnsignedFixedPoint<T> = <T as currency::Config>::UnsignedFixedPoint;
+
 pub(crate) type DefaultVaultId<T> = VaultId<<T as frame_system::Config>::AccountId, currency::CurrencyId<T>>;
 
 #[frame_support::pallet]
@@ -53,6 +59,20 @@ pub mod pallet {
 
         /// Weight information for the extrinsics in this module.
         type WeightInfo: WeightInfo;
+
+        /// Used to swap rewards into collateral when auto-compounding.
+        type Swap: traits::SwapApi<Self::AccountId, CurrencyId<Self>, BalanceOf<Self>>;
+
+        /// The number of blocks between auto-compounding rewards, zero disables auto-compounding.
+        #[pallet::constant]
+        type AutoCompoundPeriod: Get<BlockNumberFor<Self>>;
+
+        /// The maximum number of stakes that can be auto-compounded.
+        #[pallet::constant]
+        type MaxAutoCompounds: Get<u32>;
+
+        /// Reports the collateral slashed from nominators, which the insurance fund compensates.
+        type VaultSlashing: SlashingApi<(Option<Self::Nonce>, DefaultVaultId<Self>), Self::AccountId, BalanceOf<Self>>;
     }
 
     #[pallet::event]
@@ -74,6 +94,30 @@ pub mod pallet {
             nominator_id: T::AccountId,
             amount: BalanceOf<T>,
         },
+        SetInsurancePayoutCap {
+            cap: BalanceOf<T>,
+        },
+        ClaimSlashingInsurance {
+            vault_id: DefaultVaultId<T>,
+            nominator_id: T::AccountId,
+            index: T::Nonce,
+            /// The amount of slashed collateral covered by this payout.
+            compensated_collateral: BalanceOf<T>,
+            /// The amount of wrapped currency paid out of the insurance fund.
+            payout: BalanceOf<T>,
+            /// The amount of slashed collateral that remains uncompensated.
+            uncompensated_collateral: BalanceOf<T>,
+        },
+        SetAutoCompound {
+            vault_id: DefaultVaultId<T>,
+            nominator_id: T::AccountId,
+            max_slippage: Option<UnsignedFixedPoint<T>>,
+        },
+        AutoCompound {
+            vault_id: DefaultVaultId<T>,
+            nominator_id: T::AccountId,
+            amount: BalanceOf<T>,
+        },
     }
 
     #[pallet::error]
@@ -92,10 +136,37 @@ pub mod pallet {
         NominationExceedsLimit,
         /// Vault cannot withdraw.
         CollateralizationTooLow,
+        /// Vault operators cannot claim insurance for their own collateral.
+        VaultCannotClaimInsurance,
+        /// Nominator has no uncompensated slash.
+        NoSlashToCompensate,
+        /// The insurance fund or the payout cap of the staking pool is exhausted.
+        InsurancePayoutUnavailable,
+        /// The slippage bound must be at most 100%.
+        InvalidSlippage,
+        /// Maximum number of auto-compounding stakes reached.
+        TooManyAutoCompounds,
+        /// Only nominators with a stake in the vault can auto-compound.
+        NoStakeToCompound,
     }
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let period = T::AutoCompoundPeriod::get();
+            if period.is_zero() || !(n % period).is_zero() {
+                return Weight::zero();
+            }
+            let auto_compounds = AutoCompounds::<T>::get();
+            for (vault_id, nominator_id, max_slippage) in auto_compounds.iter() {
+                // failures roll back and are retried in the next period
+                if let Err(e) = Self::_auto_compound(vault_id, nominator_id, *max_slippage) {
+                    log::warn!("Failed to auto-compound rewards for {:?}: {:?}", nominator_id, e);
+                }
+            }
+            <T as Config>::WeightInfo::on_initialize(auto_compounds.len() as u32)
+        }
+    }
 
     /// Flag indicating whether this feature is enabled
     #[pallet::storage]
@@ -111,6 +182,38 @@ pub mod pallet {
     pub(super) type NominationLimit<T: Config> =
         StorageMap<_, Blake2_128Concat, DefaultVaultId<T>, BalanceOf<T>, ValueQuery>;
 
+    /// The maximum amount of wrapped currency paid out of the insurance fund for the
+    /// slashes of a single vault staking pool. Zero disables insurance claims.
+    #[pallet::storage]
+    #[pallet::getter(fn insurance_payout_cap)]
+    pub type InsurancePayoutCap<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// The amount of wrapped currency paid out of the insurance fund per vault staking pool.
+    #[pallet::storage]
+    pub(super) type InsurancePayouts<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::Nonce, Blake2_128Concat, DefaultVaultId<T>, BalanceOf<T>, ValueQuery>;
+
+    /// The amount of slashed collateral of each nominator that has already been compensated.
+    #[pallet::storage]
+    pub(super) type CompensatedSlash<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::Nonce,
+        Blake2_128Concat,
+        (DefaultVaultId<T>, T::AccountId),
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// The stakes whose rewards are periodically swapped into collateral and deposited,
+    /// with the maximum slippage relative to the oracle price.
+    #[pallet::storage]
+    pub type AutoCompounds<T: Config> = StorageValue<
+        _,
+        BoundedVec<(DefaultVaultId<T>, T::AccountId, UnsignedFixedPoint<T>), T::MaxAutoCompounds>,
+        ValueQuery,
+    >;
+
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
@@ -212,6 +315,63 @@ pub mod pallet {
             NominationLimit::<T>::insert(vault_id, limit);
             Ok(().into())
         }
+
+        /// Set the maximum payout of the insurance fund per vault staking pool (only executable by the Root account)
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - signing account
+        /// * `cap` - the new cap, denominated in the wrapped currency
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_insurance_payout_cap())]
+        #[transactional]
+        pub fn set_insurance_payout_cap(origin: OriginFor<T>, cap: BalanceOf<T>) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            InsurancePayoutCap::<T>::put(cap);
+            Self::deposit_event(Event::<T>::SetInsurancePayoutCap { cap });
+            Ok(().into())
+        }
+
+        /// Claim compensation from the insurance fund for nominated collateral that was slashed.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - the nominator
+        /// * `vault_id` - the vault that was nominated
+        /// * `index` - the nonce of the staking pool, defaults to the current pool
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as Config>::WeightInfo::claim_slashing_insurance())]
+        #[transactional]
+        pub fn claim_slashing_insurance(
+            origin: OriginFor<T>,
+            vault_id: DefaultVaultId<T>,
+            index: Option<T::Nonce>,
+        ) -> DispatchResultWithPostInfo {
+            let nominator_id = ensure_signed(origin)?;
+            let nonce = ext::staking::nonce::<T>(&vault_id);
+            Self::_claim_slashing_insurance(&vault_id, &nominator_id, index.unwrap_or(nonce))?;
+            Ok(().into())
+        }
+
+        /// Periodically compound the rewards of the caller's stake into collateral.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - the nominator or vault operator
+        /// * `vault_id` - the vault that was nominated
+        /// * `max_slippage` - the maximum slippage of swaps relative to the oracle price, `None` to opt out
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_auto_compound())]
+        #[transactional]
+        pub fn set_auto_compound(
+            origin: OriginFor<T>,
+            vault_id: DefaultVaultId<T>,
+            max_slippage: Option<UnsignedFixedPoint<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let nominator_id = ensure_signed(origin)?;
+            Self::_set_auto_compound(&vault_id, &nominator_id, max_slippage)?;
+            Ok(().into())
+        }
     }
 }
 
@@ -256,6 +416,13 @@ impl<T: Config> Pallet<T> {
 
         ext::vault_registry::decrease_total_backing_collateral(&vault_id.currencies, &amount)?;
 
+        // free the auto-compounding slot of a stake that was withdrawn entirely
+        if ext::staking::compute_stake::<T>(vault_id, nominator_id)?.is_zero() {
+            AutoCompounds::<T>::mutate(|auto_compounds| {
+                auto_compounds.retain(|(x, y, _)| x != vault_id || y != nominator_id)
+            });
+        }
+
         Self::deposit_event(Event::<T>::WithdrawCollateral {
             vault_id: vault_id.clone(),
             nominator_id: nominator_id.clone(),
@@ -299,6 +466,151 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Compensate the nominator for slashed collateral out of the insurance fund. The
+    /// payout is limited by the remaining cap of the staking pool and the fund balance,
+    /// any remainder can be claimed once these are replenished.
+    ///
+    /// # Arguments
+    /// * `vault_id` - the vault that was nominated
+    /// * `nominator_id` - the nominator to compensate
+    /// * `index` - the nonce of the staking pool
+    pub fn _claim_slashing_insurance(
+        vault_id: &DefaultVaultId<T>,
+        nominator_id: &T::AccountId,
+        index: T::Nonce,
+    ) -> DispatchResult {
+        ensure!(
+            &vault_id.account_id != nominator_id,
+            Error::<T>::VaultCannotClaimInsurance
+        );
+        let index = sp_std::cmp::min(index, ext::staking::nonce::<T>(vault_id));
+
+        let total_slash = Amount::<T>::new(
+            ext::staking::compute_total_slash::<T>(vault_id, nominator_id, index)?,
+            vault_id.collateral_currency(),
+        );
+        let compensated = Amount::<T>::new(
+            CompensatedSlash::<T>::get(index, (vault_id, nominator_id)),
+            vault_id.collateral_currency(),
+        );
+        let uncompensated = total_slash.saturating_sub(&compensated)?;
+        ensure!(!uncompensated.is_zero(), Error::<T>::NoSlashToCompensate);
+
+        let fund_account_id = ext::fee::insurance_fund_account_id::<T>();
+        let claimed = uncompensated.convert_to(vault_id.wrapped_currency())?;
+        let remaining_cap = Amount::<T>::new(
+            Self::insurance_payout_cap().saturating_sub(InsurancePayouts::<T>::get(index, vault_id)),
+            vault_id.wrapped_currency(),
+        );
+        let fund_balance = currency::get_free_balance::<T>(vault_id.wrapped_currency(), &fund_account_id);
+        let payout = claimed.min(&remaining_cap)?.min(&fund_balance)?;
+        ensure!(!payout.is_zero(), Error::<T>::InsurancePayoutUnavailable);
+
+        // a partial payout only covers the equivalent amount of collateral
+        let covered = if payout.eq(&claimed)? {
+            uncompensated.clone()
+        } else {
+            payout.convert_to(vault_id.collateral_currency())?.min(&uncompensated)?
+        };
+
+        payout.transfer(&fund_account_id, nominator_id)?;
+        InsurancePayouts::<T>::mutate(index, vault_id, |paid| {
+            *paid = paid.saturating_add(payout.amount());
+        });
+        CompensatedSlash::<T>::insert(
+            index,
+            (vault_id, nominator_id),
+            compensated.checked_add(&covered)?.amount(),
+        );
+
+        Self::deposit_event(Event::<T>::ClaimSlashingInsurance {
+            vault_id: vault_id.clone(),
+            nominator_id: nominator_id.clone(),
+            index,
+            compensated_collateral: covered.amount(),
+            payout: payout.amount(),
+            uncompensated_collateral: uncompensated.saturating_sub(&covered)?.amount(),
+        });
+        Ok(())
+    }
+
+    fn _set_auto_compound(
+        vault_id: &DefaultVaultId<T>,
+        nominator_id: &T::AccountId,
+        max_slippage: Option<UnsignedFixedPoint<T>>,
+    ) -> DispatchResult {
+        AutoCompounds::<T>::try_mutate(|auto_compounds| {
+            auto_compounds.retain(|(x, y, _)| x != vault_id || y != nominator_id);
+            if let Some(max_slippage) = max_slippage {
+                ensure!(
+                    max_slippage <= UnsignedFixedPoint::<T>::one(),
+                    Error::<T>::InvalidSlippage
+                );
+                // the number of auto-compounding stakes is bounded, so only actual stakes may
+                // take up a slot
+                ensure!(
+                    !ext::staking::compute_stake::<T>(vault_id, nominator_id)?.is_zero(),
+                    Error::<T>::NoStakeToCompound
+                );
+                auto_compounds
+                    .try_push((vault_id.clone(), nominator_id.clone(), max_slippage))
+                    .map_err(|_| Error::<T>::TooManyAutoCompounds)?;
+            }
+            Ok::<_, DispatchError>(())
+        })?;
+
+        Self::deposit_event(Event::<T>::SetAutoCompound {
+            vault_id: vault_id.clone(),
+            nominator_id: nominator_id.clone(),
+            max_slippage,
+        });
+        Ok(())
+    }
+
+    /// Withdraw the rewards of a stake, swap them into collateral and deposit the
+    /// collateral into the same stake.
+    ///
+    /// # Arguments
+    /// * `vault_id` - the vault that was nominated
+    /// * `nominator_id` - the owner of the stake
+    /// * `max_slippage` - the maximum slippage of swaps relative to the oracle price
+    #[transactional]
+    pub fn _auto_compound(
+        vault_id: &DefaultVaultId<T>,
+        nominator_id: &T::AccountId,
+        max_slippage: UnsignedFixedPoint<T>,
+    ) -> DispatchResult {
+        let collateral_currency = vault_id.collateral_currency();
+        let mut collateral = Amount::<T>::zero(collateral_currency);
+
+        for currency_id in [vault_id.wrapped_currency(), T::GetNativeCurrencyId::get()] {
+            let rewards = ext::fee::withdraw_vault_rewards::<T>(vault_id, nominator_id, currency_id)?;
+            if rewards.is_zero() {
+                continue;
+            }
+            let swapped = if currency_id == collateral_currency {
+                rewards
+            } else {
+                let expected = rewards.convert_to(collateral_currency)?;
+                let min_amount_out = expected.checked_sub(&expected.checked_mul(&max_slippage)?)?;
+                ext::swap::swap_exact_tokens_for_tokens::<T>(nominator_id, &rewards, &min_amount_out)?
+            };
+            collateral = collateral.checked_add(&swapped)?;
+        }
+
+        if collateral.is_zero() {
+            return Ok(());
+        }
+        Self::_deposit_collateral(vault_id, nominator_id, collateral.amount())?;
+
+        Self::deposit_event(Event::<T>::AutoCompound {
+            vault_id: vault_id.clone(),
+            nominator_id: nominator_id.clone(),
+            amount: collateral.amount(),
+        });
+        Ok(())
+    }
+
     /// Vault is to allow nominated collateral
     ///
     /// # Arguments
@@ -3,7 +3,7 @@ use crate::{Config, Error};
 use currency::Amount;
 use frame_support::{
     assert_ok, parameter_types,
-    traits::{ConstU32, Everything},
+    traits::{ConstU32, ConstU64, Everything},
     PalletId,
 };
 use frame_system::EnsureRoot;
@@ -132,6 +132,8 @@ impl reward::Config<CapacityRewardsInstance> for Test {
     type StakeId = CurrencyId;
     type CurrencyId = CurrencyId;
     type MaxRewardCurrencies = ConstU32<10>;
+    type CheckpointInterval = ();
+    type MaxCheckpoints = ();
 }
 
 type VaultRewardsInstance = reward::Instance2;
@@ -143,6 +145,8 @@ impl reward::Config<VaultRewardsInstance> for Test {
     type StakeId = VaultId<AccountId, CurrencyId>;
     type CurrencyId = CurrencyId;
     type MaxRewardCurrencies = ConstU32<10>;
+    type CheckpointInterval = ();
+    type MaxCheckpoints = ();
 }
 
 impl staking::Config for Test {
@@ -233,11 +237,20 @@ impl fee::Config for Test {
     type NominationApi = Nomination;
 }
 
+parameter_types! {
+    pub const OracleTreasuryAccountId: AccountId = 1000;
+}
+
 impl oracle::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type OnExchangeRateChange = ();
+    type DexPriceCumulative = ();
+    type DexStableLpPrice = ();
+    type DexPairReserves = ();
+    type TreasuryAccountId = OracleTreasuryAccountId;
     type WeightInfo = ();
     type MaxNameLength = ConstU32<255>;
+    type MaxPriceHistoryLength = ConstU32<10>;
 }
 
 parameter_types! {
@@ -254,11 +267,19 @@ impl loans::Config for Test {
     type RewardAssetId = GetNativeCurrencyId;
     type ReferenceAssetId = GetWrappedCurrencyId;
     type OnExchangeRateChange = ();
+    type RuntimeCall = RuntimeCall;
+    type Swap = ();
+    type StableLiquidity = ();
+    type PriceFeeds = ();
 }
 
 impl Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
+    type Swap = ();
+    type AutoCompoundPeriod = ConstU64<10>;
+    type MaxAutoCompounds = ConstU32<10>;
+    type VaultSlashing = VaultStaking;
 }
 
 pub type TestError = Error<Test>;
@@ -1,8 +1,8 @@
 use crate::{ext, mock::*};
 use currency::Amount;
-use frame_support::{assert_err, assert_ok};
+use frame_support::{assert_err, assert_ok, traits::Hooks};
 use mocktopus::mocking::*;
-use sp_arithmetic::FixedI128;
+use sp_arithmetic::{FixedI128, FixedU128};
 
 #[test]
 fn should_not_deposit_against_invalid_vault() {
@@ -95,3 +95,190 @@ fn should_not_withdraw_collateral() {
         );
     });
 }
+
+const NOMINATOR: AccountId = 3;
+const OTHER_NOMINATOR: AccountId = 4;
+
+fn wrapped(amount: u128) -> Amount<Test> {
+    Amount::new(amount, DEFAULT_WRAPPED_CURRENCY)
+}
+
+fn fund_insurance(amount: u128) {
+    assert_ok!(wrapped(amount).mint_to(&Fee::insurance_fund_account_id()));
+}
+
+#[test]
+fn should_set_insurance_payout_cap() {
+    run_test(|| {
+        assert_err!(
+            Nomination::set_insurance_payout_cap(RuntimeOrigin::signed(ALICE.account_id), 100),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Nomination::set_insurance_payout_cap(RuntimeOrigin::root(), 100));
+        assert_eq!(Nomination::insurance_payout_cap(), 100);
+    })
+}
+
+#[test]
+fn should_claim_slashing_insurance_up_to_fund_balance() {
+    run_test(|| {
+        ext::staking::compute_total_slash::<Test>.mock_safe(|_, _, _| MockResult::Return(Ok(100)));
+        assert_ok!(Nomination::set_insurance_payout_cap(RuntimeOrigin::root(), 1000));
+
+        assert_err!(
+            Nomination::_claim_slashing_insurance(&ALICE, &NOMINATOR, 0),
+            TestError::InsurancePayoutUnavailable
+        );
+
+        // partial payout while the fund is short
+        fund_insurance(60);
+        assert_ok!(Nomination::_claim_slashing_insurance(&ALICE, &NOMINATOR, 0));
+        assert_eq!(
+            currency::get_free_balance::<Test>(DEFAULT_WRAPPED_CURRENCY, &NOMINATOR),
+            wrapped(60)
+        );
+
+        // the remainder can be claimed once the fund is replenished
+        fund_insurance(100);
+        assert_ok!(Nomination::_claim_slashing_insurance(&ALICE, &NOMINATOR, 0));
+        assert_eq!(
+            currency::get_free_balance::<Test>(DEFAULT_WRAPPED_CURRENCY, &NOMINATOR),
+            wrapped(100)
+        );
+        assert_eq!(
+            currency::get_free_balance::<Test>(DEFAULT_WRAPPED_CURRENCY, &Fee::insurance_fund_account_id()),
+            wrapped(60)
+        );
+
+        assert_err!(
+            Nomination::_claim_slashing_insurance(&ALICE, &NOMINATOR, 0),
+            TestError::NoSlashToCompensate
+        );
+    })
+}
+
+#[test]
+fn should_limit_slashing_insurance_payouts_per_pool() {
+    run_test(|| {
+        ext::staking::compute_total_slash::<Test>.mock_safe(|_, _, _| MockResult::Return(Ok(50)));
+        assert_ok!(Nomination::set_insurance_payout_cap(RuntimeOrigin::root(), 80));
+        fund_insurance(1000);
+
+        assert_ok!(Nomination::_claim_slashing_insurance(&ALICE, &NOMINATOR, 0));
+        assert_ok!(Nomination::_claim_slashing_insurance(&ALICE, &OTHER_NOMINATOR, 0));
+        assert_eq!(
+            currency::get_free_balance::<Test>(DEFAULT_WRAPPED_CURRENCY, &NOMINATOR),
+            wrapped(50)
+        );
+        assert_eq!(
+            currency::get_free_balance::<Test>(DEFAULT_WRAPPED_CURRENCY, &OTHER_NOMINATOR),
+            wrapped(30)
+        );
+
+        assert_err!(
+            Nomination::_claim_slashing_insurance(&ALICE, &OTHER_NOMINATOR, 0),
+            TestError::InsurancePayoutUnavailable
+        );
+    })
+}
+
+#[test]
+fn should_not_claim_slashing_insurance_as_vault() {
+    run_test(|| {
+        ext::staking::compute_total_slash::<Test>.mock_safe(|_, _, _| MockResult::Return(Ok(100)));
+        assert_err!(
+            Nomination::_claim_slashing_insurance(&ALICE, &ALICE.account_id, 0),
+            TestError::VaultCannotClaimInsurance
+        );
+    })
+}
+
+#[test]
+fn should_set_auto_compound() {
+    run_test(|| {
+        assert_err!(
+            Nomination::set_auto_compound(
+                RuntimeOrigin::signed(BOB.account_id),
+                ALICE,
+                Some(FixedU128::from_rational(1, 100))
+            ),
+            TestError::NoStakeToCompound
+        );
+        ext::staking::compute_stake::<Test>.mock_safe(|_, _| MockResult::Return(Ok(100)));
+        assert_err!(
+            Nomination::set_auto_compound(
+                RuntimeOrigin::signed(BOB.account_id),
+                ALICE,
+                Some(FixedU128::from_rational(2, 1))
+            ),
+            TestError::InvalidSlippage
+        );
+
+        let max_slippage = FixedU128::from_rational(1, 100);
+        assert_ok!(Nomination::set_auto_compound(
+            RuntimeOrigin::signed(BOB.account_id),
+            ALICE,
+            Some(max_slippage)
+        ));
+        assert_ok!(Nomination::set_auto_compound(
+            RuntimeOrigin::signed(BOB.account_id),
+            ALICE,
+            Some(max_slippage)
+        ));
+        assert_eq!(
+            crate::AutoCompounds::<Test>::get().into_inner(),
+            vec![(ALICE, BOB.account_id, max_slippage)]
+        );
+
+        assert_ok!(Nomination::set_auto_compound(
+            RuntimeOrigin::signed(BOB.account_id),
+            ALICE,
+            None
+        ));
+        assert!(crate::AutoCompounds::<Test>::get().is_empty());
+    })
+}
+
+#[test]
+fn should_auto_compound_rewards() {
+    run_test(|| {
+        ext::vault_registry::vault_exists::<Test>.mock_safe(|_| MockResult::Return(true));
+        ext::vault_registry::get_backing_collateral::<Test>.mock_safe(|_| MockResult::Return(Ok(collateral(10000))));
+        ext::vault_registry::compute_collateral::<Test>.mock_safe(|_| MockResult::Return(Ok(collateral(10000))));
+        ext::vault_registry::pool_manager::deposit_collateral::<Test>.mock_safe(|_, _, _| MockResult::Return(Ok(())));
+        ext::staking::compute_stake::<Test>.mock_safe(|_, _| MockResult::Return(Ok(100)));
+        ext::fee::withdraw_vault_rewards::<Test>.mock_safe(|_, _, currency_id| {
+            let amount = if currency_id == DEFAULT_WRAPPED_CURRENCY {
+                100
+            } else {
+                0
+            };
+            MockResult::Return(Ok(Amount::new(amount, currency_id)))
+        });
+        ext::swap::swap_exact_tokens_for_tokens::<Test>.mock_safe(|_, amount_in, min_amount_out| {
+            assert_eq!(amount_in, &wrapped(100));
+            // 1% slippage relative to the 1:1 mock exchange rate
+            assert_eq!(min_amount_out, &collateral(99));
+            MockResult::Return(Ok(collateral(99)))
+        });
+
+        assert_ok!(Nomination::_opt_in_to_nomination(&ALICE));
+        assert_ok!(Nomination::set_nomination_limit(
+            RuntimeOrigin::signed(ALICE.account_id),
+            ALICE.currencies,
+            100
+        ));
+        assert_ok!(Nomination::set_auto_compound(
+            RuntimeOrigin::signed(BOB.account_id),
+            ALICE,
+            Some(FixedU128::from_rational(1, 100))
+        ));
+
+        Nomination::on_initialize(10);
+        System::assert_has_event(RuntimeEvent::Nomination(crate::Event::AutoCompound {
+            vault_id: ALICE,
+            nominator_id: BOB.account_id,
+            amount: 99,
+        }));
+    })
+}
@@ -5,12 +5,16 @@
 use codec::Codec;
 use frame_support::dispatch::DispatchError;
 
-pub use primitives::BalanceWrapper;
+pub use primitives::{oracle::Key as OracleKey, BalanceWrapper, Moment};
+use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
-    pub trait OracleApi<Balance, CurrencyId> where
+    pub trait OracleApi<Balance, CurrencyId, AccountId, BlockNumber, PriceRecord> where
         Balance: Codec,
         CurrencyId: Codec,
+        AccountId: Codec,
+        BlockNumber: Codec,
+        PriceRecord: Codec,
     {
         fn wrapped_to_collateral(
             amount: BalanceWrapper<Balance>,
@@ -21,5 +25,15 @@ sp_api::decl_runtime_apis! {
             amount: BalanceWrapper<Balance>,
             currency_id: CurrencyId,
         ) -> Result<BalanceWrapper<Balance>, DispatchError>;
+
+        /// Get the most recent aggregates of the key, newest first
+        fn get_price_history(key: OracleKey, count: u32) -> Vec<PriceRecord>;
+
+        /// Get the aggregate of the key that was in effect at the given block
+        fn get_price_at(key: OracleKey, block_number: BlockNumber) -> Option<PriceRecord>;
+
+        /// Get the oracles that had not fed a value for the key within the maximum delay
+        /// before the given time
+        fn get_stale_oracles(key: OracleKey, timestamp: Moment) -> Vec<AccountId>;
     }
 }
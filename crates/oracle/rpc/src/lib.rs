@@ -14,13 +14,16 @@ use sp_runtime::{
 };
 use std::sync::Arc;
 
-pub use oracle_rpc_runtime_api::{BalanceWrapper, OracleApi as OracleRuntimeApi};
+pub use oracle_rpc_runtime_api::{BalanceWrapper, Moment, OracleApi as OracleRuntimeApi, OracleKey};
 
 #[rpc(client, server)]
-pub trait OracleApi<BlockHash, Balance, CurrencyId>
+pub trait OracleApi<BlockHash, Balance, CurrencyId, AccountId, BlockNumber, PriceRecord>
 where
     Balance: Codec + MaybeDisplay + MaybeFromStr,
     CurrencyId: Codec,
+    AccountId: Codec,
+    BlockNumber: Codec,
+    PriceRecord: Codec,
 {
     #[method(name = "oracle_wrappedToCollateral")]
     fn wrapped_to_collateral(
@@ -37,6 +40,20 @@ where
         currency_id: CurrencyId,
         at: Option<BlockHash>,
     ) -> RpcResult<BalanceWrapper<Balance>>;
+
+    #[method(name = "oracle_getPriceHistory")]
+    fn get_price_history(&self, key: OracleKey, count: u32, at: Option<BlockHash>) -> RpcResult<Vec<PriceRecord>>;
+
+    #[method(name = "oracle_getPriceAt")]
+    fn get_price_at(
+        &self,
+        key: OracleKey,
+        block_number: BlockNumber,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<PriceRecord>>;
+
+    #[method(name = "oracle_getStaleOracles")]
+    fn get_stale_oracles(&self, key: OracleKey, timestamp: Moment, at: Option<BlockHash>) -> RpcResult<Vec<AccountId>>;
 }
 
 fn internal_err<T: ToString>(message: T) -> JsonRpseeError {
@@ -70,13 +87,18 @@ fn handle_response<T, E: std::fmt::Debug>(result: Result<Result<T, DispatchError
 }
 
 #[async_trait]
-impl<C, Block, Balance, CurrencyId> OracleApiServer<<Block as BlockT>::Hash, Balance, CurrencyId> for Oracle<C, Block>
+impl<C, Block, Balance, CurrencyId, AccountId, BlockNumber, PriceRecord>
+    OracleApiServer<<Block as BlockT>::Hash, Balance, CurrencyId, AccountId, BlockNumber, PriceRecord>
+    for Oracle<C, Block>
 where
     Block: BlockT,
     C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
-    C::Api: OracleRuntimeApi<Block, Balance, CurrencyId>,
+    C::Api: OracleRuntimeApi<Block, Balance, CurrencyId, AccountId, BlockNumber, PriceRecord>,
     Balance: Codec + MaybeDisplay + MaybeFromStr,
     CurrencyId: Codec,
+    AccountId: Codec,
+    BlockNumber: Codec,
+    PriceRecord: Codec,
 {
     fn wrapped_to_collateral(
         &self,
@@ -101,4 +123,43 @@ where
 
         handle_response(api.collateral_to_wrapped(at, amount, currency_id))
     }
+
+    fn get_price_history(
+        &self,
+        key: OracleKey,
+        count: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<PriceRecord>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_price_history(at, key, count)
+            .map_err(|e| internal_err(format!("Unable to fetch price history: {:?}", e)))
+    }
+
+    fn get_price_at(
+        &self,
+        key: OracleKey,
+        block_number: BlockNumber,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<PriceRecord>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_price_at(at, key, block_number)
+            .map_err(|e| internal_err(format!("Unable to fetch price: {:?}", e)))
+    }
+
+    fn get_stale_oracles(
+        &self,
+        key: OracleKey,
+        timestamp: Moment,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<AccountId>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_stale_oracles(at, key, timestamp)
+            .map_err(|e| internal_err(format!("Unable to fetch stale oracles: {:?}", e)))
+    }
 }
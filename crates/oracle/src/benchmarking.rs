@@ -1,5 +1,10 @@
 use super::{Pallet as Oracle, *};
-use crate::OracleKey;
+use crate::{
+    types::{
+        CommitRevealPeriod, DefaultBondedOracleParameters, DeviationAction, DeviationGuard, TwapPolicy, TwapSource,
+    },
+    OracleKey,
+};
 use frame_benchmarking::v2::{account, benchmarks, impl_benchmark_test_suite};
 use frame_support::assert_ok;
 use frame_system::RawOrigin;
@@ -105,5 +110,204 @@ pub mod benchmarks {
         assert_eq!(Oracle::<T>::is_authorized(&origin), false);
     }
 
+    #[benchmark]
+    fn set_deviation_guard() {
+        let key = OracleKey::ExchangeRate(Token(DOT));
+        let guard = DeviationGuard {
+            max_change: UnsignedFixedPoint::<T>::checked_from_rational(1, 10).unwrap(),
+            action: DeviationAction::Confirm,
+            max_violations: 3,
+        };
+
+        #[extrinsic_call]
+        set_deviation_guard(RawOrigin::Root, key.clone(), Some(guard));
+
+        assert_eq!(Oracle::<T>::deviation_guard(key), Some(guard));
+    }
+
+    #[benchmark]
+    fn resume_price_feed() {
+        let key = OracleKey::ExchangeRate(Token(DOT));
+        PausedPriceFeeds::<T>::insert(&key, ());
+        DeviationViolations::<T>::insert(&key, 3);
+        PendingAggregate::<T>::insert(&key, UnsignedFixedPoint::<T>::one());
+
+        #[extrinsic_call]
+        resume_price_feed(RawOrigin::Root, key.clone(), Some(UnsignedFixedPoint::<T>::one()));
+
+        assert!(!Oracle::<T>::is_price_feed_paused(&key));
+        assert_eq!(Aggregate::<T>::get(key), Some(UnsignedFixedPoint::<T>::one()));
+    }
+
+    #[benchmark]
+    fn set_twap_source() {
+        let key = OracleKey::ExchangeRate(Token(DOT));
+        let source = TwapSource {
+            window: 10u32.into(),
+            policy: TwapPolicy::Fallback,
+        };
+
+        #[extrinsic_call]
+        set_twap_source(RawOrigin::Root, key.clone(), Some(source));
+
+        assert_eq!(Oracle::<T>::twap_source(key), Some(source));
+    }
+
+    fn bonded_oracle_parameters<T: Config>() -> DefaultBondedOracleParameters<T> {
+        DefaultBondedOracleParameters::<T> {
+            min_bond: 1000u32.into(),
+            max_deviation: UnsignedFixedPoint::<T>::checked_from_rational(1, 10).unwrap(),
+            max_outliers: 3,
+            slash_amount: 100u32.into(),
+            max_missed_rounds: 10,
+            round_length: 60_000u32.into(),
+        }
+    }
+
+    fn bond<T: Config>(origin: &T::AccountId) {
+        BondedOracleParameters::<T>::put(bonded_oracle_parameters::<T>());
+        let amount = Amount::<T>::new(1000u32.into(), T::GetNativeCurrencyId::get());
+        amount.mint_to(origin).unwrap();
+        assert_ok!(Oracle::<T>::bond_oracle(
+            RawOrigin::Signed(origin.clone()).into(),
+            BoundedVec::try_from(vec![0; T::MaxNameLength::get() as usize]).unwrap(),
+            amount.amount()
+        ));
+    }
+
+    #[benchmark]
+    fn set_bonded_oracle_parameters() {
+        let parameters = bonded_oracle_parameters::<T>();
+
+        #[extrinsic_call]
+        set_bonded_oracle_parameters(RawOrigin::Root, Some(parameters));
+
+        assert_eq!(Oracle::<T>::bonded_oracle_parameters(), Some(parameters));
+    }
+
+    #[benchmark]
+    fn bond_oracle() {
+        let origin: T::AccountId = account("origin", 0, 0);
+        BondedOracleParameters::<T>::put(bonded_oracle_parameters::<T>());
+        let amount = Amount::<T>::new(1000u32.into(), T::GetNativeCurrencyId::get());
+        amount.mint_to(&origin).unwrap();
+
+        #[extrinsic_call]
+        bond_oracle(
+            RawOrigin::Signed(origin.clone()),
+            BoundedVec::try_from(vec![0; T::MaxNameLength::get() as usize]).unwrap(),
+            amount.amount(),
+        );
+
+        assert_eq!(Oracle::<T>::is_authorized(&origin), true);
+    }
+
+    #[benchmark]
+    fn unbond_oracle() {
+        let origin: T::AccountId = account("origin", 0, 0);
+        bond::<T>(&origin);
+
+        #[extrinsic_call]
+        unbond_oracle(RawOrigin::Signed(origin.clone()));
+
+        assert_eq!(Oracle::<T>::is_authorized(&origin), false);
+        assert!(Oracle::<T>::unbonding_oracle(&origin).is_some());
+    }
+
+    #[benchmark]
+    fn set_minimum_quorum() {
+        let key = OracleKey::ExchangeRate(Token(DOT));
+
+        #[extrinsic_call]
+        set_minimum_quorum(RawOrigin::Root, key.clone(), Some(3));
+
+        assert_eq!(Oracle::<T>::minimum_quorum(key), Some(3));
+    }
+
+    #[benchmark]
+    fn set_key_max_delay() {
+        let key = OracleKey::ExchangeRate(Token(DOT));
+        let max_delay: MomentOf<T> = 1000u32.into();
+
+        #[extrinsic_call]
+        set_key_max_delay(RawOrigin::Root, key.clone(), Some(max_delay));
+
+        assert_eq!(Oracle::<T>::key_max_delay(key), Some(max_delay));
+    }
+
+    fn commit_reveal_period<T: Config>() -> CommitRevealPeriod<BlockNumberFor<T>> {
+        CommitRevealPeriod {
+            commit_window: 10u32.into(),
+            reveal_window: 10u32.into(),
+        }
+    }
+
+    #[benchmark]
+    fn set_commit_reveal_period() {
+        let period = commit_reveal_period::<T>();
+
+        #[extrinsic_call]
+        set_commit_reveal_period(RawOrigin::Root, Some(period));
+
+        assert_eq!(Oracle::<T>::commit_reveal_period(), Some(period));
+    }
+
+    #[benchmark]
+    fn commit_values() {
+        let origin: T::AccountId = account("origin", 0, 0);
+        <AuthorizedOracles<T>>::insert(
+            origin.clone(),
+            BoundedVec::try_from(vec![0; T::MaxNameLength::get() as usize]).unwrap(),
+        );
+        CommitReveal::<T>::put(commit_reveal_period::<T>());
+        frame_system::Pallet::<T>::set_block_number(1u32.into());
+
+        #[extrinsic_call]
+        commit_values(RawOrigin::Signed(origin.clone()), H256::zero());
+
+        assert!(Oracle::<T>::commitment(&origin).is_some());
+    }
+
+    #[benchmark]
+    fn reveal_values(u: Linear<1, 1000>) {
+        let origin: T::AccountId = account("origin", 0, 0);
+        <AuthorizedOracles<T>>::insert(
+            origin.clone(),
+            BoundedVec::try_from(vec![0; T::MaxNameLength::get() as usize]).unwrap(),
+        );
+        CommitReveal::<T>::put(commit_reveal_period::<T>());
+
+        let values: Vec<_> = (0..u)
+            .map(|x| {
+                (
+                    OracleKey::ExchangeRate(CurrencyId::ForeignAsset(x)),
+                    UnsignedFixedPoint::<T>::checked_from_rational(1, x + 1).unwrap(),
+                )
+            })
+            .collect();
+        let salt = H256::repeat_byte(1);
+        let commitment = Oracle::<T>::commitment_hash(&origin, &values, &salt);
+        Commitments::<T>::insert(&origin, (BlockNumberFor::<T>::zero(), commitment));
+        frame_system::Pallet::<T>::set_block_number(15u32.into());
+
+        #[extrinsic_call]
+        reveal_values(RawOrigin::Signed(origin.clone()), values, salt);
+
+        assert!(Oracle::<T>::commitment(&origin).is_none());
+    }
+
+    #[benchmark]
+    fn set_pricing_path() {
+        let currency_id = CurrencyId::ForeignAsset(1);
+        let path = PricingPath::CrossRate {
+            intermediate: CurrencyId::ForeignAsset(2),
+        };
+
+        #[extrinsic_call]
+        set_pricing_path(RawOrigin::Root, currency_id, Some(path));
+
+        assert_eq!(Oracle::<T>::pricing_path(currency_id), Some(path));
+    }
+
     impl_benchmark_test_suite!(Oracle, crate::mock::ExtBuilder::build(), crate::mock::Test);
 }
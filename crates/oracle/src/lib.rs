@@ -25,12 +25,16 @@ extern crate mocktopus;
 #[cfg(test)]
 use mocktopus::macros::mockable;
 
-use crate::types::{BalanceOf, UnsignedFixedPoint, Version};
+use crate::types::{
+    BalanceOf, CommitRevealPeriod, CommitRevealPhase, DefaultBondedOracleParameters, DefaultDeviationGuard,
+    DeviationAction, PricingPath, TwapPolicy, TwapSource, UnsignedFixedPoint, Version,
+};
 use codec::{Decode, Encode, MaxEncodedLen};
 use currency::Amount;
 use frame_support::{
     dispatch::{DispatchError, DispatchResult},
     ensure,
+    storage::with_storage_layer,
     traits::Get,
     transactional,
     weights::Weight,
@@ -38,16 +42,29 @@ use frame_support::{
 };
 use frame_system::{ensure_root, ensure_signed, pallet_prelude::BlockNumberFor};
 use scale_info::TypeInfo;
+use sp_core::{H256, U256};
 use sp_runtime::traits::*;
 use sp_std::{convert::TryInto, vec::Vec};
-use traits::OracleApi;
+use traits::{OracleApi, PairReservesApi, PriceCumulativeApi, PriceFeedStatusApi, StableLpPriceApi};
 
 pub use pallet::*;
-pub use primitives::{oracle::Key as OracleKey, CurrencyId, TruncateFixedPointToInt};
+pub use primitives::{
+    oracle::{Key as OracleKey, PriceRecord},
+    CurrencyId, TruncateFixedPointToInt,
+};
 pub use traits::OnExchangeRateChange;
 
 pub type NameOf<T> = BoundedVec<u8, <T as pallet::Config>::MaxNameLength>;
 
+/// Maximum number of pricing paths followed to derive an exchange rate
+pub const MAX_PRICING_PATH_DEPTH: u32 = 3;
+
+/// Amount of a stable pool LP token that is valued to derive its exchange rate
+const LP_PRICING_AMOUNT: u128 = 1_000_000_000_000_000_000;
+
+pub type PriceRecordOf<T> =
+    PriceRecord<UnsignedFixedPoint<T>, <T as pallet_timestamp::Config>::Moment, BlockNumberFor<T>>;
+
 #[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, Ord, PartialOrd, TypeInfo, MaxEncodedLen)]
 pub struct TimestampedValue<Value, Moment> {
     pub value: Value,
@@ -72,12 +89,28 @@ pub mod pallet {
         /// Hook for aggregate changes.
         type OnExchangeRateChange: OnExchangeRateChange<CurrencyId>;
 
+        /// Time-weighted prices of DEX pairs, used as a price source.
+        type DexPriceCumulative: PriceCumulativeApi<CurrencyId>;
+
+        /// Virtual prices of stable pools, used to price their LP tokens.
+        type DexStableLpPrice: StableLpPriceApi<CurrencyId>;
+
+        /// Reserves of DEX pairs, used to price their LP tokens.
+        type DexPairReserves: PairReservesApi<CurrencyId>;
+
         /// Weight information for the extrinsics in this module.
         type WeightInfo: WeightInfo;
 
         /// The maximum length of an oracle name.
         #[pallet::constant]
         type MaxNameLength: Get<u32>;
+
+        /// The account receiving slashed oracle bonds.
+        type TreasuryAccountId: Get<Self::AccountId>;
+
+        /// The number of aggregates kept in the price history of each key.
+        #[pallet::constant]
+        type MaxPriceHistoryLength: Get<u32>;
     }
 
     #[pallet::event]
@@ -98,6 +131,76 @@ pub mod pallet {
         OracleRemoved {
             oracle_id: T::AccountId,
         },
+        SetDeviationGuard {
+            key: OracleKey,
+            guard: Option<DefaultDeviationGuard<T>>,
+        },
+        /// The median of a round moved further from the aggregate than allowed
+        AggregateDeviationExceeded {
+            key: OracleKey,
+            value: T::UnsignedFixedPoint,
+            previous: T::UnsignedFixedPoint,
+        },
+        PriceFeedPaused {
+            key: OracleKey,
+        },
+        PriceFeedResumed {
+            key: OracleKey,
+        },
+        SetTwapSource {
+            key: OracleKey,
+            source: Option<TwapSource<BlockNumberFor<T>>>,
+        },
+        SetBondedOracleParameters {
+            parameters: Option<DefaultBondedOracleParameters<T>>,
+        },
+        OracleBonded {
+            oracle_id: T::AccountId,
+            name: NameOf<T>,
+            amount: BalanceOf<T>,
+        },
+        /// A bonded oracle stopped submitting values, its bond is released at `unlock_time`
+        OracleUnbondingStarted {
+            oracle_id: T::AccountId,
+            unlock_time: T::Moment,
+        },
+        OracleUnbonded {
+            oracle_id: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// A bonded oracle repeatedly submitted values too far from the median
+        OracleSlashed {
+            oracle_id: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// A bonded oracle missed too many rounds and was removed
+        InactiveOracleRemoved {
+            oracle_id: T::AccountId,
+        },
+        SetMinimumQuorum {
+            key: OracleKey,
+            quorum: Option<u32>,
+        },
+        SetKeyMaxDelay {
+            key: OracleKey,
+            max_delay: Option<T::Moment>,
+        },
+        SetCommitRevealPeriod {
+            period: Option<CommitRevealPeriod<BlockNumberFor<T>>>,
+        },
+        ValuesCommitted {
+            oracle_id: T::AccountId,
+            round: BlockNumberFor<T>,
+        },
+        /// An oracle did not reveal the values it committed to
+        CommitmentNotRevealed {
+            oracle_id: T::AccountId,
+            round: BlockNumberFor<T>,
+        },
+        SetPricingPath {
+            currency_id: CurrencyId,
+            path: Option<PricingPath<CurrencyId>>,
+        },
     }
 
     #[pallet::error]
@@ -108,6 +211,40 @@ pub mod pallet {
         MissingExchangeRate,
         /// Unable to convert value
         TryIntoIntError,
+        /// Price feed paused after repeated deviation violations
+        PriceFeedPaused,
+        /// TWAP sources must be exchange rates averaged over at least one block
+        InvalidTwapSource,
+        /// Bonded oracle mode is not enabled
+        BondedOraclesDisabled,
+        /// Bonded oracle thresholds must be non-zero
+        InvalidBondedOracleParameters,
+        /// The bond is below the minimum
+        InsufficientOracleBond,
+        /// The account is already an authorized oracle
+        OracleAlreadyAuthorized,
+        /// The account is not a bonded oracle
+        NotBondedOracle,
+        /// The bond of the oracle is being released
+        OracleUnbonding,
+        /// The minimum quorum must be at least one submission
+        InvalidMinimumQuorum,
+        /// Values must be committed and revealed while commit-reveal is enabled
+        CommitRevealRequired,
+        /// Commit-reveal is not enabled
+        CommitRevealDisabled,
+        /// Commit and reveal windows must be non-zero
+        InvalidCommitRevealPeriod,
+        /// Values can only be committed in the commit window
+        NotCommitPhase,
+        /// Values can only be revealed in the reveal window
+        NotRevealPhase,
+        /// No commitment for the current round
+        MissingCommitment,
+        /// The revealed values do not match the commitment
+        CommitmentMismatch,
+        /// The pricing path does not apply to the currency
+        InvalidPricingPath,
     }
 
     #[pallet::hooks]
@@ -136,15 +273,134 @@ pub mod pallet {
     /// if a key is present, it means the values have been updated
     pub type RawValuesUpdated<T: Config> = StorageMap<_, Blake2_128Concat, OracleKey, bool>;
 
+    /// Most recent aggregates for the given key, oldest first
+    #[pallet::storage]
+    pub type PriceHistory<T: Config> =
+        StorageMap<_, Blake2_128Concat, OracleKey, BoundedVec<PriceRecordOf<T>, T::MaxPriceHistoryLength>, ValueQuery>;
+
+    /// Times of the most recent submissions of each oracle for the given key, oldest first
+    #[pallet::storage]
+    pub type SubmissionHistory<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        OracleKey,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<T::Moment, T::MaxPriceHistoryLength>,
+        ValueQuery,
+    >;
+
     /// Time until which the aggregate is valid
     #[pallet::storage]
     pub type ValidUntil<T: Config> = StorageMap<_, Blake2_128Concat, OracleKey, T::Moment>;
 
+    /// Maximum relative change of the aggregate per update
+    #[pallet::storage]
+    #[pallet::getter(fn deviation_guard)]
+    pub type DeviationGuards<T: Config> = StorageMap<_, Blake2_128Concat, OracleKey, DefaultDeviationGuard<T>>;
+
+    /// Median held back by the deviation guard until a second round confirms it
+    #[pallet::storage]
+    pub type PendingAggregate<T: Config> = StorageMap<_, Blake2_128Concat, OracleKey, UnsignedFixedPoint<T>>;
+
+    /// Number of consecutive updates that exceeded the deviation guard
+    #[pallet::storage]
+    #[pallet::getter(fn deviation_violations)]
+    pub type DeviationViolations<T: Config> = StorageMap<_, Blake2_128Concat, OracleKey, u32, ValueQuery>;
+
+    /// Keys whose price feed is paused, blocking liquidations and new issues
+    #[pallet::storage]
+    pub type PausedPriceFeeds<T: Config> = StorageMap<_, Blake2_128Concat, OracleKey, ()>;
+
+    /// DEX pair of the currency and the wrapped currency used as a price source
+    #[pallet::storage]
+    #[pallet::getter(fn twap_source)]
+    pub type TwapSources<T: Config> = StorageMap<_, Blake2_128Concat, OracleKey, TwapSource<BlockNumberFor<T>>>;
+
+    /// Accumulated price of the DEX pair at the start of the current TWAP window
+    #[pallet::storage]
+    pub type TwapObservations<T: Config> = StorageMap<_, Blake2_128Concat, OracleKey, (BlockNumberFor<T>, U256)>;
+
+    /// Time-weighted average price over the last completed window
+    #[pallet::storage]
+    #[pallet::getter(fn twap_value)]
+    pub type TwapValues<T: Config> =
+        StorageMap<_, Blake2_128Concat, OracleKey, TimestampedValue<UnsignedFixedPoint<T>, T::Moment>>;
+
+    /// Parameters of the bonded oracle mode, disabled if not set
+    #[pallet::storage]
+    #[pallet::getter(fn bonded_oracle_parameters)]
+    pub type BondedOracleParameters<T: Config> = StorageValue<_, DefaultBondedOracleParameters<T>>;
+
+    /// Stake (in the native currency) locked by bonded oracles
+    #[pallet::storage]
+    #[pallet::getter(fn oracle_bond)]
+    pub type OracleBonds<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>>;
+
+    /// Number of consecutive submissions of a bonded oracle for the key that deviated from the
+    /// median of their round
+    #[pallet::storage]
+    #[pallet::getter(fn outlier_submissions)]
+    pub type OutlierSubmissions<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, OracleKey, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Number of consecutive rounds in which a bonded oracle did not submit a value for the key
+    #[pallet::storage]
+    #[pallet::getter(fn missed_rounds)]
+    pub type MissedRounds<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, OracleKey, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Time at which the bond of an unbonding oracle is released, once the rounds it submitted
+    /// values in have been settled
+    #[pallet::storage]
+    #[pallet::getter(fn unbonding_oracle)]
+    pub type UnbondingOracles<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::Moment>;
+
+    /// Index of the current bonded oracle round of the key, i.e. the time divided by the
+    /// round length
+    #[pallet::storage]
+    pub type BondedRounds<T: Config> = StorageMap<_, Blake2_128Concat, OracleKey, T::Moment>;
+
+    /// Oracles that submitted a value for the key in the current bonded oracle round
+    #[pallet::storage]
+    pub type RoundSubmitters<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, OracleKey, Blake2_128Concat, T::AccountId, ()>;
+
     /// Maximum delay (milliseconds) for a reported value to be used
     #[pallet::storage]
     #[pallet::getter(fn max_delay)]
     pub type MaxDelay<T: Config> = StorageValue<_, T::Moment, ValueQuery>;
 
+    /// Maximum delay (milliseconds) of the key, overriding `MaxDelay`
+    #[pallet::storage]
+    #[pallet::getter(fn key_max_delay)]
+    pub type KeyMaxDelays<T: Config> = StorageMap<_, Blake2_128Concat, OracleKey, T::Moment>;
+
+    /// Derivation of the exchange rate of currencies without their own feed
+    #[pallet::storage]
+    #[pallet::getter(fn pricing_path)]
+    pub type PricingPaths<T: Config> = StorageMap<_, Blake2_128Concat, CurrencyId, PricingPath<CurrencyId>>;
+
+    /// Windows of the commit-reveal scheme, oracles feed plaintext values if not set
+    #[pallet::storage]
+    #[pallet::getter(fn commit_reveal_period)]
+    pub type CommitReveal<T: Config> = StorageValue<_, CommitRevealPeriod<BlockNumberFor<T>>>;
+
+    /// Hash committed by the oracle and the round it was committed in
+    #[pallet::storage]
+    #[pallet::getter(fn commitment)]
+    pub type Commitments<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (BlockNumberFor<T>, H256)>;
+
+    /// Number of commitments the oracle did not reveal
+    #[pallet::storage]
+    #[pallet::getter(fn unrevealed_commitments)]
+    pub type UnrevealedCommitments<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Minimum number of fresh oracle submissions for the aggregate of the key, one if not set
+    #[pallet::storage]
+    #[pallet::getter(fn minimum_quorum)]
+    pub type MinimumQuorums<T: Config> = StorageMap<_, Blake2_128Concat, OracleKey, u32>;
+
     // Oracles allowed to set the exchange rate, maps to the name
     #[pallet::storage]
     #[pallet::getter(fn authorized_oracles)]
@@ -202,6 +458,7 @@ pub mod pallet {
 
             // fail if the signer is not an authorized oracle
             ensure!(Self::is_authorized(&signer), Error::<T>::InvalidOracleSource);
+            ensure!(!CommitReveal::<T>::exists(), Error::<T>::CommitRevealRequired);
 
             Self::_feed_values(signer, values);
             Ok(Pays::No.into())
@@ -238,17 +495,364 @@ pub mod pallet {
         #[transactional]
         pub fn remove_authorized_oracle(origin: OriginFor<T>, account_id: T::AccountId) -> DispatchResult {
             ensure_root(origin)?;
-            <AuthorizedOracles<T>>::remove(account_id.clone());
+            Self::remove_oracle(&account_id)?;
             Self::deposit_event(Event::OracleRemoved { oracle_id: account_id });
             Ok(())
         }
+
+        /// Sets the maximum deviation of the aggregate for the given key (only executable
+        /// by the Root account). `None` disables the guard.
+        ///
+        /// # Arguments
+        /// * `key` - the oracle key to guard
+        /// * `guard` - the maximum relative change per update and how to handle violations
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_deviation_guard())]
+        #[transactional]
+        pub fn set_deviation_guard(
+            origin: OriginFor<T>,
+            key: OracleKey,
+            guard: Option<DefaultDeviationGuard<T>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            match guard {
+                Some(guard) => DeviationGuards::<T>::insert(&key, guard),
+                None => DeviationGuards::<T>::remove(&key),
+            }
+            Self::deposit_event(Event::SetDeviationGuard { key, guard });
+            Ok(())
+        }
+
+        /// Resumes a paused price feed (only executable by the Root account), optionally
+        /// overriding the aggregate.
+        ///
+        /// # Arguments
+        /// * `key` - the oracle key to resume
+        /// * `value` - the aggregate to use from now on, if any
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config>::WeightInfo::resume_price_feed())]
+        #[transactional]
+        pub fn resume_price_feed(
+            origin: OriginFor<T>,
+            key: OracleKey,
+            value: Option<T::UnsignedFixedPoint>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            PendingAggregate::<T>::remove(&key);
+            DeviationViolations::<T>::remove(&key);
+            PausedPriceFeeds::<T>::remove(&key);
+            if let Some(value) = value {
+                let valid_until = Self::get_current_time() + Self::get_key_max_delay(&key);
+                ValidUntil::<T>::insert(&key, valid_until);
+                Aggregate::<T>::insert(&key, value);
+                Self::record_price(&key, value, 0);
+                if let OracleKey::ExchangeRate(currency_id) = key {
+                    T::OnExchangeRateChange::on_exchange_rate_change(&currency_id);
+                }
+            }
+            Self::deposit_event(Event::PriceFeedResumed { key });
+            Ok(())
+        }
+
+        /// Uses the time-weighted average price of the DEX pair of the currency and the
+        /// wrapped currency as a price source for its exchange rate (only executable by the
+        /// Root account). `None` removes the source.
+        ///
+        /// # Arguments
+        /// * `key` - the exchange rate key
+        /// * `source` - the averaging window and how to combine the TWAP with the oracle feeds
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_twap_source())]
+        #[transactional]
+        pub fn set_twap_source(
+            origin: OriginFor<T>,
+            key: OracleKey,
+            source: Option<TwapSource<BlockNumberFor<T>>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            TwapObservations::<T>::remove(&key);
+            TwapValues::<T>::remove(&key);
+            match source {
+                Some(source) => {
+                    ensure!(
+                        matches!(key, OracleKey::ExchangeRate(_)) && !source.window.is_zero(),
+                        Error::<T>::InvalidTwapSource
+                    );
+                    TwapSources::<T>::insert(&key, source);
+                }
+                None => TwapSources::<T>::remove(&key),
+            }
+            // recompute the aggregate with or without the TWAP
+            RawValuesUpdated::<T>::insert(&key, true);
+            Self::deposit_event(Event::SetTwapSource { key, source });
+            Ok(())
+        }
+
+        /// Enables the bonded oracle mode with the given parameters (only executable by the
+        /// Root account). `None` stops new oracles from bonding; existing bonds remain.
+        ///
+        /// # Arguments
+        /// * `parameters` - the minimum bond, slashing and inactivity thresholds
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_bonded_oracle_parameters())]
+        #[transactional]
+        pub fn set_bonded_oracle_parameters(
+            origin: OriginFor<T>,
+            parameters: Option<DefaultBondedOracleParameters<T>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            match parameters {
+                Some(parameters) => {
+                    ensure!(
+                        parameters.max_outliers > 0
+                            && parameters.max_missed_rounds > 0
+                            && !parameters.round_length.is_zero(),
+                        Error::<T>::InvalidBondedOracleParameters
+                    );
+                    BondedOracleParameters::<T>::put(parameters);
+                }
+                None => BondedOracleParameters::<T>::kill(),
+            }
+            Self::deposit_event(Event::SetBondedOracleParameters { parameters });
+            Ok(())
+        }
+
+        /// Locks a bond in the native currency to become an authorized oracle.
+        ///
+        /// # Arguments
+        /// * `name` - a descriptive name for the oracle
+        /// * `amount` - the bond, at least the configured minimum
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as Config>::WeightInfo::bond_oracle())]
+        #[transactional]
+        pub fn bond_oracle(origin: OriginFor<T>, name: NameOf<T>, amount: BalanceOf<T>) -> DispatchResult {
+            let signer = ensure_signed(origin)?;
+            let parameters = Self::bonded_oracle_parameters().ok_or(Error::<T>::BondedOraclesDisabled)?;
+            ensure!(!Self::is_authorized(&signer), Error::<T>::OracleAlreadyAuthorized);
+            ensure!(
+                !UnbondingOracles::<T>::contains_key(&signer),
+                Error::<T>::OracleUnbonding
+            );
+            ensure!(amount >= parameters.min_bond, Error::<T>::InsufficientOracleBond);
+
+            Amount::<T>::new(amount, T::GetNativeCurrencyId::get()).lock_on(&signer)?;
+            OracleBonds::<T>::insert(&signer, amount);
+            Self::insert_oracle(signer.clone(), name.clone());
+
+            Self::deposit_event(Event::OracleBonded {
+                oracle_id: signer,
+                name,
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Stops being a bonded oracle. The bond is released once the current round has been
+        /// settled, so that outlier submissions can still be slashed.
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::unbond_oracle())]
+        #[transactional]
+        pub fn unbond_oracle(origin: OriginFor<T>) -> DispatchResult {
+            let signer = ensure_signed(origin)?;
+            ensure!(OracleBonds::<T>::contains_key(&signer), Error::<T>::NotBondedOracle);
+            ensure!(
+                !UnbondingOracles::<T>::contains_key(&signer),
+                Error::<T>::OracleUnbonding
+            );
+            let parameters = match Self::bonded_oracle_parameters() {
+                Some(parameters) => parameters,
+                // rounds are only settled in the bonded oracle mode
+                None => return Self::remove_oracle(&signer),
+            };
+
+            <AuthorizedOracles<T>>::remove(&signer);
+            Commitments::<T>::remove(&signer);
+            // the current round settles once the next one starts, so the bond stays locked until
+            // the end of the next round
+            let round = Self::get_current_time() / parameters.round_length;
+            let unlock_time = round
+                .saturating_add(2u32.into())
+                .saturating_mul(parameters.round_length);
+            UnbondingOracles::<T>::insert(&signer, unlock_time);
+            Self::deposit_event(Event::OracleUnbondingStarted {
+                oracle_id: signer,
+                unlock_time,
+            });
+            Ok(())
+        }
+
+        /// Sets the minimum number of fresh oracle submissions needed to publish an
+        /// aggregate for the key (only executable by the Root account). `None` resets it
+        /// to a single submission.
+        ///
+        /// # Arguments
+        /// * `key` - the oracle key
+        /// * `quorum` - the minimum number of submissions
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_minimum_quorum())]
+        #[transactional]
+        pub fn set_minimum_quorum(origin: OriginFor<T>, key: OracleKey, quorum: Option<u32>) -> DispatchResult {
+            ensure_root(origin)?;
+            match quorum {
+                Some(quorum) => {
+                    ensure!(!quorum.is_zero(), Error::<T>::InvalidMinimumQuorum);
+                    MinimumQuorums::<T>::insert(&key, quorum);
+                }
+                None => MinimumQuorums::<T>::remove(&key),
+            }
+            RawValuesUpdated::<T>::insert(&key, true);
+            Self::deposit_event(Event::SetMinimumQuorum { key, quorum });
+            Ok(())
+        }
+
+        /// Sets the maximum delay of submissions for the key (only executable by the Root
+        /// account). `None` falls back to the global maximum delay.
+        ///
+        /// # Arguments
+        /// * `key` - the oracle key
+        /// * `max_delay` - the maximum delay in milliseconds
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_key_max_delay())]
+        #[transactional]
+        pub fn set_key_max_delay(origin: OriginFor<T>, key: OracleKey, max_delay: Option<T::Moment>) -> DispatchResult {
+            ensure_root(origin)?;
+            match max_delay {
+                Some(max_delay) => KeyMaxDelays::<T>::insert(&key, max_delay),
+                None => KeyMaxDelays::<T>::remove(&key),
+            }
+            RawValuesUpdated::<T>::insert(&key, true);
+            Self::deposit_event(Event::SetKeyMaxDelay { key, max_delay });
+            Ok(())
+        }
+
+        /// Enables the commit-reveal scheme with the given windows (only executable by the
+        /// Root account). `None` lets oracles feed plaintext values again.
+        ///
+        /// # Arguments
+        /// * `period` - the length of the commit and the reveal window
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_commit_reveal_period())]
+        #[transactional]
+        pub fn set_commit_reveal_period(
+            origin: OriginFor<T>,
+            period: Option<CommitRevealPeriod<BlockNumberFor<T>>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            match period {
+                Some(period) => {
+                    ensure!(
+                        !period.commit_window.is_zero() && !period.reveal_window.is_zero(),
+                        Error::<T>::InvalidCommitRevealPeriod
+                    );
+                    CommitReveal::<T>::put(period);
+                }
+                None => CommitReveal::<T>::kill(),
+            }
+            // commitments of the previous windows can no longer be revealed
+            let _ = Commitments::<T>::clear(u32::MAX, None);
+            Self::deposit_event(Event::SetCommitRevealPeriod { period });
+            Ok(())
+        }
+
+        /// Commits to the values that will be revealed in the reveal window of the round.
+        ///
+        /// # Arguments
+        /// * `commitment` - the hash of the oracle account, the values and a salt, as computed by `commitment_hash`
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as Config>::WeightInfo::commit_values())]
+        #[transactional]
+        pub fn commit_values(origin: OriginFor<T>, commitment: H256) -> DispatchResultWithPostInfo {
+            let signer = ensure_signed(origin)?;
+            ensure!(Self::is_authorized(&signer), Error::<T>::InvalidOracleSource);
+            let round = match Self::commit_reveal_phase(frame_system::Pallet::<T>::block_number())? {
+                CommitRevealPhase::Commit(round) => round,
+                CommitRevealPhase::Reveal(_) => return Err(Error::<T>::NotCommitPhase.into()),
+            };
+
+            Commitments::<T>::insert(&signer, (round, commitment));
+            Self::deposit_event(Event::ValuesCommitted {
+                oracle_id: signer,
+                round,
+            });
+            Ok(Pays::No.into())
+        }
+
+        /// Reveals the values committed in the current round, feeding them like
+        /// `feed_values`.
+        ///
+        /// # Arguments
+        /// * `values` - a vector of (key, value) pairs to submit
+        /// * `salt` - the salt used in the commitment
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as Config>::WeightInfo::reveal_values(values.len() as u32))]
+        #[transactional]
+        pub fn reveal_values(
+            origin: OriginFor<T>,
+            values: Vec<(OracleKey, T::UnsignedFixedPoint)>,
+            salt: H256,
+        ) -> DispatchResultWithPostInfo {
+            let signer = ensure_signed(origin)?;
+            ensure!(Self::is_authorized(&signer), Error::<T>::InvalidOracleSource);
+            let round = match Self::commit_reveal_phase(frame_system::Pallet::<T>::block_number())? {
+                CommitRevealPhase::Reveal(round) => round,
+                CommitRevealPhase::Commit(_) => return Err(Error::<T>::NotRevealPhase.into()),
+            };
+
+            let commitment = Commitments::<T>::get(&signer)
+                .filter(|(committed_round, _)| *committed_round == round)
+                .map(|(_, commitment)| commitment)
+                .ok_or(Error::<T>::MissingCommitment)?;
+            ensure!(
+                commitment == Self::commitment_hash(&signer, &values, &salt),
+                Error::<T>::CommitmentMismatch
+            );
+
+            Commitments::<T>::remove(&signer);
+            Self::_feed_values(signer, values);
+            Ok(Pays::No.into())
+        }
+
+        /// Sets how the exchange rate of the currency is derived if it has no feed of its own
+        /// (only executable by the Root account). `None` removes the path.
+        ///
+        /// # Arguments
+        /// * `currency_id` - the currency to price
+        /// * `path` - the derivation of the exchange rate
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_pricing_path())]
+        #[transactional]
+        pub fn set_pricing_path(
+            origin: OriginFor<T>,
+            currency_id: CurrencyId,
+            path: Option<PricingPath<CurrencyId>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            match path {
+                Some(path) => {
+                    let is_valid = match (path, currency_id) {
+                        (PricingPath::CrossRate { intermediate }, _) => intermediate != currency_id,
+                        (PricingPath::StableLp { base }, CurrencyId::StableLpToken(_)) => base != currency_id,
+                        (PricingPath::FairReserves, CurrencyId::LpToken(_, _)) => true,
+                        _ => false,
+                    };
+                    ensure!(is_valid, Error::<T>::InvalidPricingPath);
+                    PricingPaths::<T>::insert(currency_id, path);
+                }
+                None => PricingPaths::<T>::remove(currency_id),
+            }
+            Self::deposit_event(Event::SetPricingPath { currency_id, path });
+            Ok(())
+        }
     }
 }
 
 #[cfg_attr(test, mockable)]
 impl<T: Config> Pallet<T> {
     // public only for testing purposes
-    pub fn begin_block(_height: BlockNumberFor<T>) -> u32 {
+    pub fn begin_block(height: BlockNumberFor<T>) -> u32 {
+        let twap_sources = Self::update_twap_values(height);
+        let unrevealed = Self::expire_commitments(height);
+
         // read to a temporary value, because we can't alter the map while we iterate over it
         let raw_values_updated: Vec<_> = RawValuesUpdated::<T>::iter().collect();
 
@@ -256,6 +860,7 @@ impl<T: Config> Pallet<T> {
 
         let mut updated_items = Vec::new();
         for (key, is_updated) in raw_values_updated.iter() {
+            Self::advance_bonded_round(key, current_time);
             if *is_updated || Self::is_outdated(key, current_time) {
                 let new_value = Self::update_aggregate(key);
                 updated_items.push((key.clone(), new_value));
@@ -266,7 +871,76 @@ impl<T: Config> Pallet<T> {
             Self::deposit_event(Event::<T>::AggregateUpdated { values: updated_items });
         }
 
-        raw_values_updated.len().saturated_into()
+        let unbonding = Self::release_unbonded_oracles(current_time);
+
+        raw_values_updated
+            .len()
+            .saturating_add(twap_sources)
+            .saturating_add(unrevealed)
+            .saturating_add(unbonding)
+            .saturated_into()
+    }
+
+    /// Releases the bonds of unbonding oracles whose unlock time has passed. Returns the number
+    /// of unbonding oracles.
+    fn release_unbonded_oracles(now: T::Moment) -> usize {
+        let unbonding: Vec<_> = UnbondingOracles::<T>::iter().collect();
+        for (oracle_id, unlock_time) in unbonding.iter() {
+            if *unlock_time <= now {
+                let _ = with_storage_layer(|| Self::remove_oracle(oracle_id));
+            }
+        }
+        unbonding.len()
+    }
+
+    /// Completes the TWAP windows that have elapsed and starts new ones. Returns the number
+    /// of configured sources.
+    fn update_twap_values(height: BlockNumberFor<T>) -> usize {
+        let sources: Vec<_> = TwapSources::<T>::iter().collect();
+        for (key, source) in sources.iter() {
+            let currency_id = match key {
+                OracleKey::ExchangeRate(currency_id) => *currency_id,
+                _ => continue,
+            };
+            let cumulative = match Self::get_price_cumulative(currency_id) {
+                Some(cumulative) => cumulative,
+                None => continue,
+            };
+            match TwapObservations::<T>::get(key) {
+                Some((start, _)) if height.saturating_sub(start) < source.window => {}
+                Some((start, start_cumulative)) => {
+                    let elapsed = height.saturating_sub(start);
+                    if let Some(value) = Self::average_price(start_cumulative, cumulative, elapsed) {
+                        TwapValues::<T>::insert(
+                            key,
+                            TimestampedValue {
+                                value,
+                                timestamp: Self::get_current_time(),
+                            },
+                        );
+                        RawValuesUpdated::<T>::insert(key, true);
+                    }
+                    TwapObservations::<T>::insert(key, (height, cumulative));
+                }
+                None => TwapObservations::<T>::insert(key, (height, cumulative)),
+            }
+        }
+        sources.len()
+    }
+
+    /// Accumulated price of the wrapped currency in the given currency on the DEX
+    fn get_price_cumulative(currency_id: CurrencyId) -> Option<U256> {
+        T::DexPriceCumulative::price_cumulative(T::GetWrappedCurrencyId::get(), currency_id)
+    }
+
+    fn average_price(start: U256, end: U256, elapsed: BlockNumberFor<T>) -> Option<UnsignedFixedPoint<T>> {
+        let elapsed = U256::from(elapsed.saturated_into::<u64>());
+        if elapsed.is_zero() {
+            return None;
+        }
+        // accumulators are allowed to wrap around
+        let average: u128 = end.overflowing_sub(start).0.checked_div(elapsed)?.try_into().ok()?;
+        UnsignedFixedPoint::<T>::checked_from_rational(average, traits::PRICE_CUMULATIVE_PRECISION)
     }
 
     // public only for testing purposes
@@ -278,6 +952,8 @@ impl<T: Config> Pallet<T> {
             };
             RawValues::<T>::insert(key, &oracle, timestamped);
             RawValuesUpdated::<T>::insert(key, true);
+            RoundSubmitters::<T>::insert(key, &oracle, ());
+            Self::record_submission(key, &oracle);
         }
 
         Self::deposit_event(Event::<T>::FeedValues {
@@ -286,6 +962,49 @@ impl<T: Config> Pallet<T> {
         });
     }
 
+    /// Hash that an oracle commits to before revealing the values. The account is part
+    /// of the preimage so that other oracles cannot copy the commitment.
+    pub fn commitment_hash(oracle: &T::AccountId, values: &[(OracleKey, T::UnsignedFixedPoint)], salt: &H256) -> H256 {
+        BlakeTwo256::hash_of(&(oracle, values, salt))
+    }
+
+    /// Get the round and phase of the commit-reveal scheme at the given height. Each round
+    /// starts with the commit window, which is followed by the reveal window.
+    pub fn commit_reveal_phase(
+        height: BlockNumberFor<T>,
+    ) -> Result<CommitRevealPhase<BlockNumberFor<T>>, DispatchError> {
+        let period = CommitReveal::<T>::get().ok_or(Error::<T>::CommitRevealDisabled)?;
+        let round_length = period.commit_window.saturating_add(period.reveal_window);
+        let round = height / round_length;
+        if height % round_length < period.commit_window {
+            Ok(CommitRevealPhase::Commit(round))
+        } else {
+            Ok(CommitRevealPhase::Reveal(round))
+        }
+    }
+
+    /// Removes the commitments of past rounds at the start of each round, counting them
+    /// as not revealed. Returns the number of expired commitments.
+    fn expire_commitments(height: BlockNumberFor<T>) -> usize {
+        let round = match Self::commit_reveal_phase(height) {
+            Ok(CommitRevealPhase::Commit(round)) => round,
+            _ => return 0,
+        };
+        let expired: Vec<_> = Commitments::<T>::iter()
+            .filter(|(_, (committed_round, _))| *committed_round < round)
+            .map(|(oracle_id, (committed_round, _))| (oracle_id, committed_round))
+            .collect();
+        for (oracle_id, committed_round) in expired.iter() {
+            Commitments::<T>::remove(oracle_id);
+            UnrevealedCommitments::<T>::mutate(oracle_id, |unrevealed| *unrevealed = unrevealed.saturating_add(1));
+            Self::deposit_event(Event::<T>::CommitmentNotRevealed {
+                oracle_id: oracle_id.clone(),
+                round: *committed_round,
+            });
+        }
+        expired.len()
+    }
+
     /// Public getters
 
     /// Get the exchange rate in planck per satoshi
@@ -293,16 +1012,22 @@ impl<T: Config> Pallet<T> {
         Aggregate::<T>::get(key).ok_or(Error::<T>::MissingExchangeRate.into())
     }
 
+    /// Get the exchange rate of the currency in planck per satoshi, derived through its
+    /// pricing path if the currency has no feed of its own
+    pub fn get_exchange_rate(currency_id: CurrencyId) -> Result<UnsignedFixedPoint<T>, DispatchError> {
+        Self::derive_exchange_rate(currency_id, MAX_PRICING_PATH_DEPTH)
+    }
+
     pub fn wrapped_to_collateral(amount: BalanceOf<T>, currency_id: CurrencyId) -> Result<BalanceOf<T>, DispatchError> {
         let amount = Amount::<T>::new(amount, currency_id);
 
-        let rate = Self::get_price(OracleKey::ExchangeRate(currency_id))?;
+        let rate = Self::get_exchange_rate(currency_id)?;
 
         amount.checked_mul(&rate).map(|x| x.amount())
     }
 
     pub fn collateral_to_wrapped(amount: BalanceOf<T>, currency_id: CurrencyId) -> Result<BalanceOf<T>, DispatchError> {
-        let rate = Self::get_price(OracleKey::ExchangeRate(currency_id))?;
+        let rate = Self::get_exchange_rate(currency_id)?;
         if amount.is_zero() {
             return Ok(Zero::zero());
         }
@@ -311,29 +1036,66 @@ impl<T: Config> Pallet<T> {
         amount.checked_div(&rate).map(|x| x.amount())
     }
 
+    /// True if the price feed of the key was paused by the deviation guard
+    pub fn is_price_feed_paused(key: &OracleKey) -> bool {
+        PausedPriceFeeds::<T>::contains_key(key)
+    }
+
+    /// Fails if the exchange rate of the currency is paused, in which case it must not be
+    /// used to liquidate vaults or to issue new tokens.
+    pub fn ensure_price_feed_not_paused(currency_id: CurrencyId) -> DispatchResult {
+        ensure!(
+            !Self::is_price_feed_paused(&OracleKey::ExchangeRate(currency_id)),
+            Error::<T>::PriceFeedPaused
+        );
+        Ok(())
+    }
+
     fn update_aggregate(key: &OracleKey) -> Option<T::UnsignedFixedPoint> {
         RawValuesUpdated::<T>::insert(key, false);
         let mut raw_values: Vec<_> = RawValues::<T>::iter_prefix(key).map(|(_, value)| value).collect();
-        let min_timestamp = Self::get_current_time().saturating_sub(Self::get_max_delay());
+        let max_delay = Self::get_key_max_delay(key);
+        let min_timestamp = Self::get_current_time().saturating_sub(max_delay);
         raw_values.retain(|value| value.timestamp >= min_timestamp);
+        if raw_values.len() < Self::get_minimum_quorum(key) as usize {
+            // not enough fresh submissions, only a fallback TWAP can be used
+            raw_values.clear();
+        }
+        if let (Some(source), Some(twap)) = (TwapSources::<T>::get(key), TwapValues::<T>::get(key)) {
+            if twap.timestamp >= min_timestamp {
+                match source.policy {
+                    TwapPolicy::Fallback if raw_values.is_empty() => raw_values.push(twap),
+                    TwapPolicy::Median => raw_values.push(twap),
+                    TwapPolicy::Fallback => {}
+                }
+            }
+        }
         let ret = if raw_values.len() == 0 {
             Aggregate::<T>::remove(key);
             ValidUntil::<T>::remove(key);
+            PendingAggregate::<T>::remove(key);
             None
         } else {
             let valid_until = raw_values
                 .iter()
                 .map(|x| x.timestamp)
                 .min()
-                .map(|timestamp| timestamp + Self::get_max_delay())
+                .map(|timestamp| timestamp + max_delay)
                 .unwrap_or_default(); // Unwrap will never fail, but if somehow it did, we retry next block
 
-            let value = Self::median(raw_values.iter().map(|x| x.value).collect())?;
-
-            Aggregate::<T>::insert(key, value);
-            ValidUntil::<T>::insert(key, valid_until);
-
-            Some(value)
+            let median = Self::median(raw_values.iter().map(|x| x.value).collect())?;
+
+            match Self::apply_deviation_guard(key, median) {
+                Some(value) => {
+                    // only a confirmed value extends the validity, a held feed must go stale
+                    ValidUntil::<T>::insert(key, valid_until);
+                    Aggregate::<T>::insert(key, value);
+                    Self::record_price(key, value, raw_values.len().saturated_into());
+                    Some(value)
+                }
+                // held pending confirmation, keep the current aggregate
+                None => Aggregate::<T>::get(key),
+            }
         };
 
         if let OracleKey::ExchangeRate(currency_id) = key {
@@ -343,6 +1105,322 @@ impl<T: Config> Pallet<T> {
         ret
     }
 
+    /// Settles the bonded oracle round of the key once the time of the round has passed.
+    fn advance_bonded_round(key: &OracleKey, now: T::Moment) {
+        let parameters = match Self::bonded_oracle_parameters() {
+            Some(parameters) => parameters,
+            None => return,
+        };
+        let round = now / parameters.round_length;
+        match BondedRounds::<T>::get(key) {
+            Some(current) if current >= round => return,
+            Some(_) => Self::settle_bonded_round(key, &parameters),
+            // submissions made before the first round started count towards it
+            None => {}
+        }
+        BondedRounds::<T>::insert(key, round);
+    }
+
+    /// Tracks outlier submissions and missed rounds of the bonded oracles for the key,
+    /// slashing repeated outliers and removing inactive oracles.
+    fn settle_bonded_round(key: &OracleKey, parameters: &DefaultBondedOracleParameters<T>) {
+        let submissions: Vec<_> = RoundSubmitters::<T>::drain_prefix(key)
+            .filter_map(|(oracle_id, _)| RawValues::<T>::get(key, &oracle_id).map(|value| (oracle_id, value.value)))
+            .collect();
+        let median = Self::median(submissions.iter().map(|(_, value)| *value).collect());
+        let bonded_oracles: Vec<_> = OracleBonds::<T>::iter_keys().collect();
+        for oracle_id in bonded_oracles {
+            let submission = match submissions.iter().find(|(submitter, _)| *submitter == oracle_id) {
+                Some((_, value)) => value,
+                // unbonding oracles are no longer authorized to submit values
+                None if UnbondingOracles::<T>::contains_key(&oracle_id) => continue,
+                None => {
+                    let missed = MissedRounds::<T>::mutate(key, &oracle_id, |missed| {
+                        *missed = missed.saturating_add(1);
+                        *missed
+                    });
+                    if missed >= parameters.max_missed_rounds {
+                        if with_storage_layer(|| Self::remove_oracle(&oracle_id)).is_ok() {
+                            Self::deposit_event(Event::<T>::InactiveOracleRemoved { oracle_id });
+                        }
+                    }
+                    continue;
+                }
+            };
+            MissedRounds::<T>::remove(key, &oracle_id);
+
+            let is_outlier = matches!(
+                median,
+                Some(median) if !Self::is_within_deviation(&median, submission, &parameters.max_deviation)
+            );
+            if !is_outlier {
+                OutlierSubmissions::<T>::remove(key, &oracle_id);
+                continue;
+            }
+            let outliers = OutlierSubmissions::<T>::mutate(key, &oracle_id, |outliers| {
+                *outliers = outliers.saturating_add(1);
+                *outliers
+            });
+            if outliers >= parameters.max_outliers {
+                OutlierSubmissions::<T>::remove(key, &oracle_id);
+                let _ = with_storage_layer(|| Self::slash_oracle(&oracle_id, parameters.slash_amount));
+            }
+        }
+    }
+
+    /// Moves up to `slash_amount` of the oracle's bond to the treasury
+    fn slash_oracle(oracle_id: &T::AccountId, slash_amount: BalanceOf<T>) -> DispatchResult {
+        let bond = Self::oracle_bond(oracle_id).ok_or(Error::<T>::NotBondedOracle)?;
+        let amount = Amount::<T>::new(bond.min(slash_amount), T::GetNativeCurrencyId::get());
+        amount.unlock_on(oracle_id)?;
+        amount.transfer(oracle_id, &T::TreasuryAccountId::get())?;
+        OracleBonds::<T>::insert(oracle_id, bond.saturating_sub(amount.amount()));
+        Self::deposit_event(Event::<T>::OracleSlashed {
+            oracle_id: oracle_id.clone(),
+            amount: amount.amount(),
+        });
+        Ok(())
+    }
+
+    /// Revokes the oracle's authorization and drops its values from the aggregates, releasing
+    /// its bond if it has one
+    fn remove_oracle(oracle_id: &T::AccountId) -> DispatchResult {
+        <AuthorizedOracles<T>>::remove(oracle_id);
+        let keys: Vec<_> = RawValuesUpdated::<T>::iter_keys().collect();
+        for key in keys {
+            if RawValues::<T>::take(&key, oracle_id).is_some() {
+                RawValuesUpdated::<T>::insert(&key, true);
+            }
+            RoundSubmitters::<T>::remove(&key, oracle_id);
+            OutlierSubmissions::<T>::remove(&key, oracle_id);
+            MissedRounds::<T>::remove(&key, oracle_id);
+        }
+        Commitments::<T>::remove(oracle_id);
+        UnbondingOracles::<T>::remove(oracle_id);
+        if let Some(bond) = OracleBonds::<T>::take(oracle_id) {
+            Amount::<T>::new(bond, T::GetNativeCurrencyId::get()).unlock_on(oracle_id)?;
+            Self::deposit_event(Event::<T>::OracleUnbonded {
+                oracle_id: oracle_id.clone(),
+                amount: bond,
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks the median of a round against the deviation guard of the key. Returns the
+    /// value to use as the aggregate, or `None` if the update is held until the next round
+    /// confirms it. Repeated violations pause the price feed, which is resumed once a round
+    /// agrees with either the current or the held aggregate.
+    fn apply_deviation_guard(key: &OracleKey, value: UnsignedFixedPoint<T>) -> Option<UnsignedFixedPoint<T>> {
+        let (guard, previous) = match (DeviationGuards::<T>::get(key), Aggregate::<T>::get(key)) {
+            (Some(guard), Some(previous)) => (guard, previous),
+            _ => return Some(value),
+        };
+
+        let pending = PendingAggregate::<T>::take(key);
+        if Self::is_within_deviation(&previous, &value, &guard.max_change)
+            || matches!(pending, Some(pending) if Self::is_within_deviation(&pending, &value, &guard.max_change))
+        {
+            DeviationViolations::<T>::remove(key);
+            if PausedPriceFeeds::<T>::take(key).is_some() {
+                Self::deposit_event(Event::<T>::PriceFeedResumed { key: key.clone() });
+            }
+            return Some(value);
+        }
+
+        Self::deposit_event(Event::<T>::AggregateDeviationExceeded {
+            key: key.clone(),
+            value,
+            previous,
+        });
+        let violations = DeviationViolations::<T>::mutate(key, |violations| {
+            *violations = violations.saturating_add(1);
+            *violations
+        });
+        if violations >= guard.max_violations && !Self::is_price_feed_paused(key) {
+            PausedPriceFeeds::<T>::insert(key, ());
+            Self::deposit_event(Event::<T>::PriceFeedPaused { key: key.clone() });
+        }
+
+        match guard.action {
+            DeviationAction::Clamp => {
+                let max_delta = previous.saturating_mul(guard.max_change);
+                if value > previous {
+                    Some(previous.saturating_add(max_delta))
+                } else {
+                    Some(previous.saturating_sub(max_delta))
+                }
+            }
+            DeviationAction::Confirm => {
+                PendingAggregate::<T>::insert(key, value);
+                None
+            }
+        }
+    }
+
+    fn is_within_deviation(
+        reference: &UnsignedFixedPoint<T>,
+        value: &UnsignedFixedPoint<T>,
+        max_change: &UnsignedFixedPoint<T>,
+    ) -> bool {
+        let delta = if value > reference {
+            value.saturating_sub(*reference)
+        } else {
+            reference.saturating_sub(*value)
+        };
+        delta <= reference.saturating_mul(*max_change)
+    }
+
+    /// Appends the aggregate to the price history of the key, dropping the oldest record if
+    /// the history is full.
+    fn record_price(key: &OracleKey, value: UnsignedFixedPoint<T>, oracle_count: u32) {
+        let record = PriceRecord {
+            value,
+            timestamp: Self::get_current_time(),
+            block_number: frame_system::Pallet::<T>::block_number(),
+            oracle_count,
+        };
+        PriceHistory::<T>::mutate(key, |history| {
+            if !history.is_empty() && history.len() >= T::MaxPriceHistoryLength::get() as usize {
+                history.remove(0);
+            }
+            // cannot fail since we made room above (or the history has no capacity at all)
+            let _ = history.try_push(record);
+        });
+    }
+
+    /// Appends the current time to the submission history of the oracle for the key, dropping
+    /// the oldest submission if the history is full.
+    fn record_submission(key: &OracleKey, oracle: &T::AccountId) {
+        let now = Self::get_current_time();
+        SubmissionHistory::<T>::mutate(key, oracle, |history| {
+            if history.last() == Some(&now) {
+                return;
+            }
+            if !history.is_empty() && history.len() >= T::MaxPriceHistoryLength::get() as usize {
+                history.remove(0);
+            }
+            // cannot fail since we made room above (or the history has no capacity at all)
+            let _ = history.try_push(now);
+        });
+    }
+
+    /// Get the most recent aggregates of the key, newest first
+    ///
+    /// # Arguments
+    /// * `key` - the oracle key
+    /// * `count` - the maximum number of records to return
+    pub fn get_price_history(key: OracleKey, count: u32) -> Vec<PriceRecordOf<T>> {
+        PriceHistory::<T>::get(key)
+            .into_iter()
+            .rev()
+            .take(count.saturated_into())
+            .collect()
+    }
+
+    /// Get the aggregate of the key that was in effect at the given block, if it is still in
+    /// the price history
+    ///
+    /// # Arguments
+    /// * `key` - the oracle key
+    /// * `block_number` - the block to look up
+    pub fn get_price_at(key: OracleKey, block_number: BlockNumberFor<T>) -> Option<PriceRecordOf<T>> {
+        PriceHistory::<T>::get(key)
+            .into_iter()
+            .rev()
+            .find(|record| record.block_number <= block_number)
+    }
+
+    /// Get the authorized oracles that had not fed a value for the key within the maximum
+    /// delay before the given time, as far back as their submission history goes
+    ///
+    /// # Arguments
+    /// * `key` - the oracle key
+    /// * `timestamp` - the time at which to check the submissions
+    pub fn get_stale_oracles(key: OracleKey, timestamp: T::Moment) -> Vec<T::AccountId> {
+        let min_timestamp = timestamp.saturating_sub(Self::get_key_max_delay(&key));
+        AuthorizedOracles::<T>::iter_keys()
+            .filter(|oracle| {
+                !SubmissionHistory::<T>::get(&key, oracle)
+                    .iter()
+                    .any(|submitted| (min_timestamp..=timestamp).contains(submitted))
+            })
+            .collect()
+    }
+
+    fn derive_exchange_rate(currency_id: CurrencyId, depth: u32) -> Result<UnsignedFixedPoint<T>, DispatchError> {
+        if let Some(rate) = Aggregate::<T>::get(OracleKey::ExchangeRate(currency_id)) {
+            return Ok(rate);
+        }
+        let path = match PricingPaths::<T>::get(currency_id) {
+            Some(path) if depth > 0 => path,
+            _ => return Err(Error::<T>::MissingExchangeRate.into()),
+        };
+        let depth = depth.saturating_sub(1);
+        let rate = match (path, currency_id) {
+            (PricingPath::CrossRate { intermediate }, _) => {
+                let cross_rate = Self::get_price(OracleKey::CrossRate(currency_id, intermediate))?;
+                cross_rate.checked_mul(&Self::derive_exchange_rate(intermediate, depth)?)
+            }
+            (PricingPath::StableLp { base }, _) => {
+                let base_rate = Self::derive_exchange_rate(base, depth)?;
+                Self::get_stable_lp_underlying(currency_id, base, LP_PRICING_AMOUNT)
+                    .and_then(|underlying| {
+                        UnsignedFixedPoint::<T>::checked_from_rational(LP_PRICING_AMOUNT, underlying)
+                    })
+                    .and_then(|lp_per_base| base_rate.checked_mul(&lp_per_base))
+            }
+            (PricingPath::FairReserves, CurrencyId::LpToken(token_0, token_1)) => {
+                let (currency_0, currency_1) = (token_0.into(), token_1.into());
+                let rate_0 = Self::derive_exchange_rate(currency_0, depth)?;
+                let rate_1 = Self::derive_exchange_rate(currency_1, depth)?;
+                Self::get_pair_reserves(currency_0, currency_1).and_then(|(reserve_0, reserve_1, total_supply)| {
+                    Self::fair_reserves_rate(reserve_0, reserve_1, total_supply, rate_0, rate_1)
+                })
+            }
+            _ => None,
+        };
+        rate.ok_or(Error::<T>::MissingExchangeRate.into())
+    }
+
+    /// Planck of the LP token per satoshi, using the fair reserves `sqrt(k * p_1 / p_0)` and
+    /// `sqrt(k * p_0 / p_1)` instead of the actual reserves, where `k` is the constant product
+    /// and `p_0`, `p_1` are the satoshi per planck of the currencies. The value of the pair then
+    /// only depends on `k`, which swaps cannot decrease, so the price of the LP token cannot be
+    /// inflated by skewing the reserves. Simplified, the exchange rate is
+    /// `total_supply * sqrt(rate_0 * rate_1) / (2 * sqrt(reserve_0 * reserve_1))`.
+    fn fair_reserves_rate(
+        reserve_0: u128,
+        reserve_1: u128,
+        total_supply: u128,
+        rate_0: UnsignedFixedPoint<T>,
+        rate_1: UnsignedFixedPoint<T>,
+    ) -> Option<UnsignedFixedPoint<T>> {
+        let sqrt_k = U256::from(reserve_0).checked_mul(U256::from(reserve_1))?.integer_sqrt();
+        if sqrt_k.is_zero() {
+            return None;
+        }
+        // the product of the inner values is scaled by the square of the accuracy, so its root
+        // is scaled by the accuracy
+        let sqrt_rates = Into::<U256>::into(rate_0.into_inner())
+            .checked_mul(rate_1.into_inner().into())?
+            .integer_sqrt();
+        let inner = U256::from(total_supply)
+            .checked_mul(sqrt_rates)?
+            .checked_div(sqrt_k.checked_mul(U256::from(2u32))?)?;
+        Some(UnsignedFixedPoint::<T>::from_inner(inner.try_into().ok()?))
+    }
+
+    /// Amount of the pooled currency backing the given amount of a stable pool LP token
+    fn get_stable_lp_underlying(lp_currency_id: CurrencyId, currency_id: CurrencyId, amount: u128) -> Option<u128> {
+        T::DexStableLpPrice::stable_lp_underlying(lp_currency_id, currency_id, amount)
+    }
+
+    /// Reserves of the DEX pair and the total supply of its LP token
+    fn get_pair_reserves(currency_0: CurrencyId, currency_1: CurrencyId) -> Option<(u128, u128, u128)> {
+        T::DexPairReserves::pair_reserves(currency_0, currency_1)
+    }
+
     fn median(mut raw_values: Vec<UnsignedFixedPoint<T>>) -> Option<UnsignedFixedPoint<T>> {
         let mid_index = raw_values.len().checked_div(2)?;
         raw_values.sort_unstable();
@@ -372,6 +1450,14 @@ impl<T: Config> Pallet<T> {
         <MaxDelay<T>>::get()
     }
 
+    fn get_key_max_delay(key: &OracleKey) -> T::Moment {
+        KeyMaxDelays::<T>::get(key).unwrap_or_else(Self::get_max_delay)
+    }
+
+    fn get_minimum_quorum(key: &OracleKey) -> u32 {
+        MinimumQuorums::<T>::get(key).unwrap_or(1)
+    }
+
     /// Set the current exchange rate. ONLY FOR TESTING.
     ///
     /// # Arguments
@@ -436,3 +1522,9 @@ impl<T: Config> OracleApi<Amount<T>, T::CurrencyId> for Pallet<T> {
         Ok(Amount::new(converted, currency_id))
     }
 }
+
+impl<T: Config> PriceFeedStatusApi<CurrencyId> for Pallet<T> {
+    fn ensure_price_feed_not_paused(currency_id: &CurrencyId) -> DispatchResult {
+        Pallet::<T>::ensure_price_feed_not_paused(*currency_id)
+    }
+}
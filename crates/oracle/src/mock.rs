@@ -126,11 +126,20 @@ impl currency::Config for Test {
     type CurrencyConversion = CurrencyConvert;
 }
 
+parameter_types! {
+    pub const OracleTreasuryAccountId: AccountId = 1000;
+}
+
 impl Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type OnExchangeRateChange = ();
+    type DexPriceCumulative = ();
+    type DexStableLpPrice = ();
+    type DexPairReserves = ();
+    type TreasuryAccountId = OracleTreasuryAccountId;
     type WeightInfo = ();
     type MaxNameLength = ConstU32<255>;
+    type MaxPriceHistoryLength = ConstU32<10>;
 }
 
 parameter_types! {
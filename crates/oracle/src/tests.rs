@@ -239,3 +239,997 @@ fn test_median() {
         assert_eq!(Oracle::median(input_fixedpoint), output_fixedpoint);
     }
 }
+
+mod deviation_guard_tests {
+    use super::*;
+    use crate::types::{DeviationAction, DeviationGuard};
+
+    fn key() -> OracleKey {
+        OracleKey::ExchangeRate(Token(DOT))
+    }
+
+    fn rate(x: u128) -> FixedU128 {
+        FixedU128::from(x)
+    }
+
+    fn feed_and_mine(value: FixedU128) {
+        assert_ok!(Oracle::feed_values(RuntimeOrigin::signed(3), vec![(key(), value)]));
+        mine_block();
+    }
+
+    fn setup(action: DeviationAction) {
+        Oracle::is_authorized.mock_safe(|_| MockResult::Return(true));
+        feed_and_mine(rate(100));
+        assert_ok!(Oracle::set_deviation_guard(
+            RuntimeOrigin::root(),
+            key(),
+            Some(DeviationGuard {
+                max_change: FixedU128::checked_from_rational(1, 10).unwrap(),
+                action,
+                max_violations: 2,
+            })
+        ));
+    }
+
+    #[test]
+    fn set_deviation_guard_requires_root() {
+        run_test(|| {
+            assert_err!(
+                Oracle::set_deviation_guard(RuntimeOrigin::signed(3), key(), None),
+                DispatchError::BadOrigin
+            );
+        });
+    }
+
+    #[test]
+    fn update_within_deviation_is_accepted() {
+        run_test(|| {
+            setup(DeviationAction::Clamp);
+            feed_and_mine(rate(109));
+            assert_eq!(Oracle::get_price(key()).unwrap(), rate(109));
+            assert_eq!(Oracle::deviation_violations(key()), 0);
+        });
+    }
+
+    #[test]
+    fn update_exceeding_deviation_is_clamped() {
+        run_test(|| {
+            setup(DeviationAction::Clamp);
+            feed_and_mine(rate(50));
+            assert_eq!(Oracle::get_price(key()).unwrap(), rate(90));
+            assert_eq!(Oracle::deviation_violations(key()), 1);
+            assert_emitted!(Event::AggregateDeviationExceeded {
+                key: key(),
+                value: rate(50),
+                previous: rate(100),
+            });
+
+            feed_and_mine(rate(200));
+            assert_eq!(Oracle::get_price(key()).unwrap(), rate(99));
+        });
+    }
+
+    #[test]
+    fn update_exceeding_deviation_is_held_until_confirmed() {
+        run_test(|| {
+            setup(DeviationAction::Confirm);
+            feed_and_mine(rate(50));
+            assert_eq!(Oracle::get_price(key()).unwrap(), rate(100));
+
+            // the second round agrees with the held value
+            feed_and_mine(rate(52));
+            assert_eq!(Oracle::get_price(key()).unwrap(), rate(52));
+            assert_eq!(Oracle::deviation_violations(key()), 0);
+        });
+    }
+
+    #[test]
+    fn held_update_does_not_extend_validity() {
+        run_test(|| {
+            let now = 1585776145;
+            Oracle::get_current_time.mock_safe(move || MockResult::Return(now));
+            Oracle::get_max_delay.mock_safe(|| MockResult::Return(3600));
+            setup(DeviationAction::Confirm);
+
+            Oracle::get_current_time.mock_safe(move || MockResult::Return(now + 1800));
+            feed_and_mine(rate(50));
+            assert_eq!(Oracle::get_price(key()).unwrap(), rate(100));
+            assert_eq!(crate::ValidUntil::<Test>::get(key()), Some(now + 3600));
+            assert!(Oracle::is_outdated(&key(), now + 3601));
+        });
+    }
+
+    #[test]
+    fn repeated_violations_pause_price_feed() {
+        run_test(|| {
+            setup(DeviationAction::Confirm);
+            feed_and_mine(rate(50));
+            assert_ok!(Oracle::ensure_price_feed_not_paused(Token(DOT)));
+
+            feed_and_mine(rate(200));
+            assert_eq!(Oracle::get_price(key()).unwrap(), rate(100));
+            assert_emitted!(Event::PriceFeedPaused { key: key() });
+            assert_err!(
+                Oracle::ensure_price_feed_not_paused(Token(DOT)),
+                TestError::PriceFeedPaused
+            );
+
+            // fresh agreement resumes the price feed
+            feed_and_mine(rate(200));
+            assert_eq!(Oracle::get_price(key()).unwrap(), rate(200));
+            assert_ok!(Oracle::ensure_price_feed_not_paused(Token(DOT)));
+            assert_emitted!(Event::PriceFeedResumed { key: key() });
+        });
+    }
+
+    #[test]
+    fn resume_price_feed_succeeds() {
+        run_test(|| {
+            setup(DeviationAction::Clamp);
+            feed_and_mine(rate(50));
+            feed_and_mine(rate(50));
+            assert!(Oracle::is_price_feed_paused(&key()));
+
+            assert_err!(
+                Oracle::resume_price_feed(RuntimeOrigin::signed(3), key(), None),
+                DispatchError::BadOrigin
+            );
+            assert_ok!(Oracle::resume_price_feed(RuntimeOrigin::root(), key(), Some(rate(50))));
+            assert!(!Oracle::is_price_feed_paused(&key()));
+            assert_eq!(Oracle::deviation_violations(key()), 0);
+            assert_eq!(Oracle::get_price(key()).unwrap(), rate(50));
+        });
+    }
+}
+
+mod twap_tests {
+    use super::*;
+    use crate::types::{TwapPolicy, TwapSource};
+    use sp_core::U256;
+
+    fn key() -> OracleKey {
+        OracleKey::ExchangeRate(Token(DOT))
+    }
+
+    fn set_source(policy: TwapPolicy) {
+        assert_ok!(Oracle::set_twap_source(
+            RuntimeOrigin::root(),
+            key(),
+            Some(TwapSource { window: 10, policy })
+        ));
+    }
+
+    // the dex reports a price of 2 over the window
+    fn complete_window() {
+        Oracle::get_price_cumulative.mock_safe(|_| MockResult::Return(Some(U256::zero())));
+        Oracle::begin_block(1);
+        Oracle::get_price_cumulative
+            .mock_safe(|_| MockResult::Return(Some(U256::from(20u128 * traits::PRICE_CUMULATIVE_PRECISION))));
+        Oracle::begin_block(11);
+    }
+
+    #[test]
+    fn set_twap_source_fails_with_invalid_source() {
+        run_test(|| {
+            assert_err!(
+                Oracle::set_twap_source(
+                    RuntimeOrigin::root(),
+                    OracleKey::FeeEstimation,
+                    Some(TwapSource {
+                        window: 10,
+                        policy: TwapPolicy::Fallback
+                    })
+                ),
+                TestError::InvalidTwapSource
+            );
+            assert_err!(
+                Oracle::set_twap_source(
+                    RuntimeOrigin::root(),
+                    key(),
+                    Some(TwapSource {
+                        window: 0,
+                        policy: TwapPolicy::Fallback
+                    })
+                ),
+                TestError::InvalidTwapSource
+            );
+        });
+    }
+
+    #[test]
+    fn twap_is_used_as_fallback() {
+        run_test(|| {
+            set_source(TwapPolicy::Fallback);
+            Oracle::begin_block(1);
+            assert_err!(Oracle::get_price(key()), TestError::MissingExchangeRate);
+
+            complete_window();
+            assert_eq!(Oracle::get_price(key()).unwrap(), FixedU128::from(2));
+
+            // oracle feeds take precedence
+            Oracle::is_authorized.mock_safe(|_| MockResult::Return(true));
+            assert_ok!(Oracle::feed_values(
+                RuntimeOrigin::signed(3),
+                vec![(key(), FixedU128::from(5))]
+            ));
+            Oracle::begin_block(12);
+            assert_eq!(Oracle::get_price(key()).unwrap(), FixedU128::from(5));
+        });
+    }
+
+    #[test]
+    fn twap_is_included_in_median() {
+        run_test(|| {
+            set_source(TwapPolicy::Median);
+            Oracle::is_authorized.mock_safe(|_| MockResult::Return(true));
+            assert_ok!(Oracle::feed_values(
+                RuntimeOrigin::signed(3),
+                vec![(key(), FixedU128::from(4))]
+            ));
+            assert_ok!(Oracle::feed_values(
+                RuntimeOrigin::signed(4),
+                vec![(key(), FixedU128::from(6))]
+            ));
+
+            complete_window();
+            assert_eq!(Oracle::get_price(key()).unwrap(), FixedU128::from(4));
+        });
+    }
+}
+
+mod price_history_tests {
+    use super::*;
+
+    fn key() -> OracleKey {
+        OracleKey::ExchangeRate(Token(DOT))
+    }
+
+    #[test]
+    fn aggregates_are_recorded_in_bounded_history() {
+        run_test(|| {
+            Oracle::is_authorized.mock_safe(|_| MockResult::Return(true));
+            for i in 1..=12u32 {
+                System::set_block_number(i.into());
+                assert_ok!(Oracle::feed_values(
+                    RuntimeOrigin::signed(3),
+                    vec![(key(), FixedU128::from(i as u128))]
+                ));
+                mine_block();
+            }
+
+            // history is bounded to the 10 most recent values
+            let history = Oracle::get_price_history(key(), 20);
+            assert_eq!(history.len(), 10);
+            assert_eq!(history[0].value, FixedU128::from(12));
+            assert_eq!(history[0].block_number, 12);
+            assert_eq!(history[0].oracle_count, 1);
+            assert_eq!(history[9].value, FixedU128::from(3));
+
+            assert_eq!(Oracle::get_price_history(key(), 2).len(), 2);
+            assert_eq!(Oracle::get_price_at(key(), 5).unwrap().value, FixedU128::from(5));
+            assert_eq!(Oracle::get_price_at(key(), 100).unwrap().value, FixedU128::from(12));
+            // no longer in the history
+            assert_eq!(Oracle::get_price_at(key(), 2), None);
+        });
+    }
+
+    #[test]
+    fn get_stale_oracles_succeeds() {
+        run_test(|| {
+            Oracle::get_max_delay.mock_safe(|| MockResult::Return(3600));
+            Oracle::get_current_time.mock_safe(|| MockResult::Return(10_000));
+            Oracle::insert_oracle(1, BoundedVec::default());
+            Oracle::insert_oracle(2, BoundedVec::default());
+            Oracle::_feed_values(1, vec![(key(), FixedU128::from(1))]);
+            Oracle::get_current_time.mock_safe(|| MockResult::Return(14_000));
+            Oracle::_feed_values(2, vec![(key(), FixedU128::from(1))]);
+
+            let stale_oracles = |timestamp| {
+                let mut stale = Oracle::get_stale_oracles(key(), timestamp);
+                stale.sort();
+                stale
+            };
+            // oracle 0 is authorized at genesis
+            assert_eq!(stale_oracles(9_000), vec![0, 1, 2]);
+            assert_eq!(stale_oracles(12_000), vec![0, 2]);
+            assert_eq!(stale_oracles(14_000), vec![0, 1]);
+            assert_eq!(stale_oracles(18_000), vec![0, 1, 2]);
+        });
+    }
+}
+
+mod bonded_oracle_tests {
+    use super::*;
+    use crate::types::DefaultBondedOracleParameters;
+    use currency::Amount;
+
+    const TREASURY: AccountId = 1000;
+    const ROUND_LENGTH: u64 = 60_000;
+
+    fn key() -> OracleKey {
+        OracleKey::ExchangeRate(Token(DOT))
+    }
+
+    fn parameters() -> DefaultBondedOracleParameters<Test> {
+        DefaultBondedOracleParameters::<Test> {
+            min_bond: 1000,
+            max_deviation: FixedU128::checked_from_rational(1, 10).unwrap(),
+            max_outliers: 2,
+            slash_amount: 100,
+            max_missed_rounds: 3,
+            round_length: ROUND_LENGTH,
+        }
+    }
+
+    /// Mines a block at the start of the given round, settling the previous round
+    fn mine_round(round: u64) {
+        Oracle::get_current_time.mock_safe(move || MockResult::Return(round * ROUND_LENGTH));
+        mine_block();
+    }
+
+    fn native(amount: Balance) -> Amount<Test> {
+        Amount::new(amount, DEFAULT_NATIVE_CURRENCY)
+    }
+
+    fn free_balance(account_id: AccountId) -> Balance {
+        currency::get_free_balance::<Test>(DEFAULT_NATIVE_CURRENCY, &account_id).amount()
+    }
+
+    fn reserved_balance(account_id: AccountId) -> Balance {
+        currency::get_reserved_balance::<Test>(DEFAULT_NATIVE_CURRENCY, &account_id).amount()
+    }
+
+    fn setup_bonded_oracle(oracle_id: AccountId) {
+        assert_ok!(Oracle::set_bonded_oracle_parameters(
+            RuntimeOrigin::root(),
+            Some(parameters())
+        ));
+        assert_ok!(native(1500).mint_to(&oracle_id));
+        assert_ok!(Oracle::bond_oracle(
+            RuntimeOrigin::signed(oracle_id),
+            BoundedVec::default(),
+            1000
+        ));
+    }
+
+    #[test]
+    fn bond_oracle_fails_without_parameters_or_sufficient_bond() {
+        run_test(|| {
+            assert_ok!(native(1500).mint_to(&1));
+            assert_err!(
+                Oracle::bond_oracle(RuntimeOrigin::signed(1), BoundedVec::default(), 1000),
+                TestError::BondedOraclesDisabled
+            );
+
+            assert_ok!(Oracle::set_bonded_oracle_parameters(
+                RuntimeOrigin::root(),
+                Some(parameters())
+            ));
+            assert_err!(
+                Oracle::bond_oracle(RuntimeOrigin::signed(1), BoundedVec::default(), 999),
+                TestError::InsufficientOracleBond
+            );
+            // oracle 0 is authorized at genesis
+            assert_err!(
+                Oracle::bond_oracle(RuntimeOrigin::signed(0), BoundedVec::default(), 1000),
+                TestError::OracleAlreadyAuthorized
+            );
+        });
+    }
+
+    #[test]
+    fn set_bonded_oracle_parameters_fails_with_invalid_parameters() {
+        run_test(|| {
+            assert_err!(
+                Oracle::set_bonded_oracle_parameters(
+                    RuntimeOrigin::root(),
+                    Some(DefaultBondedOracleParameters::<Test> {
+                        max_outliers: 0,
+                        ..parameters()
+                    })
+                ),
+                TestError::InvalidBondedOracleParameters
+            );
+            assert_err!(
+                Oracle::set_bonded_oracle_parameters(RuntimeOrigin::signed(1), Some(parameters())),
+                DispatchError::BadOrigin
+            );
+        });
+    }
+
+    #[test]
+    fn bond_and_unbond_oracle_succeeds() {
+        run_test(|| {
+            setup_bonded_oracle(1);
+            assert!(Oracle::is_authorized(&1));
+            assert_eq!(Oracle::oracle_bond(1), Some(1000));
+            assert_eq!(reserved_balance(1), 1000);
+            assert_emitted!(Event::OracleBonded {
+                oracle_id: 1,
+                name: BoundedVec::default(),
+                amount: 1000,
+            });
+
+            mine_round(0);
+            assert_ok!(Oracle::unbond_oracle(RuntimeOrigin::signed(1)));
+            assert!(!Oracle::is_authorized(&1));
+            assert_emitted!(Event::OracleUnbondingStarted {
+                oracle_id: 1,
+                unlock_time: 2 * ROUND_LENGTH
+            });
+            assert_err!(
+                Oracle::unbond_oracle(RuntimeOrigin::signed(1)),
+                TestError::OracleUnbonding
+            );
+            assert_err!(
+                Oracle::bond_oracle(RuntimeOrigin::signed(1), BoundedVec::default(), 1000),
+                TestError::OracleUnbonding
+            );
+
+            // the bond stays locked until the round has been settled
+            mine_round(1);
+            assert_eq!(Oracle::oracle_bond(1), Some(1000));
+            assert_eq!(reserved_balance(1), 1000);
+
+            mine_round(2);
+            assert_eq!(Oracle::oracle_bond(1), None);
+            assert_eq!(Oracle::unbonding_oracle(1), None);
+            assert_eq!(reserved_balance(1), 0);
+            assert_eq!(free_balance(1), 1500);
+            assert_emitted!(Event::OracleUnbonded {
+                oracle_id: 1,
+                amount: 1000
+            });
+
+            assert_err!(
+                Oracle::unbond_oracle(RuntimeOrigin::signed(1)),
+                TestError::NotBondedOracle
+            );
+        });
+    }
+
+    #[test]
+    fn removing_bonded_oracle_releases_bond() {
+        run_test(|| {
+            setup_bonded_oracle(1);
+            assert_ok!(Oracle::remove_authorized_oracle(RuntimeOrigin::root(), 1));
+            assert_eq!(Oracle::oracle_bond(1), None);
+            assert_eq!(reserved_balance(1), 0);
+        });
+    }
+
+    #[test]
+    fn repeated_outliers_are_slashed() {
+        run_test(|| {
+            setup_bonded_oracle(1);
+            Oracle::insert_oracle(2, BoundedVec::default());
+            Oracle::insert_oracle(3, BoundedVec::default());
+
+            let mut round = 0;
+            let mut feed_round = |outlier: u128| {
+                assert_ok!(Oracle::feed_values(
+                    RuntimeOrigin::signed(1),
+                    vec![(key(), FixedU128::from(outlier))]
+                ));
+                assert_ok!(Oracle::feed_values(
+                    RuntimeOrigin::signed(2),
+                    vec![(key(), FixedU128::from(100))]
+                ));
+                assert_ok!(Oracle::feed_values(
+                    RuntimeOrigin::signed(3),
+                    vec![(key(), FixedU128::from(100))]
+                ));
+                mine_round(round);
+                round += 1;
+                mine_round(round);
+            };
+
+            feed_round(200);
+            assert_eq!(Oracle::outlier_submissions(key(), 1), 1);
+            // a submission close to the median resets the count
+            feed_round(105);
+            assert_eq!(Oracle::outlier_submissions(key(), 1), 0);
+
+            feed_round(200);
+            feed_round(200);
+            assert_emitted!(Event::OracleSlashed {
+                oracle_id: 1,
+                amount: 100
+            });
+            assert_eq!(Oracle::outlier_submissions(key(), 1), 0);
+            assert_eq!(Oracle::oracle_bond(1), Some(900));
+            assert_eq!(reserved_balance(1), 900);
+            assert_eq!(free_balance(TREASURY), 100);
+            // slashed oracles remain authorized
+            assert!(Oracle::is_authorized(&1));
+        });
+    }
+
+    #[test]
+    fn unbonding_oracles_are_slashed_for_outliers() {
+        run_test(|| {
+            setup_bonded_oracle(1);
+            assert_ok!(Oracle::set_bonded_oracle_parameters(
+                RuntimeOrigin::root(),
+                Some(DefaultBondedOracleParameters::<Test> {
+                    max_outliers: 1,
+                    ..parameters()
+                })
+            ));
+            Oracle::insert_oracle(2, BoundedVec::default());
+            Oracle::insert_oracle(3, BoundedVec::default());
+
+            for (oracle_id, value) in [(1, 200), (2, 100), (3, 100)] {
+                assert_ok!(Oracle::feed_values(
+                    RuntimeOrigin::signed(oracle_id),
+                    vec![(key(), FixedU128::from(value))]
+                ));
+            }
+            mine_round(0);
+            // unbonding in the round of the outlier submission does not escape the slash
+            assert_ok!(Oracle::unbond_oracle(RuntimeOrigin::signed(1)));
+
+            mine_round(1);
+            assert_emitted!(Event::OracleSlashed {
+                oracle_id: 1,
+                amount: 100
+            });
+            assert_eq!(Oracle::oracle_bond(1), Some(900));
+
+            mine_round(2);
+            assert_emitted!(Event::OracleUnbonded {
+                oracle_id: 1,
+                amount: 900
+            });
+            assert_eq!(free_balance(1), 1400);
+            assert_eq!(free_balance(TREASURY), 100);
+            // the values of the removed oracle no longer count towards the aggregate
+            assert!(crate::RawValues::<Test>::get(key(), 1).is_none());
+        });
+    }
+
+    #[test]
+    fn inactive_oracles_are_removed() {
+        run_test(|| {
+            setup_bonded_oracle(1);
+            Oracle::insert_oracle(2, BoundedVec::default());
+
+            for round in 0..3 {
+                assert_not_emitted!(Event::InactiveOracleRemoved { oracle_id: 1 });
+                assert_ok!(Oracle::feed_values(
+                    RuntimeOrigin::signed(2),
+                    vec![(key(), FixedU128::from(100))]
+                ));
+                mine_round(round);
+                mine_round(round + 1);
+            }
+
+            assert_emitted!(Event::InactiveOracleRemoved { oracle_id: 1 });
+            assert!(!Oracle::is_authorized(&1));
+            assert_eq!(Oracle::oracle_bond(1), None);
+            assert_eq!(Oracle::missed_rounds(key(), 1), 0);
+            assert_eq!(free_balance(1), 1500);
+        });
+    }
+
+    #[test]
+    fn rounds_are_tracked_per_key_and_time_window() {
+        run_test(|| {
+            setup_bonded_oracle(1);
+            Oracle::insert_oracle(2, BoundedVec::default());
+            let fee_key = OracleKey::FeeEstimation;
+
+            assert_ok!(Oracle::feed_values(
+                RuntimeOrigin::signed(2),
+                vec![(key(), FixedU128::from(100)), (fee_key.clone(), FixedU128::from(3))]
+            ));
+            mine_round(0);
+            // a submission later in the same round is not missed, even though another oracle
+            // submitted first
+            assert_ok!(Oracle::feed_values(
+                RuntimeOrigin::signed(1),
+                vec![(key(), FixedU128::from(100))]
+            ));
+            mine_block();
+            assert_eq!(Oracle::missed_rounds(key(), 1), 0);
+
+            mine_round(1);
+            assert_eq!(Oracle::missed_rounds(key(), 1), 0);
+            assert_eq!(Oracle::missed_rounds(fee_key.clone(), 1), 1);
+
+            // rounds without submissions are missed as well
+            mine_round(2);
+            assert_eq!(Oracle::missed_rounds(key(), 1), 1);
+            assert_eq!(Oracle::missed_rounds(fee_key, 1), 2);
+            assert!(Oracle::is_authorized(&1));
+        });
+    }
+}
+
+mod quorum_tests {
+    use super::*;
+
+    fn key() -> OracleKey {
+        OracleKey::ExchangeRate(Token(DOT))
+    }
+
+    #[test]
+    fn aggregate_requires_minimum_quorum() {
+        run_test(|| {
+            Oracle::is_authorized.mock_safe(|_| MockResult::Return(true));
+            assert_ok!(Oracle::set_minimum_quorum(RuntimeOrigin::root(), key(), Some(2)));
+
+            assert_ok!(Oracle::feed_values(
+                RuntimeOrigin::signed(1),
+                vec![(key(), FixedU128::from(100))]
+            ));
+            mine_block();
+            assert_err!(Oracle::get_price(key()), TestError::MissingExchangeRate);
+
+            assert_ok!(Oracle::feed_values(
+                RuntimeOrigin::signed(2),
+                vec![(key(), FixedU128::from(102))]
+            ));
+            mine_block();
+            assert_ok!(Oracle::get_price(key()), FixedU128::from(101));
+
+            // lowering the quorum takes effect immediately
+            assert_ok!(Oracle::set_minimum_quorum(RuntimeOrigin::root(), key(), None));
+            assert_emitted!(Event::SetMinimumQuorum {
+                key: key(),
+                quorum: None
+            });
+            assert_eq!(Oracle::minimum_quorum(key()), None);
+        });
+    }
+
+    #[test]
+    fn quorum_is_lost_when_submissions_expire() {
+        run_test(|| {
+            let now = 1585776145;
+            Oracle::get_current_time.mock_safe(move || MockResult::Return(now));
+            Oracle::get_max_delay.mock_safe(|| MockResult::Return(3600));
+            Oracle::is_authorized.mock_safe(|_| MockResult::Return(true));
+            assert_ok!(Oracle::set_minimum_quorum(RuntimeOrigin::root(), key(), Some(2)));
+
+            assert_ok!(Oracle::feed_values(
+                RuntimeOrigin::signed(1),
+                vec![(key(), FixedU128::from(100))]
+            ));
+            Oracle::get_current_time.mock_safe(move || MockResult::Return(now + 1800));
+            assert_ok!(Oracle::feed_values(
+                RuntimeOrigin::signed(2),
+                vec![(key(), FixedU128::from(100))]
+            ));
+            mine_block();
+            assert_ok!(Oracle::get_price(key()), FixedU128::from(100));
+
+            // the first submission expired, leaving a single fresh one
+            Oracle::get_current_time.mock_safe(move || MockResult::Return(now + 3601));
+            mine_block();
+            assert_err!(Oracle::get_price(key()), TestError::MissingExchangeRate);
+        });
+    }
+
+    #[test]
+    fn set_minimum_quorum_fails_with_zero_quorum() {
+        run_test(|| {
+            assert_err!(
+                Oracle::set_minimum_quorum(RuntimeOrigin::root(), key(), Some(0)),
+                TestError::InvalidMinimumQuorum
+            );
+            assert_err!(
+                Oracle::set_minimum_quorum(RuntimeOrigin::signed(1), key(), Some(2)),
+                DispatchError::BadOrigin
+            );
+        });
+    }
+
+    #[test]
+    fn key_max_delay_overrides_global_max_delay() {
+        run_test(|| {
+            let now = 1585776145;
+            Oracle::get_current_time.mock_safe(move || MockResult::Return(now));
+            Oracle::get_max_delay.mock_safe(|| MockResult::Return(3600));
+            Oracle::insert_oracle(1, BoundedVec::default());
+
+            let fee_key = OracleKey::FeeEstimation;
+            assert_ok!(Oracle::set_key_max_delay(
+                RuntimeOrigin::root(),
+                fee_key.clone(),
+                Some(7200)
+            ));
+            assert_ok!(Oracle::set_key_max_delay(RuntimeOrigin::root(), key(), Some(600)));
+            assert_ok!(Oracle::feed_values(
+                RuntimeOrigin::signed(1),
+                vec![(key(), FixedU128::from(100)), (fee_key.clone(), FixedU128::from(3))]
+            ));
+            mine_block();
+
+            Oracle::get_current_time.mock_safe(move || MockResult::Return(now + 601));
+            mine_block();
+            assert_err!(Oracle::get_price(key()), TestError::MissingExchangeRate);
+            assert_ok!(Oracle::get_price(fee_key.clone()), FixedU128::from(3));
+            // oracle 0 is authorized at genesis
+            let mut stale = Oracle::get_stale_oracles(key(), now + 601);
+            stale.sort();
+            assert_eq!(stale, vec![0, 1]);
+            assert_eq!(Oracle::get_stale_oracles(fee_key.clone(), now + 601), vec![0]);
+
+            Oracle::get_current_time.mock_safe(move || MockResult::Return(now + 7201));
+            mine_block();
+            assert_err!(Oracle::get_price(fee_key), TestError::MissingExchangeRate);
+        });
+    }
+}
+
+mod commit_reveal_tests {
+    use super::*;
+    use crate::types::CommitRevealPeriod;
+    use sp_core::H256;
+
+    fn key() -> OracleKey {
+        OracleKey::ExchangeRate(Token(DOT))
+    }
+
+    fn enable_commit_reveal() {
+        assert_ok!(Oracle::set_commit_reveal_period(
+            RuntimeOrigin::root(),
+            Some(CommitRevealPeriod {
+                commit_window: 5,
+                reveal_window: 5,
+            })
+        ));
+    }
+
+    fn set_block(height: u64) {
+        System::set_block_number(height);
+        Oracle::begin_block(height);
+    }
+
+    fn commit(oracle_id: AccountId, value: u128, salt: H256) -> Vec<(OracleKey, FixedU128)> {
+        let values = vec![(key(), FixedU128::from(value))];
+        assert_ok!(Oracle::commit_values(
+            RuntimeOrigin::signed(oracle_id),
+            Oracle::commitment_hash(&oracle_id, &values, &salt)
+        ));
+        values
+    }
+
+    #[test]
+    fn revealed_values_enter_the_median() {
+        run_test(|| {
+            Oracle::is_authorized.mock_safe(|_| MockResult::Return(true));
+            enable_commit_reveal();
+            assert_err!(
+                Oracle::feed_values(RuntimeOrigin::signed(1), vec![(key(), FixedU128::from(100))]),
+                TestError::CommitRevealRequired
+            );
+
+            set_block(1);
+            let salt = H256::repeat_byte(1);
+            let values_1 = commit(1, 100, salt);
+            let values_2 = commit(2, 102, salt);
+            assert_err!(
+                Oracle::reveal_values(RuntimeOrigin::signed(1), values_1.clone(), salt),
+                TestError::NotRevealPhase
+            );
+
+            set_block(5);
+            assert_err!(
+                Oracle::commit_values(RuntimeOrigin::signed(3), H256::zero()),
+                TestError::NotCommitPhase
+            );
+            assert_ok!(Oracle::reveal_values(RuntimeOrigin::signed(1), values_1, salt));
+            assert_ok!(Oracle::reveal_values(RuntimeOrigin::signed(2), values_2, salt));
+            set_block(6);
+            assert_ok!(Oracle::get_price(key()), FixedU128::from(101));
+        });
+    }
+
+    #[test]
+    fn reveal_values_fails_with_mismatching_commitment() {
+        run_test(|| {
+            Oracle::is_authorized.mock_safe(|_| MockResult::Return(true));
+            enable_commit_reveal();
+
+            set_block(1);
+            let salt = H256::repeat_byte(1);
+            let values = commit(1, 100, salt);
+            // copying another oracle's commitment does not allow revealing its values
+            assert_ok!(Oracle::commit_values(
+                RuntimeOrigin::signed(2),
+                Oracle::commitment(1).unwrap().1
+            ));
+
+            set_block(5);
+            assert_err!(
+                Oracle::reveal_values(RuntimeOrigin::signed(1), vec![(key(), FixedU128::from(200))], salt),
+                TestError::CommitmentMismatch
+            );
+            assert_err!(
+                Oracle::reveal_values(RuntimeOrigin::signed(1), values.clone(), H256::zero()),
+                TestError::CommitmentMismatch
+            );
+            assert_err!(
+                Oracle::reveal_values(RuntimeOrigin::signed(2), values.clone(), salt),
+                TestError::CommitmentMismatch
+            );
+            assert_err!(
+                Oracle::reveal_values(RuntimeOrigin::signed(3), values, salt),
+                TestError::MissingCommitment
+            );
+        });
+    }
+
+    #[test]
+    fn unrevealed_commitments_are_tracked() {
+        run_test(|| {
+            Oracle::is_authorized.mock_safe(|_| MockResult::Return(true));
+            enable_commit_reveal();
+
+            set_block(1);
+            let salt = H256::repeat_byte(1);
+            let values = commit(1, 100, salt);
+            commit(2, 100, salt);
+
+            set_block(5);
+            assert_ok!(Oracle::reveal_values(RuntimeOrigin::signed(1), values.clone(), salt));
+
+            // the next round starts
+            set_block(10);
+            assert_emitted!(Event::CommitmentNotRevealed { oracle_id: 2, round: 0 });
+            assert_eq!(Oracle::unrevealed_commitments(1), 0);
+            assert_eq!(Oracle::unrevealed_commitments(2), 1);
+            assert_eq!(Oracle::commitment(2), None);
+
+            // commitments cannot be revealed in a later round
+            commit(1, 100, salt);
+            set_block(25);
+            assert_err!(
+                Oracle::reveal_values(RuntimeOrigin::signed(1), values, salt),
+                TestError::MissingCommitment
+            );
+            set_block(30);
+            assert_eq!(Oracle::unrevealed_commitments(1), 1);
+        });
+    }
+
+    #[test]
+    fn set_commit_reveal_period_fails_with_empty_window() {
+        run_test(|| {
+            assert_err!(
+                Oracle::set_commit_reveal_period(
+                    RuntimeOrigin::root(),
+                    Some(CommitRevealPeriod {
+                        commit_window: 5,
+                        reveal_window: 0,
+                    })
+                ),
+                TestError::InvalidCommitRevealPeriod
+            );
+        });
+    }
+}
+
+mod pricing_path_tests {
+    use super::*;
+    use crate::types::PricingPath;
+    use primitives::{CurrencyId, LpToken};
+
+    #[test]
+    fn cross_rate_pricing_succeeds() {
+        run_test(|| {
+            let currency_id = CurrencyId::ForeignAsset(1);
+            assert_ok!(Oracle::_set_exchange_rate(Token(DOT), FixedU128::from(2)));
+            crate::Aggregate::<Test>::insert(OracleKey::CrossRate(currency_id, Token(DOT)), FixedU128::from(5));
+            assert_err!(Oracle::get_exchange_rate(currency_id), TestError::MissingExchangeRate);
+
+            assert_ok!(Oracle::set_pricing_path(
+                RuntimeOrigin::root(),
+                currency_id,
+                Some(PricingPath::CrossRate {
+                    intermediate: Token(DOT)
+                })
+            ));
+            assert_ok!(Oracle::get_exchange_rate(currency_id), FixedU128::from(10));
+            assert_ok!(Oracle::collateral_to_wrapped(100, currency_id), 10);
+            assert_ok!(Oracle::wrapped_to_collateral(10, currency_id), 100);
+        });
+    }
+
+    #[test]
+    fn stable_lp_pricing_succeeds() {
+        run_test(|| {
+            let currency_id = CurrencyId::StableLpToken(0);
+            assert_ok!(Oracle::_set_exchange_rate(Token(DOT), FixedU128::from(2)));
+            // one LP token is backed by two DOT
+            Oracle::get_stable_lp_underlying.mock_safe(|_, _, amount| MockResult::Return(Some(amount * 2)));
+
+            assert_ok!(Oracle::set_pricing_path(
+                RuntimeOrigin::root(),
+                currency_id,
+                Some(PricingPath::StableLp { base: Token(DOT) })
+            ));
+            assert_ok!(Oracle::get_exchange_rate(currency_id), FixedU128::from(1));
+        });
+    }
+
+    #[test]
+    fn fair_reserves_pricing_is_unaffected_by_skewed_reserves() {
+        run_test(|| {
+            let currency_id = CurrencyId::LpToken(LpToken::Token(DOT), LpToken::Token(KSM));
+            assert_ok!(Oracle::_set_exchange_rate(Token(DOT), FixedU128::from(2)));
+            assert_ok!(Oracle::_set_exchange_rate(Token(KSM), FixedU128::from(8)));
+            assert_ok!(Oracle::set_pricing_path(
+                RuntimeOrigin::root(),
+                currency_id,
+                Some(PricingPath::FairReserves)
+            ));
+
+            // 200 DOT and 800 KSM are worth 200 satoshi, i.e. 0.5 satoshi per LP token
+            Oracle::get_pair_reserves.mock_safe(|_, _| MockResult::Return(Some((200, 800, 400))));
+            assert_ok!(Oracle::get_exchange_rate(currency_id), FixedU128::from(2));
+
+            // a swap that skews the reserves at the same constant product
+            Oracle::get_pair_reserves.mock_safe(|_, _| MockResult::Return(Some((100, 1600, 400))));
+            assert_ok!(Oracle::get_exchange_rate(currency_id), FixedU128::from(2));
+
+            Oracle::get_pair_reserves.mock_safe(|_, _| MockResult::Return(None));
+            assert_err!(Oracle::get_exchange_rate(currency_id), TestError::MissingExchangeRate);
+        });
+    }
+
+    #[test]
+    fn cyclic_pricing_paths_fail() {
+        run_test(|| {
+            let currency_0 = CurrencyId::ForeignAsset(1);
+            let currency_1 = CurrencyId::ForeignAsset(2);
+            crate::Aggregate::<Test>::insert(OracleKey::CrossRate(currency_0, currency_1), FixedU128::from(1));
+            crate::Aggregate::<Test>::insert(OracleKey::CrossRate(currency_1, currency_0), FixedU128::from(1));
+            assert_ok!(Oracle::set_pricing_path(
+                RuntimeOrigin::root(),
+                currency_0,
+                Some(PricingPath::CrossRate {
+                    intermediate: currency_1
+                })
+            ));
+            assert_ok!(Oracle::set_pricing_path(
+                RuntimeOrigin::root(),
+                currency_1,
+                Some(PricingPath::CrossRate {
+                    intermediate: currency_0
+                })
+            ));
+            assert_err!(Oracle::get_exchange_rate(currency_0), TestError::MissingExchangeRate);
+        });
+    }
+
+    #[test]
+    fn set_pricing_path_fails_with_invalid_path() {
+        run_test(|| {
+            assert_err!(
+                Oracle::set_pricing_path(RuntimeOrigin::root(), Token(DOT), Some(PricingPath::FairReserves)),
+                TestError::InvalidPricingPath
+            );
+            assert_err!(
+                Oracle::set_pricing_path(
+                    RuntimeOrigin::root(),
+                    Token(DOT),
+                    Some(PricingPath::StableLp { base: Token(KSM) })
+                ),
+                TestError::InvalidPricingPath
+            );
+            assert_err!(
+                Oracle::set_pricing_path(
+                    RuntimeOrigin::root(),
+                    Token(DOT),
+                    Some(PricingPath::CrossRate {
+                        intermediate: Token(DOT)
+                    })
+                ),
+                TestError::InvalidPricingPath
+            );
+        });
+    }
+}
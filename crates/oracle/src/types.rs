@@ -1,7 +1,8 @@
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
 
-pub(crate) type BalanceOf<T> = <T as currency::Config>::Balance;
+pub type BalanceOf<T> = <T as currency::Config>::Balance;
 
 pub type UnsignedFixedPoint<T> = <T as currency::Config>::UnsignedFixedPoint;
 
@@ -11,3 +12,95 @@ pub enum Version {
     /// Initial version.
     V0,
 }
+
+/// How an aggregate update that exceeds the maximum deviation is handled.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub enum DeviationAction {
+    /// Move the aggregate by at most the maximum deviation.
+    Clamp,
+    /// Keep the current aggregate until a second round confirms the new value.
+    Confirm,
+}
+
+/// Bounds on the relative change of an aggregate between two updates.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct DeviationGuard<UnsignedFixedPoint> {
+    /// Maximum relative change of the aggregate per update, e.g. 0.1 for 10%.
+    pub max_change: UnsignedFixedPoint,
+    /// How updates exceeding `max_change` are handled.
+    pub action: DeviationAction,
+    /// Number of consecutive violations after which the price feed is paused.
+    pub max_violations: u32,
+}
+
+pub type DefaultDeviationGuard<T> = DeviationGuard<UnsignedFixedPoint<T>>;
+
+/// How the time-weighted average price of a DEX pair is combined with the oracle feeds.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub enum TwapPolicy {
+    /// Only use the TWAP if no oracle fed a value within the maximum delay.
+    Fallback,
+    /// Include the TWAP in the median as if it was another oracle.
+    Median,
+}
+
+/// Configuration of a DEX pair as a price source for an exchange rate.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct TwapSource<BlockNumber> {
+    /// Number of blocks over which the price is averaged.
+    pub window: BlockNumber,
+    /// How the TWAP is combined with the oracle feeds.
+    pub policy: TwapPolicy,
+}
+
+/// Parameters of the bonded oracle mode.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct BondedOracleParameters<Balance, UnsignedFixedPoint, Moment> {
+    /// Minimum stake (in the native currency) that an operator must lock.
+    pub min_bond: Balance,
+    /// Maximum relative deviation of a submission from the median of its round.
+    pub max_deviation: UnsignedFixedPoint,
+    /// Number of consecutive outlier submissions after which the operator is slashed.
+    pub max_outliers: u32,
+    /// Amount slashed to the treasury, capped at the operator's bond.
+    pub slash_amount: Balance,
+    /// Number of consecutive missed rounds after which the operator is removed.
+    pub max_missed_rounds: u32,
+    /// Duration (milliseconds) of a round, in which each operator submits a value per key.
+    pub round_length: Moment,
+}
+
+pub type DefaultBondedOracleParameters<T> =
+    BondedOracleParameters<BalanceOf<T>, UnsignedFixedPoint<T>, <T as pallet_timestamp::Config>::Moment>;
+
+/// Windows of the two-phase commit-reveal round.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct CommitRevealPeriod<BlockNumber> {
+    /// Number of blocks in which oracles commit to the hash of their values.
+    pub commit_window: BlockNumber,
+    /// Number of blocks in which the committed values are revealed.
+    pub reveal_window: BlockNumber,
+}
+
+/// Phase of the current commit-reveal round.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub enum CommitRevealPhase<BlockNumber> {
+    /// Oracles commit to their values in the given round.
+    Commit(BlockNumber),
+    /// Oracles reveal the values committed in the given round.
+    Reveal(BlockNumber),
+}
+
+/// How the exchange rate of a currency without its own feed is derived.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub enum PricingPath<CurrencyId> {
+    /// Multiplies the `CrossRate` of the currency in the intermediate currency (e.g. a USD
+    /// stablecoin) with the exchange rate of the intermediate currency.
+    CrossRate { intermediate: CurrencyId },
+    /// Prices the LP token of a dex-stable pool at the virtual price of the pool, in terms of
+    /// the given pooled currency.
+    StableLp { base: CurrencyId },
+    /// Prices the LP token of a dex-general pair from the fair reserves of the pair, i.e. the
+    /// reserves implied by the constant product and the exchange rates of both currencies.
+    FairReserves,
+}
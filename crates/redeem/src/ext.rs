@@ -139,6 +139,13 @@ pub(crate) mod vault_registry {
         <vault_registry::Pallet<T>>::ban_vault(vault_id)
     }
 
+    pub fn record_reputation_event<T: crate::Config>(
+        vault_id: &DefaultVaultId<T>,
+        event: vault_registry::ReputationEvent,
+    ) -> DispatchResult {
+        <vault_registry::Pallet<T>>::record_reputation_event(vault_id, event)
+    }
+
     pub fn ensure_not_banned<T: crate::Config>(vault_id: &DefaultVaultId<T>) -> DispatchResult {
         <vault_registry::Pallet<T>>::ensure_not_banned(vault_id)
     }
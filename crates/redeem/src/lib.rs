@@ -45,7 +45,7 @@ use sp_std::{convert::TryInto, vec::Vec};
 use types::DefaultVaultId;
 use vault_registry::{
     types::{CurrencyId, DefaultVaultCurrencyPair},
-    CurrencySource,
+    CurrencySource, ReputationEvent,
 };
 
 pub use pallet::*;
@@ -671,12 +671,17 @@ impl<T: Config> Pallet<T> {
                 punishment_fee_in_collateral
             };
 
-            ext::vault_registry::transfer_funds_saturated::<T>(
+            let slashed = ext::vault_registry::transfer_funds_saturated::<T>(
                 CurrencySource::Collateral(vault_id.clone()),
                 CurrencySource::FreeBalance(redeemer.clone()),
                 &amount_to_slash,
             )?;
 
+            ext::vault_registry::record_reputation_event::<T>(&vault_id, ReputationEvent::Cancellation)?;
+            if !slashed.is_zero() {
+                ext::vault_registry::record_reputation_event::<T>(&vault_id, ReputationEvent::Punishment)?;
+            }
+            // the ban is recorded in the reputation of the vault by the registry
             let _ = ext::vault_registry::ban_vault::<T>(&vault_id);
 
             amount_to_slash
@@ -662,6 +662,11 @@ fn test_cancel_redeem_succeeds() {
             slashed_amount: 1,
             status: RedeemRequestStatus::Retried
         });
+        // the ban is recorded by the registry, which is mocked here
+        let reputation = vault_registry::Pallet::<Test>::get_vault_reputation(&VAULT);
+        assert_eq!(reputation.cancellations, 1);
+        assert_eq!(reputation.punishments, 1);
+        assert_eq!(reputation.bans, 0);
     })
 }
 
@@ -73,6 +73,13 @@ pub(crate) mod vault_registry {
         <vault_registry::Pallet<T>>::is_vault_liquidated(vault_id)
     }
 
+    pub fn record_reputation_event<T: crate::Config>(
+        vault_id: &DefaultVaultId<T>,
+        event: vault_registry::ReputationEvent,
+    ) -> DispatchResult {
+        <vault_registry::Pallet<T>>::record_reputation_event(vault_id, event)
+    }
+
     pub fn try_increase_to_be_redeemed_tokens<T: crate::Config>(
         vault_id: &DefaultVaultId<T>,
         tokens: &Amount<T>,
@@ -42,7 +42,7 @@ use frame_system::{ensure_root, ensure_signed};
 use sp_core::H256;
 use sp_std::vec::Vec;
 use types::DefaultVaultId;
-use vault_registry::{types::CurrencyId, CurrencySource};
+use vault_registry::{types::CurrencyId, CurrencySource, ReputationEvent};
 
 pub use pallet::*;
 
@@ -585,6 +585,9 @@ impl<T: Config> Pallet<T> {
             CurrencySource::FreeBalance(new_vault_id.account_id.clone()),
             &griefing_collateral,
         )?;
+        if !griefing_collateral.is_zero() {
+            ext::vault_registry::record_reputation_event::<T>(&replace.old_vault, ReputationEvent::Punishment)?;
+        }
 
         // if the new_vault locked additional collateral especially for this replace,
         // release it if it does not cause them to be undercollateralized
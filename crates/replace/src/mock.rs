@@ -128,6 +128,8 @@ impl reward::Config<CapacityRewardsInstance> for Test {
     type StakeId = CurrencyId;
     type CurrencyId = CurrencyId;
     type MaxRewardCurrencies = ConstU32<10>;
+    type CheckpointInterval = ();
+    type MaxCheckpoints = ();
 }
 
 type VaultRewardsInstance = reward::Instance2;
@@ -139,6 +141,8 @@ impl reward::Config<VaultRewardsInstance> for Test {
     type StakeId = VaultId<AccountId, CurrencyId>;
     type CurrencyId = CurrencyId;
     type MaxRewardCurrencies = ConstU32<10>;
+    type CheckpointInterval = ();
+    type MaxCheckpoints = ();
 }
 
 impl staking::Config for Test {
@@ -197,6 +201,10 @@ impl vault_registry::Config for Test {
 impl nomination::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
+    type Swap = ();
+    type AutoCompoundPeriod = ();
+    type MaxAutoCompounds = ConstU32<10>;
+    type VaultSlashing = VaultStaking;
 }
 
 parameter_types! {
@@ -225,11 +233,20 @@ impl pallet_timestamp::Config for Test {
     type WeightInfo = ();
 }
 
+parameter_types! {
+    pub const OracleTreasuryAccountId: AccountId = 1000;
+}
+
 impl oracle::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type OnExchangeRateChange = ();
+    type DexPriceCumulative = ();
+    type DexStableLpPrice = ();
+    type DexPairReserves = ();
+    type TreasuryAccountId = OracleTreasuryAccountId;
     type WeightInfo = ();
     type MaxNameLength = ConstU32<255>;
+    type MaxPriceHistoryLength = ConstU32<10>;
 }
 
 parameter_types! {
@@ -24,6 +24,15 @@ sp_api::decl_runtime_apis! {
         /// Calculate the number of vault rewards accrued
         fn compute_vault_reward(vault_id: VaultId, currency_id: CurrencyId) -> Result<BalanceWrapper<Balance>, DispatchError>;
 
+        /// Calculate the number of escrow rewards earned between two block heights
+        fn compute_escrow_reward_between(account_id: AccountId, currency_id: CurrencyId, from: BlockNumber, to: BlockNumber) -> Result<BalanceWrapper<Balance>, DispatchError>;
+
+        /// Calculate the number of farming rewards earned between two block heights
+        fn compute_farming_reward_between(account_id: AccountId, pool_currency_id: CurrencyId, reward_currency_id: CurrencyId, from: BlockNumber, to: BlockNumber) -> Result<BalanceWrapper<Balance>, DispatchError>;
+
+        /// Calculate the number of vault rewards earned between two block heights
+        fn compute_vault_reward_between(vault_id: VaultId, currency_id: CurrencyId, from: BlockNumber, to: BlockNumber) -> Result<BalanceWrapper<Balance>, DispatchError>;
+
         /// Estimate staking reward rate for a one year period
         fn estimate_escrow_reward_rate(account_id: AccountId, amount: Option<Balance>, lock_time: Option<BlockNumber>) -> Result<UnsignedFixedPoint, DispatchError>;
 
@@ -53,6 +53,37 @@ where
         at: Option<BlockHash>,
     ) -> RpcResult<BalanceWrapper<Balance>>;
 
+    #[method(name = "reward_computeEscrowRewardBetween")]
+    fn compute_escrow_reward_between(
+        &self,
+        account_id: AccountId,
+        currency_id: CurrencyId,
+        from: BlockNumber,
+        to: BlockNumber,
+        at: Option<BlockHash>,
+    ) -> RpcResult<BalanceWrapper<Balance>>;
+
+    #[method(name = "reward_computeFarmingRewardBetween")]
+    fn compute_farming_reward_between(
+        &self,
+        account_id: AccountId,
+        pool_currency_id: CurrencyId,
+        reward_currency_id: CurrencyId,
+        from: BlockNumber,
+        to: BlockNumber,
+        at: Option<BlockHash>,
+    ) -> RpcResult<BalanceWrapper<Balance>>;
+
+    #[method(name = "reward_computeVaultRewardBetween")]
+    fn compute_vault_reward_between(
+        &self,
+        vault_id: VaultId,
+        currency_id: CurrencyId,
+        from: BlockNumber,
+        to: BlockNumber,
+        at: Option<BlockHash>,
+    ) -> RpcResult<BalanceWrapper<Balance>>;
+
     #[method(name = "reward_estimateEscrowRewardRate")]
     fn estimate_escrow_reward_rate(
         &self,
@@ -166,6 +197,58 @@ where
         )
     }
 
+    fn compute_escrow_reward_between(
+        &self,
+        account_id: AccountId,
+        currency_id: CurrencyId,
+        from: BlockNumber,
+        to: BlockNumber,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<BalanceWrapper<Balance>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        handle_response(
+            api.compute_escrow_reward_between(at, account_id, currency_id, from, to),
+            "Unable to compute the historical reward".into(),
+        )
+    }
+
+    fn compute_farming_reward_between(
+        &self,
+        account_id: AccountId,
+        pool_currency_id: CurrencyId,
+        reward_currency_id: CurrencyId,
+        from: BlockNumber,
+        to: BlockNumber,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<BalanceWrapper<Balance>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        handle_response(
+            api.compute_farming_reward_between(at, account_id, pool_currency_id, reward_currency_id, from, to),
+            "Unable to compute the historical reward".into(),
+        )
+    }
+
+    fn compute_vault_reward_between(
+        &self,
+        vault_id: VaultId,
+        currency_id: CurrencyId,
+        from: BlockNumber,
+        to: BlockNumber,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<BalanceWrapper<Balance>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        handle_response(
+            api.compute_vault_reward_between(at, vault_id, currency_id, from, to),
+            "Unable to compute the historical reward".into(),
+        )
+    }
+
     fn estimate_escrow_reward_rate(
         &self,
         account_id: AccountId,
@@ -24,7 +24,7 @@ use sp_runtime::{
     traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, MaybeSerializeDeserialize, Saturating, Zero},
     ArithmeticError,
 };
-use sp_std::{cmp::PartialOrd, convert::TryInto, fmt::Debug};
+use sp_std::{cmp::PartialOrd, convert::TryInto, fmt::Debug, vec::Vec};
 
 pub(crate) type SignedFixedPoint<T, I = ()> = <T as Config<I>>::SignedFixedPoint;
 
@@ -63,6 +63,14 @@ pub mod pallet {
         /// The maximum number of reward currencies.
         #[pallet::constant]
         type MaxRewardCurrencies: Get<u32>;
+
+        /// The number of blocks between reward checkpoints, zero disables checkpointing.
+        #[pallet::constant]
+        type CheckpointInterval: Get<BlockNumberFor<Self>>;
+
+        /// The number of past checkpoint intervals kept in storage, older checkpoints are pruned.
+        #[pallet::constant]
+        type MaxCheckpoints: Get<u32>;
     }
 
     // The pallet's events
@@ -101,6 +109,12 @@ pub mod pallet {
         ZeroTotalStake,
         /// Maximum rewards currencies reached.
         MaxRewardCurrencies,
+        /// Reward checkpoints are not enabled for this pallet.
+        CheckpointingDisabled,
+        /// The block range is invalid or ends in the future.
+        InvalidBlockRange,
+        /// The block range starts before the oldest retained checkpoint.
+        CheckpointPruned,
     }
 
     #[pallet::hooks]
@@ -154,6 +168,59 @@ pub mod pallet {
     pub type RewardCurrencies<T: Config<I>, I: 'static = ()> =
         StorageMap<_, Blake2_128Concat, T::PoolId, BoundedBTreeSet<T::CurrencyId, T::MaxRewardCurrencies>, ValueQuery>;
 
+    /// The total rewards withdrawn by a participant, used to compute historical rewards.
+    #[pallet::storage]
+    pub type WithdrawnRewards<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::CurrencyId,
+        Blake2_128Concat,
+        (T::PoolId, T::StakeId),
+        SignedFixedPoint<T, I>,
+        ValueQuery,
+    >;
+
+    /// The reward per token of a pool at the start of each checkpoint interval. Only
+    /// intervals in which rewards were distributed are stored, and only the last
+    /// `MaxCheckpoints` intervals are retained.
+    #[pallet::storage]
+    pub type RewardPerTokenCheckpoints<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (T::CurrencyId, T::PoolId),
+        Twox64Concat,
+        BlockNumberFor<T>,
+        SignedFixedPoint<T, I>,
+        OptionQuery,
+    >;
+
+    /// The stake of a participant at the start of each checkpoint interval. Only
+    /// intervals in which the stake changed are stored, and only the last
+    /// `MaxCheckpoints` intervals are retained.
+    #[pallet::storage]
+    pub type StakeCheckpoints<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (T::PoolId, T::StakeId),
+        Twox64Concat,
+        BlockNumberFor<T>,
+        SignedFixedPoint<T, I>,
+        OptionQuery,
+    >;
+
+    /// The difference between the withdrawn rewards and the reward tally of a participant,
+    /// stored alongside each stake checkpoint.
+    #[pallet::storage]
+    pub type RewardOffsetCheckpoints<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (T::CurrencyId, T::PoolId, T::StakeId),
+        Twox64Concat,
+        BlockNumberFor<T>,
+        SignedFixedPoint<T, I>,
+        ValueQuery,
+    >;
+
     #[pallet::pallet]
     pub struct Pallet<T, I = ()>(_);
 
@@ -224,6 +291,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         stake_id: &T::StakeId,
         amount: SignedFixedPoint<T, I>,
     ) -> Result<(), DispatchError> {
+        Self::checkpoint_stake(pool_id, stake_id);
         checked_add_mut!(Stake<T, I>, (pool_id, stake_id), &amount);
         checked_add_mut!(TotalStake<T, I>, pool_id, &amount);
 
@@ -259,6 +327,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         let total_stake = Self::total_stake(pool_id);
         ensure!(!total_stake.is_zero(), Error::<T, I>::ZeroTotalStake);
 
+        Self::checkpoint_reward_per_token(pool_id, currency_id);
+
         // track currency for future deposits / withdrawals
         RewardCurrencies::<T, I>::try_mutate(pool_id, |reward_currencies| {
             reward_currencies
@@ -305,6 +375,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
             return Err(Error::<T, I>::InsufficientFunds.into());
         }
 
+        Self::checkpoint_stake(pool_id, stake_id);
         checked_sub_mut!(Stake<T, I>, (pool_id, stake_id), &amount);
         checked_sub_mut!(TotalStake<T, I>, pool_id, &amount);
 
@@ -338,6 +409,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         let reward_as_fixed =
             SignedFixedPoint::<T, I>::checked_from_integer(reward).ok_or(Error::<T, I>::TryIntoIntError)?;
         checked_sub_mut!(TotalRewards<T, I>, currency_id, &reward_as_fixed);
+        checked_add_mut!(WithdrawnRewards<T, I>, currency_id, (pool_id, stake_id), &reward_as_fixed);
 
         let stake = Self::stake(pool_id, stake_id);
         let reward_per_token = Self::reward_per_token(currency_id, pool_id);
@@ -355,6 +427,153 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         });
         Ok(reward)
     }
+
+    /// Get the start of the checkpoint interval that contains `height`.
+    fn checkpoint_at(height: BlockNumberFor<T>) -> Option<BlockNumberFor<T>> {
+        let interval = T::CheckpointInterval::get();
+        if interval.is_zero() {
+            None
+        } else {
+            Some(height.saturating_sub(height % interval))
+        }
+    }
+
+    /// Get the oldest checkpoint that is retained while `checkpoint` is the current one.
+    fn oldest_checkpoint(checkpoint: BlockNumberFor<T>) -> BlockNumberFor<T> {
+        let retained = T::CheckpointInterval::get().saturating_mul(T::MaxCheckpoints::get().into());
+        checkpoint.saturating_sub(retained)
+    }
+
+    /// Record the reward per token at the start of the current interval, must be called
+    /// before the first distribution in each interval. Expired checkpoints of the pool
+    /// are pruned at the same time, so at most `MaxCheckpoints + 1` are ever stored.
+    fn checkpoint_reward_per_token(pool_id: &T::PoolId, currency_id: T::CurrencyId) {
+        if let Some(checkpoint) = Self::checkpoint_at(frame_system::Pallet::<T>::block_number()) {
+            let key = (currency_id, pool_id);
+            if RewardPerTokenCheckpoints::<T, I>::contains_key(key, checkpoint) {
+                return;
+            }
+            let oldest = Self::oldest_checkpoint(checkpoint);
+            let expired: Vec<_> = RewardPerTokenCheckpoints::<T, I>::iter_key_prefix(key)
+                .filter(|height| *height < oldest)
+                .collect();
+            for height in expired {
+                RewardPerTokenCheckpoints::<T, I>::remove(key, height);
+            }
+            RewardPerTokenCheckpoints::<T, I>::insert(key, checkpoint, Self::reward_per_token(currency_id, pool_id));
+        }
+    }
+
+    /// Record the stake and reward offsets at the start of the current interval, must be
+    /// called before the first stake change in each interval. Expired checkpoints of the
+    /// participant are pruned at the same time.
+    fn checkpoint_stake(pool_id: &T::PoolId, stake_id: &T::StakeId) {
+        if let Some(checkpoint) = Self::checkpoint_at(frame_system::Pallet::<T>::block_number()) {
+            let key = (pool_id, stake_id);
+            if StakeCheckpoints::<T, I>::contains_key(key, checkpoint) {
+                return;
+            }
+            let oldest = Self::oldest_checkpoint(checkpoint);
+            let expired: Vec<_> = StakeCheckpoints::<T, I>::iter_key_prefix(key)
+                .filter(|height| *height < oldest)
+                .collect();
+            for height in expired {
+                StakeCheckpoints::<T, I>::remove(key, height);
+                for currency_id in RewardCurrencies::<T, I>::get(pool_id) {
+                    RewardOffsetCheckpoints::<T, I>::remove((currency_id, pool_id, stake_id), height);
+                }
+            }
+            StakeCheckpoints::<T, I>::insert(key, checkpoint, Self::stake(pool_id, stake_id));
+            for currency_id in RewardCurrencies::<T, I>::get(pool_id) {
+                RewardOffsetCheckpoints::<T, I>::insert(
+                    (currency_id, pool_id, stake_id),
+                    checkpoint,
+                    Self::reward_offset(pool_id, stake_id, currency_id),
+                );
+            }
+        }
+    }
+
+    /// The rewards earned by a participant are `stake * reward_per_token + offset`,
+    /// where the offset only changes when the stake changes.
+    fn reward_offset(pool_id: &T::PoolId, stake_id: &T::StakeId, currency_id: T::CurrencyId) -> SignedFixedPoint<T, I> {
+        WithdrawnRewards::<T, I>::get(currency_id, (pool_id, stake_id))
+            .saturating_sub(RewardTally::<T, I>::get(currency_id, (pool_id, stake_id)))
+    }
+
+    /// Compute the total rewards earned by a participant up to the start of the
+    /// checkpoint interval that contains `height`, which must not be older than the
+    /// retained checkpoints. Reads at most `MaxCheckpoints + 1` intervals.
+    fn compute_earned_reward_at(
+        pool_id: &T::PoolId,
+        stake_id: &T::StakeId,
+        currency_id: T::CurrencyId,
+        height: BlockNumberFor<T>,
+    ) -> Result<SignedFixedPoint<T, I>, DispatchError> {
+        let interval = T::CheckpointInterval::get();
+        let first = Self::checkpoint_at(height).ok_or(Error::<T, I>::CheckpointingDisabled)?;
+        let last = Self::checkpoint_at(frame_system::Pallet::<T>::block_number())
+            .ok_or(Error::<T, I>::CheckpointingDisabled)?;
+        ensure!(first >= Self::oldest_checkpoint(last), Error::<T, I>::CheckpointPruned);
+
+        // values only change in intervals with a checkpoint, so the first checkpoint
+        // at or after `height` holds the value at the start of its interval
+        let mut reward_per_token = None;
+        let mut stake = None;
+        let mut checkpoint = first;
+        while checkpoint <= last && (reward_per_token.is_none() || stake.is_none()) {
+            if reward_per_token.is_none() {
+                reward_per_token = RewardPerTokenCheckpoints::<T, I>::get((currency_id, pool_id), checkpoint);
+            }
+            if stake.is_none() {
+                stake = StakeCheckpoints::<T, I>::get((pool_id, stake_id), checkpoint).map(|stake| {
+                    let offset = RewardOffsetCheckpoints::<T, I>::get((currency_id, pool_id, stake_id), checkpoint);
+                    (stake, offset)
+                });
+            }
+            checkpoint = checkpoint.saturating_add(interval);
+        }
+
+        let reward_per_token = reward_per_token.unwrap_or_else(|| Self::reward_per_token(currency_id, pool_id));
+        let (stake, offset) = stake.unwrap_or_else(|| {
+            (
+                Self::stake(pool_id, stake_id),
+                Self::reward_offset(pool_id, stake_id, currency_id),
+            )
+        });
+        Ok(stake
+            .checked_mul(&reward_per_token)
+            .ok_or(ArithmeticError::Overflow)?
+            .checked_add(&offset)
+            .ok_or(ArithmeticError::Overflow)?)
+    }
+
+    /// Compute the rewards earned by a participant between two block heights. Heights
+    /// are rounded down to the start of their checkpoint interval, and `from` must be
+    /// within the last `MaxCheckpoints` intervals.
+    ///
+    /// # Arguments
+    /// * `from` - the block height to compute rewards from
+    /// * `to` - the block height to compute rewards until, at most the current block
+    pub fn compute_reward_between(
+        pool_id: &T::PoolId,
+        stake_id: &T::StakeId,
+        currency_id: T::CurrencyId,
+        from: BlockNumberFor<T>,
+        to: BlockNumberFor<T>,
+    ) -> Result<<SignedFixedPoint<T, I> as FixedPointNumber>::Inner, DispatchError> {
+        ensure!(
+            from <= to && to <= frame_system::Pallet::<T>::block_number(),
+            Error::<T, I>::InvalidBlockRange
+        );
+        let earned_before = Self::compute_earned_reward_at(pool_id, stake_id, currency_id, from)?;
+        let earned_after = Self::compute_earned_reward_at(pool_id, stake_id, currency_id, to)?;
+        Ok(earned_after
+            .saturating_sub(earned_before)
+            .max(Zero::zero())
+            .truncate_to_inner()
+            .ok_or(Error::<T, I>::TryIntoIntError)?)
+    }
 }
 
 pub trait RewardsApi<PoolId, StakeId, Balance>
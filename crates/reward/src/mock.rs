@@ -2,7 +2,7 @@ use crate as reward;
 use crate::{Config, Error};
 use frame_support::{
     parameter_types,
-    traits::{ConstU32, Everything},
+    traits::{ConstU32, ConstU64, Everything},
 };
 pub use primitives::{
     CurrencyId,
@@ -70,6 +70,8 @@ impl Config for Test {
     type StakeId = AccountId;
     type CurrencyId = CurrencyId;
     type MaxRewardCurrencies = ConstU32<10>;
+    type CheckpointInterval = ConstU64<10>;
+    type MaxCheckpoints = ConstU32<5>;
 }
 
 pub type TestError = Error<Test>;
@@ -174,3 +174,76 @@ fn should_distribute_with_different_rewards() {
         assert_approx_eq!(Reward::compute_reward(&(), &ALICE, ForeignAsset(0)).unwrap(), 1000, 1);
     })
 }
+
+#[test]
+fn should_compute_reward_between_checkpoints() {
+    run_test(|| {
+        assert_ok!(Reward::deposit_stake(&(), &ALICE, fixed!(50)));
+        assert_ok!(Reward::deposit_stake(&(), &BOB, fixed!(50)));
+        assert_ok!(Reward::distribute_reward(&(), Token(IBTC), fixed!(100)));
+
+        System::set_block_number(15);
+        assert_ok!(Reward::distribute_reward(&(), Token(IBTC), fixed!(100)));
+
+        System::set_block_number(25);
+        assert_ok!(Reward::deposit_stake(&(), &ALICE, fixed!(100)));
+        assert_ok!(Reward::distribute_reward(&(), Token(IBTC), fixed!(300)));
+
+        System::set_block_number(35);
+        assert_ok!(Reward::withdraw_reward(&(), &ALICE, Token(IBTC)), 325);
+
+        System::set_block_number(40);
+        assert_ok!(Reward::compute_reward_between(&(), &ALICE, Token(IBTC), 0, 10), 50);
+        assert_ok!(Reward::compute_reward_between(&(), &ALICE, Token(IBTC), 10, 20), 50);
+        // heights are rounded down to the start of the checkpoint interval
+        assert_ok!(Reward::compute_reward_between(&(), &ALICE, Token(IBTC), 12, 28), 50);
+        assert_ok!(Reward::compute_reward_between(&(), &ALICE, Token(IBTC), 20, 40), 225);
+        assert_ok!(Reward::compute_reward_between(&(), &ALICE, Token(IBTC), 0, 40), 325);
+        assert_ok!(Reward::compute_reward_between(&(), &BOB, Token(IBTC), 0, 40), 175);
+    })
+}
+
+#[test]
+fn should_prune_expired_checkpoints() {
+    run_test(|| {
+        assert_ok!(Reward::deposit_stake(&(), &ALICE, fixed!(50)));
+        assert_ok!(Reward::distribute_reward(&(), Token(IBTC), fixed!(100)));
+
+        System::set_block_number(60);
+        assert_ok!(Reward::deposit_stake(&(), &ALICE, fixed!(50)));
+        assert_ok!(Reward::distribute_reward(&(), Token(IBTC), fixed!(100)));
+
+        // only the last five intervals are retained
+        assert!(!crate::RewardPerTokenCheckpoints::<Test>::contains_key(
+            (Token(IBTC), ()),
+            0
+        ));
+        assert!(crate::RewardPerTokenCheckpoints::<Test>::contains_key(
+            (Token(IBTC), ()),
+            60
+        ));
+        assert!(!crate::StakeCheckpoints::<Test>::contains_key(((), ALICE), 0));
+        assert!(crate::StakeCheckpoints::<Test>::contains_key(((), ALICE), 60));
+
+        assert_err!(
+            Reward::compute_reward_between(&(), &ALICE, Token(IBTC), 0, 60),
+            TestError::CheckpointPruned
+        );
+        assert_ok!(Reward::compute_reward_between(&(), &ALICE, Token(IBTC), 10, 60), 0);
+    })
+}
+
+#[test]
+fn should_not_compute_reward_for_invalid_block_range() {
+    run_test(|| {
+        assert_err!(
+            Reward::compute_reward_between(&(), &ALICE, Token(IBTC), 0, 2),
+            TestError::InvalidBlockRange
+        );
+        System::set_block_number(20);
+        assert_err!(
+            Reward::compute_reward_between(&(), &ALICE, Token(IBTC), 10, 0),
+            TestError::InvalidBlockRange
+        );
+    })
+}
@@ -264,6 +264,19 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// The amount of stake that was removed from a participant by slashing. Slashes are
+    /// only included once they are applied to the participant's stake.
+    #[pallet::storage]
+    pub type SlashedStake<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::Nonce,
+        Blake2_128Concat,
+        (DefaultVaultId<T>, T::AccountId),
+        SignedFixedPoint<T>,
+        ValueQuery,
+    >;
+
     /// The nonce of the current staking pool, used in force refunds.
     /// This is a strictly increasing value.
     #[pallet::storage]
@@ -523,6 +536,28 @@ impl<T: Config> Pallet<T> {
         Ok(cmp::max(Zero::zero(), stake_sub_to_slash))
     }
 
+    /// Compute the total amount slashed from the stake of `nominator_id` in `vault_id`,
+    /// including any slash that has not been applied yet.
+    pub fn compute_total_slash_at_index(
+        nonce: T::Nonce,
+        vault_id: &DefaultVaultId<T>,
+        nominator_id: &T::AccountId,
+    ) -> Result<<SignedFixedPoint<T> as FixedPointNumber>::Inner, DispatchError> {
+        let stake = Self::stake_at_index(nonce, vault_id, nominator_id);
+        let slash_per_token = Self::slash_per_token_at_index(nonce, vault_id);
+        let slash_tally = Self::slash_tally_at_index(nonce, vault_id, nominator_id);
+        let pending_slash = cmp::max(
+            Zero::zero(),
+            Self::compute_amount_to_slash(stake, slash_per_token, slash_tally)?,
+        );
+
+        <SlashedStake<T>>::get(nonce, (vault_id, nominator_id))
+            .checked_add(&pending_slash)
+            .ok_or(ArithmeticError::Overflow)?
+            .truncate_to_inner()
+            .ok_or(Error::<T>::TryIntoIntError.into())
+    }
+
     fn increase_rewards(
         nonce: T::Nonce,
         currency_id: T::CurrencyId,
@@ -611,6 +646,9 @@ impl<T: Config> Pallet<T> {
         let to_slash = Self::compute_amount_to_slash(stake, slash_per_token, slash_tally)?;
 
         checked_sub_mut!(TotalStake<T>, nonce, vault_id, &to_slash);
+        if !to_slash.is_zero() {
+            checked_add_mut!(SlashedStake<T>, nonce, (vault_id, nominator_id), &to_slash);
+        }
 
         let stake = checked_sub_mut!(Stake<T>, nonce, (vault_id, nominator_id), &to_slash);
         <SlashTally<T>>::insert(
@@ -916,6 +954,27 @@ where
     }
 }
 
+pub trait SlashingApi<PoolId, StakeId, Balance> {
+    /// Get the total amount slashed from the stake of `stake_id` in `pool_id`.
+    fn get_total_slash(pool_id: &PoolId, stake_id: &StakeId) -> Result<Balance, DispatchError>;
+}
+
+impl<T, Balance> SlashingApi<(Option<T::Nonce>, DefaultVaultId<T>), T::AccountId, Balance> for Pallet<T>
+where
+    T: Config,
+    <T::SignedFixedPoint as FixedPointNumber>::Inner: TryInto<Balance>,
+{
+    fn get_total_slash(
+        (nonce, vault_id): &(Option<T::Nonce>, DefaultVaultId<T>),
+        nominator_id: &T::AccountId,
+    ) -> Result<Balance, DispatchError> {
+        let nonce = nonce.unwrap_or(Pallet::<T>::nonce(vault_id));
+        Pallet::<T>::compute_total_slash_at_index(nonce, vault_id, nominator_id)?
+            .try_into()
+            .map_err(|_| Error::<T>::TryIntoIntError.into())
+    }
+}
+
 pub mod migration {
     use super::*;
     use frame_support::transactional;
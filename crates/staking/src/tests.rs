@@ -181,6 +181,38 @@ fn should_stake_and_distribute_and_withdraw() {
     })
 }
 
+#[test]
+fn should_track_total_slash() {
+    run_test(|| {
+        let nonce = Staking::nonce(&VAULT);
+        assert_ok!(Staking::deposit_stake(&VAULT, &ALICE.account_id, fixed!(50)));
+        assert_ok!(Staking::deposit_stake(&VAULT, &BOB.account_id, fixed!(50)));
+        assert_ok!(Staking::slash_stake(&VAULT, fixed!(20)));
+        assert_ok!(
+            Staking::compute_total_slash_at_index(nonce, &VAULT, &ALICE.account_id),
+            10
+        );
+
+        // applying the slash does not change the total
+        assert_ok!(Staking::deposit_stake(&VAULT, &ALICE.account_id, fixed!(60)));
+        assert_ok!(Staking::compute_stake(&VAULT, &ALICE.account_id), 100);
+        assert_ok!(
+            Staking::compute_total_slash_at_index(nonce, &VAULT, &ALICE.account_id),
+            10
+        );
+
+        assert_ok!(Staking::slash_stake(&VAULT, fixed!(30)));
+        assert_ok!(
+            Staking::compute_total_slash_at_index(nonce, &VAULT, &ALICE.account_id),
+            30
+        );
+        assert_ok!(
+            Staking::compute_total_slash_at_index(nonce, &VAULT, &BOB.account_id),
+            20
+        );
+    })
+}
+
 #[test]
 fn should_stake_and_withdraw_rewards() {
     run_test(|| {
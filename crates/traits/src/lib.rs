@@ -2,6 +2,7 @@
 
 use frame_support::dispatch::DispatchError;
 use num_bigint::{BigUint, ToBigUint};
+use sp_core::U256;
 
 pub mod loans;
 pub use loans::*;
@@ -28,6 +29,124 @@ pub trait NominationApi<VaultId, Amount> {
     fn opt_in_to_nomination(vault_id: &VaultId);
 }
 
+pub trait SwapApi<AccountId, CurrencyId, Balance> {
+    /// Swap exactly `amount_in` of `currency_in` for at least `amount_out_min` of `currency_out`,
+    /// returning the amount received by `who`.
+    fn swap_exact_tokens_for_tokens(
+        who: &AccountId,
+        currency_in: CurrencyId,
+        amount_in: Balance,
+        currency_out: CurrencyId,
+        amount_out_min: Balance,
+    ) -> Result<Balance, DispatchError>;
+}
+
+impl<AccountId, CurrencyId, Balance> SwapApi<AccountId, CurrencyId, Balance> for () {
+    fn swap_exact_tokens_for_tokens(
+        _: &AccountId,
+        _: CurrencyId,
+        _: Balance,
+        _: CurrencyId,
+        _: Balance,
+    ) -> Result<Balance, DispatchError> {
+        Err(DispatchError::Other("Swaps are not supported"))
+    }
+}
+
+/// Precision of the prices accumulated by `PriceCumulativeApi`.
+pub const PRICE_CUMULATIVE_PRECISION: u128 = 1_000_000_000_000_000_000;
+
+pub trait PriceCumulativeApi<CurrencyId> {
+    /// Returns the time-weighted sum of the price of `base` denominated in `quote`, scaled by
+    /// `PRICE_CUMULATIVE_PRECISION` and accumulated per block up to the current block.
+    fn price_cumulative(base: CurrencyId, quote: CurrencyId) -> Option<U256>;
+}
+
+impl<CurrencyId> PriceCumulativeApi<CurrencyId> for () {
+    fn price_cumulative(_: CurrencyId, _: CurrencyId) -> Option<U256> {
+        None
+    }
+}
+
+pub trait PairReservesApi<CurrencyId> {
+    /// Returns the reserves of `asset_0` and `asset_1` in their DEX pair and the total supply
+    /// of the LP token of the pair.
+    fn pair_reserves(asset_0: CurrencyId, asset_1: CurrencyId) -> Option<(u128, u128, u128)>;
+}
+
+impl<CurrencyId> PairReservesApi<CurrencyId> for () {
+    fn pair_reserves(_: CurrencyId, _: CurrencyId) -> Option<(u128, u128, u128)> {
+        None
+    }
+}
+
+pub trait StableLpPriceApi<CurrencyId> {
+    /// Returns the amount of `currency_id` backing `amount` of the LP token of a stable pool
+    /// at the virtual price of the pool.
+    fn stable_lp_underlying(lp_currency_id: CurrencyId, currency_id: CurrencyId, amount: u128) -> Option<u128>;
+}
+
+impl<CurrencyId> StableLpPriceApi<CurrencyId> for () {
+    fn stable_lp_underlying(_: CurrencyId, _: CurrencyId, _: u128) -> Option<u128> {
+        None
+    }
+}
+
+pub trait PriceFeedStatusApi<CurrencyId> {
+    /// Fails if the price feed of `currency_id` is paused, in which case it must not be used
+    /// to liquidate positions.
+    fn ensure_price_feed_not_paused(currency_id: &CurrencyId) -> Result<(), DispatchError>;
+}
+
+impl<CurrencyId> PriceFeedStatusApi<CurrencyId> for () {
+    fn ensure_price_feed_not_paused(_: &CurrencyId) -> Result<(), DispatchError> {
+        Ok(())
+    }
+}
+
+pub trait StableLiquidityApi<AccountId, CurrencyId, Balance> {
+    /// Deposit `amount` of `currency_id` into the stable pool of `lp_currency_id`, returning the
+    /// amount of LP tokens minted to `who`.
+    fn add_liquidity_one_currency(
+        who: &AccountId,
+        lp_currency_id: CurrencyId,
+        currency_id: CurrencyId,
+        amount: Balance,
+        min_mint_amount: Balance,
+    ) -> Result<Balance, DispatchError>;
+
+    /// Burn `lp_amount` LP tokens of `who` for `currency_id`, returning the amount received.
+    fn remove_liquidity_one_currency(
+        who: &AccountId,
+        lp_currency_id: CurrencyId,
+        currency_id: CurrencyId,
+        lp_amount: Balance,
+        min_amount: Balance,
+    ) -> Result<Balance, DispatchError>;
+}
+
+impl<AccountId, CurrencyId, Balance> StableLiquidityApi<AccountId, CurrencyId, Balance> for () {
+    fn add_liquidity_one_currency(
+        _: &AccountId,
+        _: CurrencyId,
+        _: CurrencyId,
+        _: Balance,
+        _: Balance,
+    ) -> Result<Balance, DispatchError> {
+        Err(DispatchError::Other("Stable pools are not supported"))
+    }
+
+    fn remove_liquidity_one_currency(
+        _: &AccountId,
+        _: CurrencyId,
+        _: CurrencyId,
+        _: Balance,
+        _: Balance,
+    ) -> Result<Balance, DispatchError> {
+        Err(DispatchError::Other("Stable pools are not supported"))
+    }
+}
+
 pub trait OnExchangeRateChange<CurrencyId> {
     fn on_exchange_rate_change(currency_id: &CurrencyId);
 }
@@ -46,5 +46,12 @@ sp_api::decl_runtime_apis! {
         /// Get the amount of collateral required for the given vault to be at the
         /// current SecureCollateralThreshold with the current exchange rate
         fn get_required_collateral_for_vault(vault_id: VaultId) -> Result<BalanceWrapper<Balance>, DispatchError>;
+
+        /// Get the collateral of the liquidation vault that can be auctioned
+        fn get_auctionable_collateral(currency_id: CurrencyId) -> Result<BalanceWrapper<Balance>, DispatchError>;
+
+        /// Get the amount of wrapped tokens to pay for the given amount of collateral
+        /// in the running collateral auction
+        fn get_collateral_auction_cost(amount: BalanceWrapper<Balance>, currency_id: CurrencyId) -> Result<BalanceWrapper<Balance>, DispatchError>;
     }
 }
@@ -88,6 +88,21 @@ where
         vault_id: VaultId,
         at: Option<BlockHash>,
     ) -> RpcResult<BalanceWrapper<Balance>>;
+
+    #[method(name = "vaultRegistry_getAuctionableCollateral")]
+    fn get_auctionable_collateral(
+        &self,
+        currency_id: CurrencyId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<BalanceWrapper<Balance>>;
+
+    #[method(name = "vaultRegistry_getCollateralAuctionCost")]
+    fn get_collateral_auction_cost(
+        &self,
+        amount: BalanceWrapper<Balance>,
+        currency_id: CurrencyId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<BalanceWrapper<Balance>>;
 }
 
 fn internal_err<T: ToString>(message: T) -> JsonRpseeError {
@@ -274,4 +289,31 @@ where
             "Unable to get required collateral for vault".into(),
         )
     }
+
+    fn get_auctionable_collateral(
+        &self,
+        currency_id: CurrencyId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<BalanceWrapper<Balance>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        handle_response(
+            api.get_auctionable_collateral(at, currency_id),
+            "Unable to get auctionable collateral".into(),
+        )
+    }
+
+    fn get_collateral_auction_cost(
+        &self,
+        amount: BalanceWrapper<Balance>,
+        currency_id: CurrencyId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<BalanceWrapper<Balance>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        handle_response(
+            api.get_collateral_auction_cost(at, amount, currency_id),
+            "Unable to get collateral auction cost".into(),
+        )
+    }
 }
@@ -130,6 +130,29 @@ pub fn activate_lending_and_get_vault_id<T: crate::Config + loans::Config>() ->
     vault_id
 }
 
+fn liquidate_vault_and_start_auction<T: crate::Config + loans::Config>() -> DefaultVaultId<T> {
+    let vault_id = activate_lending_and_get_vault_id::<T>();
+    let underlying_id = Loans::<T>::underlying_id(vault_id.collateral_currency()).unwrap();
+
+    register_vault_with_collateral::<T>(vault_id.clone());
+    let amount = VaultRegistry::<T>::get_issuable_tokens_from_vault(&vault_id).unwrap();
+    VaultRegistry::<T>::try_increase_to_be_issued_tokens(&vault_id, &amount).unwrap();
+    VaultRegistry::<T>::issue_tokens(&vault_id, &amount).unwrap();
+    VaultRegistry::<T>::liquidate_vault(&vault_id).unwrap();
+
+    Oracle::<T>::_set_exchange_rate(underlying_id, UnsignedFixedPoint::<T>::one()).unwrap();
+    CollateralAuctionParameters::<T>::insert(
+        &vault_id.currencies,
+        AuctionParameters {
+            start_premium: UnsignedFixedPoint::<T>::checked_from_rational(10, 100).unwrap(),
+            decay: AuctionDecay::Exponential(UnsignedFixedPoint::<T>::checked_from_rational(1, 1000).unwrap()),
+            min_lot_size: 1u32.into(),
+            reserve_price: UnsignedFixedPoint::<T>::checked_from_rational(90, 100).unwrap(),
+        },
+    );
+    vault_id
+}
+
 #[benchmarks(where T: loans::Config)]
 pub mod benchmarks {
     use super::*;
@@ -273,6 +296,209 @@ pub mod benchmarks {
         recover_vault_id(RawOrigin::Signed(vault_id.account_id), vault_id.currencies.clone());
     }
 
+    #[benchmark]
+    fn set_reputation_parameters() {
+        let parameters = ReputationParameters {
+            execution_reward: UnsignedFixedPoint::<T>::checked_from_rational(1, 100).unwrap(),
+            cancellation_penalty: UnsignedFixedPoint::<T>::checked_from_rational(5, 100).unwrap(),
+            punishment_penalty: UnsignedFixedPoint::<T>::checked_from_rational(10, 100).unwrap(),
+            ban_penalty: UnsignedFixedPoint::<T>::checked_from_rational(20, 100).unwrap(),
+            decay_per_block: UnsignedFixedPoint::<T>::checked_from_rational(1, 10_000).unwrap(),
+            min_score: UnsignedFixedPoint::<T>::checked_from_rational(1, 2).unwrap(),
+            max_score: UnsignedFixedPoint::<T>::checked_from_rational(3, 2).unwrap(),
+        };
+
+        #[extrinsic_call]
+        set_reputation_parameters(RawOrigin::Root, Some(parameters));
+    }
+
+    #[benchmark]
+    fn refresh_vault_reputation() {
+        let vault_id = activate_lending_and_get_vault_id::<T>();
+        register_vault_with_collateral::<T>(vault_id.clone());
+        VaultReputationParameters::<T>::put(ReputationParameters {
+            execution_reward: UnsignedFixedPoint::<T>::checked_from_rational(1, 100).unwrap(),
+            decay_per_block: UnsignedFixedPoint::<T>::checked_from_rational(1, 10_000).unwrap(),
+            min_score: UnsignedFixedPoint::<T>::checked_from_rational(1, 2).unwrap(),
+            max_score: UnsignedFixedPoint::<T>::checked_from_rational(3, 2).unwrap(),
+            ..Default::default()
+        });
+        assert_ok!(VaultRegistry::<T>::record_reputation_event(
+            &vault_id,
+            ReputationEvent::Execution
+        ));
+        let caller: T::AccountId = account("Caller", 0, 0);
+
+        #[extrinsic_call]
+        refresh_vault_reputation(RawOrigin::Signed(caller), vault_id.clone());
+    }
+
+    #[benchmark]
+    fn set_collateral_reserve() {
+        let vault_id = activate_lending_and_get_vault_id::<T>();
+        register_vault_with_collateral::<T>(vault_id.clone());
+        let reserve_account_id: T::AccountId = account("Reserve", 0, 0);
+
+        #[extrinsic_call]
+        set_collateral_reserve(
+            RawOrigin::Signed(vault_id.account_id.clone()),
+            vault_id.currencies.clone(),
+            Some(reserve_account_id),
+        );
+    }
+
+    #[benchmark]
+    fn set_collateral_reserve_allowance() {
+        let vault_id = activate_lending_and_get_vault_id::<T>();
+        let reserve_account_id: T::AccountId = account("Reserve", 0, 0);
+
+        #[extrinsic_call]
+        set_collateral_reserve_allowance(
+            RawOrigin::Signed(reserve_account_id),
+            vault_id.clone(),
+            1_000_000u32.into(),
+        );
+    }
+
+    #[benchmark]
+    fn top_up_collateral() {
+        let vault_id = activate_lending_and_get_vault_id::<T>();
+        let underlying_id = Loans::<T>::underlying_id(vault_id.collateral_currency()).unwrap();
+        let reserve_account_id: T::AccountId = account("Reserve", 0, 0);
+
+        register_vault_with_collateral::<T>(vault_id.clone());
+        let amount = VaultRegistry::<T>::get_issuable_tokens_from_vault(&vault_id).unwrap();
+        assert!(!amount.is_zero());
+        VaultRegistry::<T>::try_increase_to_be_issued_tokens(&vault_id, &amount).unwrap();
+        VaultRegistry::<T>::issue_tokens(&vault_id, &amount).unwrap();
+
+        // drop the vault below the secure threshold
+        Oracle::<T>::_set_exchange_rate(
+            underlying_id.clone(),
+            UnsignedFixedPoint::<T>::checked_from_rational(2, 1).unwrap(),
+        )
+        .unwrap();
+
+        deposit_tokens::<T>(vault_id.collateral_currency(), &reserve_account_id, 1_000_000u32.into());
+        CollateralReserveAccount::<T>::insert(&vault_id, &reserve_account_id);
+        CollateralReserveAllowance::<T>::insert(&reserve_account_id, &vault_id, BalanceOf::<T>::from(1_000_000u32));
+        let origin: T::AccountId = account("Origin", 0, 0);
+
+        #[extrinsic_call]
+        top_up_collateral(RawOrigin::Signed(origin), vault_id.clone());
+    }
+
+    #[benchmark]
+    fn set_partial_liquidation_parameters() {
+        let parameters = PartialLiquidationParameters {
+            penalty: UnsignedFixedPoint::<T>::checked_from_rational(5, 100).unwrap(),
+            full_liquidation_threshold: UnsignedFixedPoint::<T>::checked_from_rational(110, 100).unwrap(),
+        };
+
+        #[extrinsic_call]
+        set_partial_liquidation_parameters(RawOrigin::Root, get_currency_pair::<T>(), Some(parameters));
+    }
+
+    #[benchmark]
+    fn set_collateral_auction_parameters() {
+        let parameters = AuctionParameters {
+            start_premium: UnsignedFixedPoint::<T>::checked_from_rational(10, 100).unwrap(),
+            decay: AuctionDecay::Linear(UnsignedFixedPoint::<T>::checked_from_rational(1, 1000).unwrap()),
+            min_lot_size: 1_000u32.into(),
+            reserve_price: UnsignedFixedPoint::<T>::checked_from_rational(90, 100).unwrap(),
+        };
+
+        #[extrinsic_call]
+        set_collateral_auction_parameters(RawOrigin::Root, get_currency_pair::<T>(), Some(parameters));
+    }
+
+    #[benchmark]
+    fn start_collateral_auction() {
+        let vault_id = liquidate_vault_and_start_auction::<T>();
+        let caller: T::AccountId = account("Caller", 0, 0);
+
+        #[extrinsic_call]
+        start_collateral_auction(RawOrigin::Signed(caller), vault_id.currencies.clone());
+    }
+
+    #[benchmark]
+    fn bid_collateral_auction() {
+        let vault_id = liquidate_vault_and_start_auction::<T>();
+        let bidder: T::AccountId = account("Bidder", 0, 0);
+        assert_ok!(VaultRegistry::<T>::start_collateral_auction(
+            RawOrigin::Signed(bidder.clone()).into(),
+            vault_id.currencies.clone()
+        ));
+        let collateral = VaultRegistry::<T>::get_auctionable_collateral(&vault_id.currencies).unwrap();
+        let collateral = collateral
+            .checked_div(&UnsignedFixedPoint::<T>::saturating_from_integer(2u32))
+            .unwrap();
+        deposit_tokens::<T>(vault_id.wrapped_currency(), &bidder, (1u32 << 31).into());
+
+        #[extrinsic_call]
+        bid_collateral_auction(
+            RawOrigin::Signed(bidder),
+            vault_id.currencies.clone(),
+            collateral.amount(),
+            (1u32 << 31).into(),
+        );
+    }
+
+    #[benchmark]
+    fn set_liquidation_bounty() {
+        let bounty = LiquidationBounty {
+            rate: UnsignedFixedPoint::<T>::checked_from_rational(5, 100).unwrap(),
+            cap: 1_000_000u32.into(),
+        };
+
+        #[extrinsic_call]
+        set_liquidation_bounty(RawOrigin::Root, get_currency_pair::<T>(), Some(bounty));
+    }
+
+    #[benchmark]
+    fn set_liquidation_report_deposit() {
+        #[extrinsic_call]
+        set_liquidation_report_deposit(RawOrigin::Root, 1_000u32.into());
+    }
+
+    #[benchmark]
+    fn report_undercollateralized_vault_for_bounty() {
+        let vault_id = activate_lending_and_get_vault_id::<T>();
+        let origin: T::AccountId = account("Origin", 0, 0);
+        let underlying_id = Loans::<T>::underlying_id(vault_id.collateral_currency()).unwrap();
+
+        register_vault_with_collateral::<T>(vault_id.clone());
+        Oracle::<T>::_set_exchange_rate(
+            underlying_id.clone(),
+            UnsignedFixedPoint::<T>::checked_from_rational(1, 1).unwrap(),
+        )
+        .unwrap();
+
+        let amount = VaultRegistry::<T>::get_issuable_tokens_from_vault(&vault_id).unwrap();
+        assert!(!amount.is_zero());
+        VaultRegistry::<T>::try_increase_to_be_issued_tokens(&vault_id, &amount).unwrap();
+        VaultRegistry::<T>::issue_tokens(&vault_id, &amount).unwrap();
+
+        Oracle::<T>::_set_exchange_rate(
+            underlying_id.clone(),
+            UnsignedFixedPoint::<T>::checked_from_rational(2147483647, 1).unwrap(),
+        )
+        .unwrap();
+
+        LiquidationBounties::<T>::insert(
+            &vault_id.currencies,
+            LiquidationBounty {
+                rate: UnsignedFixedPoint::<T>::checked_from_rational(5, 100).unwrap(),
+                cap: 1_000_000u32.into(),
+            },
+        );
+        LiquidationReportDeposit::<T>::put(BalanceOf::<T>::from(1_000u32));
+        mint_collateral::<T>(&origin, 1_000u32.into());
+
+        #[extrinsic_call]
+        report_undercollateralized_vault_for_bounty(RawOrigin::Signed(origin), vault_id.clone());
+    }
+
     impl_benchmark_test_suite! {
         VaultRegistry,
         crate::mock::ExtBuilder::build_with(Default::default()),
@@ -48,6 +48,18 @@ pub trait WeightInfo {
 	fn set_liquidation_collateral_threshold() -> Weight;
 	fn report_undercollateralized_vault() -> Weight;
 	fn recover_vault_id() -> Weight;
+	fn set_reputation_parameters() -> Weight;
+	fn refresh_vault_reputation() -> Weight;
+	fn set_collateral_reserve() -> Weight;
+	fn set_collateral_reserve_allowance() -> Weight;
+	fn top_up_collateral() -> Weight;
+	fn set_partial_liquidation_parameters() -> Weight;
+	fn set_collateral_auction_parameters() -> Weight;
+	fn start_collateral_auction() -> Weight;
+	fn bid_collateral_auction() -> Weight;
+	fn set_liquidation_bounty() -> Weight;
+	fn set_liquidation_report_deposit() -> Weight;
+	fn report_undercollateralized_vault_for_bounty() -> Weight;
 }
 
 /// Weights for vault_registry using the Substrate node and recommended hardware.
@@ -437,6 +449,224 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: VaultRegistry VaultReputationParameters (r:0 w:1)
+	/// Proof: VaultRegistry VaultReputationParameters (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	fn set_reputation_parameters() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `711`
+		//  Estimated: `0`
+		// Minimum execution time: 13_245_000 picoseconds.
+		Weight::from_parts(13_626_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VaultRegistry Vaults (r:1 w:0)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultReputationParameters (r:1 w:0)
+	/// Proof: VaultRegistry VaultReputationParameters (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultReputations (r:1 w:0)
+	/// Proof: VaultRegistry VaultReputations (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Nonce (r:1 w:0)
+	/// Proof: VaultStaking Nonce (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: VaultStaking TotalCurrentStake (r:1 w:0)
+	/// Proof: VaultStaking TotalCurrentStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry SecureCollateralThreshold (r:1 w:0)
+	/// Proof: VaultRegistry SecureCollateralThreshold (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: VaultRewards Stake (r:1 w:1)
+	/// Proof: VaultRewards Stake (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: VaultRewards TotalStake (r:1 w:1)
+	/// Proof: VaultRewards TotalStake (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: VaultRewards RewardCurrencies (r:1 w:0)
+	/// Proof: VaultRewards RewardCurrencies (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: VaultRewards RewardPerToken (r:2 w:0)
+	/// Proof: VaultRewards RewardPerToken (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: VaultRewards RewardTally (r:2 w:2)
+	/// Proof: VaultRewards RewardTally (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: VaultCapacity Stake (r:1 w:1)
+	/// Proof: VaultCapacity Stake (max_values: None, max_size: Some(79), added: 2554, mode: MaxEncodedLen)
+	/// Storage: VaultCapacity TotalStake (r:1 w:1)
+	/// Proof: VaultCapacity TotalStake (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:1 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn refresh_vault_reputation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5157`
+		//  Estimated: `83837`
+		// Minimum execution time: 357_562_000 picoseconds.
+		Weight::from_parts(361_804_000, 83837)
+			.saturating_add(T::DbWeight::get().reads(36_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: VaultRegistry Vaults (r:1 w:0)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry CollateralReserveAccount (r:0 w:1)
+	/// Proof: VaultRegistry CollateralReserveAccount (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	fn set_collateral_reserve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `706`
+		//  Estimated: `3725`
+		// Minimum execution time: 27_051_000 picoseconds.
+		Weight::from_parts(27_712_000, 3725)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VaultRegistry CollateralReserveAllowance (r:0 w:1)
+	/// Proof: VaultRegistry CollateralReserveAllowance (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	fn set_collateral_reserve_allowance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_218_000 picoseconds.
+		Weight::from_parts(14_632_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VaultRegistry CollateralReserveAccount (r:1 w:0)
+	/// Proof: VaultRegistry CollateralReserveAccount (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry Vaults (r:1 w:0)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry CollateralReserveAllowance (r:1 w:1)
+	/// Proof: VaultRegistry CollateralReserveAllowance (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Nonce (r:1 w:0)
+	/// Proof: VaultStaking Nonce (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: VaultStaking TotalCurrentStake (r:1 w:1)
+	/// Proof: VaultStaking TotalCurrentStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Stake (r:1 w:1)
+	/// Proof: VaultStaking Stake (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: VaultRewards Stake (r:1 w:1)
+	/// Proof: VaultRewards Stake (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry SecureCollateralThreshold (r:1 w:0)
+	/// Proof: VaultRegistry SecureCollateralThreshold (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:1 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry TotalUserVaultCollateral (r:1 w:1)
+	/// Proof: VaultRegistry TotalUserVaultCollateral (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry SystemCollateralCeiling (r:1 w:0)
+	/// Proof: VaultRegistry SystemCollateralCeiling (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	fn top_up_collateral() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5893`
+		//  Estimated: `210771`
+		// Minimum execution time: 868_305_000 picoseconds.
+		Weight::from_parts(874_110_000, 210771)
+			.saturating_add(T::DbWeight::get().reads(70_u64))
+			.saturating_add(T::DbWeight::get().writes(37_u64))
+	}
+	/// Storage: VaultRegistry VaultPartialLiquidationParameters (r:0 w:1)
+	/// Proof: VaultRegistry VaultPartialLiquidationParameters (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	fn set_partial_liquidation_parameters() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 13_904_000 picoseconds.
+		Weight::from_parts(14_285_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VaultRegistry CollateralAuctions (r:0 w:1)
+	/// Proof: VaultRegistry CollateralAuctions (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry CollateralAuctionParameters (r:0 w:1)
+	/// Proof: VaultRegistry CollateralAuctionParameters (max_values: None, max_size: Some(87), added: 2562, mode: MaxEncodedLen)
+	fn set_collateral_auction_parameters() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 15_127_000 picoseconds.
+		Weight::from_parts(15_560_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: VaultRegistry CollateralAuctionParameters (r:1 w:0)
+	/// Proof: VaultRegistry CollateralAuctionParameters (max_values: None, max_size: Some(87), added: 2562, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry CollateralAuctions (r:1 w:1)
+	/// Proof: VaultRegistry CollateralAuctions (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry LiquidationVault (r:1 w:0)
+	/// Proof: VaultRegistry LiquidationVault (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn start_collateral_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1064`
+		//  Estimated: `3589`
+		// Minimum execution time: 38_412_000 picoseconds.
+		Weight::from_parts(39_170_000, 3589)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VaultRegistry CollateralAuctionParameters (r:1 w:0)
+	/// Proof: VaultRegistry CollateralAuctionParameters (max_values: None, max_size: Some(87), added: 2562, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry LiquidationVault (r:1 w:1)
+	/// Proof: VaultRegistry LiquidationVault (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry CollateralAuctions (r:1 w:1)
+	/// Proof: VaultRegistry CollateralAuctions (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:1 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry TotalUserVaultCollateral (r:1 w:1)
+	/// Proof: VaultRegistry TotalUserVaultCollateral (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn bid_collateral_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2417`
+		//  Estimated: `8760`
+		// Minimum execution time: 141_385_000 picoseconds.
+		Weight::from_parts(143_902_000, 8760)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: VaultRegistry LiquidationBounties (r:0 w:1)
+	/// Proof: VaultRegistry LiquidationBounties (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	fn set_liquidation_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 13_871_000 picoseconds.
+		Weight::from_parts(14_190_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VaultRegistry LiquidationReportDeposit (r:0 w:1)
+	/// Proof: VaultRegistry LiquidationReportDeposit (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn set_liquidation_report_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_652_000 picoseconds.
+		Weight::from_parts(13_004_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VaultRegistry LiquidationReportDeposit (r:1 w:0)
+	/// Proof: VaultRegistry LiquidationReportDeposit (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry LiquidationBounties (r:1 w:0)
+	/// Proof: VaultRegistry LiquidationBounties (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry Vaults (r:1 w:1)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry LiquidationVault (r:1 w:1)
+	/// Proof: VaultRegistry LiquidationVault (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry LiquidationCollateralThreshold (r:1 w:0)
+	/// Proof: VaultRegistry LiquidationCollateralThreshold (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry CollateralReserveAccount (r:1 w:0)
+	/// Proof: VaultRegistry CollateralReserveAccount (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultPartialLiquidationParameters (r:1 w:0)
+	/// Proof: VaultRegistry VaultPartialLiquidationParameters (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:4 w:4)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry TotalUserVaultCollateral (r:1 w:1)
+	/// Proof: VaultRegistry TotalUserVaultCollateral (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	fn report_undercollateralized_vault_for_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7102`
+		//  Estimated: `151830`
+		// Minimum execution time: 1_318_224_000 picoseconds.
+		Weight::from_parts(1_331_708_000, 151830)
+			.saturating_add(T::DbWeight::get().reads(65_u64))
+			.saturating_add(T::DbWeight::get().writes(34_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -825,4 +1055,222 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: VaultRegistry VaultReputationParameters (r:0 w:1)
+	/// Proof: VaultRegistry VaultReputationParameters (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	fn set_reputation_parameters() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `711`
+		//  Estimated: `0`
+		// Minimum execution time: 13_245_000 picoseconds.
+		Weight::from_parts(13_626_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VaultRegistry Vaults (r:1 w:0)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultReputationParameters (r:1 w:0)
+	/// Proof: VaultRegistry VaultReputationParameters (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultReputations (r:1 w:0)
+	/// Proof: VaultRegistry VaultReputations (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Nonce (r:1 w:0)
+	/// Proof: VaultStaking Nonce (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: VaultStaking TotalCurrentStake (r:1 w:0)
+	/// Proof: VaultStaking TotalCurrentStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry SecureCollateralThreshold (r:1 w:0)
+	/// Proof: VaultRegistry SecureCollateralThreshold (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: VaultRewards Stake (r:1 w:1)
+	/// Proof: VaultRewards Stake (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: VaultRewards TotalStake (r:1 w:1)
+	/// Proof: VaultRewards TotalStake (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: VaultRewards RewardCurrencies (r:1 w:0)
+	/// Proof: VaultRewards RewardCurrencies (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: VaultRewards RewardPerToken (r:2 w:0)
+	/// Proof: VaultRewards RewardPerToken (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: VaultRewards RewardTally (r:2 w:2)
+	/// Proof: VaultRewards RewardTally (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: VaultCapacity Stake (r:1 w:1)
+	/// Proof: VaultCapacity Stake (max_values: None, max_size: Some(79), added: 2554, mode: MaxEncodedLen)
+	/// Storage: VaultCapacity TotalStake (r:1 w:1)
+	/// Proof: VaultCapacity TotalStake (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:1 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn refresh_vault_reputation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5157`
+		//  Estimated: `83837`
+		// Minimum execution time: 357_562_000 picoseconds.
+		Weight::from_parts(361_804_000, 83837)
+			.saturating_add(RocksDbWeight::get().reads(36_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: VaultRegistry Vaults (r:1 w:0)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry CollateralReserveAccount (r:0 w:1)
+	/// Proof: VaultRegistry CollateralReserveAccount (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	fn set_collateral_reserve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `706`
+		//  Estimated: `3725`
+		// Minimum execution time: 27_051_000 picoseconds.
+		Weight::from_parts(27_712_000, 3725)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VaultRegistry CollateralReserveAllowance (r:0 w:1)
+	/// Proof: VaultRegistry CollateralReserveAllowance (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	fn set_collateral_reserve_allowance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_218_000 picoseconds.
+		Weight::from_parts(14_632_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VaultRegistry CollateralReserveAccount (r:1 w:0)
+	/// Proof: VaultRegistry CollateralReserveAccount (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry Vaults (r:1 w:0)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry CollateralReserveAllowance (r:1 w:1)
+	/// Proof: VaultRegistry CollateralReserveAllowance (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Nonce (r:1 w:0)
+	/// Proof: VaultStaking Nonce (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: VaultStaking TotalCurrentStake (r:1 w:1)
+	/// Proof: VaultStaking TotalCurrentStake (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Stake (r:1 w:1)
+	/// Proof: VaultStaking Stake (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: VaultRewards Stake (r:1 w:1)
+	/// Proof: VaultRewards Stake (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry SecureCollateralThreshold (r:1 w:0)
+	/// Proof: VaultRegistry SecureCollateralThreshold (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:1 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry TotalUserVaultCollateral (r:1 w:1)
+	/// Proof: VaultRegistry TotalUserVaultCollateral (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry SystemCollateralCeiling (r:1 w:0)
+	/// Proof: VaultRegistry SystemCollateralCeiling (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	fn top_up_collateral() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5893`
+		//  Estimated: `210771`
+		// Minimum execution time: 868_305_000 picoseconds.
+		Weight::from_parts(874_110_000, 210771)
+			.saturating_add(RocksDbWeight::get().reads(70_u64))
+			.saturating_add(RocksDbWeight::get().writes(37_u64))
+	}
+	/// Storage: VaultRegistry VaultPartialLiquidationParameters (r:0 w:1)
+	/// Proof: VaultRegistry VaultPartialLiquidationParameters (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	fn set_partial_liquidation_parameters() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 13_904_000 picoseconds.
+		Weight::from_parts(14_285_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VaultRegistry CollateralAuctions (r:0 w:1)
+	/// Proof: VaultRegistry CollateralAuctions (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry CollateralAuctionParameters (r:0 w:1)
+	/// Proof: VaultRegistry CollateralAuctionParameters (max_values: None, max_size: Some(87), added: 2562, mode: MaxEncodedLen)
+	fn set_collateral_auction_parameters() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 15_127_000 picoseconds.
+		Weight::from_parts(15_560_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: VaultRegistry CollateralAuctionParameters (r:1 w:0)
+	/// Proof: VaultRegistry CollateralAuctionParameters (max_values: None, max_size: Some(87), added: 2562, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry CollateralAuctions (r:1 w:1)
+	/// Proof: VaultRegistry CollateralAuctions (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry LiquidationVault (r:1 w:0)
+	/// Proof: VaultRegistry LiquidationVault (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn start_collateral_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1064`
+		//  Estimated: `3589`
+		// Minimum execution time: 38_412_000 picoseconds.
+		Weight::from_parts(39_170_000, 3589)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VaultRegistry CollateralAuctionParameters (r:1 w:0)
+	/// Proof: VaultRegistry CollateralAuctionParameters (max_values: None, max_size: Some(87), added: 2562, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry LiquidationVault (r:1 w:1)
+	/// Proof: VaultRegistry LiquidationVault (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry CollateralAuctions (r:1 w:1)
+	/// Proof: VaultRegistry CollateralAuctions (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:1 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry TotalUserVaultCollateral (r:1 w:1)
+	/// Proof: VaultRegistry TotalUserVaultCollateral (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn bid_collateral_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2417`
+		//  Estimated: `8760`
+		// Minimum execution time: 141_385_000 picoseconds.
+		Weight::from_parts(143_902_000, 8760)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: VaultRegistry LiquidationBounties (r:0 w:1)
+	/// Proof: VaultRegistry LiquidationBounties (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	fn set_liquidation_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 13_871_000 picoseconds.
+		Weight::from_parts(14_190_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VaultRegistry LiquidationReportDeposit (r:0 w:1)
+	/// Proof: VaultRegistry LiquidationReportDeposit (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn set_liquidation_report_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_652_000 picoseconds.
+		Weight::from_parts(13_004_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VaultRegistry LiquidationReportDeposit (r:1 w:0)
+	/// Proof: VaultRegistry LiquidationReportDeposit (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry LiquidationBounties (r:1 w:0)
+	/// Proof: VaultRegistry LiquidationBounties (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry Vaults (r:1 w:1)
+	/// Proof: VaultRegistry Vaults (max_values: None, max_size: Some(260), added: 2735, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry LiquidationVault (r:1 w:1)
+	/// Proof: VaultRegistry LiquidationVault (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry LiquidationCollateralThreshold (r:1 w:0)
+	/// Proof: VaultRegistry LiquidationCollateralThreshold (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry CollateralReserveAccount (r:1 w:0)
+	/// Proof: VaultRegistry CollateralReserveAccount (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry VaultPartialLiquidationParameters (r:1 w:0)
+	/// Proof: VaultRegistry VaultPartialLiquidationParameters (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:4 w:4)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: VaultRegistry TotalUserVaultCollateral (r:1 w:1)
+	/// Proof: VaultRegistry TotalUserVaultCollateral (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	fn report_undercollateralized_vault_for_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7102`
+		//  Estimated: `151830`
+		// Minimum execution time: 1_318_224_000 picoseconds.
+		Weight::from_parts(1_331_708_000, 151830)
+			.saturating_add(RocksDbWeight::get().reads(65_u64))
+			.saturating_add(RocksDbWeight::get().writes(34_u64))
+	}
 }
@@ -112,12 +112,16 @@ pub(crate) mod capacity {
 
 #[cfg_attr(test, mockable)]
 pub(crate) mod oracle {
-    use frame_support::dispatch::DispatchError;
-    use oracle::{types::UnsignedFixedPoint, OracleKey};
+    use frame_support::dispatch::{DispatchError, DispatchResult};
+    use oracle::{types::UnsignedFixedPoint, CurrencyId, OracleKey};
 
     pub fn get_price<T: crate::Config>(key: OracleKey) -> Result<UnsignedFixedPoint<T>, DispatchError> {
         <oracle::Pallet<T>>::get_price(key)
     }
+
+    pub fn ensure_price_feed_not_paused<T: crate::Config>(currency_id: CurrencyId) -> DispatchResult {
+        <oracle::Pallet<T>>::ensure_price_feed_not_paused(currency_id)
+    }
 }
 
 #[cfg_attr(test, mockable)]
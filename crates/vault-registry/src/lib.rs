@@ -32,13 +32,17 @@ use mocktopus::macros::mockable;
 use primitives::VaultCurrencyPair;
 
 use crate::types::{
-    BalanceOf, BtcAddress, CurrencyId, DefaultSystemVault, RichSystemVault, RichVault, UnsignedFixedPoint, Version,
+    BalanceOf, BtcAddress, CurrencyId, DefaultAuctionParameters, DefaultLiquidationBounty,
+    DefaultPartialLiquidationParameters, DefaultReputationParameters, DefaultSystemVault, DefaultVaultReputation,
+    RichSystemVault, RichVault, UnsignedFixedPoint, Version,
 };
 
 use crate::types::DefaultVaultCurrencyPair;
 #[doc(inline)]
 pub use crate::types::{
-    BtcPublicKey, CurrencySource, DefaultVault, DefaultVaultId, SystemVault, Vault, VaultId, VaultStatus,
+    AuctionDecay, AuctionParameters, BtcPublicKey, CurrencySource, DefaultVault, DefaultVaultId, LiquidationBounty,
+    PartialLiquidationParameters, ReputationEvent, ReputationParameters, SystemVault, Vault, VaultId, VaultReputation,
+    VaultStatus,
 };
 pub use currency::Amount;
 use currency::Rounding;
@@ -141,6 +145,7 @@ pub mod pallet {
 
             match call {
                 Call::report_undercollateralized_vault { .. } => valid_tx(b"report_undercollateralized_vault".to_vec()),
+                Call::top_up_collateral { .. } => valid_tx(b"top_up_collateral".to_vec()),
                 _ => InvalidTransaction::Call.into(),
             }
         }
@@ -247,23 +252,15 @@ pub mod pallet {
         }
 
         #[pallet::call_index(4)]
-        #[pallet::weight(<T as Config>::WeightInfo::report_undercollateralized_vault())]
+        #[pallet::weight(<T as Config>::WeightInfo::report_undercollateralized_vault().saturating_add(<T as Config>::WeightInfo::top_up_collateral()))]
         #[transactional]
         pub fn report_undercollateralized_vault(
             _origin: OriginFor<T>,
             vault_id: DefaultVaultId<T>,
         ) -> DispatchResultWithPostInfo {
             log::info!("Vault reported");
-            let vault = Self::get_vault_from_id(&vault_id)?;
-            let liquidation_threshold =
-                Self::liquidation_collateral_threshold(&vault_id.currencies).ok_or(Error::<T>::ThresholdNotSet)?;
-            if Self::is_vault_below_liquidation_threshold(&vault, liquidation_threshold)? {
-                Self::liquidate_vault(&vault_id)?;
-                Ok(().into())
-            } else {
-                log::info!("Not liquidating; vault not below liquidation threshold");
-                Err(Error::<T>::VaultNotBelowLiquidationThreshold.into())
-            }
+            Self::_report_undercollateralized_vault(&vault_id)?;
+            Ok(().into())
         }
 
         /// Changes the minimum amount of collateral required for registration
@@ -389,6 +386,308 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Changes the parameters of the vault reputation score. Setting `None` disables
+        /// the reputation multiplier, i.e. rewards are distributed by collateral only.
+        ///
+        /// # Arguments
+        /// * `parameters` - the new reputation parameters
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_reputation_parameters())]
+        #[transactional]
+        pub fn set_reputation_parameters(
+            origin: OriginFor<T>,
+            parameters: Option<DefaultReputationParameters<T>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            if let Some(ref parameters) = parameters {
+                let one = UnsignedFixedPoint::<T>::one();
+                ensure!(
+                    parameters.min_score <= one && one <= parameters.max_score && parameters.decay_per_block <= one,
+                    Error::<T>::InvalidReputationParameters
+                );
+            }
+            VaultReputationParameters::<T>::set(parameters.clone());
+            Self::deposit_event(Event::<T>::SetReputationParameters { parameters });
+            Ok(())
+        }
+
+        /// Applies the decay of the vault's reputation score to its reward stake. Can be
+        /// called by anyone.
+        ///
+        /// # Arguments
+        /// * `vault_id` - the vault to refresh
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as Config>::WeightInfo::refresh_vault_reputation())]
+        #[transactional]
+        pub fn refresh_vault_reputation(origin: OriginFor<T>, vault_id: DefaultVaultId<T>) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::get_active_vault_from_id(&vault_id)?;
+            PoolManager::<T>::on_vault_settings_change(&vault_id)?;
+            Ok(())
+        }
+
+        /// Designates the account from which collateral is automatically deposited when the
+        /// vault drops below its secure threshold. The reserve account additionally needs to
+        /// approve an allowance through `set_collateral_reserve_allowance`.
+        ///
+        /// # Arguments
+        /// * `currency_pair` - the currency pair of the vault
+        /// * `reserve_account_id` - the reserve account, or `None` to remove it
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_collateral_reserve())]
+        #[transactional]
+        pub fn set_collateral_reserve(
+            origin: OriginFor<T>,
+            currency_pair: DefaultVaultCurrencyPair<T>,
+            reserve_account_id: Option<T::AccountId>,
+        ) -> DispatchResult {
+            let account_id = ensure_signed(origin)?;
+            let vault_id = VaultId::new(account_id, currency_pair.collateral, currency_pair.wrapped);
+            Self::get_active_vault_from_id(&vault_id)?;
+            CollateralReserveAccount::<T>::set(&vault_id, reserve_account_id.clone());
+            Self::deposit_event(Event::<T>::SetCollateralReserve {
+                vault_id,
+                reserve_account_id,
+            });
+            Ok(())
+        }
+
+        /// Sets the maximum amount of collateral that may be moved from the caller's account
+        /// into the given vault when topping up its collateral.
+        ///
+        /// # Arguments
+        /// * `vault_id` - the vault that may draw from the caller's account
+        /// * `allowance` - the maximum amount of collateral, zero revokes the allowance
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_collateral_reserve_allowance())]
+        #[transactional]
+        pub fn set_collateral_reserve_allowance(
+            origin: OriginFor<T>,
+            vault_id: DefaultVaultId<T>,
+            allowance: BalanceOf<T>,
+        ) -> DispatchResult {
+            let reserve_account_id = ensure_signed(origin)?;
+            if allowance.is_zero() {
+                CollateralReserveAllowance::<T>::remove(&reserve_account_id, &vault_id);
+            } else {
+                CollateralReserveAllowance::<T>::insert(&reserve_account_id, &vault_id, allowance);
+            }
+            Self::deposit_event(Event::<T>::SetCollateralReserveAllowance {
+                vault_id,
+                reserve_account_id,
+                allowance,
+            });
+            Ok(())
+        }
+
+        /// Moves collateral from the vault's reserve account into the vault, restoring it to
+        /// the secure threshold as far as the allowance and the reserve's balance permit.
+        /// Can be called by anyone, and is submitted by the off-chain worker.
+        ///
+        /// # Arguments
+        /// * `vault_id` - the vault to top up
+        #[pallet::call_index(15)]
+        #[pallet::weight(<T as Config>::WeightInfo::top_up_collateral())]
+        #[transactional]
+        pub fn top_up_collateral(_origin: OriginFor<T>, vault_id: DefaultVaultId<T>) -> DispatchResult {
+            let (reserve_account_id, amount) = Self::get_collateral_top_up(&vault_id)?;
+            Self::_top_up_collateral(&vault_id, &reserve_account_id, &amount)
+        }
+
+        /// Changes the parameters of partial liquidations for the given currency pair. Setting
+        /// `None` disables partial liquidations, i.e. undercollateralized vaults are always
+        /// liquidated in full (only executable by the Root account).
+        ///
+        /// # Arguments
+        /// * `currency_pair` - the currency pair to change
+        /// * `parameters` - the new partial liquidation parameters
+        #[pallet::call_index(16)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_partial_liquidation_parameters())]
+        #[transactional]
+        pub fn set_partial_liquidation_parameters(
+            origin: OriginFor<T>,
+            currency_pair: DefaultVaultCurrencyPair<T>,
+            parameters: Option<DefaultPartialLiquidationParameters<T>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            if let Some(ref parameters) = parameters {
+                // vaults must not be left with less collateral than the value of their tokens
+                let penalty_factor = UnsignedFixedPoint::<T>::one()
+                    .checked_add(&parameters.penalty)
+                    .ok_or(ArithmeticError::Overflow)?;
+                ensure!(
+                    parameters.full_liquidation_threshold >= penalty_factor,
+                    Error::<T>::InvalidPartialLiquidationParameters
+                );
+            }
+            VaultPartialLiquidationParameters::<T>::set(&currency_pair, parameters.clone());
+            Self::deposit_event(Event::<T>::SetPartialLiquidationParameters {
+                currency_pair,
+                parameters,
+            });
+            Ok(())
+        }
+
+        /// Changes the parameters of collateral auctions for the given currency pair. Setting
+        /// `None` disables collateral auctions and ends any running auction (only executable
+        /// by the Root account).
+        ///
+        /// # Arguments
+        /// * `currency_pair` - the currency pair to change
+        /// * `parameters` - the new auction parameters
+        #[pallet::call_index(17)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_collateral_auction_parameters())]
+        #[transactional]
+        pub fn set_collateral_auction_parameters(
+            origin: OriginFor<T>,
+            currency_pair: DefaultVaultCurrencyPair<T>,
+            parameters: Option<DefaultAuctionParameters<T>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            match parameters {
+                Some(ref parameters) => {
+                    let decay = match parameters.decay {
+                        AuctionDecay::Linear(decay) | AuctionDecay::Exponential(decay) => decay,
+                    };
+                    let start_price = UnsignedFixedPoint::<T>::one().saturating_add(parameters.start_premium);
+                    ensure!(
+                        decay <= UnsignedFixedPoint::<T>::one() && parameters.reserve_price < start_price,
+                        Error::<T>::InvalidAuctionParameters
+                    );
+                }
+                None => CollateralAuctions::<T>::remove(&currency_pair),
+            }
+            CollateralAuctionParameters::<T>::set(&currency_pair, parameters.clone());
+            Self::deposit_event(Event::<T>::SetCollateralAuctionParameters {
+                currency_pair,
+                parameters,
+            });
+            Ok(())
+        }
+
+        /// Starts a Dutch auction selling the collateral of the liquidation vault for wrapped
+        /// tokens. Can be called by anyone if no auction is running, or if the price of the
+        /// running auction has decayed to its reserve price.
+        ///
+        /// # Arguments
+        /// * `currency_pair` - the currency pair of the liquidation vault
+        #[pallet::call_index(18)]
+        #[pallet::weight(<T as Config>::WeightInfo::start_collateral_auction())]
+        #[transactional]
+        pub fn start_collateral_auction(
+            origin: OriginFor<T>,
+            currency_pair: DefaultVaultCurrencyPair<T>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            let parameters =
+                Self::collateral_auction_parameters(&currency_pair).ok_or(Error::<T>::CollateralAuctionsDisabled)?;
+            if CollateralAuctions::<T>::contains_key(&currency_pair) {
+                ensure!(
+                    Self::get_collateral_auction_price(&currency_pair)?.is_zero(),
+                    Error::<T>::CollateralAuctionAlreadyRunning
+                );
+                Self::deposit_event(Event::<T>::EndCollateralAuction {
+                    currency_pair: currency_pair.clone(),
+                });
+            }
+            let collateral = Self::get_auctionable_collateral(&currency_pair)?;
+            ensure!(
+                collateral.ge(&Amount::new(parameters.min_lot_size, currency_pair.collateral))?
+                    && !collateral.is_zero(),
+                Error::<T>::InsufficientAuctionableCollateral
+            );
+
+            CollateralAuctions::<T>::insert(&currency_pair, ext::security::active_block_number::<T>());
+            Self::deposit_event(Event::<T>::StartCollateralAuction {
+                currency_pair,
+                collateral: collateral.amount(),
+            });
+            Ok(())
+        }
+
+        /// Buys collateral from the running auction. The wrapped tokens paid are burned,
+        /// reducing the tokens issued by the liquidation vault.
+        ///
+        /// # Arguments
+        /// * `currency_pair` - the currency pair of the liquidation vault
+        /// * `collateral` - the amount of collateral to buy
+        /// * `max_wrapped` - the maximum amount of wrapped tokens to pay
+        #[pallet::call_index(19)]
+        #[pallet::weight(<T as Config>::WeightInfo::bid_collateral_auction())]
+        #[transactional]
+        pub fn bid_collateral_auction(
+            origin: OriginFor<T>,
+            currency_pair: DefaultVaultCurrencyPair<T>,
+            collateral: BalanceOf<T>,
+            max_wrapped: BalanceOf<T>,
+        ) -> DispatchResult {
+            let bidder = ensure_signed(origin)?;
+            Self::_bid_collateral_auction(
+                &bidder,
+                &currency_pair,
+                &Amount::new(collateral, currency_pair.collateral),
+                &Amount::new(max_wrapped, currency_pair.wrapped),
+            )
+        }
+
+        /// Changes the bounty paid to reporters of undercollateralized vaults for the given
+        /// currency pair. Setting `None` disables the bounty (only executable by the Root account).
+        ///
+        /// # Arguments
+        /// * `currency_pair` - the currency pair to change
+        /// * `bounty` - the new liquidation bounty
+        #[pallet::call_index(20)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_liquidation_bounty())]
+        #[transactional]
+        pub fn set_liquidation_bounty(
+            origin: OriginFor<T>,
+            currency_pair: DefaultVaultCurrencyPair<T>,
+            bounty: Option<DefaultLiquidationBounty<T>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            if let Some(ref bounty) = bounty {
+                ensure!(
+                    bounty.rate <= UnsignedFixedPoint::<T>::one(),
+                    Error::<T>::InvalidLiquidationBounty
+                );
+            }
+            LiquidationBounties::<T>::set(&currency_pair, bounty.clone());
+            Self::deposit_event(Event::<T>::SetLiquidationBounty { currency_pair, bounty });
+            Ok(())
+        }
+
+        /// Changes the deposit that reporters of undercollateralized vaults forfeit if the
+        /// reported vault is not below the liquidation threshold (only executable by the Root account).
+        ///
+        /// # Arguments
+        /// * `deposit` - the new deposit, in the griefing currency
+        #[pallet::call_index(21)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_liquidation_report_deposit())]
+        #[transactional]
+        pub fn set_liquidation_report_deposit(origin: OriginFor<T>, deposit: BalanceOf<T>) -> DispatchResult {
+            ensure_root(origin)?;
+            LiquidationReportDeposit::<T>::put(deposit);
+            Self::deposit_event(Event::<T>::SetLiquidationReportDeposit { deposit });
+            Ok(())
+        }
+
+        /// Reports an undercollateralized vault, paying the reporter a bounty from the liquidated
+        /// collateral. The reporter forfeits the `LiquidationReportDeposit` if the vault is
+        /// active but not below the liquidation threshold.
+        ///
+        /// # Arguments
+        /// * `vault_id` - the vault to report
+        #[pallet::call_index(22)]
+        #[pallet::weight(<T as Config>::WeightInfo::report_undercollateralized_vault_for_bounty())]
+        #[transactional]
+        pub fn report_undercollateralized_vault_for_bounty(
+            origin: OriginFor<T>,
+            vault_id: DefaultVaultId<T>,
+        ) -> DispatchResult {
+            let reporter_id = ensure_signed(origin)?;
+            Self::_report_undercollateralized_vault_for_bounty(&reporter_id, &vault_id)
+        }
     }
 
     #[pallet::event]
@@ -509,6 +808,71 @@ pub mod pallet {
             vault_id: DefaultVaultId<T>,
             custom_threshold: Option<UnsignedFixedPoint<T>>,
         },
+        UpdateVaultReputation {
+            vault_id: DefaultVaultId<T>,
+            event: ReputationEvent,
+            score: UnsignedFixedPoint<T>,
+        },
+        SetReputationParameters {
+            parameters: Option<DefaultReputationParameters<T>>,
+        },
+        SetCollateralReserve {
+            vault_id: DefaultVaultId<T>,
+            reserve_account_id: Option<T::AccountId>,
+        },
+        SetCollateralReserveAllowance {
+            vault_id: DefaultVaultId<T>,
+            reserve_account_id: T::AccountId,
+            allowance: BalanceOf<T>,
+        },
+        TopUpCollateral {
+            vault_id: DefaultVaultId<T>,
+            reserve_account_id: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        SetPartialLiquidationParameters {
+            currency_pair: DefaultVaultCurrencyPair<T>,
+            parameters: Option<DefaultPartialLiquidationParameters<T>>,
+        },
+        PartiallyLiquidateVault {
+            vault_id: DefaultVaultId<T>,
+            liquidated_tokens: BalanceOf<T>,
+            liquidated_collateral: BalanceOf<T>,
+        },
+        SetCollateralAuctionParameters {
+            currency_pair: DefaultVaultCurrencyPair<T>,
+            parameters: Option<DefaultAuctionParameters<T>>,
+        },
+        StartCollateralAuction {
+            currency_pair: DefaultVaultCurrencyPair<T>,
+            collateral: BalanceOf<T>,
+        },
+        BidCollateralAuction {
+            currency_pair: DefaultVaultCurrencyPair<T>,
+            bidder_id: T::AccountId,
+            collateral: BalanceOf<T>,
+            burned_tokens: BalanceOf<T>,
+        },
+        EndCollateralAuction {
+            currency_pair: DefaultVaultCurrencyPair<T>,
+        },
+        SetLiquidationBounty {
+            currency_pair: DefaultVaultCurrencyPair<T>,
+            bounty: Option<DefaultLiquidationBounty<T>>,
+        },
+        SetLiquidationReportDeposit {
+            deposit: BalanceOf<T>,
+        },
+        PayLiquidationBounty {
+            vault_id: DefaultVaultId<T>,
+            reporter_id: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        SlashLiquidationReportDeposit {
+            vault_id: DefaultVaultId<T>,
+            reporter_id: T::AccountId,
+            amount: BalanceOf<T>,
+        },
     }
 
     #[pallet::error]
@@ -567,6 +931,32 @@ pub mod pallet {
 
         // Minimum collateral was not found for the given currency
         MinimumCollateralNotSet,
+
+        /// Reputation scores must be bounded around one and decay by at most one per block
+        InvalidReputationParameters,
+        /// The vault has not designated a collateral reserve account
+        NoCollateralReserve,
+        /// The vault is above its secure threshold, or the reserve has no collateral available
+        NoCollateralToTopUp,
+        /// Partially liquidated vaults must remain backed by at least the value of their tokens plus the penalty
+        InvalidPartialLiquidationParameters,
+        /// The auction price may decay by at most the full price per block, and must start above
+        /// the reserve price
+        InvalidAuctionParameters,
+        /// No auction parameters are set for the currency pair
+        CollateralAuctionsDisabled,
+        /// An auction is already running for the currency pair
+        CollateralAuctionAlreadyRunning,
+        /// No auction is running for the currency pair
+        NoCollateralAuction,
+        /// The liquidation vault does not hold enough collateral to auction
+        InsufficientAuctionableCollateral,
+        /// The bid is below the minimum lot size
+        BidBelowMinimumLotSize,
+        /// The price of the collateral exceeds the maximum amount of wrapped tokens to pay
+        AuctionPriceExceedsLimit,
+        /// The liquidation bounty may be at most the liquidated collateral
+        InvalidLiquidationBounty,
     }
 
     /// The minimum collateral (e.g. DOT/KSM) a Vault needs to provide to register.
@@ -633,6 +1023,72 @@ pub mod pallet {
     pub(super) type TotalUserVaultCollateral<T: Config> =
         StorageMap<_, Blake2_128Concat, DefaultVaultCurrencyPair<T>, BalanceOf<T>, ValueQuery>;
 
+    /// Parameters of the reputation score that weighs a vault's share of the vault rewards.
+    /// If not set, rewards are distributed proportional to collateral only.
+    #[pallet::storage]
+    #[pallet::getter(fn reputation_parameters)]
+    pub(super) type VaultReputationParameters<T: Config> = StorageValue<_, DefaultReputationParameters<T>, OptionQuery>;
+
+    /// Track record of each vault, used to compute its reputation score.
+    #[pallet::storage]
+    pub(super) type VaultReputations<T: Config> =
+        StorageMap<_, Blake2_128Concat, DefaultVaultId<T>, DefaultVaultReputation<T>, OptionQuery>;
+
+    /// Account from which collateral is deposited into the vault before it would get liquidated.
+    #[pallet::storage]
+    #[pallet::getter(fn collateral_reserve_account)]
+    pub(super) type CollateralReserveAccount<T: Config> =
+        StorageMap<_, Blake2_128Concat, DefaultVaultId<T>, T::AccountId, OptionQuery>;
+
+    /// Remaining amount of collateral that a reserve account allows to be moved into a vault.
+    #[pallet::storage]
+    #[pallet::getter(fn collateral_reserve_allowance)]
+    pub(super) type CollateralReserveAllowance<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        DefaultVaultId<T>,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Parameters of partial liquidations per currency pair. If not set, undercollateralized
+    /// vaults are liquidated in full.
+    #[pallet::storage]
+    #[pallet::getter(fn partial_liquidation_parameters)]
+    pub(super) type VaultPartialLiquidationParameters<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        DefaultVaultCurrencyPair<T>,
+        DefaultPartialLiquidationParameters<T>,
+        OptionQuery,
+    >;
+
+    /// Parameters of the collateral auctions per currency pair. If not set, liquidated collateral
+    /// can only be redeemed.
+    #[pallet::storage]
+    #[pallet::getter(fn collateral_auction_parameters)]
+    pub(super) type CollateralAuctionParameters<T: Config> =
+        StorageMap<_, Blake2_128Concat, DefaultVaultCurrencyPair<T>, DefaultAuctionParameters<T>, OptionQuery>;
+
+    /// Block height at which the running collateral auction of the liquidation vault started.
+    #[pallet::storage]
+    #[pallet::getter(fn collateral_auction)]
+    pub(super) type CollateralAuctions<T: Config> =
+        StorageMap<_, Blake2_128Concat, DefaultVaultCurrencyPair<T>, BlockNumberFor<T>, OptionQuery>;
+
+    /// Bounty paid to reporters of undercollateralized vaults, per currency pair.
+    #[pallet::storage]
+    #[pallet::getter(fn liquidation_bounty)]
+    pub(super) type LiquidationBounties<T: Config> =
+        StorageMap<_, Blake2_128Concat, DefaultVaultCurrencyPair<T>, DefaultLiquidationBounty<T>, OptionQuery>;
+
+    /// Deposit in the griefing currency that is forfeited on false reports of undercollateralized vaults.
+    #[pallet::storage]
+    #[pallet::getter(fn liquidation_report_deposit)]
+    pub(super) type LiquidationReportDeposit<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
     #[pallet::type_value]
     pub(super) fn DefaultForStorageVersion() -> Version {
         Version::V6
@@ -687,6 +1143,11 @@ impl<T: Config> Pallet<T> {
             let call = Call::report_undercollateralized_vault { vault_id: vault };
             let _ = SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into());
         }
+        for vault in Self::vaults_to_top_up() {
+            log::info!("Topping up vault {:?}", vault);
+            let call = Call::top_up_collateral { vault_id: vault };
+            let _ = SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into());
+        }
     }
 
     /// Public functions
@@ -1275,6 +1736,7 @@ impl<T: Config> Pallet<T> {
                     user_id: redeemer_id.clone(),
                 });
             }
+            Self::record_reputation_event(vault_id, ReputationEvent::Execution)?;
         } else {
             // NOTE: previously we calculated the amount to release based on the Vault's `backing_collateral`
             // but this may now be wrong in the pull-based approach if the Vault is left with excess collateral
@@ -1390,6 +1852,10 @@ impl<T: Config> Pallet<T> {
         old_vault.execute_redeem_tokens(tokens)?;
         new_vault.execute_issue_tokens(tokens)?;
 
+        if !old_vault.data.is_liquidated() {
+            Self::record_reputation_event(old_vault_id, ReputationEvent::Execution)?;
+        }
+
         Self::deposit_event(Event::<T>::ReplaceTokens {
             old_vault_id: old_vault_id.clone(),
             new_vault_id: new_vault_id.clone(),
@@ -1435,6 +1901,9 @@ impl<T: Config> Pallet<T> {
         old_vault.cancel_redeem_tokens(tokens)?;
         new_vault.cancel_issue_tokens(tokens)?;
 
+        if !old_vault.data.is_liquidated() {
+            Self::record_reputation_event(old_vault_id, ReputationEvent::Cancellation)?;
+        }
         Ok(())
     }
 
@@ -1461,6 +1930,9 @@ impl<T: Config> Pallet<T> {
 
     fn undercollateralized_vaults() -> impl Iterator<Item = DefaultVaultId<T>> {
         <Vaults<T>>::iter().filter_map(|(vault_id, vault)| {
+            if ext::oracle::ensure_price_feed_not_paused::<T>(vault_id.collateral_currency()).is_err() {
+                return None;
+            }
             if let Some(liquidation_threshold) = Self::liquidation_collateral_threshold(&vault.id.currencies) {
                 if Self::is_vault_below_liquidation_threshold(&vault, liquidation_threshold).unwrap_or(false) {
                     return Some(vault_id);
@@ -1470,6 +1942,63 @@ impl<T: Config> Pallet<T> {
         })
     }
 
+    fn vaults_to_top_up() -> impl Iterator<Item = DefaultVaultId<T>> {
+        <CollateralReserveAccount<T>>::iter_keys().filter(|vault_id| {
+            // vaults below the liquidation threshold are topped up when reported
+            let below_liquidation_threshold = Self::get_vault_from_id(vault_id)
+                .and_then(|vault| {
+                    let threshold = Self::liquidation_collateral_threshold(&vault_id.currencies)
+                        .ok_or(Error::<T>::ThresholdNotSet)?;
+                    Self::is_vault_below_liquidation_threshold(&vault, threshold)
+                })
+                .unwrap_or(true);
+            !below_liquidation_threshold && Self::get_collateral_top_up(vault_id).is_ok()
+        })
+    }
+
+    /// Get the reserve account of the vault and the amount of collateral that can be moved
+    /// from it, i.e. the collateral missing to reach the secure threshold, capped by the
+    /// reserve's allowance and free balance.
+    ///
+    /// # Arguments
+    /// * `vault_id` - the id of the vault
+    pub fn get_collateral_top_up(vault_id: &DefaultVaultId<T>) -> Result<(T::AccountId, Amount<T>), DispatchError> {
+        let reserve_account_id = Self::collateral_reserve_account(vault_id).ok_or(Error::<T>::NoCollateralReserve)?;
+
+        let required_collateral = Self::get_required_collateral_for_vault(vault_id.clone())?;
+        let missing_collateral = required_collateral.saturating_sub(&Self::get_backing_collateral(vault_id)?)?;
+        let allowance = Amount::new(
+            Self::collateral_reserve_allowance(&reserve_account_id, vault_id),
+            vault_id.collateral_currency(),
+        );
+        let free_balance = ext::currency::get_free_balance::<T>(vault_id.collateral_currency(), &reserve_account_id);
+
+        let amount = missing_collateral.min(&allowance)?.min(&free_balance)?;
+        ensure!(!amount.is_zero(), Error::<T>::NoCollateralToTopUp);
+        Ok((reserve_account_id, amount))
+    }
+
+    fn _top_up_collateral(
+        vault_id: &DefaultVaultId<T>,
+        reserve_account_id: &T::AccountId,
+        amount: &Amount<T>,
+    ) -> DispatchResult {
+        amount.transfer(reserve_account_id, &vault_id.account_id)?;
+        Self::try_deposit_collateral(vault_id, amount)?;
+        CollateralReserveAllowance::<T>::try_mutate(reserve_account_id, vault_id, |allowance| -> DispatchResult {
+            *allowance = allowance
+                .checked_sub(&amount.amount())
+                .ok_or(ArithmeticError::Underflow)?;
+            Ok(())
+        })?;
+        Self::deposit_event(Event::<T>::TopUpCollateral {
+            vault_id: vault_id.clone(),
+            reserve_account_id: reserve_account_id.clone(),
+            amount: amount.amount(),
+        });
+        Ok(())
+    }
+
     /// Liquidates a vault, transferring all of its token balances to the
     /// `LiquidationVault`, as well as the collateral.
     ///
@@ -1496,6 +2025,288 @@ impl<T: Config> Pallet<T> {
         Ok(to_slash)
     }
 
+    /// Get the amount of issued tokens, and the collateral including the penalty, that need to be
+    /// moved to the liquidation vault to restore the vault to its secure threshold. Returns `None`
+    /// if partial liquidations are disabled or the vault needs to be liquidated in full.
+    ///
+    /// # Arguments
+    /// * `vault_id` - the id of the vault to liquidate
+    pub fn get_partial_liquidation(
+        vault_id: &DefaultVaultId<T>,
+    ) -> Result<Option<(Amount<T>, Amount<T>)>, DispatchError> {
+        let parameters = match Self::partial_liquidation_parameters(&vault_id.currencies) {
+            Some(parameters) => parameters,
+            None => return Ok(None),
+        };
+        let vault = Self::get_active_rich_vault_from_id(vault_id)?;
+        let collateral = vault.get_total_collateral()?;
+        let backed_tokens = vault.backed_tokens()?;
+
+        // deeply insolvent vaults are liquidated in full
+        if Self::is_collateral_below_threshold(&collateral, &backed_tokens, parameters.full_liquidation_threshold)? {
+            return Ok(None);
+        }
+
+        // After liquidating tokens worth `x` collateral, the collateralization is
+        //     `(collateral - x * (1 + penalty)) / (backed - x)`
+        // Setting this to the secure threshold gives:
+        //     `x = (backed * secure - collateral) / (secure - (1 + penalty))`
+        let secure_threshold = vault.get_secure_threshold()?;
+        let penalty_factor = UnsignedFixedPoint::<T>::one()
+            .checked_add(&parameters.penalty)
+            .ok_or(ArithmeticError::Overflow)?;
+        let divisor = match secure_threshold.checked_sub(&penalty_factor) {
+            Some(divisor) if !divisor.is_zero() => divisor,
+            _ => return Ok(None),
+        };
+        let required_collateral = Self::get_required_collateral_for_wrapped_with_threshold(
+            &backed_tokens,
+            secure_threshold,
+            vault_id.collateral_currency(),
+        )?;
+        let tokens = required_collateral
+            .saturating_sub(&collateral)?
+            .checked_div(&divisor)?
+            .convert_to(vault_id.wrapped_currency())?;
+
+        // tokens that are being redeemed can not be moved to the liquidation vault
+        let liquidatable_tokens = vault.issued_tokens().checked_sub(&vault.to_be_redeemed_tokens())?;
+        if tokens.is_zero() || tokens.ge(&liquidatable_tokens)? {
+            return Ok(None);
+        }
+
+        let liquidated_collateral = tokens
+            .convert_to(vault_id.collateral_currency())?
+            .checked_mul(&penalty_factor)?
+            .min(&collateral)?;
+        Ok(Some((tokens, liquidated_collateral)))
+    }
+
+    /// Moves part of the vault's issued tokens and collateral to the liquidation vault, without
+    /// liquidating the vault itself.
+    ///
+    /// # Arguments
+    /// * `vault_id` - the id of the vault to liquidate
+    /// * `tokens` - the issued tokens to move
+    /// * `collateral` - the collateral to move, including the penalty
+    pub fn partially_liquidate_vault(
+        vault_id: &DefaultVaultId<T>,
+        tokens: &Amount<T>,
+        collateral: &Amount<T>,
+    ) -> DispatchResult {
+        let mut vault = Self::get_active_rich_vault_from_id(vault_id)?;
+        vault.partially_liquidate(tokens, collateral)?;
+
+        Self::deposit_event(Event::<T>::PartiallyLiquidateVault {
+            vault_id: vault_id.clone(),
+            liquidated_tokens: tokens.amount(),
+            liquidated_collateral: collateral.amount(),
+        });
+        Ok(())
+    }
+
+    /// Get the collateral of the liquidation vault that can be auctioned, i.e. excluding the
+    /// collateral backing tokens that are pending issue.
+    ///
+    /// # Arguments
+    /// * `currency_pair` - the currency pair of the liquidation vault
+    pub fn get_auctionable_collateral(currency_pair: &DefaultVaultCurrencyPair<T>) -> Result<Amount<T>, DispatchError> {
+        let liquidation_vault = Self::get_rich_liquidation_vault(currency_pair);
+        Self::calculate_collateral(
+            &liquidation_vault.collateral(),
+            &liquidation_vault.redeemable_tokens()?,
+            &liquidation_vault.to_be_backed_tokens()?,
+        )
+    }
+
+    /// Get the current price of the running collateral auction, relative to the oracle price.
+    /// The price is zero once it decayed to the reserve price, which ends the auction.
+    ///
+    /// # Arguments
+    /// * `currency_pair` - the currency pair of the liquidation vault
+    pub fn get_collateral_auction_price(
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+    ) -> Result<UnsignedFixedPoint<T>, DispatchError> {
+        let started_at = Self::collateral_auction(currency_pair).ok_or(Error::<T>::NoCollateralAuction)?;
+        let parameters =
+            Self::collateral_auction_parameters(currency_pair).ok_or(Error::<T>::CollateralAuctionsDisabled)?;
+        let elapsed: u32 = ext::security::active_block_number::<T>()
+            .saturating_sub(started_at)
+            .saturated_into();
+
+        let one = UnsignedFixedPoint::<T>::one();
+        let start_price = one.saturating_add(parameters.start_premium);
+        let remaining = match parameters.decay {
+            AuctionDecay::Linear(decay) => {
+                one.saturating_sub(decay.saturating_mul(UnsignedFixedPoint::<T>::saturating_from_integer(elapsed)))
+            }
+            AuctionDecay::Exponential(decay) => one.saturating_sub(decay).saturating_pow(elapsed as usize),
+        };
+        let price = start_price.saturating_mul(remaining);
+        if price <= parameters.reserve_price {
+            return Ok(Zero::zero());
+        }
+        Ok(price)
+    }
+
+    /// Get the amount of wrapped tokens to pay for the given collateral in the running auction.
+    ///
+    /// # Arguments
+    /// * `currency_pair` - the currency pair of the liquidation vault
+    /// * `collateral` - the amount of collateral to buy
+    pub fn get_collateral_auction_cost(
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+        collateral: &Amount<T>,
+    ) -> Result<Amount<T>, DispatchError> {
+        let price = Self::get_collateral_auction_price(currency_pair)?;
+        collateral
+            .convert_to(currency_pair.wrapped)?
+            .checked_rounded_mul(&price, Rounding::Up)
+    }
+
+    fn _bid_collateral_auction(
+        bidder: &T::AccountId,
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+        collateral: &Amount<T>,
+        max_wrapped: &Amount<T>,
+    ) -> DispatchResult {
+        let parameters =
+            Self::collateral_auction_parameters(currency_pair).ok_or(Error::<T>::CollateralAuctionsDisabled)?;
+        let auctionable_collateral = Self::get_auctionable_collateral(currency_pair)?;
+        ensure!(
+            collateral.le(&auctionable_collateral)?,
+            Error::<T>::InsufficientAuctionableCollateral
+        );
+        // smaller bids are only allowed to buy the remainder of the auction
+        ensure!(
+            collateral.ge(&Amount::new(parameters.min_lot_size, currency_pair.collateral))?
+                || collateral.eq(&auctionable_collateral)?,
+            Error::<T>::BidBelowMinimumLotSize
+        );
+
+        let cost = Self::get_collateral_auction_cost(currency_pair, collateral)?;
+        ensure!(!cost.is_zero(), Error::<T>::NoCollateralAuction);
+        ensure!(cost.le(max_wrapped)?, Error::<T>::AuctionPriceExceedsLimit);
+        ensure!(
+            cost.le(&Self::get_rich_liquidation_vault(currency_pair).redeemable_tokens()?)?,
+            Error::<T>::InsufficientTokensCommitted
+        );
+
+        // burn the proceeds to reduce the tokens issued by the liquidation vault
+        cost.lock_on(bidder)?;
+        cost.burn_from(bidder)?;
+        Self::transfer_funds(
+            CurrencySource::LiquidationVault(currency_pair.clone()),
+            CurrencySource::FreeBalance(bidder.clone()),
+            collateral,
+        )?;
+        // need to requery since the liquidation vault gets modified in `transfer_funds`
+        let mut liquidation_vault = Self::get_rich_liquidation_vault(currency_pair);
+        liquidation_vault.burn_issued(&cost)?;
+
+        Self::deposit_event(Event::<T>::BidCollateralAuction {
+            currency_pair: currency_pair.clone(),
+            bidder_id: bidder.clone(),
+            collateral: collateral.amount(),
+            burned_tokens: cost.amount(),
+        });
+
+        if Self::get_auctionable_collateral(currency_pair)?.is_zero() {
+            CollateralAuctions::<T>::remove(currency_pair);
+            Self::deposit_event(Event::<T>::EndCollateralAuction {
+                currency_pair: currency_pair.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Liquidates the vault if it is below the liquidation threshold, unless its collateral
+    /// reserve can prevent the liquidation. Returns the collateral moved to the liquidation
+    /// vault, if any.
+    ///
+    /// # Arguments
+    /// * `vault_id` - the id of the reported vault
+    pub fn _report_undercollateralized_vault(vault_id: &DefaultVaultId<T>) -> Result<Option<Amount<T>>, DispatchError> {
+        let vault = Self::get_vault_from_id(vault_id)?;
+        // don't liquidate on a price that the oracle deviation guard considers unreliable
+        ext::oracle::ensure_price_feed_not_paused::<T>(vault_id.collateral_currency())?;
+        let liquidation_threshold =
+            Self::liquidation_collateral_threshold(&vault_id.currencies).ok_or(Error::<T>::ThresholdNotSet)?;
+        if !Self::is_vault_below_liquidation_threshold(&vault, liquidation_threshold)? {
+            log::info!("Not liquidating; vault not below liquidation threshold");
+            return Err(Error::<T>::VaultNotBelowLiquidationThreshold.into());
+        }
+
+        // only draw from the reserve if doing so prevents the liquidation
+        if let Ok((reserve_account_id, amount)) = Self::get_collateral_top_up(vault_id) {
+            let collateral = Self::get_backing_collateral(vault_id)?.checked_add(&amount)?;
+            let issued_tokens = Amount::new(vault.issued_tokens, vault_id.wrapped_currency());
+            if !Self::is_collateral_below_threshold(&collateral, &issued_tokens, liquidation_threshold)? {
+                Self::_top_up_collateral(vault_id, &reserve_account_id, &amount)?;
+                return Ok(None);
+            }
+        }
+
+        let liquidated_collateral = match Self::get_partial_liquidation(vault_id)? {
+            Some((tokens, collateral)) => {
+                Self::partially_liquidate_vault(vault_id, &tokens, &collateral)?;
+                collateral
+            }
+            None => Self::liquidate_vault(vault_id)?,
+        };
+        Ok(Some(liquidated_collateral))
+    }
+
+    fn _report_undercollateralized_vault_for_bounty(
+        reporter_id: &T::AccountId,
+        vault_id: &DefaultVaultId<T>,
+    ) -> DispatchResult {
+        // reporters racing to report the same vault only lose the transaction fee
+        Self::get_active_vault_from_id(vault_id)?;
+
+        let deposit = Amount::new(
+            Self::liquidation_report_deposit(),
+            T::GetGriefingCollateralCurrencyId::get(),
+        );
+        deposit.lock_on(reporter_id)?;
+
+        let liquidated_collateral = match Self::_report_undercollateralized_vault(vault_id) {
+            Ok(liquidated_collateral) => liquidated_collateral,
+            Err(err) if err == Error::<T>::VaultNotBelowLiquidationThreshold.into() => {
+                deposit.burn_from(reporter_id)?;
+                Self::deposit_event(Event::<T>::SlashLiquidationReportDeposit {
+                    vault_id: vault_id.clone(),
+                    reporter_id: reporter_id.clone(),
+                    amount: deposit.amount(),
+                });
+                return Ok(());
+            }
+            Err(err) => return Err(err),
+        };
+        deposit.unlock_on(reporter_id)?;
+
+        let bounty = match (Self::liquidation_bounty(&vault_id.currencies), liquidated_collateral) {
+            (Some(bounty), Some(liquidated_collateral)) => liquidated_collateral
+                .checked_mul(&bounty.rate)?
+                .min(&Amount::new(bounty.cap, vault_id.collateral_currency()))?
+                .min(&Self::get_rich_liquidation_vault(&vault_id.currencies).collateral())?,
+            _ => return Ok(()),
+        };
+        if !bounty.is_zero() {
+            Self::transfer_funds(
+                CurrencySource::LiquidationVault(vault_id.currencies.clone()),
+                CurrencySource::FreeBalance(reporter_id.clone()),
+                &bounty,
+            )?;
+            Self::deposit_event(Event::<T>::PayLiquidationBounty {
+                vault_id: vault_id.clone(),
+                reporter_id: reporter_id.clone(),
+                amount: bounty.amount(),
+            });
+        }
+        Ok(())
+    }
+
     pub fn try_increase_total_backing_collateral(
         currency_pair: &DefaultVaultCurrencyPair<T>,
         amount: &Amount<T>,
@@ -1544,7 +2355,7 @@ impl<T: Config> Pallet<T> {
             vault_id: vault.id(),
             banned_until,
         });
-        Ok(())
+        Self::record_reputation_event(vault_id, ReputationEvent::Ban)
     }
 
     pub fn ensure_not_banned(vault_id: &DefaultVaultId<T>) -> DispatchResult {
@@ -1552,6 +2363,109 @@ impl<T: Config> Pallet<T> {
         vault.ensure_not_banned()
     }
 
+    /// Get the track record of the vault, or a neutral one if nothing was recorded yet
+    pub fn get_vault_reputation(vault_id: &DefaultVaultId<T>) -> DefaultVaultReputation<T> {
+        VaultReputations::<T>::get(vault_id).unwrap_or_else(|| DefaultVaultReputation::<T> {
+            score: UnsignedFixedPoint::<T>::one(),
+            last_updated: ext::security::active_block_number::<T>(),
+            ..Default::default()
+        })
+    }
+
+    /// Moves the reputation score towards one, by `decay_per_block` for every block
+    /// since it was last updated.
+    fn decay_reputation_score(
+        reputation: &DefaultVaultReputation<T>,
+        parameters: &DefaultReputationParameters<T>,
+    ) -> UnsignedFixedPoint<T> {
+        let one = UnsignedFixedPoint::<T>::one();
+        let elapsed: u32 = ext::security::active_block_number::<T>()
+            .saturating_sub(reputation.last_updated)
+            .unique_saturated_into();
+        let retained = one
+            .saturating_sub(parameters.decay_per_block)
+            .saturating_pow(elapsed as usize);
+
+        if reputation.score >= one {
+            one.saturating_add(reputation.score.saturating_sub(one).saturating_mul(retained))
+        } else {
+            one.saturating_sub(one.saturating_sub(reputation.score).saturating_mul(retained))
+        }
+    }
+
+    /// Get the factor by which the reward stake of the vault is multiplied. This is one if
+    /// no reputation parameters are set.
+    pub fn get_reputation_multiplier(vault_id: &DefaultVaultId<T>) -> UnsignedFixedPoint<T> {
+        match Self::reputation_parameters() {
+            Some(parameters) => {
+                let reputation = Self::get_vault_reputation(vault_id);
+                Self::decay_reputation_score(&reputation, &parameters).clamp(parameters.min_score, parameters.max_score)
+            }
+            None => UnsignedFixedPoint::<T>::one(),
+        }
+    }
+
+    /// Stores the decayed reputation score of the vault, such that the reward stake does not
+    /// keep a stale multiplier, and returns the multiplier.
+    pub(crate) fn apply_reputation_decay(vault_id: &DefaultVaultId<T>) -> UnsignedFixedPoint<T> {
+        let multiplier = Self::get_reputation_multiplier(vault_id);
+        if Self::reputation_parameters().is_some() {
+            VaultReputations::<T>::mutate(vault_id, |reputation| {
+                if let Some(reputation) = reputation {
+                    reputation.score = multiplier;
+                    reputation.last_updated = ext::security::active_block_number::<T>();
+                }
+            });
+        }
+        multiplier
+    }
+
+    /// Records an action of the vault in its track record and adjusts its reputation score.
+    /// The reward stake of the vault is updated accordingly.
+    ///
+    /// # Arguments
+    /// * `vault_id` - the id of the vault
+    /// * `event` - the action of the vault
+    pub fn record_reputation_event(vault_id: &DefaultVaultId<T>, event: ReputationEvent) -> DispatchResult {
+        let mut reputation = Self::get_vault_reputation(vault_id);
+        let parameters = Self::reputation_parameters();
+
+        match event {
+            ReputationEvent::Execution => {
+                reputation.successful_executions = reputation.successful_executions.saturating_add(1)
+            }
+            ReputationEvent::Cancellation => reputation.cancellations = reputation.cancellations.saturating_add(1),
+            ReputationEvent::Punishment => reputation.punishments = reputation.punishments.saturating_add(1),
+            ReputationEvent::Ban => reputation.bans = reputation.bans.saturating_add(1),
+        }
+
+        if let Some(ref parameters) = parameters {
+            let score = Self::decay_reputation_score(&reputation, parameters);
+            reputation.score = match event {
+                ReputationEvent::Execution => score.saturating_add(parameters.execution_reward),
+                ReputationEvent::Cancellation => score.saturating_sub(parameters.cancellation_penalty),
+                ReputationEvent::Punishment => score.saturating_sub(parameters.punishment_penalty),
+                ReputationEvent::Ban => score.saturating_sub(parameters.ban_penalty),
+            }
+            .clamp(parameters.min_score, parameters.max_score);
+        }
+        reputation.last_updated = ext::security::active_block_number::<T>();
+
+        let score = reputation.score;
+        VaultReputations::<T>::insert(vault_id, reputation);
+        Self::deposit_event(Event::<T>::UpdateVaultReputation {
+            vault_id: vault_id.clone(),
+            event,
+            score,
+        });
+
+        // liquidated vaults have no reward stake
+        if parameters.is_some() && !Self::is_vault_liquidated(vault_id)? {
+            PoolManager::<T>::on_vault_settings_change(vault_id)?;
+        }
+        Ok(())
+    }
+
     /// Threshold checks
     pub fn is_vault_below_secure_threshold(vault_id: &DefaultVaultId<T>) -> Result<bool, DispatchError> {
         let vault = Self::get_rich_vault_from_id(&vault_id)?;
@@ -2053,6 +2967,8 @@ impl<T: Config> Pallet<T> {
                     .unwrap()
                     .checked_div(&rich_vault.get_secure_threshold().unwrap())
                     .unwrap()
+                    .checked_mul(&Self::get_reputation_multiplier(&vault_id))
+                    .unwrap()
             };
 
             assert_eq!(ext::reward::get_stake::<T>(&vault_id).unwrap(), expected_stake.amount());
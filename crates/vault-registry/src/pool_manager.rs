@@ -66,8 +66,11 @@ impl<T: Config> PoolManager<T> {
         } else {
             let total_collateral = ext::staking::total_current_stake::<T>(vault_id)?;
             let secure_threshold = Pallet::<T>::get_vault_secure_threshold(vault_id)?;
+            let reputation_multiplier = Pallet::<T>::apply_reputation_decay(vault_id);
 
-            total_collateral.checked_div(&secure_threshold)?
+            total_collateral
+                .checked_div(&secure_threshold)?
+                .checked_mul(&reputation_multiplier)?
         };
 
         ext::reward::set_stake(vault_id, &new_reward_stake)?;
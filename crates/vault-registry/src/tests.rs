@@ -1443,3 +1443,638 @@ fn test_offchain_worker_unsigned_transaction_submission() {
         );
     })
 }
+
+mod reputation_tests {
+    use super::{assert_eq, *};
+    use crate::{types::DefaultReputationParameters, Error, ReputationEvent};
+    use reward::RewardsApi;
+    use sp_runtime::{traits::Zero, DispatchError::BadOrigin};
+
+    fn rational(n: u128, d: u128) -> UnsignedFixedPoint {
+        UnsignedFixedPoint::checked_from_rational(n, d).unwrap()
+    }
+
+    fn default_parameters() -> DefaultReputationParameters<Test> {
+        DefaultReputationParameters::<Test> {
+            execution_reward: rational(1, 2),
+            cancellation_penalty: rational(1, 10),
+            punishment_penalty: rational(2, 10),
+            ban_penalty: rational(9, 10),
+            decay_per_block: UnsignedFixedPoint::zero(),
+            min_score: rational(1, 2),
+            max_score: rational(2, 1),
+        }
+    }
+
+    fn set_parameters(parameters: DefaultReputationParameters<Test>) {
+        assert_ok!(VaultRegistry::set_reputation_parameters(
+            RuntimeOrigin::root(),
+            Some(parameters)
+        ));
+    }
+
+    fn reward_stake(id: &DefaultVaultId<Test>) -> Balance {
+        VaultRewards::get_stake(&id.collateral_currency(), id).unwrap()
+    }
+
+    #[test]
+    fn reputation_is_tracked_without_affecting_rewards_if_parameters_are_not_set() {
+        run_test(|| {
+            let id = create_vault(vault_id(4));
+
+            assert_ok!(VaultRegistry::record_reputation_event(&id, ReputationEvent::Execution));
+            assert_ok!(VaultRegistry::record_reputation_event(
+                &id,
+                ReputationEvent::Cancellation
+            ));
+            assert_ok!(VaultRegistry::record_reputation_event(&id, ReputationEvent::Punishment));
+
+            let reputation = VaultRegistry::get_vault_reputation(&id);
+            assert_eq!(reputation.successful_executions, 1);
+            assert_eq!(reputation.cancellations, 1);
+            assert_eq!(reputation.punishments, 1);
+            assert_eq!(reputation.bans, 0);
+            assert_eq!(reputation.score, UnsignedFixedPoint::one());
+            assert_eq!(VaultRegistry::get_reputation_multiplier(&id), UnsignedFixedPoint::one());
+        })
+    }
+
+    #[test]
+    fn set_reputation_parameters_fails_with_invalid_parameters() {
+        run_test(|| {
+            assert_noop!(
+                VaultRegistry::set_reputation_parameters(RuntimeOrigin::signed(1), Some(default_parameters())),
+                BadOrigin
+            );
+            assert_noop!(
+                VaultRegistry::set_reputation_parameters(
+                    RuntimeOrigin::root(),
+                    Some(DefaultReputationParameters::<Test> {
+                        min_score: rational(3, 2),
+                        ..default_parameters()
+                    })
+                ),
+                Error::<Test>::InvalidReputationParameters
+            );
+            assert_noop!(
+                VaultRegistry::set_reputation_parameters(
+                    RuntimeOrigin::root(),
+                    Some(DefaultReputationParameters::<Test> {
+                        max_score: rational(1, 2),
+                        ..default_parameters()
+                    })
+                ),
+                Error::<Test>::InvalidReputationParameters
+            );
+            assert_noop!(
+                VaultRegistry::set_reputation_parameters(
+                    RuntimeOrigin::root(),
+                    Some(DefaultReputationParameters::<Test> {
+                        decay_per_block: rational(3, 2),
+                        ..default_parameters()
+                    })
+                ),
+                Error::<Test>::InvalidReputationParameters
+            );
+        })
+    }
+
+    #[test]
+    fn reputation_score_is_bounded() {
+        run_test(|| {
+            let id = create_vault(vault_id(4));
+            set_parameters(default_parameters());
+
+            for _ in 0..3 {
+                assert_ok!(VaultRegistry::record_reputation_event(&id, ReputationEvent::Execution));
+            }
+            assert_eq!(VaultRegistry::get_reputation_multiplier(&id), rational(2, 1));
+
+            assert_ok!(VaultRegistry::record_reputation_event(&id, ReputationEvent::Ban));
+            assert_eq!(VaultRegistry::get_reputation_multiplier(&id), rational(11, 10));
+
+            assert_ok!(VaultRegistry::record_reputation_event(&id, ReputationEvent::Ban));
+            assert_eq!(VaultRegistry::get_reputation_multiplier(&id), rational(1, 2));
+            assert_emitted!(Event::UpdateVaultReputation {
+                vault_id: id,
+                event: ReputationEvent::Ban,
+                score: rational(1, 2),
+            });
+        })
+    }
+
+    #[test]
+    fn reputation_score_decays_towards_one() {
+        run_test(|| {
+            let id = create_vault(vault_id(4));
+            set_parameters(DefaultReputationParameters::<Test> {
+                decay_per_block: rational(1, 2),
+                ..default_parameters()
+            });
+
+            assert_ok!(VaultRegistry::record_reputation_event(&id, ReputationEvent::Execution));
+            assert_eq!(VaultRegistry::get_reputation_multiplier(&id), rational(3, 2));
+
+            Security::<Test>::set_active_block_number(3);
+            assert_eq!(VaultRegistry::get_reputation_multiplier(&id), rational(9, 8));
+
+            // the penalty applies to the decayed score
+            assert_ok!(VaultRegistry::record_reputation_event(&id, ReputationEvent::Punishment));
+            assert_eq!(VaultRegistry::get_reputation_multiplier(&id), rational(37, 40));
+
+            Security::<Test>::set_active_block_number(4);
+            assert_eq!(VaultRegistry::get_reputation_multiplier(&id), rational(77, 80));
+        })
+    }
+
+    #[test]
+    fn reward_stake_is_weighted_by_reputation() {
+        run_test(|| {
+            let id = vault_id(4);
+            create_vault_with_collateral(&id, 100);
+            // collateral / secure threshold
+            assert_eq!(reward_stake(&id), 50);
+
+            set_parameters(default_parameters());
+            assert_ok!(VaultRegistry::record_reputation_event(&id, ReputationEvent::Execution));
+            assert_eq!(reward_stake(&id), 75);
+
+            set_parameters(DefaultReputationParameters::<Test> {
+                decay_per_block: rational(1, 2),
+                ..default_parameters()
+            });
+            Security::<Test>::set_active_block_number(2);
+            assert_ok!(VaultRegistry::refresh_vault_reputation(
+                RuntimeOrigin::signed(1),
+                id.clone()
+            ));
+            assert_eq!(reward_stake(&id), 62);
+            // updating the reward stake stores the decayed score
+            let reputation = VaultRegistry::get_vault_reputation(&id);
+            assert_eq!(reputation.score, rational(5, 4));
+            assert_eq!(reputation.last_updated, 2);
+        })
+    }
+}
+
+mod collateral_reserve_tests {
+    use super::{assert_eq, *};
+    use crate::{CollateralReserveAllowance, Error};
+
+    const RESERVE: AccountId = 5;
+
+    fn set_exchange_rate(rate: u128) {
+        <oracle::Pallet<Test>>::_set_exchange_rate(DEFAULT_COLLATERAL_CURRENCY, UnsignedFixedPoint::from(rate))
+            .unwrap();
+    }
+
+    fn setup_reserve(allowance: u128) -> DefaultVaultId<Test> {
+        // 200% collateralized at an exchange rate of 10
+        let id = create_sample_vault_and_issue_tokens(DEFAULT_COLLATERAL / 20);
+        assert_ok!(VaultRegistry::set_collateral_reserve(
+            RuntimeOrigin::signed(id.account_id),
+            id.currencies.clone(),
+            Some(RESERVE)
+        ));
+        assert_ok!(VaultRegistry::set_collateral_reserve_allowance(
+            RuntimeOrigin::signed(RESERVE),
+            id.clone(),
+            allowance
+        ));
+        id
+    }
+
+    fn free_balance(account_id: AccountId) -> u128 {
+        crate::ext::currency::get_free_balance::<Test>(DEFAULT_COLLATERAL_CURRENCY, &account_id).amount()
+    }
+
+    #[test]
+    fn should_top_up_collateral_up_to_allowance() {
+        run_test(|| {
+            let id = setup_reserve(30_000);
+            assert_noop!(
+                VaultRegistry::top_up_collateral(RuntimeOrigin::signed(1), id.clone()),
+                Error::<Test>::NoCollateralToTopUp
+            );
+
+            // 150_000 collateral required at the secure threshold
+            set_exchange_rate(15);
+            assert_ok!(VaultRegistry::top_up_collateral(RuntimeOrigin::signed(1), id.clone()));
+            assert_emitted!(Event::TopUpCollateral {
+                vault_id: id.clone(),
+                reserve_account_id: RESERVE,
+                amount: 30_000,
+            });
+            assert_eq!(VaultRegistry::get_backing_collateral(&id).unwrap(), amount(130_000));
+            assert_eq!(free_balance(RESERVE), RICH_COLLATERAL - 30_000);
+            assert_eq!(CollateralReserveAllowance::<Test>::get(RESERVE, &id), 0);
+
+            assert_noop!(
+                VaultRegistry::top_up_collateral(RuntimeOrigin::signed(1), id.clone()),
+                Error::<Test>::NoCollateralToTopUp
+            );
+        })
+    }
+
+    #[test]
+    fn should_top_up_collateral_instead_of_liquidating() {
+        run_test(|| {
+            let id = setup_reserve(100_000);
+
+            // 100% collateralized, i.e. below the liquidation threshold
+            set_exchange_rate(20);
+            assert_ok!(VaultRegistry::report_undercollateralized_vault(
+                RuntimeOrigin::signed(1),
+                id.clone()
+            ));
+            assert!(!VaultRegistry::is_vault_liquidated(&id).unwrap());
+            assert_eq!(VaultRegistry::get_backing_collateral(&id).unwrap(), amount(200_000));
+            assert_eq!(free_balance(RESERVE), RICH_COLLATERAL - 100_000);
+        })
+    }
+
+    #[test]
+    fn should_liquidate_if_top_up_is_insufficient() {
+        run_test(|| {
+            let id = setup_reserve(5_000);
+
+            set_exchange_rate(20);
+            assert_ok!(VaultRegistry::report_undercollateralized_vault(
+                RuntimeOrigin::signed(1),
+                id.clone()
+            ));
+            assert!(VaultRegistry::is_vault_liquidated(&id).unwrap());
+            // the reserve is not drawn from in vain
+            assert_eq!(free_balance(RESERVE), RICH_COLLATERAL);
+            assert_eq!(CollateralReserveAllowance::<Test>::get(RESERVE, &id), 5_000);
+        })
+    }
+
+    #[test]
+    fn should_submit_top_up_from_offchain_worker() {
+        let mut externalities = crate::mock::ExtBuilder::build();
+        let (pool, pool_state) = TestTransactionPoolExt::new();
+        externalities.register_extension(TransactionPoolExt::new(pool));
+
+        externalities.execute_with(|| {
+            System::set_block_number(1);
+            Security::<Test>::set_active_block_number(1);
+            set_default_thresholds();
+            let id = setup_reserve(100_000);
+            set_exchange_rate(15);
+
+            VaultRegistry::_offchain_worker();
+
+            let tx = pool_state.write().transactions.pop().unwrap();
+            assert!(pool_state.read().transactions.is_empty());
+            let tx = Extrinsic::decode(&mut &*tx).unwrap();
+            assert_eq!(tx.signature, None);
+            assert_eq!(
+                tx.call,
+                crate::mock::RuntimeCall::VaultRegistry(crate::Call::top_up_collateral { vault_id: id })
+            );
+        })
+    }
+}
+
+mod partial_liquidation_tests {
+    use super::{assert_eq, *};
+    use crate::{types::DefaultPartialLiquidationParameters, Error};
+
+    fn rational(n: u128, d: u128) -> UnsignedFixedPoint {
+        UnsignedFixedPoint::checked_from_rational(n, d).unwrap()
+    }
+
+    fn setup() -> DefaultVaultId<Test> {
+        // 200% collateralized at an exchange rate of 10
+        let id = create_sample_vault_and_issue_tokens(DEFAULT_COLLATERAL / 20);
+        VaultRegistry::_set_liquidation_collateral_threshold(DEFAULT_CURRENCY_PAIR, rational(150, 100));
+        assert_ok!(VaultRegistry::set_partial_liquidation_parameters(
+            RuntimeOrigin::root(),
+            DEFAULT_CURRENCY_PAIR,
+            Some(DefaultPartialLiquidationParameters::<Test> {
+                penalty: rational(25, 100),
+                full_liquidation_threshold: rational(125, 100),
+            })
+        ));
+        id
+    }
+
+    #[test]
+    fn should_reject_invalid_parameters() {
+        run_test(|| {
+            assert_noop!(
+                VaultRegistry::set_partial_liquidation_parameters(
+                    RuntimeOrigin::root(),
+                    DEFAULT_CURRENCY_PAIR,
+                    Some(DefaultPartialLiquidationParameters::<Test> {
+                        penalty: rational(25, 100),
+                        full_liquidation_threshold: rational(120, 100),
+                    })
+                ),
+                Error::<Test>::InvalidPartialLiquidationParameters
+            );
+        })
+    }
+
+    #[test]
+    fn should_partially_liquidate_to_secure_threshold() {
+        run_test(|| {
+            let id = setup();
+
+            // ~133% collateralized
+            <oracle::Pallet<Test>>::_set_exchange_rate(id.collateral_currency(), UnsignedFixedPoint::from(15)).unwrap();
+            let (tokens, collateral) = VaultRegistry::get_partial_liquidation(&id).unwrap().unwrap();
+            assert_ok!(VaultRegistry::report_undercollateralized_vault(
+                RuntimeOrigin::signed(1),
+                id.clone()
+            ));
+            assert_emitted!(Event::PartiallyLiquidateVault {
+                vault_id: id.clone(),
+                liquidated_tokens: tokens.amount(),
+                liquidated_collateral: collateral.amount(),
+            });
+
+            assert!(!VaultRegistry::is_vault_liquidated(&id).unwrap());
+            let vault = VaultRegistry::get_vault_from_id(&id).unwrap();
+            assert_eq!(vault.issued_tokens, DEFAULT_COLLATERAL / 20 - tokens.amount());
+            let liquidation_vault = VaultRegistry::get_rich_liquidation_vault(&DEFAULT_CURRENCY_PAIR);
+            assert_eq!(liquidation_vault.data.issued_tokens, tokens.amount());
+            assert_eq!(liquidation_vault.data.collateral, collateral.amount());
+
+            // the vault is back at the secure threshold, up to rounding
+            let collateralization = VaultRegistry::get_collateralization_from_vault(id, false).unwrap();
+            assert!(collateralization > rational(199, 100) && collateralization < rational(201, 100));
+        })
+    }
+
+    #[test]
+    fn should_fully_liquidate_deeply_insolvent_vault() {
+        run_test(|| {
+            let id = setup();
+
+            // ~118% collateralized
+            <oracle::Pallet<Test>>::_set_exchange_rate(id.collateral_currency(), UnsignedFixedPoint::from(17)).unwrap();
+            assert_eq!(VaultRegistry::get_partial_liquidation(&id), Ok(None));
+            assert_ok!(VaultRegistry::report_undercollateralized_vault(
+                RuntimeOrigin::signed(1),
+                id.clone()
+            ));
+            assert!(VaultRegistry::is_vault_liquidated(&id).unwrap());
+        })
+    }
+}
+
+mod collateral_auction_tests {
+    use super::{assert_eq, *};
+    use crate::{AuctionDecay, AuctionParameters, Error};
+    use frame_support::dispatch::DispatchResult;
+    use sp_runtime::traits::Zero;
+
+    const BIDDER: AccountId = 1;
+
+    fn setup(decay: AuctionDecay<UnsignedFixedPoint>, reserve_price: UnsignedFixedPoint) {
+        // 5_000 tokens at an exchange rate of 10, liquidated at 110%
+        let id = create_sample_vault_and_issue_tokens(DEFAULT_COLLATERAL / 20);
+        assert_ok!(VaultRegistry::liquidate_vault(&id));
+        assert_eq!(
+            VaultRegistry::get_auctionable_collateral(&DEFAULT_CURRENCY_PAIR),
+            Ok(amount(55_000))
+        );
+
+        assert_ok!(VaultRegistry::set_collateral_auction_parameters(
+            RuntimeOrigin::root(),
+            DEFAULT_CURRENCY_PAIR,
+            Some(AuctionParameters {
+                start_premium: UnsignedFixedPoint::checked_from_rational(10, 100).unwrap(),
+                decay,
+                min_lot_size: 1_000,
+                reserve_price,
+            })
+        ));
+        assert_ok!(VaultRegistry::start_collateral_auction(
+            RuntimeOrigin::signed(BIDDER),
+            DEFAULT_CURRENCY_PAIR
+        ));
+        wrapped(2_000).mint_to(&BIDDER).unwrap();
+    }
+
+    fn linear(n: u128, d: u128) -> AuctionDecay<UnsignedFixedPoint> {
+        AuctionDecay::Linear(UnsignedFixedPoint::checked_from_rational(n, d).unwrap())
+    }
+
+    fn bid(collateral: u128, max_wrapped: u128) -> DispatchResult {
+        VaultRegistry::bid_collateral_auction(
+            RuntimeOrigin::signed(BIDDER),
+            DEFAULT_CURRENCY_PAIR,
+            collateral,
+            max_wrapped,
+        )
+    }
+
+    #[test]
+    fn should_sell_collateral_at_decaying_price() {
+        run_test(|| {
+            setup(linear(1, 100), UnsignedFixedPoint::zero());
+            assert_eq!(
+                VaultRegistry::get_collateral_auction_cost(&DEFAULT_CURRENCY_PAIR, &amount(11_000)),
+                Ok(wrapped(1_210))
+            );
+
+            Security::<Test>::set_active_block_number(11);
+            assert_eq!(
+                VaultRegistry::get_collateral_auction_cost(&DEFAULT_CURRENCY_PAIR, &amount(11_000)),
+                Ok(wrapped(1_089))
+            );
+            assert_ok!(bid(11_000, 1_089));
+            assert_emitted!(Event::BidCollateralAuction {
+                currency_pair: DEFAULT_CURRENCY_PAIR,
+                bidder_id: BIDDER,
+                collateral: 11_000,
+                burned_tokens: 1_089,
+            });
+
+            let free_balance =
+                |currency_id| crate::ext::currency::get_free_balance::<Test>(currency_id, &BIDDER).amount();
+            assert_eq!(free_balance(DEFAULT_COLLATERAL_CURRENCY), 11_000);
+            assert_eq!(free_balance(DEFAULT_WRAPPED_CURRENCY), 2_000 - 1_089);
+
+            let liquidation_vault = VaultRegistry::get_rich_liquidation_vault(&DEFAULT_CURRENCY_PAIR);
+            assert_eq!(liquidation_vault.data.issued_tokens, 5_000 - 1_089);
+            assert_eq!(liquidation_vault.data.collateral, 55_000 - 11_000);
+        })
+    }
+
+    #[test]
+    fn should_reject_invalid_bids() {
+        run_test(|| {
+            setup(linear(1, 100), UnsignedFixedPoint::zero());
+            assert_noop!(bid(500, 2_000), Error::<Test>::BidBelowMinimumLotSize);
+            assert_noop!(bid(11_000, 1_000), Error::<Test>::AuctionPriceExceedsLimit);
+            assert_noop!(bid(60_000, 2_000), Error::<Test>::InsufficientAuctionableCollateral);
+            assert_noop!(
+                VaultRegistry::start_collateral_auction(RuntimeOrigin::signed(BIDDER), DEFAULT_CURRENCY_PAIR),
+                Error::<Test>::CollateralAuctionAlreadyRunning
+            );
+        })
+    }
+
+    #[test]
+    fn should_restart_expired_auction() {
+        run_test(|| {
+            setup(linear(1, 10), UnsignedFixedPoint::zero());
+
+            Security::<Test>::set_active_block_number(11);
+            assert_eq!(
+                VaultRegistry::get_collateral_auction_price(&DEFAULT_CURRENCY_PAIR),
+                Ok(UnsignedFixedPoint::zero())
+            );
+            assert_noop!(bid(11_000, 2_000), Error::<Test>::NoCollateralAuction);
+
+            assert_ok!(VaultRegistry::start_collateral_auction(
+                RuntimeOrigin::signed(BIDDER),
+                DEFAULT_CURRENCY_PAIR
+            ));
+            assert_eq!(
+                VaultRegistry::get_collateral_auction_cost(&DEFAULT_CURRENCY_PAIR, &amount(11_000)),
+                Ok(wrapped(1_210))
+            );
+        })
+    }
+
+    #[test]
+    fn should_end_auction_at_reserve_price() {
+        run_test(|| {
+            // no collateral is sold below the oracle price
+            setup(linear(1, 100), UnsignedFixedPoint::one());
+
+            Security::<Test>::set_active_block_number(10);
+            assert_eq!(
+                VaultRegistry::get_collateral_auction_cost(&DEFAULT_CURRENCY_PAIR, &amount(11_000)),
+                Ok(wrapped(1_102))
+            );
+
+            Security::<Test>::set_active_block_number(11);
+            assert_eq!(
+                VaultRegistry::get_collateral_auction_price(&DEFAULT_CURRENCY_PAIR),
+                Ok(UnsignedFixedPoint::zero())
+            );
+            assert_noop!(bid(11_000, 2_000), Error::<Test>::NoCollateralAuction);
+
+            assert_ok!(VaultRegistry::start_collateral_auction(
+                RuntimeOrigin::signed(BIDDER),
+                DEFAULT_CURRENCY_PAIR
+            ));
+            assert_emitted!(Event::EndCollateralAuction {
+                currency_pair: DEFAULT_CURRENCY_PAIR,
+            });
+            assert_eq!(
+                VaultRegistry::get_collateral_auction_cost(&DEFAULT_CURRENCY_PAIR, &amount(11_000)),
+                Ok(wrapped(1_210))
+            );
+        })
+    }
+
+    #[test]
+    fn should_reject_reserve_price_above_start_price() {
+        run_test(|| {
+            assert_noop!(
+                VaultRegistry::set_collateral_auction_parameters(
+                    RuntimeOrigin::root(),
+                    DEFAULT_CURRENCY_PAIR,
+                    Some(AuctionParameters {
+                        start_premium: UnsignedFixedPoint::checked_from_rational(10, 100).unwrap(),
+                        decay: linear(1, 100),
+                        min_lot_size: 1_000,
+                        reserve_price: UnsignedFixedPoint::checked_from_rational(110, 100).unwrap(),
+                    })
+                ),
+                Error::<Test>::InvalidAuctionParameters
+            );
+        })
+    }
+}
+
+mod liquidation_bounty_tests {
+    use super::{assert_eq, *};
+    use crate::{Error, LiquidationBounty};
+
+    const REPORTER: AccountId = 1;
+
+    fn setup() -> DefaultVaultId<Test> {
+        // 200% collateralized at an exchange rate of 10
+        let id = create_sample_vault_and_issue_tokens(DEFAULT_COLLATERAL / 20);
+        assert_ok!(VaultRegistry::set_liquidation_bounty(
+            RuntimeOrigin::root(),
+            DEFAULT_CURRENCY_PAIR,
+            Some(LiquidationBounty {
+                rate: UnsignedFixedPoint::checked_from_rational(5, 100).unwrap(),
+                cap: 3_000,
+            })
+        ));
+        assert_ok!(VaultRegistry::set_liquidation_report_deposit(
+            RuntimeOrigin::root(),
+            1_000
+        ));
+        griefing(1_000).mint_to(&REPORTER).unwrap();
+        id
+    }
+
+    fn free_balance(currency_id: CurrencyId) -> u128 {
+        crate::ext::currency::get_free_balance::<Test>(currency_id, &REPORTER).amount()
+    }
+
+    #[test]
+    fn should_pay_capped_bounty_to_reporter() {
+        run_test(|| {
+            let id = setup();
+
+            // 100% collateralized, i.e. below the liquidation threshold
+            <oracle::Pallet<Test>>::_set_exchange_rate(id.collateral_currency(), UnsignedFixedPoint::from(20)).unwrap();
+            assert_ok!(VaultRegistry::report_undercollateralized_vault_for_bounty(
+                RuntimeOrigin::signed(REPORTER),
+                id.clone()
+            ));
+            assert!(VaultRegistry::is_vault_liquidated(&id).unwrap());
+            assert_emitted!(Event::PayLiquidationBounty {
+                vault_id: id.clone(),
+                reporter_id: REPORTER,
+                amount: 3_000,
+            });
+            assert_eq!(free_balance(DEFAULT_COLLATERAL_CURRENCY), 3_000);
+            assert_eq!(free_balance(DEFAULT_NATIVE_CURRENCY), 1_000);
+
+            let liquidation_vault = VaultRegistry::get_rich_liquidation_vault(&DEFAULT_CURRENCY_PAIR);
+            assert_eq!(liquidation_vault.data.collateral, DEFAULT_COLLATERAL - 3_000);
+
+            // later reporters only lose the transaction fee
+            assert_noop!(
+                VaultRegistry::report_undercollateralized_vault_for_bounty(RuntimeOrigin::signed(REPORTER), id),
+                Error::<Test>::VaultLiquidated
+            );
+        })
+    }
+
+    #[test]
+    fn should_slash_deposit_of_false_report() {
+        run_test(|| {
+            let id = setup();
+
+            assert_ok!(VaultRegistry::report_undercollateralized_vault_for_bounty(
+                RuntimeOrigin::signed(REPORTER),
+                id.clone()
+            ));
+            assert!(!VaultRegistry::is_vault_liquidated(&id).unwrap());
+            assert_emitted!(Event::SlashLiquidationReportDeposit {
+                vault_id: id,
+                reporter_id: REPORTER,
+                amount: 1_000,
+            });
+            assert_eq!(free_balance(DEFAULT_NATIVE_CURRENCY), 0);
+            assert_eq!(
+                crate::ext::currency::get_reserved_balance::<Test>(DEFAULT_NATIVE_CURRENCY, &REPORTER).amount(),
+                0
+            );
+        })
+    }
+}
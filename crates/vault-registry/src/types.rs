@@ -5,6 +5,7 @@ use frame_support::{
     dispatch::{DispatchError, DispatchResult},
     ensure,
     traits::Get,
+    RuntimeDebug,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 pub use primitives::{VaultCurrencyPair, VaultId};
@@ -186,6 +187,96 @@ pub struct SystemVault<Balance, CurrencyId: Copy> {
     pub currency_pair: VaultCurrencyPair<CurrencyId>,
 }
 
+/// Track record of a vault, used to weigh its share of the vault rewards.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct VaultReputation<BlockNumber, UnsignedFixedPoint> {
+    /// Number of successfully executed redeem and replace requests
+    pub successful_executions: u32,
+    /// Number of redeem and replace requests that were cancelled
+    pub cancellations: u32,
+    /// Number of times the vault's collateral was slashed as punishment
+    pub punishments: u32,
+    /// Number of times the vault was banned
+    pub bans: u32,
+    /// Reward multiplier at `last_updated`, decays towards one over time
+    pub score: UnsignedFixedPoint,
+    /// Block height at which the score was last updated
+    pub last_updated: BlockNumber,
+}
+
+/// Actions of a vault that affect its reputation score.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum ReputationEvent {
+    /// A redeem or replace request was executed by the vault
+    Execution,
+    /// A redeem or replace request of the vault was cancelled
+    Cancellation,
+    /// Collateral of the vault was slashed as punishment
+    Punishment,
+    /// The vault was banned
+    Ban,
+}
+
+/// Governance-set parameters of the vault reputation score.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ReputationParameters<UnsignedFixedPoint> {
+    /// Score increase for every successful execution
+    pub execution_reward: UnsignedFixedPoint,
+    /// Score decrease for every cancellation
+    pub cancellation_penalty: UnsignedFixedPoint,
+    /// Score decrease for every punishment
+    pub punishment_penalty: UnsignedFixedPoint,
+    /// Score decrease for every ban
+    pub ban_penalty: UnsignedFixedPoint,
+    /// Fraction by which the distance between the score and one shrinks every block
+    pub decay_per_block: UnsignedFixedPoint,
+    /// Lower bound of the score
+    pub min_score: UnsignedFixedPoint,
+    /// Upper bound of the score
+    pub max_score: UnsignedFixedPoint,
+}
+
+/// Governance-set parameters of partial vault liquidations.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PartialLiquidationParameters<UnsignedFixedPoint> {
+    /// Collateral slashed on top of the value of the liquidated tokens, as a share of that value
+    pub penalty: UnsignedFixedPoint,
+    /// Collateralization below which the vault is liquidated in full
+    pub full_liquidation_threshold: UnsignedFixedPoint,
+}
+
+/// Shape of the price decay of collateral auctions.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum AuctionDecay<UnsignedFixedPoint> {
+    /// The price drops by the given fraction of the start price every block
+    Linear(UnsignedFixedPoint),
+    /// The price drops by the given fraction of the current price every block
+    Exponential(UnsignedFixedPoint),
+}
+
+/// Governance-set parameters of the Dutch auctions selling liquidated collateral.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AuctionParameters<UnsignedFixedPoint, Balance> {
+    /// Premium over the oracle price at which an auction starts
+    pub start_premium: UnsignedFixedPoint,
+    /// Decay of the price over the course of the auction
+    pub decay: AuctionDecay<UnsignedFixedPoint>,
+    /// Minimum amount of collateral per bid, unless the remainder of the auction is bought
+    pub min_lot_size: Balance,
+    /// Price relative to the oracle price below which no collateral is sold. The auction ends
+    /// once its price decays to the reserve price, after which it may be restarted.
+    pub reserve_price: UnsignedFixedPoint,
+}
+
+/// Governance-set reward for reporting an undercollateralized vault.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct LiquidationBounty<UnsignedFixedPoint, Balance> {
+    /// Share of the liquidated collateral paid to the reporter
+    pub rate: UnsignedFixedPoint,
+    /// Maximum amount of collateral paid to the reporter
+    pub cap: Balance,
+}
+
 impl<
         AccountId: Ord,
         BlockNumber: Default,
@@ -232,6 +323,16 @@ pub type DefaultVault<T> = Vault<
 
 pub type DefaultSystemVault<T> = SystemVault<BalanceOf<T>, CurrencyId<T>>;
 
+pub type DefaultVaultReputation<T> = VaultReputation<BlockNumberFor<T>, UnsignedFixedPoint<T>>;
+
+pub type DefaultReputationParameters<T> = ReputationParameters<UnsignedFixedPoint<T>>;
+
+pub type DefaultPartialLiquidationParameters<T> = PartialLiquidationParameters<UnsignedFixedPoint<T>>;
+
+pub type DefaultAuctionParameters<T> = AuctionParameters<UnsignedFixedPoint<T>, BalanceOf<T>>;
+
+pub type DefaultLiquidationBounty<T> = LiquidationBounty<UnsignedFixedPoint<T>, BalanceOf<T>>;
+
 #[cfg_attr(any(test, feature = "integration-tests"), visibility::make(pub))]
 trait UpdatableVault<T: Config> {
     fn increase_issued(&mut self, tokens: &Amount<T>) -> DispatchResult;
@@ -638,6 +739,24 @@ impl<T: Config> RichVault<T> {
         Ok(liquidated_collateral_excluding_to_be_redeemed)
     }
 
+    /// Moves `tokens` of the issued tokens, together with `collateral`, to the liquidation vault.
+    /// Unlike `liquidate`, the vault remains active.
+    pub(crate) fn partially_liquidate(&mut self, tokens: &Amount<T>, collateral: &Amount<T>) -> DispatchResult {
+        let vault_id = self.id();
+
+        // Clear `to_be_replaced` tokens, since they may no longer be backed by the remaining issued tokens.
+        let _ = Pallet::<T>::withdraw_replace_request(&self.data.id, &self.to_be_replaced_tokens())?;
+        // the vault struct was modified in the call above - we need to re-fetch,
+        // otherwise changes get overwritten below
+        *self = Pallet::<T>::get_rich_vault_from_id(&vault_id)?;
+
+        self.slash_to_liquidation_vault(collateral)?;
+
+        let mut liquidation_vault = Pallet::<T>::get_rich_liquidation_vault(&self.data.id.currencies);
+        liquidation_vault.increase_issued(tokens)?;
+        self.decrease_issued(tokens)
+    }
+
     pub fn ensure_not_banned(&self) -> DispatchResult {
         if self.is_banned() {
             Err(Error::<T>::VaultBanned.into())
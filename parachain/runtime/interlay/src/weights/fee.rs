@@ -166,4 +166,15 @@ impl<T: frame_system::Config> fee::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Fee InsuranceFundCut (r:0 w:1)
+	/// Proof: Fee InsuranceFundCut (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn set_insurance_fund_cut	() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked yet. Regenerate with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_116_000 picoseconds.
+		Weight::from_parts(8_367_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
@@ -354,4 +354,86 @@ impl<T: frame_system::Config> nomination::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(60_u64))
 			.saturating_add(T::DbWeight::get().writes(34_u64))
 	}
+	/// Storage: Nomination InsurancePayoutCap (r:0 w:1)
+	/// Proof: Nomination InsurancePayoutCap (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn set_insurance_payout_cap	() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked yet. Regenerate with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_712_000 picoseconds.
+		Weight::from_parts(9_104_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Security ParachainStatus (r:1 w:0)
+	/// Proof: Security ParachainStatus (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Nonce (r:1 w:0)
+	/// Proof: VaultStaking Nonce (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Stake (r:1 w:0)
+	/// Proof: VaultStaking Stake (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: VaultStaking SlashPerToken (r:1 w:0)
+	/// Proof: VaultStaking SlashPerToken (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: VaultStaking SlashTally (r:1 w:0)
+	/// Proof: VaultStaking SlashTally (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: VaultStaking SlashedStake (r:1 w:0)
+	/// Proof: VaultStaking SlashedStake (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: Nomination CompensatedSlash (r:1 w:1)
+	/// Proof: Nomination CompensatedSlash (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:2 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Nomination InsurancePayoutCap (r:1 w:0)
+	/// Proof: Nomination InsurancePayoutCap (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Nomination InsurancePayouts (r:1 w:1)
+	/// Proof: Nomination InsurancePayouts (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_slashing_insurance	() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked yet. Regenerate with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `1874`
+		//  Estimated: `8168`
+		// Minimum execution time: 118_402_000 picoseconds.
+		Weight::from_parts(120_337_000, 8168)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Nomination AutoCompounds (r:1 w:1)
+	/// Proof: Nomination AutoCompounds (max_values: Some(1), max_size: Some(12401), added: 12896, mode: MaxEncodedLen)
+	fn set_auto_compound	() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked yet. Regenerate with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `2871`
+		//  Estimated: `13886`
+		// Minimum execution time: 21_334_000 picoseconds.
+		Weight::from_parts(22_046_000, 13886)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nomination AutoCompounds (r:1 w:0)
+	/// Proof: Nomination AutoCompounds (max_values: Some(1), max_size: Some(12401), added: 12896, mode: MaxEncodedLen)
+	/// Storage: Fee VaultRewards (r:4 w:0)
+	/// Proof: Fee VaultRewards (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Nonce (r:1 w:0)
+	/// Proof: VaultStaking Nonce (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Stake (r:1 w:1)
+	/// Proof: VaultStaking Stake (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: VaultStaking RewardTally (r:2 w:2)
+	/// Proof: VaultStaking RewardTally (max_values: None, max_size: Some(150), added: 2625, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:4 w:4)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	fn on_initialize	(n: u32, ) -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked yet. Regenerate with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `2871`
+		//  Estimated: `13886`
+		// Minimum execution time: 6_812_000 picoseconds.
+		Weight::from_parts(7_094_000, 13886)
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(187_436_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((24_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((16_u64).saturating_mul(n.into())))
+	}
 }
\ No newline at end of file
@@ -902,6 +902,11 @@ impl annuity::Config<VaultAnnuityInstance> for Runtime {
     type WeightInfo = weights::annuity_vault_annuity::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+    pub const RewardCheckpointInterval: BlockNumber = DAYS;
+    pub const MaxRewardCheckpoints: u32 = 90;
+}
+
 pub type EscrowRewardsInstance = reward::Instance1;
 
 impl reward::Config<EscrowRewardsInstance> for Runtime {
@@ -911,6 +916,8 @@ impl reward::Config<EscrowRewardsInstance> for Runtime {
     type StakeId = AccountId;
     type CurrencyId = CurrencyId;
     type MaxRewardCurrencies = ConstU32<1>;
+    type CheckpointInterval = RewardCheckpointInterval;
+    type MaxCheckpoints = MaxRewardCheckpoints;
 }
 
 pub type VaultRewardsInstance = reward::Instance2;
@@ -922,6 +929,8 @@ impl reward::Config<VaultRewardsInstance> for Runtime {
     type StakeId = VaultId;
     type CurrencyId = CurrencyId;
     type MaxRewardCurrencies = ConstU32<2>;
+    type CheckpointInterval = RewardCheckpointInterval;
+    type MaxCheckpoints = MaxRewardCheckpoints;
 }
 
 pub type VaultCapacityInstance = reward::Instance3;
@@ -933,6 +942,8 @@ impl reward::Config<VaultCapacityInstance> for Runtime {
     type StakeId = CurrencyId;
     type CurrencyId = CurrencyId;
     type MaxRewardCurrencies = ConstU32<2>;
+    type CheckpointInterval = ();
+    type MaxCheckpoints = ();
 }
 
 type FarmingRewardsInstance = reward::Instance4;
@@ -944,6 +955,8 @@ impl reward::Config<FarmingRewardsInstance> for Runtime {
     type StakeId = AccountId;
     type CurrencyId = CurrencyId;
     type MaxRewardCurrencies = ConstU32<10>;
+    type CheckpointInterval = RewardCheckpointInterval;
+    type MaxCheckpoints = MaxRewardCheckpoints;
 }
 
 parameter_types! {
@@ -1121,8 +1134,13 @@ pub type OracleName = oracle::NameOf<Runtime>;
 impl oracle::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type OnExchangeRateChange = (vault_registry::PoolManager<Runtime>, Loans);
+    type DexPriceCumulative = DexGeneral;
+    type DexStableLpPrice = DexStable;
+    type DexPairReserves = DexGeneral;
+    type TreasuryAccountId = TreasuryAccount;
     type WeightInfo = weights::oracle::WeightInfo<Runtime>;
     type MaxNameLength = ConstU32<255>;
+    type MaxPriceHistoryLength = ConstU32<100>;
 }
 
 parameter_types! {
@@ -1167,9 +1185,17 @@ impl replace::Config for Runtime {
 
 pub use nomination::Event as NominationEvent;
 
+parameter_types! {
+    pub const AutoCompoundPeriod: BlockNumber = DAYS;
+}
+
 impl nomination::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = weights::nomination::WeightInfo<Runtime>;
+    type Swap = DexSwapRouter;
+    type AutoCompoundPeriod = AutoCompoundPeriod;
+    type MaxAutoCompounds = ConstU32<100>;
+    type VaultSlashing = VaultStaking;
 }
 
 impl clients_info::Config for Runtime {
@@ -1205,6 +1231,10 @@ impl loans::Config for Runtime {
     type RewardAssetId = GetNativeCurrencyId;
     type ReferenceAssetId = GetWrappedCurrencyId;
     type OnExchangeRateChange = vault_registry::PoolManager<Runtime>;
+    type RuntimeCall = RuntimeCall;
+    type Swap = DexSwapRouter;
+    type StableLiquidity = DexStable;
+    type PriceFeeds = Oracle;
 }
 
 construct_runtime! {
@@ -1283,7 +1313,7 @@ construct_runtime! {
         UnknownTokens: orml_unknown_tokens::{Pallet, Storage, Event} = 95,
 
         // # Lending & AMM
-        Loans: loans::{Pallet, Call, Storage, Event<T>, Config<T>} = 100,
+        Loans: loans::{Pallet, Call, Storage, Event<T>, Config<T>, ValidateUnsigned} = 100,
         DexGeneral: dex_general::{Pallet, Call, Storage, Event<T>} = 101,
         DexStable: dex_stable::{Pallet, Call, Storage, Event<T>}  = 102,
         DexSwapRouter: dex_swap_router::{Pallet, Call, Event<T>} = 103,
@@ -1648,7 +1678,10 @@ impl_runtime_apis! {
     impl oracle_rpc_runtime_api::OracleApi<
         Block,
         Balance,
-        CurrencyId
+        CurrencyId,
+        AccountId,
+        BlockNumber,
+        oracle::PriceRecordOf<Runtime>,
     > for Runtime {
         fn wrapped_to_collateral( amount: BalanceWrapper<Balance>, currency_id: CurrencyId) -> Result<BalanceWrapper<Balance>, DispatchError> {
             let result = Oracle::wrapped_to_collateral(amount.amount, currency_id)?;
@@ -1659,6 +1692,18 @@ impl_runtime_apis! {
             let result = Oracle::collateral_to_wrapped(amount.amount, currency_id)?;
             Ok(BalanceWrapper{amount:result})
         }
+
+        fn get_price_history(key: oracle::OracleKey, count: u32) -> Vec<oracle::PriceRecordOf<Runtime>> {
+            Oracle::get_price_history(key, count)
+        }
+
+        fn get_price_at(key: oracle::OracleKey, block_number: BlockNumber) -> Option<oracle::PriceRecordOf<Runtime>> {
+            Oracle::get_price_at(key, block_number)
+        }
+
+        fn get_stale_oracles(key: oracle::OracleKey, timestamp: Moment) -> Vec<AccountId> {
+            Oracle::get_stale_oracles(key, timestamp)
+        }
     }
 
     impl vault_registry_rpc_runtime_api::VaultRegistryApi<
@@ -1717,6 +1762,19 @@ impl_runtime_apis! {
             let result = VaultRegistry::get_required_collateral_for_vault(vault_id)?;
             Ok(BalanceWrapper{amount:result.amount()})
         }
+
+        fn get_auctionable_collateral(currency_id: CurrencyId) -> Result<BalanceWrapper<Balance>, DispatchError> {
+            let currency_pair = primitives::VaultCurrencyPair { collateral: currency_id, wrapped: GetWrappedCurrencyId::get() };
+            let result = VaultRegistry::get_auctionable_collateral(&currency_pair)?;
+            Ok(BalanceWrapper{amount:result.amount()})
+        }
+
+        fn get_collateral_auction_cost(amount: BalanceWrapper<Balance>, currency_id: CurrencyId) -> Result<BalanceWrapper<Balance>, DispatchError> {
+            let currency_pair = primitives::VaultCurrencyPair { collateral: currency_id, wrapped: GetWrappedCurrencyId::get() };
+            let collateral = Amount::new(amount.amount, currency_id);
+            let result = VaultRegistry::get_collateral_auction_cost(&currency_pair, &collateral)?;
+            Ok(BalanceWrapper{amount:result.amount()})
+        }
     }
 
     impl escrow_rpc_runtime_api::EscrowApi<
@@ -1765,6 +1823,30 @@ impl_runtime_apis! {
             Ok(balance)
         }
 
+        fn compute_escrow_reward_between(account_id: AccountId, currency_id: CurrencyId, from: BlockNumber, to: BlockNumber) -> Result<BalanceWrapper<Balance>, DispatchError> {
+            let amount = EscrowRewards::compute_reward_between(&(), &account_id, currency_id, from, to)?
+                .try_into()
+                .map_err(|_| reward::Error::<Runtime, EscrowRewardsInstance>::TryIntoIntError)?;
+            let balance = BalanceWrapper::<Balance> { amount };
+            Ok(balance)
+        }
+
+        fn compute_farming_reward_between(account_id: AccountId, pool_currency_id: CurrencyId, reward_currency_id: CurrencyId, from: BlockNumber, to: BlockNumber) -> Result<BalanceWrapper<Balance>, DispatchError> {
+            let amount = FarmingRewards::compute_reward_between(&pool_currency_id, &account_id, reward_currency_id, from, to)?
+                .try_into()
+                .map_err(|_| reward::Error::<Runtime, FarmingRewardsInstance>::TryIntoIntError)?;
+            let balance = BalanceWrapper::<Balance> { amount };
+            Ok(balance)
+        }
+
+        fn compute_vault_reward_between(vault_id: VaultId, currency_id: CurrencyId, from: BlockNumber, to: BlockNumber) -> Result<BalanceWrapper<Balance>, DispatchError> {
+            let amount = VaultRewards::compute_reward_between(&vault_id.collateral_currency(), &vault_id, currency_id, from, to)?
+                .try_into()
+                .map_err(|_| reward::Error::<Runtime, VaultRewardsInstance>::TryIntoIntError)?;
+            let balance = BalanceWrapper::<Balance> { amount };
+            Ok(balance)
+        }
+
         fn estimate_escrow_reward_rate(
             account_id: AccountId,
             amount: Option<Balance>,
@@ -1863,6 +1945,10 @@ impl_runtime_apis! {
             Loans::get_account_liquidation_threshold_liquidity(&account)
             .and_then(|liquidity| liquidity.to_rpc_tuple())
         }
+
+        fn get_account_position(account: AccountId) -> Result<primitives::AccountPosition<Balance>, DispatchError> {
+            Loans::get_account_position(&account)
+        }
     }
 
     impl dex_general_rpc_runtime_api::DexGeneralApi<Block, AccountId, CurrencyId> for Runtime {
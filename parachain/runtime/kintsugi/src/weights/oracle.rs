@@ -112,4 +112,195 @@ impl<T: frame_system::Config> oracle::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(21_332_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Oracle DeviationGuards (r:0 w:1)
+	/// Proof Skipped: Oracle DeviationGuards (max_values: None, max_size: None, mode: Measured)
+	fn set_deviation_guard	() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked yet. Regenerate with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_512_000 picoseconds.
+		Weight::from_parts(15_103_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Oracle PendingAggregate (r:0 w:1)
+	/// Proof Skipped: Oracle PendingAggregate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle DeviationViolations (r:0 w:1)
+	/// Proof Skipped: Oracle DeviationViolations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle PausedPriceFeeds (r:0 w:1)
+	/// Proof Skipped: Oracle PausedPriceFeeds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle Aggregate (r:0 w:1)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn resume_price_feed	() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked yet. Regenerate with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 22_874_000 picoseconds.
+		Weight::from_parts(23_619_000, 0)
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Oracle TwapObservations (r:0 w:1)
+	/// Proof Skipped: Oracle TwapObservations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle TwapValues (r:0 w:1)
+	/// Proof Skipped: Oracle TwapValues (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle TwapSources (r:0 w:1)
+	/// Proof Skipped: Oracle TwapSources (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle RawValuesUpdated (r:0 w:1)
+	/// Proof Skipped: Oracle RawValuesUpdated (max_values: None, max_size: None, mode: Measured)
+	fn set_twap_source	() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked yet. Regenerate with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 19_208_000 picoseconds.
+		Weight::from_parts(19_874_000, 0)
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Oracle BondedOracleParameters (r:0 w:1)
+	/// Proof Skipped: Oracle BondedOracleParameters (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_bonded_oracle_parameters	() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked yet. Regenerate with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 13_927_000 picoseconds.
+		Weight::from_parts(14_419_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Oracle BondedOracleParameters (r:1 w:0)
+	/// Proof Skipped: Oracle BondedOracleParameters (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Oracle AuthorizedOracles (r:1 w:1)
+	/// Proof Skipped: Oracle AuthorizedOracles (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Oracle OracleBonds (r:0 w:1)
+	/// Proof Skipped: Oracle OracleBonds (max_values: None, max_size: None, mode: Measured)
+	fn bond_oracle	() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked yet. Regenerate with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `1156`
+		//  Estimated: `10446`
+		// Minimum execution time: 46_213_000 picoseconds.
+		Weight::from_parts(47_382_000, 10446)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Oracle OracleBonds (r:1 w:1)
+	/// Proof Skipped: Oracle OracleBonds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Oracle AuthorizedOracles (r:0 w:1)
+	/// Proof Skipped: Oracle AuthorizedOracles (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle OutlierSubmissions (r:0 w:1)
+	/// Proof Skipped: Oracle OutlierSubmissions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle MissedRounds (r:0 w:1)
+	/// Proof Skipped: Oracle MissedRounds (max_values: None, max_size: None, mode: Measured)
+	fn unbond_oracle	() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked yet. Regenerate with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `1287`
+		//  Estimated: `8337`
+		// Minimum execution time: 42_591_000 picoseconds.
+		Weight::from_parts(43_706_000, 8337)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Oracle MinimumQuorums (r:0 w:1)
+	/// Proof Skipped: Oracle MinimumQuorums (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle RawValuesUpdated (r:0 w:1)
+	/// Proof Skipped: Oracle RawValuesUpdated (max_values: None, max_size: None, mode: Measured)
+	fn set_minimum_quorum	() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked yet. Regenerate with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_617_000 picoseconds.
+		Weight::from_parts(15_102_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Oracle KeyMaxDelays (r:0 w:1)
+	/// Proof Skipped: Oracle KeyMaxDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle RawValuesUpdated (r:0 w:1)
+	/// Proof Skipped: Oracle RawValuesUpdated (max_values: None, max_size: None, mode: Measured)
+	fn set_key_max_delay	() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked yet. Regenerate with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_351_000 picoseconds.
+		Weight::from_parts(14_893_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Oracle Commitments (r:0 w:1)
+	/// Proof Skipped: Oracle Commitments (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle CommitReveal (r:0 w:1)
+	/// Proof Skipped: Oracle CommitReveal (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_commit_reveal_period	() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked yet. Regenerate with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 15_204_000 picoseconds.
+		Weight::from_parts(15_869_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Oracle AuthorizedOracles (r:1 w:0)
+	/// Proof Skipped: Oracle AuthorizedOracles (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Number (r:1 w:0)
+	/// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Oracle CommitReveal (r:1 w:0)
+	/// Proof Skipped: Oracle CommitReveal (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Oracle Commitments (r:0 w:1)
+	/// Proof Skipped: Oracle Commitments (max_values: None, max_size: None, mode: Measured)
+	fn commit_values	() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked yet. Regenerate with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `311`
+		//  Estimated: `3776`
+		// Minimum execution time: 27_412_000 picoseconds.
+		Weight::from_parts(28_305_000, 3776)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Oracle AuthorizedOracles (r:1 w:0)
+	/// Proof Skipped: Oracle AuthorizedOracles (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Number (r:1 w:0)
+	/// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Oracle CommitReveal (r:1 w:0)
+	/// Proof Skipped: Oracle CommitReveal (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Oracle Commitments (r:1 w:1)
+	/// Proof Skipped: Oracle Commitments (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Oracle RawValuesUpdated (r:0 w:1000)
+	/// Proof Skipped: Oracle RawValuesUpdated (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle RoundSubmitters (r:0 w:1000)
+	/// Proof Skipped: Oracle RoundSubmitters (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle RawValues (r:0 w:1000)
+	/// Proof Skipped: Oracle RawValues (max_values: None, max_size: None, mode: Measured)
+	fn reveal_values	(u: u32, ) -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked yet. Regenerate with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `402`
+		//  Estimated: `3867`
+		// Minimum execution time: 41_873_000 picoseconds.
+		Weight::from_parts(43_510_000, 3867)
+			// Standard Error: 11_204
+			.saturating_add(Weight::from_parts(6_281_944, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(u.into())))
+	}
+	/// Storage: Oracle PricingPaths (r:0 w:1)
+	/// Proof: Oracle PricingPaths (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	fn set_pricing_path	() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked yet. Regenerate with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 13_862_000 picoseconds.
+		Weight::from_parts(14_377_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
@@ -93,8 +93,14 @@ pub trait RuntimeApiCollection:
     + pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
     + cumulus_primitives_core::CollectCollationInfo<Block>
     + btc_relay_rpc_runtime_api::BtcRelayApi<Block, H256Le>
-    + oracle_rpc_runtime_api::OracleApi<Block, Balance, CurrencyId>
-    + vault_registry_rpc_runtime_api::VaultRegistryApi<
+    + oracle_rpc_runtime_api::OracleApi<
+        Block,
+        Balance,
+        CurrencyId,
+        AccountId,
+        BlockNumber,
+        oracle::PriceRecord<UnsignedFixedPoint, Moment, BlockNumber>,
+    > + vault_registry_rpc_runtime_api::VaultRegistryApi<
         Block,
         VaultId<AccountId, CurrencyId>,
         Balance,
@@ -143,8 +149,14 @@ where
         + pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
         + cumulus_primitives_core::CollectCollationInfo<Block>
         + btc_relay_rpc_runtime_api::BtcRelayApi<Block, H256Le>
-        + oracle_rpc_runtime_api::OracleApi<Block, Balance, CurrencyId>
-        + vault_registry_rpc_runtime_api::VaultRegistryApi<
+        + oracle_rpc_runtime_api::OracleApi<
+            Block,
+            Balance,
+            CurrencyId,
+            AccountId,
+            BlockNumber,
+            oracle::PriceRecord<UnsignedFixedPoint, Moment, BlockNumber>,
+        > + vault_registry_rpc_runtime_api::VaultRegistryApi<
             Block,
             VaultId<AccountId, CurrencyId>,
             Balance,
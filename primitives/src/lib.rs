@@ -351,6 +351,23 @@ pub mod oracle {
     pub enum Key {
         ExchangeRate(CurrencyId),
         FeeEstimation,
+        /// Planck of the first currency per planck of the second, used to price a currency
+        /// through an intermediate currency such as a USD stablecoin
+        CrossRate(CurrencyId, CurrencyId),
+    }
+
+    /// An aggregate recorded in the price history of a key
+    #[derive(Serialize, Deserialize, Encode, Decode, Clone, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PriceRecord<UnsignedFixedPoint, Moment, BlockNumber> {
+        /// the aggregate
+        pub value: UnsignedFixedPoint,
+        /// the time at which the aggregate was set
+        pub timestamp: Moment,
+        /// the block in which the aggregate was set
+        pub block_number: BlockNumber,
+        /// the number of values the aggregate was computed from
+        pub oracle_count: u32,
     }
 }
 
@@ -418,6 +435,50 @@ mod loans {
     pub type Shortfall = FixedU128;
     pub type Liquidity = FixedU128;
     pub const SECONDS_PER_YEAR: Timestamp = 365 * 24 * 60 * 60;
+
+    /// Position of an account in a market, as returned by the runtime API.
+    #[derive(Serialize, Deserialize, Encode, Decode, Clone, Eq, PartialEq, Debug, TypeInfo)]
+    #[serde(rename_all = "camelCase")]
+    pub struct MarketPosition<Balance> {
+        /// Underlying currency of the market
+        pub currency_id: CurrencyId,
+        /// Underlying amount of the lend tokens of the account
+        pub supplied: Balance,
+        /// Lend tokens of the account, including those deposited as collateral
+        pub lend_tokens: Balance,
+        /// Whether the lend tokens of the account are deposited as collateral
+        pub is_collateral: bool,
+        /// Borrow balance of the account, including accrued interest
+        pub borrowed: Balance,
+    }
+
+    /// Fixed-term borrow of an account, as returned by the runtime API.
+    #[derive(Serialize, Deserialize, Encode, Decode, Clone, Eq, PartialEq, Debug, TypeInfo)]
+    #[serde(rename_all = "camelCase")]
+    pub struct FixedTermPosition<Balance> {
+        /// Borrowed currency
+        pub currency_id: CurrencyId,
+        /// Maturity of the bucket the account borrowed from
+        pub maturity: Timestamp,
+        /// Amount required to repay the borrow now, including interest and penalties
+        pub borrowed: Balance,
+    }
+
+    /// Positions and health of an account, as returned by the runtime API.
+    #[derive(Serialize, Deserialize, Encode, Decode, Clone, Eq, PartialEq, Debug, TypeInfo)]
+    #[serde(rename_all = "camelCase")]
+    pub struct AccountPosition<Balance> {
+        /// Positions in the markets the account supplied to or borrowed from
+        pub markets: Vec<MarketPosition<Balance>>,
+        /// Fixed-term borrows of the account, which count towards its health factor
+        pub fixed_term_borrows: Vec<FixedTermPosition<Balance>>,
+        /// Ratio of the liquidation threshold value of the collateral to the value of the debt,
+        /// `None` if the account has no debt
+        pub health_factor: Option<Rate>,
+        /// Relative drop of all collateral prices, against the prices of the borrowed assets, at
+        /// which the account can be liquidated. `None` if the account has no debt.
+        pub liquidation_price_drop: Option<Ratio>,
+    }
 }
 
 pub trait CurrencyInfo {
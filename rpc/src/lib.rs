@@ -4,8 +4,8 @@
 //! capabilities that are specific to this project's runtime configuration.
 
 use primitives::{
-    issue::IssueRequest, redeem::RedeemRequest, replace::ReplaceRequest, AccountId, Balance, Block, BlockNumber,
-    CurrencyId, H256Le, Hash, Nonce, StablePoolId, VaultId,
+    issue::IssueRequest, oracle::PriceRecord, redeem::RedeemRequest, replace::ReplaceRequest, AccountId, Balance,
+    Block, BlockNumber, CurrencyId, H256Le, Hash, Moment, Nonce, StablePoolId, VaultId,
 };
 use sc_consensus_manual_seal::rpc::{EngineCommand, ManualSeal, ManualSealApiServer};
 pub use sc_rpc_api::DenyUnsafe;
@@ -74,7 +74,14 @@ where
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: btc_relay_rpc::BtcRelayRuntimeApi<Block, H256Le>,
-    C::Api: oracle_rpc::OracleRuntimeApi<Block, Balance, CurrencyId>,
+    C::Api: oracle_rpc::OracleRuntimeApi<
+        Block,
+        Balance,
+        CurrencyId,
+        AccountId,
+        BlockNumber,
+        PriceRecord<FixedU128, Moment, BlockNumber>,
+    >,
     C::Api: vault_registry_rpc::VaultRegistryRuntimeApi<
         Block,
         VaultId<AccountId, CurrencyId>,